@@ -11,6 +11,7 @@ pub enum Action {
     ChangeCardPriorityToMedium,
     ChangeCardPriorityToLow,
     ClearAllToasts,
+    CompareSaves,
     Delete,
     DeleteBoard,
     Down,
@@ -35,6 +36,7 @@ pub enum Action {
     SaveState,
     StopUserInput,
     TakeUserInput,
+    ToggleChecklistItem,
     ToggleCommandPalette,
     Undo,
     Up,
@@ -56,6 +58,7 @@ impl Display for Action {
             Action::ChangeCardPriorityToMedium => "Change card priority to medium",
             Action::ChangeCardPriorityToLow => "Change card priority to low",
             Action::ClearAllToasts => "Clear all toasts",
+            Action::CompareSaves => "Compare saves",
             Action::Delete => "Delete focused element",
             Action::DeleteBoard => "Delete Board",
             Action::Down => "Go down",
@@ -80,6 +83,7 @@ impl Display for Action {
             Action::SaveState => "Save Kanban state",
             Action::StopUserInput => "Stop input mode",
             Action::TakeUserInput => "Enter input mode",
+            Action::ToggleChecklistItem => "Toggle checklist item",
             Action::ToggleCommandPalette => "Open command palette",
             Action::Undo => "Undo",
             Action::Up => "Go up",
@@ -1,39 +1,50 @@
 use crate::{
     app::{
         actions::Action,
+        board_template::BoardTemplate,
+        card_filter::CardFilter,
+        card_template::CardTemplate,
         handle_exit,
-        kanban::{Board, Boards, Card, CardPriority, CardStatus, Cards},
+        kanban::{
+            ArchivedCard, Board, BoardSortMode, Boards, Card, CardPriority, CardRecurrence,
+            CardStatus, Cards, ChecklistItem, Comment,
+        },
+        save_diff::SaveDiffChange,
         state::{AppState, AppStatus, Focus, KeyBindings, PathCheckState},
         ActionHistory, App, AppConfig, AppReturn, ConfigEnum, DateTimeFormat, MainMenuItem,
         VisibleBoardsAndCards,
     },
-    constants::{
-        DEFAULT_TOAST_DURATION, FIELD_NOT_SET, IO_EVENT_WAIT_TIME, MOUSE_OUT_OF_BOUNDS_COORDINATES,
-    },
+    constants::{DEFAULT_TOAST_DURATION, IO_EVENT_WAIT_TIME, MOUSE_OUT_OF_BOUNDS_COORDINATES},
     error::NavigationError,
     inputs::{key::Key, mouse::Mouse},
     io::{
-        data_handler::{get_config, save_theme, write_config},
+        data_handler::{
+            get_available_local_save_files, get_config, get_saved_board_templates,
+            import_boards_from_file, save_board_template, save_card_template, save_theme,
+            write_config,
+        },
+        exchange::merge_imported_boards,
         io_handler::refresh_visible_boards_and_cards,
+        sync::merge::ConflictResolution,
         IoEvent,
     },
     ui::{
         text_box::TextBox,
         theme::{Theme, ThemeEnum},
         widgets::{
-            command_palette::CommandPaletteWidget,
+            command_palette::{start_blocker_search, CommandPaletteWidget},
             toast::{Toast, ToastType},
         },
         PopUp, TextColorOptions, TextModifierOptions, View,
     },
     util::{
-        date_format_converter, date_format_finder, get_first_next_focus_keybinding,
-        get_first_prv_focus_keybinding, parse_hex_to_rgb, send_error_toast, send_info_toast,
+        format_date_time, get_first_next_focus_keybinding, get_first_prv_focus_keybinding,
+        naive_local_to_utc, parse_hex_to_rgb, send_error_toast, send_info_toast,
         send_warning_toast, send_warning_toast_with_duration, update_current_board_and_card,
-        update_current_visible_boards_and_cards,
+        update_current_visible_boards_and_cards, utc_to_naive_local,
     },
 };
-use chrono::NaiveDateTime;
+use chrono::Utc;
 use linked_hash_map::LinkedHashMap;
 use ratatui::{style::Color, widgets::ListState};
 use std::{fs, path::Path, str::FromStr, time::Duration};
@@ -515,6 +526,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
         match app.state.focus {
             Focus::NewBoardName => app.state.text_buffers.board_name.reset(),
             Focus::NewBoardDescription => app.state.text_buffers.board_description.reset(),
+            Focus::NewBoardWipLimit => app.state.text_buffers.board_wip_limit.reset(),
             Focus::CardName => app.state.text_buffers.card_name.reset(),
             Focus::CardDescription => app.state.text_buffers.card_description.reset(),
             Focus::EmailIDField => app.state.text_buffers.email_id.reset(),
@@ -569,14 +581,22 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         app.close_popup();
                     }
                 }
-                PopUp::CardStatusSelector => {
+                PopUp::CardStatusSelector | PopUp::CardRecurrenceSelector => {
                     if app.state.card_being_edited.is_some() {
                         app.set_popup(PopUp::ConfirmDiscardCardChanges);
                     } else {
                         app.close_popup();
                     }
                 }
-                PopUp::CustomHexColorPromptBG | PopUp::CustomHexColorPromptFG => {
+                PopUp::CustomHexColorPromptBG
+                | PopUp::CustomHexColorPromptFG
+                | PopUp::ImportBoardsPrompt
+                | PopUp::FilterPrompt
+                | PopUp::ArchivedCards
+                | PopUp::EditBoard
+                | PopUp::SaveCardTemplatePrompt
+                | PopUp::SaveBoardTemplatePrompt
+                | PopUp::CreateWorkspacePrompt => {
                     app.close_popup();
                 }
                 _ => {}
@@ -685,7 +705,11 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
         // Handle user input for anything other than the command palette
         match app.state.focus {
             Focus::NewBoardName => {
-                if app.config.keybindings.next_focus.contains(&key) {
+                if app.config.keybindings.accept.contains(&key)
+                    && app.state.z_stack.last() == Some(&PopUp::EditBoard)
+                {
+                    return handle_edit_board_submit(app);
+                } else if app.config.keybindings.next_focus.contains(&key) {
                     handle_next_focus(app);
                 } else if app.config.keybindings.prv_focus.contains(&key) {
                     handle_prv_focus(app);
@@ -696,6 +720,28 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
             Focus::NewBoardDescription => {
                 app.state.text_buffers.board_description.input(key);
             }
+            Focus::NewBoardWipLimit => {
+                if app.config.keybindings.accept.contains(&key)
+                    && app.state.z_stack.last() == Some(&PopUp::EditBoard)
+                {
+                    return handle_edit_board_submit(app);
+                } else if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else {
+                    app.state.text_buffers.board_wip_limit.input(key);
+                }
+            }
+            Focus::CardTemplate => {
+                if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else if app.config.keybindings.accept.contains(&key) {
+                    open_card_template_picker(app);
+                }
+            }
             Focus::CardName => {
                 if app.config.keybindings.next_focus.contains(&key) {
                     handle_next_focus(app);
@@ -734,6 +780,15 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     app.set_popup(PopUp::CardStatusSelector);
                 }
             }
+            Focus::CardRecurrence => {
+                if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else if key == Key::Enter {
+                    app.set_popup(PopUp::CardRecurrenceSelector);
+                }
+            }
             Focus::CardTags => {
                 if let Some((_, current_card)) = &mut app.state.card_being_edited {
                     match key {
@@ -943,6 +998,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
             }
             Focus::CardComments => {
+                let comment_author = app.get_comment_author();
                 if let Some((_, current_card)) = &mut app.state.card_being_edited {
                     let current_selected = app
                         .state
@@ -976,7 +1032,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                             {
                                 current_card
                                     .comments
-                                    .insert(insert_index + 1, "".to_owned());
+                                    .insert(insert_index + 1, Comment::new(&comment_author, ""));
                                 app.state
                                     .text_buffers
                                     .prepare_tags_and_comments_for_card(current_card);
@@ -985,7 +1041,9 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                     .card_view_comment_list
                                     .select(Some(insert_index + 1));
                             } else {
-                                current_card.comments.push("".to_owned());
+                                current_card
+                                    .comments
+                                    .push(Comment::new(&comment_author, ""));
                                 app.state
                                     .text_buffers
                                     .prepare_tags_and_comments_for_card(current_card);
@@ -1033,7 +1091,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                     .get_mut(selected_comment_index)
                                 {
                                     current_comment_text_box.input(key);
-                                    current_card.comments[selected_comment_index] =
+                                    current_card.comments[selected_comment_index].body =
                                         current_comment_text_box.get_joined_lines();
                                 }
                             } else {
@@ -1052,6 +1110,179 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     return AppReturn::Continue;
                 }
             }
+            Focus::CardChecklist => {
+                if let Some((_, current_card)) = &mut app.state.card_being_edited {
+                    let current_selected = app
+                        .state
+                        .app_list_states
+                        .card_view_checklist_list
+                        .selected()
+                        .unwrap_or(0);
+                    match key {
+                        Key::ShiftRight => {
+                            let next_selected = app
+                                .state
+                                .app_list_states
+                                .card_view_checklist_list
+                                .selected()
+                                .map_or(0, |selected| selected + 1);
+                            if next_selected < current_card.checklist.len() {
+                                app.state
+                                    .app_list_states
+                                    .card_view_checklist_list
+                                    .select(Some(next_selected));
+                            }
+                        }
+                        Key::ShiftLeft => {
+                            if !current_card.checklist.is_empty() {
+                                app.state
+                                    .app_list_states
+                                    .card_view_checklist_list
+                                    .select(Some(current_selected.saturating_sub(1)));
+                            }
+                        }
+                        Key::Enter => {
+                            let insert_index = app
+                                .state
+                                .app_list_states
+                                .card_view_checklist_list
+                                .selected()
+                                .map_or(current_card.checklist.len(), |selected| selected + 1)
+                                .min(current_card.checklist.len());
+                            current_card
+                                .checklist
+                                .insert(insert_index, ChecklistItem::default());
+                            app.state
+                                .text_buffers
+                                .prepare_tags_and_comments_for_card(current_card);
+                            app.state
+                                .app_list_states
+                                .card_view_checklist_list
+                                .select(Some(insert_index));
+                        }
+                        Key::Delete => {
+                            if current_card.checklist.is_empty() {
+                                send_error_toast(
+                                    &mut app.widgets.toast_widget,
+                                    "No checklist items to delete",
+                                );
+                            } else if let Some(delete_index) = app
+                                .state
+                                .app_list_states
+                                .card_view_checklist_list
+                                .selected()
+                            {
+                                if delete_index < current_card.checklist.len() {
+                                    current_card.checklist.remove(delete_index);
+                                }
+                                app.state
+                                    .text_buffers
+                                    .prepare_tags_and_comments_for_card(current_card);
+                                if delete_index != 0 {
+                                    app.state
+                                        .app_list_states
+                                        .card_view_checklist_list
+                                        .select(Some(delete_index - 1));
+                                }
+                            }
+                        }
+                        _ if app.config.keybindings.next_focus.contains(&key) => {
+                            handle_next_focus(app)
+                        }
+                        _ if app.config.keybindings.prv_focus.contains(&key) => {
+                            handle_prv_focus(app)
+                        }
+                        _ => {
+                            if let Some(selected_item_index) = app
+                                .state
+                                .app_list_states
+                                .card_view_checklist_list
+                                .selected()
+                            {
+                                if let Some(current_item_text_box) = app
+                                    .state
+                                    .text_buffers
+                                    .card_checklist
+                                    .get_mut(selected_item_index)
+                                {
+                                    current_item_text_box.input(key);
+                                    current_card.checklist[selected_item_index].text =
+                                        current_item_text_box.get_joined_lines();
+                                }
+                            } else {
+                                send_warning_toast(
+                                    &mut app.widgets.toast_widget,
+                                    &format!(
+                                        "No Checklist item selected to edit, use {} or {}",
+                                        Key::ShiftRight,
+                                        Key::ShiftLeft
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                } else {
+                    return AppReturn::Continue;
+                }
+            }
+            Focus::CardBlockedBy => {
+                if let Some((_, current_card)) = &app.state.card_being_edited {
+                    let blocked_by_len = current_card.blocked_by.len();
+                    let current_selected = app
+                        .state
+                        .app_list_states
+                        .card_view_blocked_by_list
+                        .selected()
+                        .unwrap_or(0);
+                    match key {
+                        Key::ShiftRight => {
+                            let next_selected = app
+                                .state
+                                .app_list_states
+                                .card_view_blocked_by_list
+                                .selected()
+                                .map_or(0, |selected| selected + 1);
+                            if next_selected < blocked_by_len {
+                                app.state
+                                    .app_list_states
+                                    .card_view_blocked_by_list
+                                    .select(Some(next_selected));
+                            }
+                        }
+                        Key::ShiftLeft if blocked_by_len > 0 => {
+                            app.state
+                                .app_list_states
+                                .card_view_blocked_by_list
+                                .select(Some(current_selected.saturating_sub(1)));
+                        }
+                        Key::Enter => {
+                            let current_card = current_card.clone();
+                            start_blocker_search(app, &current_card);
+                            app.widgets.command_palette.already_in_user_input_mode = true;
+                            app.widgets.command_palette.last_focus = Some(Focus::CardBlockedBy);
+                        }
+                        Key::Delete => {
+                            if blocked_by_len == 0 {
+                                send_error_toast(
+                                    &mut app.widgets.toast_widget,
+                                    "No blockers to remove",
+                                );
+                            } else {
+                                handle_remove_blocker(app);
+                            }
+                        }
+                        _ if app.config.keybindings.next_focus.contains(&key) => {
+                            handle_next_focus(app)
+                        }
+                        _ if app.config.keybindings.prv_focus.contains(&key) => {
+                            handle_prv_focus(app)
+                        }
+                        _ => {}
+                    }
+                } else {
+                    return AppReturn::Continue;
+                }
+            }
             Focus::EmailIDField => {
                 if app.config.keybindings.next_focus.contains(&key) {
                     handle_next_focus(app);
@@ -1170,6 +1401,8 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                             }
                             app.state.app_status = AppStatus::Initialized;
                             return AppReturn::Continue;
+                        } else if app.state.z_stack.last() == Some(&PopUp::EditBoard) {
+                            return handle_edit_board_submit(app);
                         } else {
                             log::debug!("Dont know what to do with Submit button in user input mode for popup: {:?}", app.state.z_stack.last());
                         }
@@ -1209,6 +1442,16 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
                 _ => {}
             },
+            Focus::ChangeCardRecurrencePopup => match key {
+                Key::Up => app.select_card_recurrence_prv(),
+                Key::Down => app.select_card_recurrence_next(),
+                Key::Enter => {
+                    handle_change_card_recurrence(app);
+                }
+                _ if app.config.keybindings.next_focus.contains(&key) => handle_next_focus(app),
+                _ if app.config.keybindings.prv_focus.contains(&key) => handle_prv_focus(app),
+                _ => {}
+            },
             Focus::TextInput => {
                 let accept_keys = &app.config.keybindings.accept;
                 if accept_keys.contains(&key) {
@@ -1219,6 +1462,24 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         Some(PopUp::CustomHexColorPromptBG) => {
                             return handle_custom_hex_color_prompt(app, false)
                         }
+                        Some(PopUp::ImportBoardsPrompt) => return handle_import_boards_prompt(app),
+                        Some(PopUp::SaveCardTemplatePrompt) => {
+                            return handle_save_card_template_prompt(app)
+                        }
+                        Some(PopUp::SaveBoardTemplatePrompt) => {
+                            return handle_save_board_template_prompt(app)
+                        }
+                        Some(PopUp::CreateWorkspacePrompt) => {
+                            return handle_create_workspace_prompt(app).await
+                        }
+                        Some(PopUp::FilterPrompt) => return handle_filter_prompt(app),
+                        Some(PopUp::ArchivedCards) => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.state.set_focus(Focus::ArchivedCardsList);
+                        }
+                        Some(PopUp::CardRecurrenceSelector) => {
+                            return handle_change_card_recurrence(app)
+                        }
                         _ => {
                             log::debug!(
                                 "TextInput is not used in the current popup: {:?}",
@@ -1234,6 +1495,53 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         Some(PopUp::CustomHexColorPromptBG) => {
                             app.state.text_buffers.theme_editor_bg_hex.input(key);
                         }
+                        Some(PopUp::ImportBoardsPrompt) => {
+                            app.state.text_buffers.import_file_path.input(key);
+                        }
+                        Some(PopUp::SaveCardTemplatePrompt) => {
+                            app.state.text_buffers.card_template_name.input(key);
+                        }
+                        Some(PopUp::SaveBoardTemplatePrompt) => {
+                            app.state.text_buffers.board_template_name.input(key);
+                        }
+                        Some(PopUp::CreateWorkspacePrompt) => {
+                            app.state.text_buffers.workspace_name.input(key);
+                        }
+                        Some(PopUp::FilterPrompt)
+                            if app.config.keybindings.next_focus.contains(&key) =>
+                        {
+                            handle_next_focus(app);
+                        }
+                        Some(PopUp::FilterPrompt) => {
+                            app.state.text_buffers.filter_query.input(key);
+                        }
+                        Some(PopUp::ArchivedCards)
+                            if app.config.keybindings.next_focus.contains(&key) =>
+                        {
+                            app.state.app_status = AppStatus::Initialized;
+                            handle_next_focus(app);
+                        }
+                        Some(PopUp::CardRecurrenceSelector)
+                            if app.config.keybindings.next_focus.contains(&key) =>
+                        {
+                            // Card edits stay in user input mode
+                            if app.state.card_being_edited.is_none() {
+                                app.state.app_status = AppStatus::Initialized;
+                            }
+                            handle_next_focus(app);
+                        }
+                        Some(PopUp::CardRecurrenceSelector) => {
+                            app.state.text_buffers.card_recurrence_days.input(key);
+                        }
+                        Some(PopUp::ArchivedCards) => {
+                            app.state.text_buffers.archive_search.input(key);
+                            let query = app.state.text_buffers.archive_search.get_joined_lines();
+                            let has_matches = !app.boards.search_archived_cards(&query).is_empty();
+                            app.state
+                                .app_list_states
+                                .archived_cards
+                                .select(has_matches.then_some(0));
+                        }
                         _ => {
                             log::debug!(
                                 "No user input handler found for focus: {:?}",
@@ -1243,6 +1551,17 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     }
                 }
             }
+            Focus::FilterName => {
+                if app.config.keybindings.accept.contains(&key) {
+                    return handle_filter_prompt(app);
+                } else if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else {
+                    app.state.text_buffers.filter_name.input(key);
+                }
+            }
             Focus::DTPCalender
             | Focus::DTPMonth
             | Focus::DTPYear
@@ -1307,14 +1626,14 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 AppReturn::Continue
             }
             Action::ResetUI => {
-                let default_theme = app.config.default_theme.clone();
+                let default_theme = app.config.workspace_default_theme();
                 for theme in app.all_themes.iter_mut() {
                     if theme.name == default_theme {
                         app.current_theme = theme.clone();
                     }
                 }
                 app.widgets.toast_widget.toasts = vec![];
-                app.set_view(app.config.default_view);
+                app.set_view(app.config.workspace_default_view());
                 send_info_toast(
                     &mut app.widgets.toast_widget,
                     "UI reset, all toasts cleared",
@@ -1339,6 +1658,13 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 if let Some(popup) = app.state.z_stack.last() {
                     match popup {
                         PopUp::ChangeView => app.select_default_view_prv(),
+                        PopUp::CardTemplatePicker => app.select_card_template_prv(),
+                        PopUp::BoardTemplatePicker => app.select_board_template_prv(),
+                        PopUp::WorkspacePicker => app.select_workspace_prv(),
+                        PopUp::ChangeBoardSortMode | PopUp::SortBoardOnce => {
+                            app.board_sort_mode_selector_prv()
+                        }
+                        PopUp::ActionHistory => app.action_history_prv(),
                         PopUp::CardStatusSelector => app.select_card_status_prv(),
                         PopUp::SelectDefaultView => app.select_default_view_prv(),
                         PopUp::ChangeTheme => app.select_change_theme_prv(),
@@ -1356,14 +1682,29 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                         PopUp::ChangeDateFormatPopup => app.change_date_format_popup_prv(),
                         PopUp::FilterByTag => app.filter_by_tag_popup_prv(),
+                        PopUp::FilterPrompt if app.state.focus == Focus::SavedFilters => {
+                            app.saved_filters_prv()
+                        }
+                        PopUp::ArchivedCards if app.state.focus == Focus::ArchivedCardsList => {
+                            app.archived_cards_prv()
+                        }
                         PopUp::ViewCard => {
                             if app.state.focus == Focus::CardDescription {
                                 app.state.text_buffers.card_description.scroll((-1, 0));
+                            } else if app.state.focus == Focus::CardChecklist {
+                                app.card_view_checklist_prv();
+                            } else if app.state.focus == Focus::CardBlockedBy {
+                                app.card_view_blocked_by_prv();
                             }
                         }
                         PopUp::CardPrioritySelector => {
                             app.select_card_priority_prv();
                         }
+                        PopUp::CardRecurrenceSelector
+                            if app.state.focus == Focus::ChangeCardRecurrencePopup =>
+                        {
+                            app.select_card_recurrence_prv();
+                        }
                         PopUp::DateTimePicker => {
                             handle_date_time_picker_action(app, None, Some(action));
                         }
@@ -1406,8 +1747,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                     }
                     View::LoadLocalSave => {
-                        app.load_save_prv(false);
-                        app.dispatch(IoEvent::LoadLocalPreview).await;
+                        if app.state.focus == Focus::SaveDiff {
+                            app.save_diff_prv();
+                        } else {
+                            app.load_save_prv(false);
+                            load_local_save_preview(app).await;
+                        }
                     }
                     View::LoadCloudSave => {
                         app.load_save_prv(true);
@@ -1458,6 +1803,13 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 if let Some(popup) = app.state.z_stack.last() {
                     match popup {
                         PopUp::ChangeView => app.select_default_view_next(),
+                        PopUp::CardTemplatePicker => app.select_card_template_next(),
+                        PopUp::BoardTemplatePicker => app.select_board_template_next(),
+                        PopUp::WorkspacePicker => app.select_workspace_next(),
+                        PopUp::ChangeBoardSortMode | PopUp::SortBoardOnce => {
+                            app.board_sort_mode_selector_next()
+                        }
+                        PopUp::ActionHistory => app.action_history_next(),
                         PopUp::CardStatusSelector => app.select_card_status_next(),
                         PopUp::SelectDefaultView => app.select_default_view_next(),
                         PopUp::ChangeTheme => app.select_change_theme_next(),
@@ -1472,9 +1824,19 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                         PopUp::ChangeDateFormatPopup => app.change_date_format_popup_next(),
                         PopUp::FilterByTag => app.filter_by_tag_popup_next(),
+                        PopUp::FilterPrompt if app.state.focus == Focus::SavedFilters => {
+                            app.saved_filters_next()
+                        }
+                        PopUp::ArchivedCards if app.state.focus == Focus::ArchivedCardsList => {
+                            app.archived_cards_next()
+                        }
                         PopUp::ViewCard => {
                             if app.state.focus == Focus::CardDescription {
                                 app.state.text_buffers.card_description.scroll((1, 0))
+                            } else if app.state.focus == Focus::CardChecklist {
+                                app.card_view_checklist_next();
+                            } else if app.state.focus == Focus::CardBlockedBy {
+                                app.card_view_blocked_by_next();
                             }
                         }
                         PopUp::SaveThemePrompt => {
@@ -1483,6 +1845,11 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         PopUp::CardPrioritySelector => {
                             app.select_card_priority_next();
                         }
+                        PopUp::CardRecurrenceSelector
+                            if app.state.focus == Focus::ChangeCardRecurrencePopup =>
+                        {
+                            app.select_card_recurrence_next();
+                        }
                         PopUp::DateTimePicker => {
                             handle_date_time_picker_action(app, None, Some(action));
                         }
@@ -1525,8 +1892,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                     }
                     View::LoadLocalSave => {
-                        app.load_save_next(false);
-                        app.dispatch(IoEvent::LoadLocalPreview).await;
+                        if app.state.focus == Focus::SaveDiff {
+                            app.save_diff_next();
+                        } else {
+                            app.load_save_next(false);
+                            load_local_save_preview(app).await;
+                        }
                     }
                     View::LoadCloudSave => {
                         app.load_save_next(true);
@@ -1574,7 +1945,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 reset_mouse(app);
                 if let Some(popup) = app.state.z_stack.last() {
                     match popup {
-                        PopUp::ConfirmDiscardCardChanges => {
+                        PopUp::ConfirmDiscardCardChanges | PopUp::SyncConflicts => {
                             toggle_focus_between_submit_and_extra(app);
                         }
                         PopUp::DateTimePicker => {
@@ -1586,6 +1957,10 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     && View::views_with_kanban_board().contains(&app.state.current_view)
                 {
                     handle_horizontal_navigation(app, NavigationDirection::Right);
+                } else if app.state.current_view == View::LoadLocalSave && app.state.save_diff_mode
+                {
+                    app.save_diff_target_next();
+                    app.dispatch(IoEvent::LoadSaveDiff).await;
                 }
                 AppReturn::Continue
             }
@@ -1593,7 +1968,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 reset_mouse(app);
                 if let Some(popup) = app.state.z_stack.last() {
                     match popup {
-                        PopUp::ConfirmDiscardCardChanges => {
+                        PopUp::ConfirmDiscardCardChanges | PopUp::SyncConflicts => {
                             toggle_focus_between_submit_and_extra(app);
                         }
                         PopUp::DateTimePicker => {
@@ -1605,6 +1980,10 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     && View::views_with_kanban_board().contains(&app.state.current_view)
                 {
                     handle_horizontal_navigation(app, NavigationDirection::Left);
+                } else if app.state.current_view == View::LoadLocalSave && app.state.save_diff_mode
+                {
+                    app.save_diff_target_prv();
+                    app.dispatch(IoEvent::LoadSaveDiff).await;
                 }
                 AppReturn::Continue
             }
@@ -1619,7 +1998,37 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                             match popup {
                                 PopUp::EditGeneralConfig
                                 | PopUp::CustomHexColorPromptFG
-                                | PopUp::CustomHexColorPromptBG => {
+                                | PopUp::CustomHexColorPromptBG
+                                | PopUp::ImportBoardsPrompt
+                                | PopUp::SaveCardTemplatePrompt
+                                | PopUp::SaveBoardTemplatePrompt
+                                | PopUp::CreateWorkspacePrompt => {
+                                    app.state.app_status = AppStatus::UserInput;
+                                    log::info!("Taking user input");
+                                }
+                                PopUp::FilterPrompt
+                                    if matches!(
+                                        app.state.focus,
+                                        Focus::TextInput | Focus::FilterName
+                                    ) =>
+                                {
+                                    app.state.app_status = AppStatus::UserInput;
+                                    log::info!("Taking user input");
+                                }
+                                PopUp::ArchivedCards | PopUp::CardRecurrenceSelector
+                                    if app.state.focus == Focus::TextInput =>
+                                {
+                                    app.state.app_status = AppStatus::UserInput;
+                                    log::info!("Taking user input");
+                                }
+                                PopUp::EditBoard
+                                    if matches!(
+                                        app.state.focus,
+                                        Focus::NewBoardName
+                                            | Focus::NewBoardDescription
+                                            | Focus::NewBoardWipLimit
+                                    ) =>
+                                {
                                     app.state.app_status = AppStatus::UserInput;
                                     log::info!("Taking user input");
                                 }
@@ -1649,9 +2058,27 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 if let Some(popup) = app.state.z_stack.last() {
                     match popup {
                         PopUp::ChangeView => handle_change_view(app),
+                        PopUp::ChangeBoardSortMode | PopUp::SortBoardOnce => {
+                            return handle_board_sort_mode_selection(app);
+                        }
+                        PopUp::ActionHistory => return handle_action_history_selection(app),
+                        PopUp::ArchivedCards => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                                return AppReturn::Continue;
+                            }
+                            return handle_restore_archived_card(app);
+                        }
                         PopUp::CardStatusSelector => {
                             return handle_change_card_status(app, None);
                         }
+                        PopUp::CardRecurrenceSelector => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                                return AppReturn::Continue;
+                            }
+                            return handle_change_card_recurrence(app);
+                        }
                         PopUp::EditGeneralConfig => {
                             if app.state.current_view == View::CreateTheme {
                                 handle_create_theme_action(app);
@@ -1673,6 +2100,33 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         PopUp::CustomHexColorPromptBG => {
                             return handle_custom_hex_color_prompt(app, false)
                         }
+                        PopUp::ImportBoardsPrompt => return handle_import_boards_prompt(app),
+                        PopUp::SaveCardTemplatePrompt => {
+                            return handle_save_card_template_prompt(app)
+                        }
+                        PopUp::CardTemplatePicker => return handle_card_template_selection(app),
+                        PopUp::SaveBoardTemplatePrompt => {
+                            return handle_save_board_template_prompt(app)
+                        }
+                        PopUp::BoardTemplatePicker => return handle_board_template_selection(app),
+                        PopUp::WorkspacePicker => return handle_workspace_selection(app).await,
+                        PopUp::CreateWorkspacePrompt => {
+                            return handle_create_workspace_prompt(app).await
+                        }
+                        PopUp::FilterPrompt => return handle_filter_prompt(app),
+                        PopUp::EditBoard
+                            if matches!(
+                                app.state.focus,
+                                Focus::NewBoardName
+                                    | Focus::NewBoardDescription
+                                    | Focus::NewBoardWipLimit
+                            ) =>
+                        {
+                            app.state.app_status = AppStatus::UserInput;
+                            return AppReturn::Continue;
+                        }
+                        PopUp::EditBoard => return handle_edit_board_submit(app),
+                        PopUp::SyncConflicts => return handle_sync_conflict_resolution(app).await,
                         PopUp::ViewCard => return handle_general_actions_view_card(app),
                         PopUp::CommandPalette => {
                             unreachable!("Command palette should not be handled here");
@@ -1729,7 +2183,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         AppReturn::Continue
                     }
                     View::LoadLocalSave => {
-                        app.dispatch(IoEvent::LoadSaveLocal).await;
+                        if app.state.focus == Focus::SaveDiff {
+                            handle_restore_card_from_save_diff(app);
+                            app.dispatch(IoEvent::LoadSaveDiff).await;
+                        } else {
+                            app.dispatch(IoEvent::LoadSaveLocal).await;
+                        }
                         AppReturn::Continue
                     }
                     View::EditKeybindings => {
@@ -1907,7 +2366,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 View::LoadLocalSave => {
                     app.dispatch(IoEvent::DeleteLocalSave).await;
                     tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
-                    app.dispatch(IoEvent::LoadLocalPreview).await;
+                    load_local_save_preview(app).await;
                     AppReturn::Continue
                 }
                 View::LoadCloudSave => {
@@ -1926,21 +2385,20 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         Focus::Body => {
                             if let Some(current_board_id) = app.state.current_board_id {
                                 if let Some(current_card_id) = app.state.current_card_id {
-                                    match app.boards.get_mut_board_with_id(current_board_id) {
+                                    match app.boards.get_board_with_id(current_board_id) {
                                         Some(current_board) => {
-                                            let current_board_id = current_board.id;
                                             let card_index =
                                                 current_board.cards.get_card_index(current_card_id);
                                             if let Some(card_index) = card_index {
-                                                let card = current_board
-                                                    .cards
-                                                    .get_card_with_index(card_index)
-                                                    .unwrap()
-                                                    .clone();
-                                                let card_name = card.name.clone();
-                                                current_board
-                                                    .cards
-                                                    .remove_card_with_id(current_card_id);
+                                                let archived_card = app
+                                                    .boards
+                                                    .archive_card(current_board_id, current_card_id)
+                                                    .unwrap();
+                                                let card_name = archived_card.card.name.clone();
+                                                let current_board = app
+                                                    .boards
+                                                    .get_board_with_id(current_board_id)
+                                                    .unwrap();
                                                 if card_index > 0 {
                                                     let new_current_card_id = Some(
                                                         current_board
@@ -1967,16 +2425,16 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                                         None,
                                                     );
                                                 }
-                                                log::warn!("Deleted card {}", card_name);
+                                                log::warn!("Archived card {}", card_name);
                                                 app.action_history_manager.new_action(
-                                                    ActionHistory::DeleteCard(
-                                                        card,
-                                                        current_board.id,
-                                                    ),
+                                                    ActionHistory::ArchiveCards(vec![(
+                                                        current_board_id,
+                                                        archived_card,
+                                                    )]),
                                                 );
                                                 send_warning_toast(
                                                     &mut app.widgets.toast_widget,
-                                                    &format!("Deleted card {}", card_name),
+                                                    &format!("Archived card {}", card_name),
                                                 );
                                                 if let Some(visible_cards) = app
                                                     .visible_boards_and_cards
@@ -2033,6 +2491,21 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                             }
                             AppReturn::Continue
                         }
+                        Focus::CardBlockedBy
+                            if app.state.z_stack.last() == Some(&PopUp::ViewCard) =>
+                        {
+                            handle_remove_blocker(app)
+                        }
+                        Focus::SavedFilters
+                            if app.state.z_stack.last() == Some(&PopUp::FilterPrompt) =>
+                        {
+                            handle_delete_saved_filter(app)
+                        }
+                        Focus::ArchivedCardsList
+                            if app.state.z_stack.last() == Some(&PopUp::ArchivedCards) =>
+                        {
+                            handle_delete_archived_card(app)
+                        }
                         _ => AppReturn::Continue,
                     }
                 }
@@ -2161,6 +2634,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     return AppReturn::Continue;
                 }
                 if app.state.focus == Focus::Body {
+                    if is_current_board_sorted(app) {
+                        return AppReturn::Continue;
+                    }
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else {
@@ -2264,6 +2740,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     return AppReturn::Continue;
                 }
                 if app.state.focus == Focus::Body {
+                    if is_current_board_sorted(app) {
+                        return AppReturn::Continue;
+                    }
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else {
@@ -2367,6 +2846,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else if let Some(current_board_id) = app.state.current_board_id {
+                        if is_move_to_adjacent_board_blocked(app, current_board_id, true) {
+                            return AppReturn::Continue;
+                        }
                         let boards: &mut Boards = if app.filtered_boards.is_empty() {
                             &mut app.boards
                         } else {
@@ -2484,6 +2966,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else if let Some(current_board) = app.state.current_board_id {
+                        if is_move_to_adjacent_board_blocked(app, current_board, false) {
+                            return AppReturn::Continue;
+                        }
                         let boards: &mut Boards = if app.filtered_boards.is_empty() {
                             &mut app.boards
                         } else {
@@ -2651,6 +3136,13 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 log::info!("Cleared toast messages");
                 AppReturn::Continue
             }
+            Action::ToggleChecklistItem => handle_toggle_checklist_item(app),
+            Action::CompareSaves => {
+                if app.state.z_stack.is_empty() && app.state.current_view == View::LoadLocalSave {
+                    toggle_save_diff_mode(app).await;
+                }
+                AppReturn::Continue
+            }
         }
     } else {
         // Warn user that they are not in user input mode
@@ -2870,11 +3362,16 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                     }
                 }
             }
-            PopUp::CardStatusSelector => {
+            PopUp::EditBoard => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::ChangeCardStatusPopup => {
-                            return handle_change_card_status(app, None);
+                        Focus::SubmitButton => {
+                            return handle_edit_board_submit(app);
+                        }
+                        Focus::NewBoardName
+                        | Focus::NewBoardDescription
+                        | Focus::NewBoardWipLimit => {
+                            app.state.app_status = AppStatus::UserInput;
                         }
                         Focus::CloseButton => {
                             app.close_popup();
@@ -2883,40 +3380,99 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                     }
                 }
             }
-            PopUp::EditGeneralConfig => {
+            PopUp::ActionHistory => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::EditGeneralConfigPopup => {
-                            app.state.app_status = AppStatus::UserInput;
+                        Focus::ActionHistoryPopup => {
+                            return handle_action_history_selection(app);
                         }
                         Focus::CloseButton => {
-                            app.state.app_status = AppStatus::Initialized;
-                            app.close_popup();
-                        }
-                        Focus::SubmitButton => {
-                            if app.state.current_view == View::CreateTheme {
-                                handle_create_theme_action(app);
-                            } else {
-                                handle_edit_general_config(app);
-                            }
-                            app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
                         }
                         _ => {}
                     }
                 }
             }
-            PopUp::EditSpecificKeyBinding => {
+            PopUp::ArchivedCards => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::EditSpecificKeyBindingPopup => {
-                            app.state.app_status = AppStatus::KeyBindMode;
+                        Focus::ArchivedCardsList => {
+                            return handle_restore_archived_card(app);
+                        }
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
                         }
                         Focus::CloseButton => {
-                            app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
                         }
-                        Focus::SubmitButton => {
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::ArchivedCardsList {
+                    app.archived_cards_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::ArchivedCardsList {
+                    app.archived_cards_next();
+                }
+            }
+            PopUp::ChangeBoardSortMode | PopUp::SortBoardOnce => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ChangeBoardSortModePopup => {
+                            return handle_board_sort_mode_selection(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::CardStatusSelector => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ChangeCardStatusPopup => {
+                            return handle_change_card_status(app, None);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::EditGeneralConfig => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::EditGeneralConfigPopup => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
+                        }
+                        Focus::SubmitButton => {
+                            if app.state.current_view == View::CreateTheme {
+                                handle_create_theme_action(app);
+                            } else {
+                                handle_edit_general_config(app);
+                            }
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::EditSpecificKeyBinding => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::EditSpecificKeyBindingPopup => {
+                            app.state.app_status = AppStatus::KeyBindMode;
+                        }
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
+                        }
+                        Focus::SubmitButton => {
                             handle_edit_specific_keybinding(app);
                             app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
@@ -2948,7 +3504,7 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                         Focus::CloseButton => {
                             let config_theme = {
                                 let all_themes = Theme::all_default_themes();
-                                let default_theme = app.config.default_theme.clone();
+                                let default_theme = app.config.workspace_default_theme();
                                 all_themes.iter().find(|t| t.name == default_theme).cloned()
                             };
                             if let Some(theme) = config_theme {
@@ -3027,6 +3583,143 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                     }
                 }
             }
+            PopUp::SyncConflicts => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton | Focus::ExtraFocus => {
+                            app.state.set_focus(mouse_focus);
+                            return handle_sync_conflict_resolution(app).await;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::ImportBoardsPrompt => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton => {
+                            handle_import_boards_prompt(app);
+                        }
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::SaveCardTemplatePrompt => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton => {
+                            handle_save_card_template_prompt(app);
+                        }
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::CardTemplatePicker => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SelectCardTemplatePopup => {
+                            return handle_card_template_selection(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::SaveBoardTemplatePrompt => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton => {
+                            handle_save_board_template_prompt(app);
+                        }
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::BoardTemplatePicker => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SelectBoardTemplatePopup => {
+                            return handle_board_template_selection(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::WorkspacePicker => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SelectWorkspacePopup => {
+                            return handle_workspace_selection(app).await;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::CreateWorkspacePrompt => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton => {
+                            return handle_create_workspace_prompt(app).await;
+                        }
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::FilterPrompt => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton | Focus::SavedFilters => {
+                            handle_filter_prompt(app);
+                        }
+                        Focus::TextInput | Focus::FilterName => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::SavedFilters {
+                    app.saved_filters_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::SavedFilters {
+                    app.saved_filters_next();
+                }
+            }
             PopUp::ViewCard => {
                 if left_button_pressed {
                     match mouse_focus {
@@ -3063,6 +3756,13 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                             app.set_popup(PopUp::CardStatusSelector);
                             return AppReturn::Continue;
                         }
+                        Focus::CardRecurrence => {
+                            if app.state.card_being_edited.is_none() {
+                                handle_edit_new_card(app);
+                            }
+                            app.set_popup(PopUp::CardRecurrenceSelector);
+                            return AppReturn::Continue;
+                        }
                         Focus::CardDueDate => {
                             if app.state.card_being_edited.is_none() {
                                 handle_edit_new_card(app);
@@ -3095,6 +3795,22 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                     }
                 }
             }
+            PopUp::CardRecurrenceSelector => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        Focus::ChangeCardRecurrencePopup => {
+                            return handle_change_card_recurrence(app)
+                        }
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        _ => {}
+                    }
+                }
+            }
             PopUp::ConfirmDiscardCardChanges => {
                 if left_button_pressed {
                     match mouse_focus {
@@ -3342,6 +4058,7 @@ async fn handle_left_click_for_view(app: &mut App<'_>) -> Option<AppReturn> {
         }
         Focus::NewBoardName
         | Focus::NewBoardDescription
+        | Focus::NewBoardWipLimit
         | Focus::CardName
         | Focus::CardDescription => {
             app.state.app_status = AppStatus::UserInput;
@@ -3355,12 +4072,15 @@ async fn handle_left_click_for_view(app: &mut App<'_>) -> Option<AppReturn> {
         }
         Focus::LoadSave => {
             if app.state.app_list_states.load_save.selected().is_some() {
-                app.dispatch(IoEvent::LoadLocalPreview).await;
+                load_local_save_preview(app).await;
             }
         }
         Focus::ThemeEditor => {
             return Some(handle_create_theme_action(app));
         }
+        Focus::CardTemplate => {
+            open_card_template_picker(app);
+        }
         _ => {}
     }
     None
@@ -3419,6 +4139,9 @@ fn move_dragged_card(app: &mut App<'_>) {
             return;
         }
         let hovered_card_id = app.state.current_card_id.unwrap();
+        if is_current_board_sorted(app) {
+            return;
+        }
         // same board so swap cards
         let hovered_board = app.boards.get_board_with_id(hovered_board_id);
         if hovered_board.is_none() {
@@ -3502,6 +4225,14 @@ fn move_dragged_card(app: &mut App<'_>) {
             .unwrap()
             .clone();
         let dragged_card_name = dragged_card.name.clone();
+        if let Some(error) = app_boards.get_blocked_move_error(dragged_card_id, hovered_board_id) {
+            log::warn!("{}", error);
+            send_error_toast(&mut app.widgets.toast_widget, &error);
+            return;
+        }
+        if is_move_over_wip_limit(app, hovered_board_id) {
+            return;
+        }
         if hovered_card_id.is_none() {
             // check if hovered board is empty
             if hovered_board.cards.is_empty() {
@@ -3753,7 +4484,9 @@ fn handle_config_menu_action(app: &mut App) -> AppReturn {
             | ConfigEnum::DisableAnimations
             | ConfigEnum::AutoLogin
             | ConfigEnum::ShowLineNumbers
-            | ConfigEnum::EnableMouseSupport => {
+            | ConfigEnum::EnableMouseSupport
+            | ConfigEnum::EnforceWipLimits
+            | ConfigEnum::StorageMode => {
                 AppConfig::edit_config(
                     app,
                     config_enum,
@@ -3767,6 +4500,20 @@ fn handle_config_menu_action(app: &mut App) -> AppReturn {
             ConfigEnum::DateFormat => {
                 app.set_popup(PopUp::ChangeDateFormatPopup);
             }
+            ConfigEnum::SyncBackend => {
+                if app.state.user_login_data.auth_token.is_some() {
+                    send_warning_toast(
+                        &mut app.widgets.toast_widget,
+                        "Please logout before changing the sync backend",
+                    );
+                    return AppReturn::Continue;
+                }
+                AppConfig::edit_config(
+                    app,
+                    config_enum,
+                    &app.config.get_toggled_value_as_string(config_enum),
+                );
+            }
             ConfigEnum::DatePickerCalenderFormat => {
                 AppConfig::edit_config(
                     app,
@@ -3802,12 +4549,13 @@ async fn handle_main_menu_action(app: &mut App<'_>) -> AppReturn {
                 }
             }
             MainMenuItem::View => {
-                app.set_view(app.config.default_view);
+                app.set_view(app.config.workspace_default_view());
             }
             MainMenuItem::Help => {
                 app.set_view(View::HelpMenu);
             }
             MainMenuItem::LoadSaveLocal => {
+                reset_preview_boards(app);
                 app.set_view(View::LoadLocalSave);
             }
             MainMenuItem::LoadSaveCloud => {
@@ -3817,6 +4565,12 @@ async fn handle_main_menu_action(app: &mut App<'_>) -> AppReturn {
                     app.dispatch(IoEvent::GetCloudData).await;
                 }
             }
+            MainMenuItem::StartFromBoardTemplate => {
+                app.set_popup(PopUp::BoardTemplatePicker);
+            }
+            MainMenuItem::SwitchWorkspace => {
+                app.set_popup(PopUp::WorkspacePicker);
+            }
         }
     }
     AppReturn::Continue
@@ -3966,7 +4720,7 @@ pub async fn handle_go_to_previous_view(app: &mut App<'_>) -> AppReturn {
             PopUp::ChangeTheme => {
                 let config_theme = {
                     let all_themes = Theme::all_default_themes();
-                    let default_theme = app.config.default_theme.clone();
+                    let default_theme = app.config.workspace_default_theme();
                     all_themes.iter().find(|t| t.name == default_theme).cloned()
                 };
                 if let Some(theme) = config_theme {
@@ -3991,6 +4745,10 @@ pub async fn handle_go_to_previous_view(app: &mut App<'_>) -> AppReturn {
             AppReturn::Continue
         }
         View::LoadLocalSave => {
+            if app.state.save_diff_mode {
+                toggle_save_diff_mode(app).await;
+                return AppReturn::Continue;
+            }
             app.state.app_list_states.load_save = ListState::default();
             go_to_previous_view_without_extras(app);
             AppReturn::Continue
@@ -4060,11 +4818,9 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
     if let Some(card_being_edited) = &mut app.state.card_being_edited {
         let card = &mut card_being_edited.1;
         if selected_status == CardStatus::Complete {
-            card.date_completed = chrono::Local::now()
-                .format(app.config.date_time_format.to_parser_string())
-                .to_string();
+            card.date_completed = Some(Utc::now());
         } else {
-            card.date_completed = FIELD_NOT_SET.to_string();
+            card.date_completed = None;
         }
         card.card_status = selected_status;
         app.close_popup();
@@ -4072,12 +4828,9 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
         return AppReturn::Continue;
     } else if let Some(current_board_id) = app.state.current_board_id {
         let mut card_found = String::new();
-        let boards: &mut Boards = if app.filtered_boards.is_empty() {
-            &mut app.boards
-        } else {
-            &mut app.filtered_boards
-        };
-        if let Some(current_board) = boards.get_mut_board_with_id(current_board_id) {
+        let mut next_card_due_date = None;
+        // The filtered boards are copies, changes have to go to the boards they were built from
+        if let Some(current_board) = app.boards.get_mut_board_with_id(current_board_id) {
             if let Some(current_card_id) = app.state.current_card_id {
                 if let Some(current_card) =
                     current_board.cards.get_mut_card_with_id(current_card_id)
@@ -4085,32 +4838,54 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
                     let temp_old_card = current_card.clone();
                     current_card.card_status = selected_status.clone();
                     if current_card.card_status == CardStatus::Complete {
-                        current_card.date_completed = chrono::Local::now()
-                            .format(app.config.date_time_format.to_parser_string())
-                            .to_string();
+                        current_card.date_completed = Some(Utc::now());
                     } else {
-                        current_card.date_completed = FIELD_NOT_SET.to_string();
+                        current_card.date_completed = None;
                     }
-                    current_card.date_modified = chrono::Local::now()
-                        .format(app.config.date_time_format.to_parser_string())
-                        .to_string();
-                    app.action_history_manager
-                        .new_action(ActionHistory::EditCard(
-                            temp_old_card,
-                            current_card.clone(),
-                            current_board_id,
-                        ));
+                    current_card.mark_modified();
+                    let completed_card = current_card.clone();
                     log::info!(
                         "Changed status to \"{}\" for card \"{}\"",
                         selected_status,
                         current_card.name
                     );
                     card_found.clone_from(&current_card.name);
+                    let next_card = if temp_old_card.card_status != CardStatus::Complete
+                        && selected_status == CardStatus::Complete
+                    {
+                        current_board.add_next_occurrence(current_card_id)
+                    } else {
+                        None
+                    };
+                    if let Some(next_card) = next_card {
+                        next_card_due_date = next_card.due_date;
+                        log::info!(
+                            "Added the next occurrence of recurring card \"{}\"",
+                            next_card.name
+                        );
+                        app.action_history_manager.new_action(
+                            ActionHistory::CompleteRecurringCard(
+                                temp_old_card,
+                                completed_card,
+                                Box::new(next_card),
+                                current_board_id,
+                            ),
+                        );
+                    } else {
+                        app.action_history_manager
+                            .new_action(ActionHistory::EditCard(
+                                temp_old_card,
+                                completed_card,
+                                current_board_id,
+                            ));
+                    }
                     app.close_popup();
                 }
             }
         }
         if !card_found.is_empty() {
+            refresh_card_filter(app);
+            refresh_visible_boards_and_cards(app);
             send_info_toast(
                 &mut app.widgets.toast_widget,
                 &format!(
@@ -4118,6 +4893,16 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
                     selected_status, card_found
                 ),
             );
+            if let Some(next_card_due_date) = next_card_due_date {
+                send_info_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!(
+                        "Added the next \"{}\", due {}",
+                        card_found,
+                        format_date_time(Some(next_card_due_date), app.config.date_time_format)
+                    ),
+                );
+            }
         } else {
             send_error_toast(
                 &mut app.widgets.toast_widget,
@@ -4155,21 +4940,14 @@ fn handle_change_card_priority(app: &mut App, priority: Option<CardPriority>) ->
         return AppReturn::Continue;
     } else if let Some(current_board_id) = app.state.current_board_id {
         let mut card_found = String::new();
-        let boards: &mut Boards = if app.filtered_boards.is_empty() {
-            &mut app.boards
-        } else {
-            &mut app.filtered_boards
-        };
-        if let Some(current_board) = boards.get_mut_board_with_id(current_board_id) {
+        if let Some(current_board) = app.boards.get_mut_board_with_id(current_board_id) {
             if let Some(current_card_id) = app.state.current_card_id {
                 if let Some(current_card) =
                     current_board.cards.get_mut_card_with_id(current_card_id)
                 {
                     let temp_old_card = current_card.clone();
                     current_card.priority = selected_priority.clone();
-                    current_card.date_modified = chrono::Local::now()
-                        .format(app.config.date_time_format.to_parser_string())
-                        .to_string();
+                    current_card.mark_modified();
                     app.action_history_manager
                         .new_action(ActionHistory::EditCard(
                             temp_old_card,
@@ -4187,6 +4965,8 @@ fn handle_change_card_priority(app: &mut App, priority: Option<CardPriority>) ->
             }
         }
         if !card_found.is_empty() {
+            refresh_card_filter(app);
+            refresh_visible_boards_and_cards(app);
             send_info_toast(
                 &mut app.widgets.toast_widget,
                 &format!(
@@ -4204,27 +4984,109 @@ fn handle_change_card_priority(app: &mut App, priority: Option<CardPriority>) ->
     AppReturn::Continue
 }
 
-fn handle_edit_general_config(app: &mut App) {
-    let config_item_index = app.state.app_table_states.config.selected().unwrap_or(0);
-    let config_item_list = AppConfig::to_view_list(&app.config);
-    let config_item = &config_item_list[config_item_index];
-    let default_key = String::from("");
-    let config_item_key = config_item.first().unwrap_or(&default_key);
-    let config_enum = ConfigEnum::from_str(config_item_key);
-    if config_enum.is_err() {
-        log::error!("Error checking which config item is being edited");
-        return;
-    }
-    let config_enum = config_enum.unwrap();
-    let new_value = app.state.text_buffers.general_config.get_joined_lines();
-    if new_value.is_empty() {
-        log::error!(
-            "Could not find new value for config item {}",
-            config_item_key
-        );
-        send_error_toast(
-            &mut app.widgets.toast_widget,
-            &format!(
+fn handle_change_card_recurrence(app: &mut App) -> AppReturn {
+    let selected_recurrence = if app.state.focus == Focus::TextInput {
+        let days = app
+            .state
+            .text_buffers
+            .card_recurrence_days
+            .get_joined_lines();
+        match days.trim().parse::<u16>() {
+            Ok(days) if days > 0 => Some(CardRecurrence::EveryDays(days)),
+            _ => {
+                send_error_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("Invalid number of days \"{}\"", days),
+                );
+                return AppReturn::Continue;
+            }
+        }
+    } else {
+        let selected_index = app
+            .state
+            .app_list_states
+            .card_recurrence_selector
+            .selected()
+            .unwrap_or(0);
+        // The first entry of the list turns recurrence off
+        selected_index
+            .checked_sub(1)
+            .and_then(|index| CardRecurrence::all().get(index).copied())
+    };
+    let recurrence_name =
+        selected_recurrence.map_or("None".to_string(), |recurrence| recurrence.to_string());
+
+    if let Some(card_being_edited) = &mut app.state.card_being_edited {
+        card_being_edited.1.recurrence = selected_recurrence;
+        app.close_popup();
+        app.state.set_focus(Focus::CardRecurrence);
+        return AppReturn::Continue;
+    } else if let Some(current_board_id) = app.state.current_board_id {
+        let mut card_found = String::new();
+        if let Some(current_board) = app.boards.get_mut_board_with_id(current_board_id) {
+            if let Some(current_card_id) = app.state.current_card_id {
+                if let Some(current_card) =
+                    current_board.cards.get_mut_card_with_id(current_card_id)
+                {
+                    let temp_old_card = current_card.clone();
+                    current_card.recurrence = selected_recurrence;
+                    current_card.mark_modified();
+                    app.action_history_manager
+                        .new_action(ActionHistory::EditCard(
+                            temp_old_card,
+                            current_card.clone(),
+                            current_board_id,
+                        ));
+                    log::info!(
+                        "Changed recurrence to \"{}\" for card \"{}\"",
+                        recurrence_name,
+                        current_card.name
+                    );
+                    card_found.clone_from(&current_card.name);
+                    app.close_popup();
+                }
+            }
+        }
+        if !card_found.is_empty() {
+            refresh_visible_boards_and_cards(app);
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!(
+                    "Changed recurrence to \"{}\" for card \"{}\"",
+                    recurrence_name, card_found
+                ),
+            );
+        } else {
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                "Error Could not find current card",
+            );
+        }
+    }
+    AppReturn::Continue
+}
+
+fn handle_edit_general_config(app: &mut App) {
+    let config_item_index = app.state.app_table_states.config.selected().unwrap_or(0);
+    let config_item_list = AppConfig::to_view_list(&app.config);
+    let config_item = &config_item_list[config_item_index];
+    let default_key = String::from("");
+    let config_item_key = config_item.first().unwrap_or(&default_key);
+    let config_enum = ConfigEnum::from_str(config_item_key);
+    if config_enum.is_err() {
+        log::error!("Error checking which config item is being edited");
+        return;
+    }
+    let config_enum = config_enum.unwrap();
+    let new_value = app.state.text_buffers.general_config.get_joined_lines();
+    if new_value.is_empty() {
+        log::error!(
+            "Could not find new value for config item {}",
+            config_item_key
+        );
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
                 "Could not find new value for config item {}",
                 config_item_key
             ),
@@ -4313,6 +5175,14 @@ fn handle_new_board_action(app: &mut App) {
         let new_board_name = new_board_name.trim();
         let new_board_description = app.state.text_buffers.board_description.get_joined_lines();
         let new_board_description = new_board_description.trim();
+        let new_board_wip_limit =
+            match parse_wip_limit(&app.state.text_buffers.board_wip_limit.get_joined_lines()) {
+                Ok(wip_limit) => wip_limit,
+                Err(error) => {
+                    send_warning_toast(&mut app.widgets.toast_widget, &error);
+                    return;
+                }
+            };
         let mut same_name_exists = false;
         for board in app.boards.get_boards().iter() {
             if board.name == new_board_name {
@@ -4321,7 +5191,10 @@ fn handle_new_board_action(app: &mut App) {
             }
         }
         if !new_board_name.is_empty() && !same_name_exists {
-            let new_board = Board::new(new_board_name, new_board_description);
+            let new_board = Board {
+                wip_limit: new_board_wip_limit,
+                ..Board::new(new_board_name, new_board_description)
+            };
             app.boards.add_board(new_board.clone());
             app.action_history_manager
                 .new_action(ActionHistory::CreateBoard(new_board.clone()));
@@ -4329,10 +5202,9 @@ fn handle_new_board_action(app: &mut App) {
             update_current_board_and_card(&mut app.state, new_current_board_id, None);
 
             app.set_view(
-                *app.state
+                app.state
                     .prev_view
-                    .as_ref()
-                    .unwrap_or(&app.config.default_view),
+                    .unwrap_or(app.config.workspace_default_view()),
             );
         } else {
             log::warn!("New board name is empty or already exists");
@@ -4342,10 +5214,9 @@ fn handle_new_board_action(app: &mut App) {
             );
         }
         app.set_view(
-            *app.state
+            app.state
                 .prev_view
-                .as_ref()
-                .unwrap_or(&app.config.default_view),
+                .unwrap_or(app.config.workspace_default_view()),
         );
         if let Some(previous_focus) = &app.state.prev_focus {
             app.state.set_focus(*previous_focus);
@@ -4357,6 +5228,7 @@ fn handle_new_board_action(app: &mut App) {
     }
     if !app.filtered_boards.is_empty() {
         app.state.filter_tags = None;
+        app.state.card_filter = None;
         send_warning_toast(&mut app.widgets.toast_widget, "Filter Reset");
     }
 }
@@ -4377,8 +5249,31 @@ fn handle_general_actions_view_card(app: &mut App) -> AppReturn {
             app.set_popup(PopUp::CardStatusSelector);
             AppReturn::Continue
         }
-        Focus::CardName | Focus::CardDescription | Focus::CardTags | Focus::CardComments => {
-            handle_edit_new_card(app)
+        Focus::CardRecurrence => {
+            if app.state.card_being_edited.is_none() {
+                handle_edit_new_card(app);
+            }
+            app.set_popup(PopUp::CardRecurrenceSelector);
+            AppReturn::Continue
+        }
+        Focus::CardName
+        | Focus::CardDescription
+        | Focus::CardTags
+        | Focus::CardComments
+        | Focus::CardChecklist => handle_edit_new_card(app),
+        Focus::CardBlockedBy => {
+            let card = match &app.state.card_being_edited {
+                Some((_, card_being_edited)) => Some(card_being_edited.clone()),
+                None => app
+                    .state
+                    .current_card_id
+                    .and_then(|card_id| app.boards.find_card_with_id(card_id))
+                    .cloned(),
+            };
+            if let Some(card) = card {
+                start_blocker_search(app, &card);
+            }
+            AppReturn::Continue
         }
         Focus::CardDueDate => {
             if app.state.card_being_edited.is_none() {
@@ -4454,18 +5349,11 @@ fn handle_date_time_picker_action(app: &mut App, key: Option<Key>, action: Optio
                 | Focus::DTPMinute
                 | Focus::DTPSecond => {
                     if let Some((_, card)) = &mut app.state.card_being_edited {
-                        if let Some(selected_date) = app.widgets.date_time_picker.selected_date_time
-                        {
-                            card.due_date = selected_date
-                                .format(app.config.date_time_format.to_parser_string())
-                                .to_string();
-                        } else {
-                            card.due_date = chrono::Local::now()
-                                .naive_local()
-                                .format(app.config.date_time_format.to_parser_string())
-                                .to_string()
-                        }
-                        log::debug!("Changed due date to {}", card.due_date);
+                        card.due_date = match app.widgets.date_time_picker.selected_date_time {
+                            Some(selected_date) => naive_local_to_utc(selected_date),
+                            None => Some(Utc::now()),
+                        };
+                        log::debug!("Changed due date to {:?}", card.due_date);
                     }
                     app.widgets.date_time_picker.close_date_picker();
                 }
@@ -4501,14 +5389,11 @@ fn handle_new_card_action(app: &mut App) {
         let new_card_description = app.state.text_buffers.card_description.get_joined_lines();
         let new_card_description = new_card_description.trim();
 
-        let corrected_date_time_format =
-            DateTimeFormat::add_time_to_date_format(app.config.date_time_format);
-
         let new_card_due_date = app
             .widgets
             .date_time_picker
-            .get_date_time_as_string(corrected_date_time_format);
-        let new_card_due_date = new_card_due_date.trim();
+            .selected_date_time
+            .and_then(naive_local_to_utc);
         let mut same_name_exists = false;
         let current_board_id = app.state.current_board_id.unwrap_or((0, 0));
         let current_board = app.boards.get_board_with_id(current_board_id);
@@ -4523,10 +5408,9 @@ fn handle_new_card_action(app: &mut App) {
             log::debug!("Current board not found");
             send_error_toast(&mut app.widgets.toast_widget, "Something went wrong");
             app.set_view(
-                *app.state
+                app.state
                     .prev_view
-                    .as_ref()
-                    .unwrap_or(&app.config.default_view),
+                    .unwrap_or(app.config.workspace_default_view()),
             );
             return;
         }
@@ -4539,15 +5423,21 @@ fn handle_new_card_action(app: &mut App) {
             );
             return;
         }
+        if is_move_over_wip_limit(app, current_board_id) {
+            return;
+        }
 
+        let (new_card_priority, new_card_tags) = match &app.state.new_card_template {
+            Some(card_template) => (card_template.priority.clone(), card_template.tags.clone()),
+            None => (CardPriority::Low, vec![]),
+        };
         let new_card = Card::new(
             new_card_name,
             new_card_description,
             new_card_due_date,
-            CardPriority::Low,
-            vec![],
+            new_card_priority,
+            new_card_tags,
             vec![],
-            app.config.date_time_format,
         );
         let current_board = app.boards.get_mut_board_with_id(current_board_id);
         if let Some(current_board) = current_board {
@@ -4564,18 +5454,16 @@ fn handle_new_card_action(app: &mut App) {
             log::debug!("Current board not found");
             send_error_toast(&mut app.widgets.toast_widget, "Something went wrong");
             app.set_view(
-                *app.state
+                app.state
                     .prev_view
-                    .as_ref()
-                    .unwrap_or(&app.config.default_view),
+                    .unwrap_or(app.config.workspace_default_view()),
             );
             return;
         }
         app.set_view(
-            *app.state
+            app.state
                 .prev_view
-                .as_ref()
-                .unwrap_or(&app.config.default_view),
+                .unwrap_or(app.config.workspace_default_view()),
         );
 
         if let Some(previous_focus) = &app.state.prev_focus {
@@ -4585,11 +5473,14 @@ fn handle_new_card_action(app: &mut App) {
         reset_new_card_form(app);
     } else if app.state.focus == Focus::CardDueDate {
         app.set_popup(PopUp::DateTimePicker);
+    } else if app.state.focus == Focus::CardTemplate {
+        open_card_template_picker(app);
     } else if app.state.app_status == AppStatus::Initialized {
         app.state.app_status = AppStatus::UserInput;
     }
     if !app.filtered_boards.is_empty() {
         app.state.filter_tags = None;
+        app.state.card_filter = None;
         app.state.all_available_tags = None;
         app.state.app_list_states.filter_by_tag_list.select(None);
         send_warning_toast(&mut app.widgets.toast_widget, "Filter Reset");
@@ -5099,6 +5990,13 @@ fn handle_next_focus(app: &mut App) {
     {
         next_focus = Focus::CardName;
     }
+    if app.state.z_stack.is_empty()
+        && app.state.current_view == View::LoadLocalSave
+        && !app.state.save_diff_mode
+        && next_focus == Focus::SaveDiff
+    {
+        next_focus = Focus::Body;
+    }
     if app.state.z_stack.last() == Some(&PopUp::DateTimePicker)
         && !app.widgets.date_time_picker.time_picker_active
     {
@@ -5145,6 +6043,13 @@ fn handle_prv_focus(app: &mut App) {
     {
         prv_focus = Focus::CardComments;
     }
+    if app.state.z_stack.is_empty()
+        && app.state.current_view == View::LoadLocalSave
+        && !app.state.save_diff_mode
+        && prv_focus == Focus::SaveDiff
+    {
+        prv_focus = Focus::Body;
+    }
     if app.state.z_stack.last() == Some(&PopUp::DateTimePicker)
         && !app.widgets.date_time_picker.time_picker_active
     {
@@ -5185,7 +6090,7 @@ fn handle_save_theme_prompt(app: &mut App) {
             Duration::from_secs(5)
         );
     }
-    let default_view = app.config.default_view;
+    let default_view = app.config.workspace_default_view();
     app.set_view(default_view);
     app.all_themes.push(app.state.theme_being_edited.clone());
     app.state.theme_being_edited = Theme::default();
@@ -5223,92 +6128,533 @@ fn handle_custom_hex_color_prompt(app: &mut App, fg: bool) -> AppReturn {
     AppReturn::Continue
 }
 
-fn handle_theme_maker_scroll_up(app: &mut App) {
-    let style_index = if app.state.focus == Focus::StyleEditorFG {
-        0
-    } else if app.state.focus == Focus::StyleEditorBG {
-        1
-    } else if app.state.focus == Focus::StyleEditorModifier {
-        2
-    } else {
-        log::debug!("Invalid focus found for theme maker scroll up");
+fn handle_import_boards_prompt(app: &mut App) -> AppReturn {
+    let file_path = app.state.text_buffers.import_file_path.get_joined_lines();
+    let file_path = file_path.trim();
+    if file_path.is_empty() {
+        send_error_toast(&mut app.widgets.toast_widget, "No file path provided");
+        return AppReturn::Continue;
+    }
+    match import_boards_from_file(
+        Path::new(file_path),
+        None,
+        None,
+        app.config.date_time_format,
+    ) {
+        Ok(import_report) => {
+            for card_error in &import_report.card_errors {
+                log::warn!("{}", card_error);
+            }
+            if !import_report.card_errors.is_empty() {
+                send_warning_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!(
+                        "{} cards could not be fully imported, check the logs for details",
+                        import_report.card_errors.len()
+                    ),
+                );
+            }
+            let (boards_added, cards_added) =
+                merge_imported_boards(&mut app.boards, import_report.boards);
+            log::info!(
+                "Imported {} boards and {} cards from {}",
+                boards_added,
+                cards_added,
+                file_path
+            );
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Imported {} boards and {} cards", boards_added, cards_added),
+            );
+            app.close_popup();
+            refresh_visible_boards_and_cards(app);
+        }
+        Err(e) => {
+            log::error!("Error importing boards: {}", e);
+            send_error_toast(&mut app.widgets.toast_widget, &e);
+        }
+    }
+    AppReturn::Continue
+}
+
+pub fn open_card_template_picker(app: &mut App) {
+    if app.card_templates.is_empty() {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            "No card templates saved yet, use \"Save Current Card as Template\" to add one",
+        );
         return;
-    };
-    if app.state.app_list_states.edit_specific_style[style_index]
-        .selected()
-        .is_none()
-    {
-        app.state.app_list_states.edit_specific_style[style_index].select_first();
     }
-    let current_selected_index = app.state.app_list_states.edit_specific_style[style_index]
+    app.set_popup(PopUp::CardTemplatePicker);
+}
+
+/// Fills the new card form from the selected template, the first entry clears the template
+fn handle_card_template_selection(app: &mut App) -> AppReturn {
+    let selected_index = app
+        .state
+        .app_list_states
+        .card_template_selector
         .selected()
-        .unwrap();
-    let total_length = if app.state.focus == Focus::StyleEditorModifier {
-        TextModifierOptions::iter().count()
-    } else {
-        TextColorOptions::iter().count()
-    };
-    let next_selection = if current_selected_index > 0 {
-        current_selected_index - 1
-    } else {
-        total_length - 1
-    };
-    app.state.app_list_states.edit_specific_style[style_index].select(Some(next_selection));
+        .unwrap_or(0);
+    let card_template = selected_index
+        .checked_sub(1)
+        .and_then(|index| app.card_templates.get(index).cloned());
+    app.close_popup();
+    app.state.set_focus(Focus::CardName);
+    if let Some(card_template) = &card_template {
+        let existing_card_names: Vec<String> = app
+            .state
+            .current_board_id
+            .and_then(|board_id| app.boards.get_board_with_id(board_id))
+            .map(|board| {
+                board
+                    .cards
+                    .get_all_cards()
+                    .iter()
+                    .map(|card| card.name.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let existing_card_names: Vec<&str> =
+            existing_card_names.iter().map(String::as_str).collect();
+        app.state.text_buffers.card_name = TextBox::from_string_with_newline_sep(
+            card_template.card_name_for_board(&existing_card_names, app.config.date_time_format),
+            true,
+        );
+        app.state.text_buffers.card_description =
+            TextBox::from_string_with_newline_sep(card_template.description.clone(), false);
+        match card_template.due_date(Utc::now()) {
+            Ok(due_date) => {
+                app.widgets.date_time_picker.selected_date_time = due_date.map(utc_to_naive_local);
+            }
+            Err(e) => {
+                log::warn!("{}", e);
+                send_warning_toast(&mut app.widgets.toast_widget, &e);
+            }
+        }
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Using card template \"{}\"", card_template.name),
+        );
+    }
+    app.state.new_card_template = card_template;
+    AppReturn::Continue
 }
 
-fn handle_theme_maker_scroll_down(app: &mut App) {
-    let style_index = if app.state.focus == Focus::StyleEditorFG {
-        0
-    } else if app.state.focus == Focus::StyleEditorBG {
-        1
-    } else if app.state.focus == Focus::StyleEditorModifier {
-        2
-    } else {
-        log::debug!("Invalid focus found for theme maker scroll down");
-        return;
+fn handle_save_card_template_prompt(app: &mut App) -> AppReturn {
+    let template_name = app.state.text_buffers.card_template_name.get_joined_lines();
+    let template_name = template_name.trim();
+    if template_name.is_empty() {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "Template name cannot be empty",
+        );
+        return AppReturn::Continue;
+    }
+    let Some(card) = app
+        .state
+        .current_card_id
+        .and_then(|card_id| app.boards.find_card_with_id(card_id))
+    else {
+        send_error_toast(&mut app.widgets.toast_widget, "Could not find current card");
+        return AppReturn::Continue;
     };
-    if app.state.app_list_states.edit_specific_style[style_index]
-        .selected()
-        .is_none()
-    {
-        app.state.app_list_states.edit_specific_style[style_index].select_first();
+    let card_template = CardTemplate::from_card(template_name, card);
+    match save_card_template(&card_template) {
+        Ok(template_path) => {
+            log::info!(
+                "Saved card template \"{}\" to {}",
+                card_template.name,
+                template_path
+            );
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Saved card template \"{}\"", card_template.name),
+            );
+            app.card_templates
+                .retain(|saved_template| saved_template.name != card_template.name);
+            app.card_templates.push(card_template);
+            app.card_templates
+                .sort_by_key(|card_template| card_template.name.to_lowercase());
+            app.close_popup();
+        }
+        Err(e) => {
+            log::error!("Error saving card template: {}", e);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Error saving card template: {}", e),
+            );
+        }
     }
-    let current_selected_index = app.state.app_list_states.edit_specific_style[style_index]
+    AppReturn::Continue
+}
+
+/// Replaces the current boards with empty boards from the selected template, as a single
+/// undoable action
+fn handle_board_template_selection(app: &mut App) -> AppReturn {
+    let selected_index = app
+        .state
+        .app_list_states
+        .board_template_selector
         .selected()
-        .unwrap();
-    let total_length = if app.state.focus == Focus::StyleEditorModifier {
-        TextModifierOptions::iter().count()
-    } else {
-        TextColorOptions::iter().count()
-    };
-    let next_selection = if current_selected_index < total_length - 1 {
-        current_selected_index + 1
-    } else {
-        0
+        .unwrap_or(0);
+    let Some(board_template) = app.board_templates.get(selected_index).cloned() else {
+        send_error_toast(&mut app.widgets.toast_widget, "No board template selected");
+        return AppReturn::Continue;
     };
-    app.state.app_list_states.edit_specific_style[style_index].select(Some(next_selection));
+    app.close_popup();
+    let previous_boards = app.boards.clone();
+    let new_boards = board_template.create_boards();
+    app.boards.set_boards(new_boards.clone());
+    app.action_history_manager
+        .new_action(ActionHistory::ApplyBoardTemplate(
+            board_template.name.clone(),
+            previous_boards,
+            new_boards,
+        ));
+    update_current_board_and_card(&mut app.state, None, None);
+    refresh_visible_boards_and_cards(app);
+    if !View::views_with_kanban_board().contains(&app.state.current_view) {
+        app.set_view(app.config.workspace_default_view());
+    }
+    log::info!("Started from board template \"{}\"", board_template.name);
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!(
+            "Started from board template \"{}\", undo to get the previous boards back",
+            board_template.name
+        ),
+    );
+    AppReturn::Continue
 }
 
-fn handle_edit_new_card(app: &mut App) -> AppReturn {
-    app.state.app_status = AppStatus::UserInput;
-    if app.state.current_board_id.is_none() || app.state.current_card_id.is_none() {
-        send_error_toast(&mut app.widgets.toast_widget, "No card selected to edit");
+/// The first item creates a new workspace, the others switch to the workspace
+async fn handle_workspace_selection(app: &mut App<'_>) -> AppReturn {
+    let selected_index = app
+        .state
+        .app_list_states
+        .workspace_selector
+        .selected()
+        .unwrap_or(0);
+    if selected_index == 0 {
         app.close_popup();
+        app.set_popup(PopUp::CreateWorkspacePrompt);
         return AppReturn::Continue;
     }
-    let board = app
-        .boards
-        .get_board_with_id(app.state.current_board_id.unwrap());
-    if board.is_none() {
-        send_error_toast(
+    let Some(workspace) = app
+        .config
+        .workspace_names()
+        .get(selected_index - 1)
+        .cloned()
+    else {
+        send_error_toast(&mut app.widgets.toast_widget, "No workspace selected");
+        return AppReturn::Continue;
+    };
+    app.close_popup();
+    if workspace == app.config.active_workspace() {
+        send_info_toast(
             &mut app.widgets.toast_widget,
-            "No board found for editing card",
+            &format!("Already in workspace {}", workspace),
         );
-        app.close_popup();
         return AppReturn::Continue;
     }
-    let card = board
-        .unwrap()
+    app.dispatch(IoEvent::SwitchWorkspace(workspace)).await;
+    AppReturn::Continue
+}
+
+async fn handle_create_workspace_prompt(app: &mut App<'_>) -> AppReturn {
+    let workspace_name = app.state.text_buffers.workspace_name.get_joined_lines();
+    let mut new_config = app.config.clone();
+    let workspace = match new_config.add_workspace(&workspace_name) {
+        Ok(workspace) => workspace,
+        Err(err) => {
+            send_error_toast(&mut app.widgets.toast_widget, &err);
+            return AppReturn::Continue;
+        }
+    };
+    if let Err(err) = write_config(&new_config) {
+        log::error!("Cannot create workspace: {}", err);
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Cannot create workspace: {}", err),
+        );
+        return AppReturn::Continue;
+    }
+    app.config = new_config;
+    app.close_popup();
+    log::info!("Created workspace \"{}\"", workspace);
+    app.dispatch(IoEvent::SwitchWorkspace(workspace)).await;
+    AppReturn::Continue
+}
+
+fn handle_save_board_template_prompt(app: &mut App) -> AppReturn {
+    let template_name = app
+        .state
+        .text_buffers
+        .board_template_name
+        .get_joined_lines();
+    let template_name = template_name.trim();
+    if template_name.is_empty() {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "Template name cannot be empty",
+        );
+        return AppReturn::Continue;
+    }
+    if BoardTemplate::all_default_templates()
+        .iter()
+        .any(|board_template| board_template.name.eq_ignore_ascii_case(template_name))
+    {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "\"{}\" is an inbuilt board template, pick another name",
+                template_name
+            ),
+        );
+        return AppReturn::Continue;
+    }
+    if app.boards.is_empty() {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "There are no boards to save as a template",
+        );
+        return AppReturn::Continue;
+    }
+    let board_template = BoardTemplate::from_boards(template_name, "", &app.boards);
+    match save_board_template(&board_template) {
+        Ok(template_path) => {
+            log::info!(
+                "Saved board template \"{}\" to {}",
+                board_template.name,
+                template_path
+            );
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Saved board template \"{}\"", board_template.name),
+            );
+            app.board_templates = BoardTemplate::all_default_templates();
+            app.board_templates.extend(get_saved_board_templates());
+            app.close_popup();
+        }
+        Err(e) => {
+            log::error!("Error saving board template: {}", e);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Error saving board template: {}", e),
+            );
+        }
+    }
+    AppReturn::Continue
+}
+
+/// Applies the query, or the selected saved filter, and saves the query first if a name was
+/// given for it
+fn handle_filter_prompt(app: &mut App) -> AppReturn {
+    if app.state.focus == Focus::SavedFilters {
+        return handle_apply_saved_filter(app);
+    }
+    let card_filter =
+        match CardFilter::parse(&app.state.text_buffers.filter_query.get_joined_lines()) {
+            Ok(card_filter) => card_filter,
+            Err(e) => {
+                send_error_toast(&mut app.widgets.toast_widget, &e);
+                return AppReturn::Continue;
+            }
+        };
+    let filter_name = app
+        .state
+        .text_buffers
+        .filter_name
+        .get_joined_lines()
+        .trim()
+        .to_string();
+    if !filter_name.is_empty() {
+        app.config
+            .saved_filters
+            .insert(filter_name.clone(), card_filter.query().to_string());
+        if let Err(e) = write_config(&app.config) {
+            log::error!("Error saving filter: {}", e);
+            send_error_toast(&mut app.widgets.toast_widget, &e);
+            return AppReturn::Continue;
+        }
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Saved filter \"{}\"", filter_name),
+        );
+    }
+    let query = card_filter.query().to_string();
+    if apply_card_filter(app, card_filter) {
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Filtered by \"{}\"", query),
+        );
+        app.close_popup();
+    }
+    AppReturn::Continue
+}
+
+fn get_selected_saved_filter(app: &App) -> Option<(String, String)> {
+    let selected_index = app.state.app_list_states.saved_filters_list.selected()?;
+    app.config
+        .saved_filters
+        .iter()
+        .nth(selected_index)
+        .map(|(name, query)| (name.clone(), query.clone()))
+}
+
+fn handle_apply_saved_filter(app: &mut App) -> AppReturn {
+    let Some((filter_name, query)) = get_selected_saved_filter(app) else {
+        send_warning_toast(&mut app.widgets.toast_widget, "No saved filter selected");
+        return AppReturn::Continue;
+    };
+    match CardFilter::parse(&query) {
+        Ok(card_filter) => {
+            if apply_card_filter(app, card_filter) {
+                send_info_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("Filtered by \"{}\"", filter_name),
+                );
+                app.close_popup();
+            }
+        }
+        Err(e) => {
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Saved filter \"{}\" is invalid: {}", filter_name, e),
+            );
+        }
+    }
+    AppReturn::Continue
+}
+
+fn handle_delete_saved_filter(app: &mut App) -> AppReturn {
+    let Some((filter_name, _)) = get_selected_saved_filter(app) else {
+        return AppReturn::Continue;
+    };
+    app.config.saved_filters.remove(&filter_name);
+    if let Err(e) = write_config(&app.config) {
+        log::error!("Error deleting saved filter: {}", e);
+        send_error_toast(&mut app.widgets.toast_widget, &e);
+        return AppReturn::Continue;
+    }
+    if app.config.saved_filters.is_empty() {
+        app.state.app_list_states.saved_filters_list.select(None);
+    } else {
+        app.saved_filters_prv();
+    }
+    send_warning_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Deleted saved filter \"{}\"", filter_name),
+    );
+    AppReturn::Continue
+}
+
+async fn handle_sync_conflict_resolution(app: &mut App<'_>) -> AppReturn {
+    let resolution = match app.state.focus {
+        Focus::SubmitButton => ConflictResolution::KeepLocal,
+        Focus::ExtraFocus => ConflictResolution::KeepRemote,
+        _ => return AppReturn::Continue,
+    };
+    let all_conflicts_resolved = if let Some(pending_sync) = &mut app.state.pending_sync {
+        pending_sync.resolve_current_conflict(resolution)
+    } else {
+        app.close_popup();
+        return AppReturn::Continue;
+    };
+    if all_conflicts_resolved {
+        app.close_popup();
+        app.dispatch(IoEvent::ApplySyncResolution).await;
+    }
+    AppReturn::Continue
+}
+
+fn handle_theme_maker_scroll_up(app: &mut App) {
+    let style_index = if app.state.focus == Focus::StyleEditorFG {
+        0
+    } else if app.state.focus == Focus::StyleEditorBG {
+        1
+    } else if app.state.focus == Focus::StyleEditorModifier {
+        2
+    } else {
+        log::debug!("Invalid focus found for theme maker scroll up");
+        return;
+    };
+    if app.state.app_list_states.edit_specific_style[style_index]
+        .selected()
+        .is_none()
+    {
+        app.state.app_list_states.edit_specific_style[style_index].select_first();
+    }
+    let current_selected_index = app.state.app_list_states.edit_specific_style[style_index]
+        .selected()
+        .unwrap();
+    let total_length = if app.state.focus == Focus::StyleEditorModifier {
+        TextModifierOptions::iter().count()
+    } else {
+        TextColorOptions::iter().count()
+    };
+    let next_selection = if current_selected_index > 0 {
+        current_selected_index - 1
+    } else {
+        total_length - 1
+    };
+    app.state.app_list_states.edit_specific_style[style_index].select(Some(next_selection));
+}
+
+fn handle_theme_maker_scroll_down(app: &mut App) {
+    let style_index = if app.state.focus == Focus::StyleEditorFG {
+        0
+    } else if app.state.focus == Focus::StyleEditorBG {
+        1
+    } else if app.state.focus == Focus::StyleEditorModifier {
+        2
+    } else {
+        log::debug!("Invalid focus found for theme maker scroll down");
+        return;
+    };
+    if app.state.app_list_states.edit_specific_style[style_index]
+        .selected()
+        .is_none()
+    {
+        app.state.app_list_states.edit_specific_style[style_index].select_first();
+    }
+    let current_selected_index = app.state.app_list_states.edit_specific_style[style_index]
+        .selected()
+        .unwrap();
+    let total_length = if app.state.focus == Focus::StyleEditorModifier {
+        TextModifierOptions::iter().count()
+    } else {
+        TextColorOptions::iter().count()
+    };
+    let next_selection = if current_selected_index < total_length - 1 {
+        current_selected_index + 1
+    } else {
+        0
+    };
+    app.state.app_list_states.edit_specific_style[style_index].select(Some(next_selection));
+}
+
+fn handle_edit_new_card(app: &mut App) -> AppReturn {
+    app.state.app_status = AppStatus::UserInput;
+    if app.state.current_board_id.is_none() || app.state.current_card_id.is_none() {
+        send_error_toast(&mut app.widgets.toast_widget, "No card selected to edit");
+        app.close_popup();
+        return AppReturn::Continue;
+    }
+    let board = app
+        .boards
+        .get_board_with_id(app.state.current_board_id.unwrap());
+    if board.is_none() {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "No board found for editing card",
+        );
+        app.close_popup();
+        return AppReturn::Continue;
+    }
+    let card = board
+        .unwrap()
         .cards
         .get_card_with_id(app.state.current_card_id.unwrap());
     if card.is_none() {
@@ -5322,148 +6668,715 @@ fn handle_edit_new_card(app: &mut App) -> AppReturn {
     {
         return AppReturn::Continue;
     }
-    app.state.card_being_edited = Some((app.state.current_board_id.unwrap(), card.clone()));
-    app.state.text_buffers.card_name.reset();
-    app.state.text_buffers.card_name.insert_str(&card.name);
-    // To avoid reversing the order of the description we create a new TextBox, as insert_str reverses the order (adds them one by one)
-    app.state.text_buffers.card_description =
-        TextBox::from_string_with_newline_sep(card.description.clone(), false);
-    app.state.text_buffers.card_tags = Vec::new();
-    card.tags.iter().for_each(|tag| {
-        app.state
-            .text_buffers
-            .card_tags
-            .push(TextBox::from_string_with_newline_sep(tag.to_string(), true));
-    });
-    app.state.text_buffers.card_comments = Vec::new();
-    card.comments.iter().for_each(|comment| {
+    app.state.card_being_edited = Some((app.state.current_board_id.unwrap(), card.clone()));
+    app.state.text_buffers.card_name.reset();
+    app.state.text_buffers.card_name.insert_str(&card.name);
+    // To avoid reversing the order of the description we create a new TextBox, as insert_str reverses the order (adds them one by one)
+    app.state.text_buffers.card_description =
+        TextBox::from_string_with_newline_sep(card.description.clone(), false);
+    app.state.text_buffers.card_tags = Vec::new();
+    card.tags.iter().for_each(|tag| {
+        app.state
+            .text_buffers
+            .card_tags
+            .push(TextBox::from_string_with_newline_sep(tag.to_string(), true));
+    });
+    app.state.text_buffers.card_comments = Vec::new();
+    card.comments.iter().for_each(|comment| {
+        app.state
+            .text_buffers
+            .card_comments
+            .push(TextBox::from_string_with_newline_sep(
+                comment.body.clone(),
+                true,
+            ));
+    });
+    app.state.text_buffers.card_checklist = Vec::new();
+    card.checklist.iter().for_each(|item| {
+        app.state
+            .text_buffers
+            .card_checklist
+            .push(TextBox::from_string_with_newline_sep(
+                item.text.clone(),
+                true,
+            ));
+    });
+    if let Some(due_date) = card.due_date {
+        app.widgets.date_time_picker.selected_date_time = Some(utc_to_naive_local(due_date));
+    }
+    log::info!("Editing Card '{}'", card.name);
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Editing Card '{}'", card.name),
+    );
+    AppReturn::Continue
+}
+
+fn handle_edit_card_submit(app: &mut App) -> AppReturn {
+    if app.state.current_board_id.is_none() {
+        return AppReturn::Continue;
+    }
+    if app.state.current_card_id.is_none() {
+        return AppReturn::Continue;
+    }
+    let board = app
+        .boards
+        .get_mut_board_with_id(app.state.current_board_id.unwrap());
+    if board.is_none() {
+        return AppReturn::Continue;
+    }
+    let board = board.unwrap();
+    let card = board
+        .cards
+        .get_mut_card_with_id(app.state.current_card_id.unwrap());
+    if card.is_none() {
+        return AppReturn::Continue;
+    }
+    let card = card.unwrap();
+    let mut edited_card = if let Some(edited_card) = &app.state.card_being_edited {
+        edited_card.1.clone()
+    } else {
+        log::debug!("No card being edited found");
+        return AppReturn::Continue;
+    };
+    edited_card.description = app.state.text_buffers.card_description.get_joined_lines();
+    let card_name = app.state.text_buffers.card_name.get_joined_lines();
+    edited_card.name.clone_from(&card_name);
+    edited_card.mark_modified();
+
+    let (comment_actions, card_with_comment_changes) =
+        split_comment_changes(card, &mut edited_card, board.id);
+    for comment_action in comment_actions {
+        app.action_history_manager.new_action(comment_action);
+    }
+    let mut card_without_other_changes = card_with_comment_changes.clone();
+    card_without_other_changes.date_modified = edited_card.date_modified;
+    card_without_other_changes.revision = edited_card.revision;
+    let card_was_changed = card_without_other_changes != edited_card;
+    let card_was_completed = card_with_comment_changes.card_status != CardStatus::Complete
+        && edited_card.card_status == CardStatus::Complete;
+    *card = edited_card.clone();
+    let next_card = if card_was_completed {
+        board.add_next_occurrence(edited_card.id)
+    } else {
+        None
+    };
+    let next_card_due_date = next_card.as_ref().and_then(|next_card| next_card.due_date);
+    if let Some(next_card) = next_card {
+        app.action_history_manager
+            .new_action(ActionHistory::CompleteRecurringCard(
+                card_with_comment_changes,
+                edited_card,
+                Box::new(next_card),
+                board.id,
+            ));
+    } else if card_was_changed {
+        app.action_history_manager
+            .new_action(ActionHistory::EditCard(
+                card_with_comment_changes,
+                edited_card,
+                board.id,
+            ));
+    }
+    app.state.card_being_edited = None;
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Changes to Card '{}' saved", card_name),
+    );
+    if let Some(next_card_due_date) = next_card_due_date {
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "Added the next \"{}\", due {}",
+                card_name,
+                format_date_time(Some(next_card_due_date), app.config.date_time_format)
+            ),
+        );
+        refresh_visible_boards_and_cards(app);
+    }
+    app.state.set_focus(Focus::CardName);
+    app.state.app_status = AppStatus::Initialized;
+    let calculated_tags = app.calculate_tags();
+    if calculated_tags.is_empty() {
+        app.state.all_available_tags = None;
+    } else {
+        app.state.all_available_tags = Some(calculated_tags);
+    };
+    refresh_card_filter(app);
+    AppReturn::Continue
+}
+
+/// Comments edited or deleted while editing a card are recorded as their own actions so they
+/// can be undone one by one. Marks the edited comments as edited and returns the actions along
+/// with the old card with only those comment changes applied, which the rest of the edit is
+/// recorded against
+fn split_comment_changes(
+    old_card: &Card,
+    edited_card: &mut Card,
+    board_id: (u64, u64),
+) -> (Vec<ActionHistory>, Card) {
+    let mut comment_actions = vec![];
+    // Deleted last to first, so undoing them in reverse puts each back at its old index
+    for (comment_index, old_comment) in old_card.comments.iter().enumerate().rev() {
+        if !edited_card
+            .comments
+            .iter()
+            .any(|comment| comment.id == old_comment.id)
+        {
+            comment_actions.push(ActionHistory::DeleteComment(
+                old_comment.clone(),
+                comment_index,
+                old_card.id,
+                board_id,
+            ));
+        }
+    }
+    let mut card_with_comment_changes = old_card.clone();
+    card_with_comment_changes
+        .comments
+        .retain(|old_comment| edited_card.comments.iter().any(|c| c.id == old_comment.id));
+    let edited_at = Utc::now();
+    for comment in edited_card.comments.iter_mut() {
+        if let Some(old_comment) = old_card.comments.iter().find(|c| c.id == comment.id) {
+            if old_comment.body != comment.body {
+                comment.edited_at = Some(edited_at);
+                card_with_comment_changes.replace_comment(comment.clone());
+                comment_actions.push(ActionHistory::EditComment(
+                    old_comment.clone(),
+                    comment.clone(),
+                    old_card.id,
+                    board_id,
+                ));
+            }
+        }
+    }
+    (comment_actions, card_with_comment_changes)
+}
+
+fn handle_toggle_checklist_item(app: &mut App) -> AppReturn {
+    if app.state.z_stack.last() != Some(&PopUp::ViewCard) || app.state.focus != Focus::CardChecklist
+    {
+        return AppReturn::Continue;
+    }
+    let selected_index = app
+        .state
+        .app_list_states
+        .card_view_checklist_list
+        .selected()
+        .unwrap_or(0);
+    if let Some((_, card_being_edited)) = &mut app.state.card_being_edited {
+        if let Some(item) = card_being_edited.checklist.get_mut(selected_index) {
+            item.done = !item.done;
+        }
+        return AppReturn::Continue;
+    }
+    if app.state.current_board_id.is_none() || app.state.current_card_id.is_none() {
+        return AppReturn::Continue;
+    }
+    let board_id = app.state.current_board_id.unwrap();
+    let card = app
+        .boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| {
+            board
+                .cards
+                .get_mut_card_with_id(app.state.current_card_id.unwrap())
+        });
+    if card.is_none() {
+        return AppReturn::Continue;
+    }
+    let card = card.unwrap();
+    if selected_index >= card.checklist.len() {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            "No checklist item selected to toggle",
+        );
+        return AppReturn::Continue;
+    }
+    let old_card = card.clone();
+    card.checklist[selected_index].done = !card.checklist[selected_index].done;
+    card.mark_modified();
+    app.action_history_manager
+        .new_action(ActionHistory::EditCard(old_card, card.clone(), board_id));
+    refresh_card_filter(app);
+    AppReturn::Continue
+}
+
+fn add_blocker_to_card(app: &mut App, card_id: (u64, u64), blocker_id: (u64, u64)) {
+    let Some(blocker_name) = app
+        .boards
+        .find_card_with_id(blocker_id)
+        .map(|blocker| blocker.name.clone())
+    else {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "Could not find blocking card",
+        );
+        return;
+    };
+    if card_id == blocker_id {
+        send_error_toast(&mut app.widgets.toast_widget, "A card can not block itself");
+        return;
+    }
+    if app
+        .boards
+        .would_create_dependency_cycle(card_id, blocker_id)
+    {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "Can not add '{}' as a blocker as it is already blocked by this card",
+                blocker_name
+            ),
+        );
+        return;
+    }
+    // While the card is being edited the link is saved along with the other changes
+    if let Some((_, card_being_edited)) = &mut app.state.card_being_edited {
+        if card_being_edited.id == card_id {
+            if card_being_edited.blocked_by.contains(&blocker_id) {
+                send_warning_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("Card is already blocked by '{}'", blocker_name),
+                );
+            } else {
+                card_being_edited.blocked_by.push(blocker_id);
+            }
+            return;
+        }
+    }
+    let Some((_, board)) = app.boards.find_board_with_card_id(card_id) else {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "Could not find card to block",
+        );
+        return;
+    };
+    let board_id = board.id;
+    let card = app
+        .boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+        .unwrap();
+    if card.blocked_by.contains(&blocker_id) {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Card is already blocked by '{}'", blocker_name),
+        );
+        return;
+    }
+    let old_card = card.clone();
+    card.blocked_by.push(blocker_id);
+    card.mark_modified();
+    let info_msg = format!("Card '{}' is now blocked by '{}'", card.name, blocker_name);
+    app.action_history_manager
+        .new_action(ActionHistory::EditCard(old_card, card.clone(), board_id));
+    log::info!("{}", info_msg);
+    send_info_toast(&mut app.widgets.toast_widget, &info_msg);
+    refresh_card_filter(app);
+}
+
+fn handle_remove_blocker(app: &mut App) -> AppReturn {
+    let selected_index = app
+        .state
+        .app_list_states
+        .card_view_blocked_by_list
+        .selected()
+        .unwrap_or(0);
+    if let Some((_, card_being_edited)) = &mut app.state.card_being_edited {
+        if selected_index < card_being_edited.blocked_by.len() {
+            card_being_edited.blocked_by.remove(selected_index);
+            if selected_index > 0 && selected_index >= card_being_edited.blocked_by.len() {
+                app.state
+                    .app_list_states
+                    .card_view_blocked_by_list
+                    .select(Some(selected_index - 1));
+            }
+        }
+        return AppReturn::Continue;
+    }
+    if app.state.current_board_id.is_none() || app.state.current_card_id.is_none() {
+        return AppReturn::Continue;
+    }
+    let board_id = app.state.current_board_id.unwrap();
+    let card = app
+        .boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| {
+            board
+                .cards
+                .get_mut_card_with_id(app.state.current_card_id.unwrap())
+        });
+    let Some(card) = card else {
+        return AppReturn::Continue;
+    };
+    if selected_index >= card.blocked_by.len() {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            "No blocker selected to remove",
+        );
+        return AppReturn::Continue;
+    }
+    let old_card = card.clone();
+    card.blocked_by.remove(selected_index);
+    card.mark_modified();
+    let card_name = card.name.clone();
+    app.action_history_manager
+        .new_action(ActionHistory::EditCard(old_card, card.clone(), board_id));
+    if selected_index > 0 && selected_index >= app.get_card_view_blocked_by_len() {
         app.state
-            .text_buffers
-            .card_comments
-            .push(TextBox::from_string_with_newline_sep(
-                comment.to_string(),
-                true,
-            ));
-    });
-    if card.due_date != FIELD_NOT_SET && !card.due_date.is_empty() {
-        if let Ok(current_format) = date_format_finder(card.due_date.trim()) {
-            app.widgets.date_time_picker.selected_date_time = match NaiveDateTime::parse_from_str(
-                card.due_date.trim(),
-                current_format.to_parser_string(),
-            ) {
-                Ok(date_time) => Some(date_time),
-                Err(_) => None,
-            };
-        }
+            .app_list_states
+            .card_view_blocked_by_list
+            .select(Some(selected_index - 1));
     }
-    log::info!("Editing Card '{}'", card.name);
     send_info_toast(
         &mut app.widgets.toast_widget,
-        &format!("Editing Card '{}'", card.name),
+        &format!("Removed a blocker of '{}'", card_name),
     );
+    refresh_card_filter(app);
     AppReturn::Continue
 }
 
-fn handle_edit_card_submit(app: &mut App) -> AppReturn {
-    let mut need_to_send_warning_toast = false;
-    let mut warning_due_date = String::new();
-    if app.state.current_board_id.is_none() {
-        return AppReturn::Continue;
+/// Sets the sort mode of the current board, or sorts it once when picked from the sort once
+/// popup, either way the new order is recorded so it can be undone
+/// The action history popup lists the newest action first with the start of the history last,
+/// selecting an entry undoes or redoes actions until that entry is the last one applied
+fn handle_action_history_selection(app: &mut App) -> AppReturn {
+    let history_len = app.action_history_manager.history.len();
+    let selected_index = app.state.app_list_states.action_history.selected();
+    app.close_popup();
+    if let Some(selected_index) = selected_index.filter(|index| *index <= history_len) {
+        app.jump_to_history_index(history_len - selected_index);
     }
-    if app.state.current_card_id.is_none() {
+    AppReturn::Continue
+}
+
+fn get_selected_archived_card(app: &App) -> Option<((u64, u64), ArchivedCard)> {
+    let selected_index = app.state.app_list_states.archived_cards.selected()?;
+    let query = app.state.text_buffers.archive_search.get_joined_lines();
+    app.boards
+        .search_archived_cards(&query)
+        .get(selected_index)
+        .map(|(board, archived_card)| (board.id, (*archived_card).clone()))
+}
+
+/// Keeps the selection of the archive popup on a card after the list got shorter
+fn clamp_archived_cards_selection(app: &mut App) {
+    let query = app.state.text_buffers.archive_search.get_joined_lines();
+    let archived_cards_len = app.boards.search_archived_cards(&query).len();
+    let selected_index = app
+        .state
+        .app_list_states
+        .archived_cards
+        .selected()
+        .unwrap_or(0);
+    app.state
+        .app_list_states
+        .archived_cards
+        .select((archived_cards_len > 0).then(|| selected_index.min(archived_cards_len - 1)));
+}
+
+fn handle_restore_archived_card(app: &mut App) -> AppReturn {
+    let Some((board_id, archived_card)) = get_selected_archived_card(app) else {
+        send_warning_toast(&mut app.widgets.toast_widget, "No archived card selected");
+        return AppReturn::Continue;
+    };
+    if is_move_over_wip_limit(app, board_id) {
         return AppReturn::Continue;
     }
-    let board = app
+    let card_id = archived_card.card.id;
+    if app
         .boards
-        .get_mut_board_with_id(app.state.current_board_id.unwrap());
-    if board.is_none() {
+        .restore_archived_card(board_id, card_id)
+        .is_none()
+    {
         return AppReturn::Continue;
     }
-    let board = board.unwrap();
-    let card = board
-        .cards
-        .get_mut_card_with_id(app.state.current_card_id.unwrap());
-    if card.is_none() {
+    log::info!("Restored card {}", archived_card.card.name);
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Restored card {}", archived_card.card.name),
+    );
+    app.action_history_manager
+        .new_action(ActionHistory::RestoreArchivedCard(board_id, archived_card));
+    clamp_archived_cards_selection(app);
+    refresh_card_filter(app);
+    refresh_visible_boards_and_cards(app);
+    AppReturn::Continue
+}
+
+fn handle_delete_archived_card(app: &mut App) -> AppReturn {
+    let Some((board_id, archived_card)) = get_selected_archived_card(app) else {
         return AppReturn::Continue;
-    }
-    let card = card.unwrap();
-    let mut edited_card = if let Some(edited_card) = &app.state.card_being_edited {
-        edited_card.1.clone()
+    };
+    let Some(board) = app.boards.get_mut_board_with_id(board_id) else {
+        return AppReturn::Continue;
+    };
+    let Some(archive_index) = board
+        .archived_cards
+        .iter()
+        .position(|board_archived_card| board_archived_card.card.id == archived_card.card.id)
+    else {
+        return AppReturn::Continue;
+    };
+    board.archived_cards.remove(archive_index);
+    log::warn!("Permanently deleted card {}", archived_card.card.name);
+    send_warning_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Permanently deleted card {}", archived_card.card.name),
+    );
+    app.action_history_manager
+        .new_action(ActionHistory::DeleteArchivedCard(
+            board_id,
+            archived_card,
+            archive_index,
+        ));
+    clamp_archived_cards_selection(app);
+    AppReturn::Continue
+}
+
+fn handle_board_sort_mode_selection(app: &mut App) -> AppReturn {
+    let sort_once = app.state.z_stack.last() == Some(&PopUp::SortBoardOnce);
+    let sort_modes = if sort_once {
+        BoardSortMode::all_automatic()
     } else {
-        log::debug!("No card being edited found");
+        BoardSortMode::all()
+    };
+    let selected_sort_mode = app
+        .state
+        .app_list_states
+        .board_sort_mode_selector
+        .selected()
+        .and_then(|index| sort_modes.get(index).copied());
+    app.close_popup();
+    let Some(selected_sort_mode) = selected_sort_mode else {
+        return AppReturn::Continue;
+    };
+    let Some(board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_mut_board_with_id(board_id))
+    else {
+        send_warning_toast(&mut app.widgets.toast_widget, "No board selected to sort");
         return AppReturn::Continue;
     };
-    let card_due_date = edited_card.due_date.clone();
-    let parsed_due_date = date_format_converter(card_due_date.trim(), app.config.date_time_format);
-    let parsed_date = match parsed_due_date {
-        Ok(date) => {
-            if date.is_empty() {
-                FIELD_NOT_SET.to_string()
+    if sort_once && board.sort_mode != BoardSortMode::Manual {
+        let warning_msg = format!(
+            "Board '{}' is already sorted by {}",
+            board.name, board.sort_mode
+        );
+        send_warning_toast(&mut app.widgets.toast_widget, &warning_msg);
+        return AppReturn::Continue;
+    }
+    let old_ordering = board.get_ordering();
+    if sort_once {
+        board.cards.sort_by_mode(selected_sort_mode);
+    } else {
+        board.set_sort_mode(selected_sort_mode);
+    }
+    let new_ordering = board.get_ordering();
+    let info_msg = if sort_once {
+        format!("Sorted board '{}' by {}", board.name, selected_sort_mode)
+    } else {
+        format!(
+            "Board '{}' is now sorted {}",
+            board.name,
+            if selected_sort_mode == BoardSortMode::Manual {
+                "manually".to_string()
             } else {
-                date
-            }
-        }
-        Err(_) => {
-            if card_due_date.trim() != FIELD_NOT_SET {
-                need_to_send_warning_toast = true;
-                warning_due_date = card_due_date;
+                format!("by {}", selected_sort_mode)
             }
-            FIELD_NOT_SET.to_string()
-        }
+        )
     };
-    edited_card.due_date = parsed_date;
-    edited_card.date_modified = chrono::Local::now()
-        .format(app.config.date_time_format.to_parser_string())
-        .to_string();
-    app.action_history_manager
-        .new_action(ActionHistory::EditCard(
-            card.clone(),
-            edited_card.clone(),
-            board.id,
-        ));
-    *card = edited_card;
+    if new_ordering != old_ordering {
+        app.action_history_manager
+            .new_action(ActionHistory::SortBoard(
+                board.id,
+                old_ordering,
+                new_ordering,
+            ));
+    }
+    log::info!("{}", info_msg);
+    send_info_toast(&mut app.widgets.toast_widget, &info_msg);
+    refresh_card_filter(app);
+    refresh_visible_boards_and_cards(app);
+    AppReturn::Continue
+}
 
-    let description = app.state.text_buffers.card_description.get_joined_lines();
-    card.description = description;
+/// An empty value means no limit
+fn parse_wip_limit(value: &str) -> Result<Option<u32>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    match value.parse::<u32>() {
+        Ok(wip_limit) if wip_limit > 0 => Ok(Some(wip_limit)),
+        _ => Err(format!(
+            "Invalid WIP limit \"{}\", it must be a positive number or empty for no limit",
+            value
+        )),
+    }
+}
 
-    let card_name = app.state.text_buffers.card_name.get_joined_lines();
-    card.name.clone_from(&card_name);
-    app.state.card_being_edited = None;
-    if need_to_send_warning_toast {
-        let all_date_formats = DateTimeFormat::get_all_date_formats()
-            .iter()
-            .map(|x| x.to_human_readable_string())
-            .collect::<Vec<&str>>()
-            .join(", ");
-        send_warning_toast_with_duration(
+/// Applies the edit board popup to the current board, the board name can not be empty or the
+/// name of another board
+fn handle_edit_board_submit(app: &mut App) -> AppReturn {
+    let board_name = app.state.text_buffers.board_name.get_joined_lines();
+    let board_name = board_name.trim();
+    let board_description = app.state.text_buffers.board_description.get_joined_lines();
+    let board_description = board_description.trim();
+    let wip_limit =
+        match parse_wip_limit(&app.state.text_buffers.board_wip_limit.get_joined_lines()) {
+            Ok(wip_limit) => wip_limit,
+            Err(error) => {
+                send_warning_toast(&mut app.widgets.toast_widget, &error);
+                return AppReturn::Continue;
+            }
+        };
+    let Some(current_board_id) = app.state.current_board_id else {
+        send_error_toast(
             &mut app.widgets.toast_widget,
-            &format!(
-                "Invalid date format '{}'. Please use any of the following {}. Date has been reset and other changes have been saved.",
-                warning_due_date, all_date_formats
-            ),
-            Duration::from_secs(10),
+            "Could not find the board to edit",
         );
-        log::warn!(
-            "Invalid date format '{}'. Please use any of the following {}. Date has been reset and other changes have been saved.",
-            warning_due_date, all_date_formats
+        app.close_popup();
+        return AppReturn::Continue;
+    };
+    if board_name.is_empty() {
+        send_warning_toast(&mut app.widgets.toast_widget, "Board name cannot be empty");
+        return AppReturn::Continue;
+    }
+    let same_name_exists = app
+        .boards
+        .get_boards()
+        .iter()
+        .any(|board| board.id != current_board_id && board.name == board_name);
+    if same_name_exists {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!("A board named '{}' already exists", board_name),
         );
+        return AppReturn::Continue;
     }
-    send_info_toast(
-        &mut app.widgets.toast_widget,
-        &format!("Changes to Card '{}' saved", card_name),
+    let Some(board) = app.boards.get_mut_board_with_id(current_board_id) else {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "Could not find the board to edit",
+        );
+        app.close_popup();
+        return AppReturn::Continue;
+    };
+    let old_board = Board {
+        cards: Cards::default(),
+        ..board.clone()
+    };
+    board.name = board_name.to_string();
+    board.description = board_description.to_string();
+    board.wip_limit = wip_limit;
+    let new_board = Board {
+        cards: Cards::default(),
+        ..board.clone()
+    };
+    if board.is_over_wip_limit() {
+        let warning_msg = format!(
+            "Board '{}' already has {} cards which is over its WIP limit",
+            board.name,
+            board.cards.len()
+        );
+        send_warning_toast(&mut app.widgets.toast_widget, &warning_msg);
+    }
+    app.close_popup();
+    if old_board == new_board {
+        send_info_toast(&mut app.widgets.toast_widget, "No changes to the board");
+        return AppReturn::Continue;
+    }
+    let info_msg = format!("Edited board '{}'", new_board.name);
+    app.action_history_manager
+        .new_action(ActionHistory::EditBoard(old_board, new_board));
+    log::info!("{}", info_msg);
+    send_info_toast(&mut app.widgets.toast_widget, &info_msg);
+    refresh_card_filter(app);
+    refresh_visible_boards_and_cards(app);
+    AppReturn::Continue
+}
+
+/// Sends a warning toast and returns true if the current board is not manually sorted, the
+/// cards of sorted boards can not be moved around
+fn is_current_board_sorted(app: &mut App) -> bool {
+    let Some(current_board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+    else {
+        return false;
+    };
+    if current_board.sort_mode == BoardSortMode::Manual {
+        return false;
+    }
+    let warning_msg = format!(
+        "Board '{}' is sorted by {}, change its sort mode to Manual to move cards within it",
+        current_board.name, current_board.sort_mode
     );
-    app.state.set_focus(Focus::CardName);
-    app.state.app_status = AppStatus::Initialized;
-    let calculated_tags = app.calculate_tags();
-    if calculated_tags.is_empty() {
-        app.state.all_available_tags = None;
+    send_warning_toast(&mut app.widgets.toast_widget, &warning_msg);
+    true
+}
+
+/// Sends an error toast and returns true if the current card is blocked and the board next to
+/// the current one (in the direction of the move) is in progress, or if that board is at its
+/// WIP limit and WIP limits are enforced
+fn is_move_to_adjacent_board_blocked(
+    app: &mut App,
+    current_board_id: (u64, u64),
+    move_right: bool,
+) -> bool {
+    let Some(current_card_id) = app.state.current_card_id else {
+        return false;
+    };
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
     } else {
-        app.state.all_available_tags = Some(calculated_tags);
+        &app.filtered_boards
     };
-    if !app.filtered_boards.is_empty() {
-        filter_boards(app);
+    let moved_to_board_id = boards
+        .get_board_index(current_board_id)
+        .and_then(|index| {
+            if move_right {
+                index.checked_add(1)
+            } else {
+                index.checked_sub(1)
+            }
+        })
+        .and_then(|index| boards.get_board_with_index(index))
+        .map(|board| board.id);
+    let Some(moved_to_board_id) = moved_to_board_id else {
+        return false;
+    };
+    match app
+        .boards
+        .get_blocked_move_error(current_card_id, moved_to_board_id)
+    {
+        Some(error) => {
+            log::warn!("{}", error);
+            send_error_toast(&mut app.widgets.toast_widget, &error);
+            true
+        }
+        None => is_move_over_wip_limit(app, moved_to_board_id),
+    }
+}
+
+/// Checks if adding a card to the board would take it past its WIP limit. Only warns about it
+/// unless WIP limits are enforced, returns true if the move should not happen
+fn is_move_over_wip_limit(app: &mut App, to_board_id: (u64, u64)) -> bool {
+    let Some(error) = app.boards.get_wip_limit_error(to_board_id) else {
+        return false;
+    };
+    log::warn!("{}", error);
+    if app.config.enforce_wip_limits {
+        send_error_toast(&mut app.widgets.toast_widget, &error);
+        true
+    } else {
+        send_warning_toast(&mut app.widgets.toast_widget, &error);
+        false
     }
-    AppReturn::Continue
 }
 
 fn handle_filter_by_tag(app: &mut App) {
@@ -5510,52 +7423,106 @@ fn handle_filter_by_tag(app: &mut App) {
                 app.state.filter_tags = Some(filter_tags);
             }
         }
-        Focus::SubmitButton => filter_boards(app),
+        Focus::SubmitButton => apply_tag_filter(app),
         _ => {}
     }
 }
 
-fn filter_boards(app: &mut App) {
-    if app.state.filter_tags.is_none() {
+fn apply_tag_filter(app: &mut App) {
+    let Some(filter_tags) = app.state.filter_tags.clone() else {
         send_warning_toast(&mut app.widgets.toast_widget, "No tags selected to filter");
         app.close_popup();
         return;
+    };
+    if apply_card_filter(app, CardFilter::from_tags(&filter_tags)) {
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Filtered by {} tags", filter_tags.len()),
+        );
+    }
+    app.close_popup();
+    app.state.app_list_states.filter_by_tag_list.select(None);
+}
+
+/// Returns false and leaves the current view alone if no card matches the filter
+pub fn apply_card_filter(app: &mut App, card_filter: CardFilter) -> bool {
+    let new_query = card_filter.query().to_string();
+    let previous_filter = app.state.card_filter.replace(card_filter);
+    update_current_board_and_card(&mut app.state, None, None);
+    if build_filtered_boards(app) == 0 {
+        send_warning_toast(&mut app.widgets.toast_widget, "No cards match the filter");
+        app.state.card_filter = previous_filter;
+        build_filtered_boards(app);
+        return false;
+    }
+    let previous_query = previous_filter.map(|card_filter| card_filter.query().to_string());
+    if previous_query.as_deref() != Some(new_query.as_str()) {
+        app.action_history_manager
+            .new_action(ActionHistory::ChangeFilter(previous_query, Some(new_query)));
     }
-    let all_boards = app.boards.clone();
+    true
+}
 
+/// Sets the filter to `query` without recording it, used by undo and redo
+pub fn restore_card_filter(app: &mut App, query: Option<&str>) -> Result<(), String> {
+    app.state.card_filter = query.map(CardFilter::parse).transpose()?;
+    app.state.filter_tags = None;
     update_current_board_and_card(&mut app.state, None, None);
+    if app.state.card_filter.is_some() {
+        refresh_card_filter(app);
+    } else {
+        app.filtered_boards.reset();
+    }
+    refresh_visible_boards_and_cards(app);
+    Ok(())
+}
+
+/// Rebuilds the filtered view after cards were changed, the filter is cleared once no card
+/// matches it anymore
+pub fn refresh_card_filter(app: &mut App) {
+    if app.state.card_filter.is_some() && build_filtered_boards(app) == 0 {
+        app.state.card_filter = None;
+        app.state.filter_tags = None;
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            "No cards match the filter anymore, filter cleared",
+        );
+    }
+}
 
-    let filter_tags = app.state.filter_tags.clone().unwrap();
+/// Builds `App::filtered_boards` from `AppState::card_filter` and returns the number of
+/// matching cards
+fn build_filtered_boards(app: &mut App) -> usize {
     let mut filtered_boards = Vec::new();
-    for board in all_boards.get_boards() {
-        let mut filtered_cards = Vec::new();
-        for card in board.cards.get_all_cards() {
-            let mut card_tags = card.tags.clone();
-            card_tags.retain(|tag| filter_tags.contains(&tag.to_lowercase()));
-            if !card_tags.is_empty() {
-                filtered_cards.push(card.clone());
-            }
-        }
-        if !filtered_cards.is_empty() {
-            filtered_boards.push(Board {
-                id: board.id,
-                name: board.name.clone(),
-                description: board.description.clone(),
-                cards: Cards::from(filtered_cards),
-            });
+    let mut matching_cards = 0;
+    if let Some(card_filter) = &app.state.card_filter {
+        for board in app.boards.get_boards() {
+            let filtered_cards: Vec<Card> = board
+                .cards
+                .get_all_cards()
+                .iter()
+                .filter(|card| card_filter.matches(card))
+                .cloned()
+                .collect();
+            if !filtered_cards.is_empty() {
+                matching_cards += filtered_cards.len();
+                filtered_boards.push(Board {
+                    archived_cards: Vec::new(),
+                    id: board.id,
+                    name: board.name.clone(),
+                    description: board.description.clone(),
+                    cards: Cards::from(filtered_cards),
+                    in_progress: board.in_progress,
+                    manual_order: Vec::new(),
+                    sort_mode: board.sort_mode,
+                    wip_limit: board.wip_limit,
+                });
+            }
         }
     }
     app.filtered_boards = Boards::from(filtered_boards);
     refresh_visible_boards_and_cards(app);
-    send_info_toast(
-        &mut app.widgets.toast_widget,
-        &format!(
-            "Filtered by {} tags",
-            app.state.filter_tags.clone().unwrap().len()
-        ),
-    );
-    app.close_popup();
-    app.state.app_list_states.filter_by_tag_list.select(None);
+    matching_cards
 }
 
 fn handle_command_palette_card_selection(app: &mut App) {
@@ -5581,6 +7548,14 @@ fn handle_command_palette_card_selection(app: &mut App) {
         return;
     }
     let card_id = all_card_details[card_details_index].1;
+    if let Some(blocked_card_id) = app.widgets.command_palette.pending_blocker_link.take() {
+        add_blocker_to_card(app, blocked_card_id, card_id);
+        app.widgets.command_palette.already_in_user_input_mode = false;
+        if app.state.z_stack.last() == Some(&PopUp::ViewCard) {
+            app.state.set_focus(Focus::CardBlockedBy);
+        }
+        return;
+    }
     let new_current_board_id = Some(app.boards.find_board_with_card_id(card_id).unwrap().1.id);
     let new_current_card_id = Some(card_id);
 
@@ -5664,12 +7639,14 @@ pub async fn handle_send_reset_password_link_action(app: &mut App<'_>) {
 fn reset_new_board_form(app: &mut App) {
     app.state.text_buffers.board_name.reset();
     app.state.text_buffers.board_description.reset();
+    app.state.text_buffers.board_wip_limit.reset();
 }
 
-fn reset_new_card_form(app: &mut App) {
+pub fn reset_new_card_form(app: &mut App) {
     app.state.text_buffers.card_name.reset();
     app.state.text_buffers.card_description.reset();
     app.widgets.date_time_picker.reset();
+    app.state.new_card_template = None;
 }
 
 fn reset_login_form(app: &mut App) {
@@ -5795,4 +7772,81 @@ pub fn reset_preview_boards(app: &mut App) {
     app.preview_boards_and_cards = None;
     app.state.preview_file_name = None;
     app.state.preview_visible_boards_and_cards = LinkedHashMap::new();
+    app.state.save_diff = None;
+    app.state.save_diff_mode = false;
+    app.state.save_diff_target = None;
+    app.state.app_list_states.save_diff = ListState::default();
+}
+
+/// The preview of the selected save is replaced with its changes while comparing saves
+async fn load_local_save_preview(app: &mut App<'_>) {
+    if app.state.save_diff_mode {
+        app.dispatch(IoEvent::LoadSaveDiff).await;
+    } else {
+        app.dispatch(IoEvent::LoadLocalPreview).await;
+    }
+}
+
+async fn toggle_save_diff_mode(app: &mut App<'_>) {
+    if app.state.save_diff_mode {
+        app.state.save_diff_mode = false;
+        app.state.save_diff = None;
+        app.state.app_list_states.save_diff = ListState::default();
+        app.state.set_focus(Focus::Body);
+        load_local_save_preview(app).await;
+        return;
+    }
+    if get_available_local_save_files(&app.config).is_none_or(|save_files| save_files.is_empty()) {
+        send_warning_toast(&mut app.widgets.toast_widget, "No saves to compare");
+        return;
+    }
+    if app.state.app_list_states.load_save.selected().is_none() {
+        app.state.app_list_states.load_save.select(Some(0));
+    }
+    app.state.save_diff_mode = true;
+    app.state.save_diff_target = None;
+    load_local_save_preview(app).await;
+}
+
+/// Puts the card of the selected change back the way it is in the older save
+fn handle_restore_card_from_save_diff(app: &mut App) {
+    let Some(save_diff) = &app.state.save_diff else {
+        return;
+    };
+    let Some(entry) = app
+        .state
+        .app_list_states
+        .save_diff
+        .selected()
+        .and_then(|index| save_diff.entries.get(index))
+    else {
+        return;
+    };
+    let Some(older_card) = &entry.older_card else {
+        let message = match entry.change {
+            SaveDiffChange::CardAdded => {
+                format!("'{}' is not in '{}'", entry.name, save_diff.older_name)
+            }
+            _ => "Only cards can be restored".to_string(),
+        };
+        send_warning_toast(&mut app.widgets.toast_widget, &message);
+        return;
+    };
+    let card_name = older_card.card.name.clone();
+    let save_name = save_diff.older_name.clone();
+    let previous_boards = app.boards.clone();
+    older_card.restore(&mut app.boards);
+    let boards = app.boards.clone();
+    app.action_history_manager
+        .new_action(ActionHistory::RestoreCardFromSave(
+            card_name.clone(),
+            save_name.clone(),
+            previous_boards,
+            boards,
+        ));
+    refresh_visible_boards_and_cards(app);
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Restored card '{}' from '{}'", card_name, save_name),
+    );
 }
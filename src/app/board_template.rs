@@ -0,0 +1,95 @@
+use crate::app::{
+    inbuilt_board_templates::{basic_kanban_template, bug_triage_template, scrum_sprint_template},
+    kanban::{Board, Boards},
+};
+use serde::{Deserialize, Serialize};
+
+/// A set of boards a fresh workspace can start from, the inbuilt ones live in
+/// inbuilt_board_templates.rs and the user defined ones are saved as json in the board templates
+/// folder of the config directory
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BoardTemplate {
+    pub name: String,
+    pub description: String,
+    pub boards: Vec<BoardTemplateBoard>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BoardTemplateBoard {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub in_progress: bool,
+    #[serde(default)]
+    pub wip_limit: Option<u32>,
+}
+
+impl BoardTemplateBoard {
+    pub fn new(name: &str, description: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            in_progress: false,
+            wip_limit: None,
+        }
+    }
+
+    pub fn in_progress(mut self, wip_limit: Option<u32>) -> Self {
+        self.in_progress = true;
+        self.wip_limit = wip_limit;
+        self
+    }
+}
+
+impl BoardTemplate {
+    pub fn all_default_templates() -> Vec<BoardTemplate> {
+        vec![
+            basic_kanban_template(),
+            scrum_sprint_template(),
+            bug_triage_template(),
+        ]
+    }
+
+    /// Keeps the boards and their settings, cards are left out
+    pub fn from_boards(name: &str, description: &str, boards: &Boards) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            description: description.to_string(),
+            boards: boards
+                .get_boards()
+                .iter()
+                .map(|board| BoardTemplateBoard {
+                    name: board.name.clone(),
+                    description: board.description.clone(),
+                    in_progress: board.in_progress,
+                    wip_limit: board.wip_limit,
+                })
+                .collect(),
+        }
+    }
+
+    /// Empty boards with new ids, ready to replace the current ones
+    pub fn create_boards(&self) -> Boards {
+        Boards::from(
+            self.boards
+                .iter()
+                .map(|template_board| {
+                    let mut board = Board::new(&template_board.name, &template_board.description);
+                    board.in_progress = template_board.in_progress;
+                    board.wip_limit = template_board.wip_limit;
+                    board
+                })
+                .collect::<Vec<Board>>(),
+        )
+    }
+
+    /// Board names in order, used next to the template name in lists
+    pub fn summary(&self) -> String {
+        self.boards
+            .iter()
+            .map(|board| board.name.as_str())
+            .collect::<Vec<&str>>()
+            .join(" → ")
+    }
+}
@@ -0,0 +1,444 @@
+use crate::app::kanban::{Card, CardPriority, CardStatus};
+use chrono::{Duration, Utc};
+use std::{fmt, str::FromStr};
+
+/// A parsed filter query like `tag:bug priority:high due:<7d text:"login" -tag:wontfix`.
+/// Every term has to match for a card to match, comma separated values in a term match if any
+/// of them does (`tag:bug,ui`) and a leading `-` negates a term. Words without a key are
+/// searched for in the card text
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardFilter {
+    query: String,
+    terms: Vec<FilterTerm>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct FilterTerm {
+    negated: bool,
+    condition: FilterCondition,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FilterCondition {
+    /// Lowercase tags, any of them
+    Tag(Vec<String>),
+    Priority(Vec<CardPriority>),
+    Status(Vec<CardStatus>),
+    Due(DueCondition),
+    /// Lowercase text, any of them
+    Text(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DueCondition {
+    /// Due before now plus the duration, overdue cards included
+    Within(Duration),
+    /// Due after now plus the duration
+    After(Duration),
+    Overdue,
+    /// Has a due date
+    Any,
+    /// Has no due date
+    NotSet,
+}
+
+impl CardFilter {
+    pub fn parse(query: &str) -> Result<Self, String> {
+        if !query.matches('"').count().is_multiple_of(2) {
+            return Err("Filter query has an unclosed quote".to_string());
+        }
+        let terms = split_outside_quotes(query, char::is_whitespace)
+            .into_iter()
+            .map(parse_term)
+            .collect::<Result<Vec<FilterTerm>, String>>()?;
+        if terms.is_empty() {
+            return Err("Filter query is empty".to_string());
+        }
+        Ok(Self {
+            query: query.trim().to_string(),
+            terms,
+        })
+    }
+
+    /// Matches cards that have any of the tags, used by the filter by tag popup
+    pub fn from_tags(tags: &[String]) -> Self {
+        let query_values: Vec<String> = tags.iter().map(|tag| quote_if_needed(tag)).collect();
+        Self {
+            query: format!("tag:{}", query_values.join(",")),
+            terms: vec![FilterTerm {
+                negated: false,
+                condition: FilterCondition::Tag(
+                    tags.iter().map(|tag| tag.to_lowercase()).collect(),
+                ),
+            }],
+        }
+    }
+
+    pub fn matches(&self, card: &Card) -> bool {
+        self.terms
+            .iter()
+            .all(|term| term.condition.matches(card) != term.negated)
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+}
+
+impl FromStr for CardFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for CardFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.query)
+    }
+}
+
+impl FilterCondition {
+    fn matches(&self, card: &Card) -> bool {
+        match self {
+            FilterCondition::Tag(tags) => card
+                .tags
+                .iter()
+                .any(|card_tag| tags.contains(&card_tag.to_lowercase())),
+            FilterCondition::Priority(priorities) => priorities.contains(&card.priority),
+            FilterCondition::Status(statuses) => statuses.contains(&card.card_status),
+            FilterCondition::Due(due_condition) => due_condition.matches(card),
+            FilterCondition::Text(texts) => texts.iter().any(|text| card_contains_text(card, text)),
+        }
+    }
+}
+
+impl DueCondition {
+    fn matches(&self, card: &Card) -> bool {
+        let now = Utc::now();
+        match (self, card.due_date) {
+            (DueCondition::NotSet, due_date) => due_date.is_none(),
+            (_, None) => false,
+            (DueCondition::Any, Some(_)) => true,
+            (DueCondition::Overdue, Some(due_date)) => {
+                due_date < now && card.card_status != CardStatus::Complete
+            }
+            (DueCondition::Within(duration), Some(due_date)) => now
+                .checked_add_signed(*duration)
+                .is_some_and(|limit| due_date < limit),
+            (DueCondition::After(duration), Some(due_date)) => now
+                .checked_add_signed(*duration)
+                .is_some_and(|limit| due_date > limit),
+        }
+    }
+}
+
+fn card_contains_text(card: &Card, text: &str) -> bool {
+    card.name.to_lowercase().contains(text)
+        || card.description.to_lowercase().contains(text)
+        || card
+            .tags
+            .iter()
+            .any(|tag| tag.to_lowercase().contains(text))
+        || card
+            .comments
+            .iter()
+            .any(|comment| comment.body.to_lowercase().contains(text))
+        || card
+            .checklist
+            .iter()
+            .any(|item| item.text.to_lowercase().contains(text))
+}
+
+fn parse_term(token: &str) -> Result<FilterTerm, String> {
+    let (negated, token) = match token.strip_prefix('-') {
+        Some(token) if !token.is_empty() => (true, token),
+        _ => (false, token),
+    };
+    let (key, value) = match token.split_once(':') {
+        Some((key, value)) if !key.contains('"') => (key.to_lowercase(), value),
+        _ => ("text".to_string(), token),
+    };
+    let values: Vec<String> = split_outside_quotes(value, |c| c == ',')
+        .into_iter()
+        .map(unquote)
+        .filter(|value| !value.is_empty())
+        .collect();
+    if values.is_empty() {
+        return Err(format!("No value given for \"{}\"", key));
+    }
+    let condition = match key.as_str() {
+        "tag" => FilterCondition::Tag(values.iter().map(|tag| tag.to_lowercase()).collect()),
+        "priority" => FilterCondition::Priority(
+            values
+                .iter()
+                .map(|priority| CardPriority::from_str(priority))
+                .collect::<Result<Vec<CardPriority>, String>>()?,
+        ),
+        "status" => FilterCondition::Status(
+            values
+                .iter()
+                .map(|status| CardStatus::from_str(status))
+                .collect::<Result<Vec<CardStatus>, String>>()?,
+        ),
+        "due" => {
+            if values.len() > 1 {
+                return Err("\"due\" only takes a single value".to_string());
+            }
+            FilterCondition::Due(parse_due_condition(&values[0])?)
+        }
+        "text" => FilterCondition::Text(values.iter().map(|text| text.to_lowercase()).collect()),
+        _ => {
+            return Err(format!(
+                "Unknown filter \"{}\", expected tag, priority, status, due or text",
+                key
+            ))
+        }
+    };
+    Ok(FilterTerm { negated, condition })
+}
+
+/// `<7d`, `>2w`, `<12h`, `overdue`, `any` or `none`
+fn parse_due_condition(value: &str) -> Result<DueCondition, String> {
+    let invalid_due = || {
+        format!(
+            "Invalid due filter \"{}\", expected something like <7d, >2w, overdue, any or none",
+            value
+        )
+    };
+    match value.to_lowercase().as_str() {
+        "overdue" => return Ok(DueCondition::Overdue),
+        "any" => return Ok(DueCondition::Any),
+        "none" => return Ok(DueCondition::NotSet),
+        _ => {}
+    }
+    let (is_within, duration) = if let Some(duration) = value.strip_prefix('<') {
+        (true, duration)
+    } else if let Some(duration) = value.strip_prefix('>') {
+        (false, duration)
+    } else {
+        return Err(invalid_due());
+    };
+    let duration = parse_duration(duration).ok_or_else(invalid_due)?;
+    if is_within {
+        Ok(DueCondition::Within(duration))
+    } else {
+        Ok(DueCondition::After(duration))
+    }
+}
+
+/// `12h`, `7d` or `2w`, durations that would take the current time past the dates chrono can
+/// represent are rejected
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let unit = value.chars().last()?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    let duration = match unit {
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }?;
+    Utc::now().checked_add_signed(duration).map(|_| duration)
+}
+
+/// Splits on every character matching `is_separator` that is not inside double quotes, the
+/// quotes are kept and empty parts are dropped
+fn split_outside_quotes(value: &str, is_separator: impl Fn(char) -> bool) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut in_quotes = false;
+    let mut part_start = 0;
+    for (index, c) in value.char_indices() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        } else if !in_quotes && is_separator(c) {
+            parts.push(&value[part_start..index]);
+            part_start = index + c.len_utf8();
+        }
+    }
+    parts.push(&value[part_start..]);
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+fn unquote(value: &str) -> String {
+    value.replace('"', "").trim().to_string()
+}
+
+fn quote_if_needed(value: &str) -> String {
+    if value.contains(|c: char| c.is_whitespace() || c == ',' || c == ':') {
+        format!("\"{}\"", value)
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn term(negated: bool, condition: FilterCondition) -> FilterTerm {
+        FilterTerm { negated, condition }
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn card(name: &str, tags: &[&str], priority: CardPriority) -> Card {
+        Card::new(name, "", None, priority, strings(tags), vec![])
+    }
+
+    #[test]
+    fn parses_every_kind_of_term() {
+        let filter = CardFilter::parse(
+            "  tag:Bug,ui Priority:HIGH status:active,stale due:<7d text:\"login page\" -tag:wontfix  ",
+        )
+        .unwrap();
+        assert_eq!(
+            filter.query(),
+            "tag:Bug,ui Priority:HIGH status:active,stale due:<7d text:\"login page\" -tag:wontfix"
+        );
+        assert_eq!(
+            filter.terms,
+            vec![
+                term(false, FilterCondition::Tag(strings(&["bug", "ui"]))),
+                term(false, FilterCondition::Priority(vec![CardPriority::High])),
+                term(
+                    false,
+                    FilterCondition::Status(vec![CardStatus::Active, CardStatus::Stale])
+                ),
+                term(
+                    false,
+                    FilterCondition::Due(DueCondition::Within(Duration::days(7)))
+                ),
+                term(false, FilterCondition::Text(strings(&["login page"]))),
+                term(true, FilterCondition::Tag(strings(&["wontfix"]))),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_due_conditions() {
+        let due_conditions = [
+            ("due:<12h", DueCondition::Within(Duration::hours(12))),
+            ("due:>2w", DueCondition::After(Duration::weeks(2))),
+            ("due:Overdue", DueCondition::Overdue),
+            ("due:any", DueCondition::Any),
+            ("due:none", DueCondition::NotSet),
+        ];
+        for (query, due_condition) in due_conditions {
+            assert_eq!(
+                CardFilter::parse(query).unwrap().terms,
+                vec![term(false, FilterCondition::Due(due_condition))],
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn words_without_a_key_are_text() {
+        let filter = CardFilter::parse("Login \"sign up: page\" -draft").unwrap();
+        assert_eq!(
+            filter.terms,
+            vec![
+                term(false, FilterCondition::Text(strings(&["login"]))),
+                term(false, FilterCondition::Text(strings(&["sign up: page"]))),
+                term(true, FilterCondition::Text(strings(&["draft"]))),
+            ]
+        );
+    }
+
+    #[test]
+    fn quoted_values_keep_spaces_and_commas() {
+        let filter = CardFilter::parse("tag:\"needs review\",\"a,b\"").unwrap();
+        assert_eq!(
+            filter.terms,
+            vec![term(
+                false,
+                FilterCondition::Tag(strings(&["needs review", "a,b"]))
+            )]
+        );
+    }
+
+    #[test]
+    fn tag_filters_parse_back_from_their_query() {
+        let filter = CardFilter::from_tags(&strings(&["needs review", "a,b", "bug"]));
+        assert_eq!(CardFilter::parse(filter.query()), Ok(filter));
+    }
+
+    #[test]
+    fn rejects_malformed_queries() {
+        let malformed_queries = [
+            ("", "Filter query is empty"),
+            ("   ", "Filter query is empty"),
+            ("tag:", "No value given for \"tag\""),
+            ("tag:,", "No value given for \"tag\""),
+            ("-status:\"\"", "No value given for \"status\""),
+            ("text:\"login", "Filter query has an unclosed quote"),
+            (
+                "owner:me",
+                "Unknown filter \"owner\", expected tag, priority, status, due or text",
+            ),
+            ("priority:urgent", "Invalid card priority \"urgent\""),
+            ("status:active,done", "Invalid card status \"done\""),
+            ("due:<7d,>2w", "\"due\" only takes a single value"),
+        ];
+        for (query, error) in malformed_queries {
+            assert_eq!(
+                CardFilter::parse(query),
+                Err(error.to_string()),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_due_conditions() {
+        for value in ["7d", "<", "<d", "<7", "<7y", "<xd", "=7d", "soon"] {
+            let query = format!("due:{}", value);
+            assert_eq!(
+                CardFilter::parse(&query),
+                Err(format!(
+                    "Invalid due filter \"{}\", expected something like <7d, >2w, overdue, any or none",
+                    value
+                )),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_due_conditions_out_of_range() {
+        for value in [
+            "<99999999999999d",
+            ">100000000w",
+            "<-100000000w",
+            "<9223372036854775807h",
+            "<99999999999999999999d",
+        ] {
+            let query = format!("due:{}", value);
+            assert_eq!(
+                CardFilter::parse(&query),
+                Err(format!(
+                    "Invalid due filter \"{}\", expected something like <7d, >2w, overdue, any or none",
+                    value
+                )),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn every_term_has_to_match() {
+        let filter = CardFilter::parse("tag:bug,ui -priority:low login").unwrap();
+        assert!(filter.matches(&card("Fix login", &["Bug"], CardPriority::High)));
+        assert!(!filter.matches(&card("Fix login", &["bug"], CardPriority::Low)));
+        assert!(!filter.matches(&card("Fix login", &["docs"], CardPriority::High)));
+        assert!(!filter.matches(&card("Fix signup", &["ui"], CardPriority::High)));
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    app::{
+        card_filter::parse_duration,
+        kanban::{Card, CardPriority},
+        DateTimeFormat,
+    },
+    util::format_date_time,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A named starting point for new cards, saved as json in the card templates folder of the
+/// config directory
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CardTemplate {
+    pub name: String,
+    /// Name given to new cards, `{date}` is replaced with today's date and `{n}` with the lowest
+    /// number that keeps the name unique in the board
+    pub card_name: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub priority: CardPriority,
+    /// Due date relative to when the card is created, like `+3d`, `+2w` or `+12h`
+    pub due_in: Option<String>,
+}
+
+impl CardTemplate {
+    /// Keeps the name, description, tags and priority of the card, the due date is kept relative
+    /// to when the card was created
+    pub fn from_card(name: &str, card: &Card) -> Self {
+        let due_in = card
+            .due_date
+            .zip(card.date_created)
+            .map(|(due_date, date_created)| due_date - date_created)
+            .filter(|due_in| *due_in > Duration::zero())
+            .map(|due_in| {
+                if due_in.num_hours() % 24 == 0 {
+                    format!("+{}d", due_in.num_days())
+                } else {
+                    format!("+{}h", due_in.num_hours().max(1))
+                }
+            });
+        Self {
+            name: name.trim().to_string(),
+            card_name: card.name.clone(),
+            description: card.description.clone(),
+            tags: card.tags.clone(),
+            priority: card.priority.clone(),
+            due_in,
+        }
+    }
+
+    pub fn due_date(&self, now: DateTime<Utc>) -> Result<Option<DateTime<Utc>>, String> {
+        match &self.due_in {
+            Some(due_in) => now
+                .checked_add_signed(parse_relative_due_date(due_in)?)
+                .map(Some)
+                .ok_or_else(|| format!("Relative due date \"{}\" is too far away", due_in)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn card_name_for_board(
+        &self,
+        existing_card_names: &[&str],
+        date_time_format: DateTimeFormat,
+    ) -> String {
+        let today = format_date_time(
+            Some(Utc::now()),
+            DateTimeFormat::remove_time_from_date_format(date_time_format),
+        );
+        let card_name = self.card_name.replace("{date}", &today);
+        if !card_name.contains("{n}") {
+            return card_name;
+        }
+        (1..)
+            .map(|number| card_name.replace("{n}", &number.to_string()))
+            .find(|card_name| !existing_card_names.contains(&card_name.as_str()))
+            .unwrap_or(card_name)
+    }
+
+    /// Short description of what the template fills in, used next to its name in lists
+    pub fn summary(&self) -> String {
+        let mut summary = vec![format!("Priority: {}", self.priority)];
+        if !self.tags.is_empty() {
+            summary.push(format!("Tags: {}", self.tags.join(", ")));
+        }
+        if let Some(due_in) = &self.due_in {
+            summary.push(format!("Due: {}", due_in));
+        }
+        summary.join(" | ")
+    }
+}
+
+/// `+3d`, `+2w` or `+12h`, the leading `+` is optional
+pub fn parse_relative_due_date(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    parse_duration(value.strip_prefix('+').unwrap_or(value))
+        .filter(|duration| *duration >= Duration::zero())
+        .ok_or_else(|| {
+            format!(
+                "Invalid relative due date \"{}\", expected something like +3d, +2w or +12h",
+                value
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(due_in: Option<&str>) -> CardTemplate {
+        CardTemplate {
+            name: "Template".to_string(),
+            card_name: "Card".to_string(),
+            description: String::new(),
+            tags: Vec::new(),
+            priority: CardPriority::Low,
+            due_in: due_in.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn due_dates_are_relative_to_now() {
+        let now = Utc::now();
+        let due_dates = [
+            (None, None),
+            (Some("+3d"), Some(now + Duration::days(3))),
+            (Some("2w"), Some(now + Duration::weeks(2))),
+            (Some(" +12h "), Some(now + Duration::hours(12))),
+        ];
+        for (due_in, due_date) in due_dates {
+            assert_eq!(template(due_in).due_date(now), Ok(due_date), "{:?}", due_in);
+        }
+    }
+
+    #[test]
+    fn relative_due_dates_out_of_range_are_rejected() {
+        for due_in in [
+            "+99999999999999d",
+            "+100000000w",
+            "+9223372036854775807h",
+            "-3d",
+        ] {
+            assert_eq!(
+                parse_relative_due_date(due_in),
+                Err(format!(
+                    "Invalid relative due date \"{}\", expected something like +3d, +2w or +12h",
+                    due_in
+                ))
+            );
+            assert!(template(Some(due_in)).due_date(Utc::now()).is_err());
+        }
+    }
+
+    #[test]
+    fn due_dates_past_the_last_representable_date_are_rejected() {
+        let late = DateTime::<Utc>::MAX_UTC - Duration::days(1);
+        assert_eq!(
+            template(Some("+3d")).due_date(late),
+            Err("Relative due date \"+3d\" is too far away".to_string())
+        );
+    }
+}
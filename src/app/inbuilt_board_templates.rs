@@ -0,0 +1,47 @@
+use crate::app::board_template::{BoardTemplate, BoardTemplateBoard};
+
+pub fn basic_kanban_template() -> BoardTemplate {
+    BoardTemplate {
+        name: "Basic Kanban".to_string(),
+        description: "A simple flow from idea to done".to_string(),
+        boards: vec![
+            BoardTemplateBoard::new("Backlog", "Ideas and work that is not planned yet"),
+            BoardTemplateBoard::new("Todo", "Planned work, next up on top"),
+            BoardTemplateBoard::new("Doing", "Work that is being done right now")
+                .in_progress(Some(3)),
+            BoardTemplateBoard::new("Review", "Done but waiting for a second look")
+                .in_progress(None),
+            BoardTemplateBoard::new("Done", "Finished work"),
+        ],
+    }
+}
+
+pub fn scrum_sprint_template() -> BoardTemplate {
+    BoardTemplate {
+        name: "Scrum Sprint".to_string(),
+        description: "Product backlog and a single sprint".to_string(),
+        boards: vec![
+            BoardTemplateBoard::new("Product Backlog", "Everything the product might need"),
+            BoardTemplateBoard::new("Sprint Backlog", "Committed to in the current sprint"),
+            BoardTemplateBoard::new("In Progress", "Being worked on this sprint")
+                .in_progress(Some(5)),
+            BoardTemplateBoard::new("In Review", "Waiting for review or testing").in_progress(None),
+            BoardTemplateBoard::new("Done", "Meets the definition of done"),
+        ],
+    }
+}
+
+pub fn bug_triage_template() -> BoardTemplate {
+    BoardTemplate {
+        name: "Bug Triage".to_string(),
+        description: "Incoming bug reports through to a fix".to_string(),
+        boards: vec![
+            BoardTemplateBoard::new("New", "Reported and not looked at yet"),
+            BoardTemplateBoard::new("Confirmed", "Reproduced and ready to be fixed"),
+            BoardTemplateBoard::new("Fixing", "Fix in progress").in_progress(Some(3)),
+            BoardTemplateBoard::new("Verifying", "Fixed, waiting to be verified").in_progress(None),
+            BoardTemplateBoard::new("Closed", "Fix verified and released"),
+            BoardTemplateBoard::new("Won't Fix", "Duplicates, not a bug or out of scope"),
+        ],
+    }
+}
@@ -1,18 +1,32 @@
-use crate::{
-    app::DateTimeFormat,
-    constants::{FIELD_NA, FIELD_NOT_SET},
-};
+use crate::constants::FIELD_NOT_SET;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::fmt;
+use std::{
+    cmp::{Ordering, Reverse},
+    fmt,
+    str::FromStr,
+};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
 use uuid::Uuid;
 
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct Board {
+    /// Deleted and auto archived cards, oldest first
+    pub archived_cards: Vec<ArchivedCard>,
     pub cards: Cards,
     pub description: String,
     pub id: (u64, u64),
+    /// Blocked cards can not be moved into boards that are in progress
+    pub in_progress: bool,
+    /// Card order to go back to when the board is switched back to manual sorting, only set
+    /// while the board is sorted
+    pub manual_order: Vec<(u64, u64)>,
     pub name: String,
+    pub sort_mode: BoardSortMode,
+    /// Most cards the board should hold, moves past it are warned about or blocked depending on
+    /// the enforce_wip_limits config
+    pub wip_limit: Option<u32>,
 }
 
 impl Board {
@@ -21,7 +35,12 @@ impl Board {
             id: get_id(),
             name: name.to_owned(),
             description: description.to_owned(),
+            archived_cards: Vec::new(),
             cards: Cards::default(),
+            in_progress: false,
+            manual_order: Vec::new(),
+            sort_mode: BoardSortMode::Manual,
+            wip_limit: None,
         }
     }
 
@@ -60,41 +79,301 @@ impl Board {
             Some(description) => description,
             None => return Err("board description is invalid for board".to_string()),
         };
-        // Mainly for backwards compatibility, recent versions use value["cards"]["cards"] due to Cards being a struct
-        let cards = match value["cards"].as_array() {
+        // Older saves that stored cards as a plain array are migrated in io::migrations
+        let cards = match value["cards"]["cards"].as_array() {
             Some(cards) => cards
                 .iter()
                 .map(Card::from_json)
                 .collect::<Result<Cards, String>>()?,
-            None => match value["cards"]["cards"].as_array() {
-                Some(cards) => cards
-                    .iter()
-                    .map(Card::from_json)
-                    .collect::<Result<Cards, String>>()?,
-                None => return Err("board cards is invalid for board".to_string()),
+            None => return Err("board cards is invalid for board".to_string()),
+        };
+        let in_progress = match value["in_progress"].as_bool() {
+            Some(in_progress) => in_progress,
+            None => return Err("board in_progress is invalid for board".to_string()),
+        };
+        let sort_mode = match value["sort_mode"].as_str().map(BoardSortMode::from_str) {
+            Some(Ok(sort_mode)) => sort_mode,
+            _ => return Err("board sort_mode is invalid for board".to_string()),
+        };
+        let manual_order = match value["manual_order"].as_array() {
+            Some(manual_order) => manual_order
+                .iter()
+                .map(|card_id| match card_id.as_array().map(|id| id.as_slice()) {
+                    Some([id_1, id_2]) => match (id_1.as_u64(), id_2.as_u64()) {
+                        (Some(id_1), Some(id_2)) => Ok((id_1, id_2)),
+                        _ => Err("board manual_order is invalid for board".to_string()),
+                    },
+                    _ => Err("board manual_order is invalid for board".to_string()),
+                })
+                .collect::<Result<Vec<(u64, u64)>, String>>()?,
+            None => return Err("board manual_order is invalid for board".to_string()),
+        };
+        let wip_limit = match &value["wip_limit"] {
+            Value::Null => None,
+            wip_limit => match wip_limit
+                .as_u64()
+                .and_then(|limit| u32::try_from(limit).ok())
+            {
+                Some(wip_limit) => Some(wip_limit),
+                None => return Err("board wip_limit is invalid for board".to_string()),
             },
         };
+        let archived_cards = match value["archived_cards"].as_array() {
+            Some(archived_cards) => archived_cards
+                .iter()
+                .map(ArchivedCard::from_json)
+                .collect::<Result<Vec<ArchivedCard>, String>>()?,
+            None => return Err("board archived_cards is invalid for board".to_string()),
+        };
 
         Ok(Self {
             id,
             name: name.to_string(),
             description: description.to_string(),
+            archived_cards,
             cards,
+            in_progress,
+            manual_order,
+            sort_mode,
+            wip_limit,
         })
     }
+
+    /// Copies the name, description and WIP limit of `other`, used to apply and undo board edits
+    pub fn set_details(&mut self, other: &Board) {
+        self.name = other.name.clone();
+        self.description = other.description.clone();
+        self.wip_limit = other.wip_limit;
+    }
+
+    /// True if adding a card would take the board past its WIP limit
+    pub fn is_at_wip_limit(&self) -> bool {
+        self.wip_limit
+            .is_some_and(|wip_limit| self.cards.len() >= wip_limit as usize)
+    }
+
+    pub fn is_over_wip_limit(&self) -> bool {
+        self.wip_limit
+            .is_some_and(|wip_limit| self.cards.len() > wip_limit as usize)
+    }
+
+    /// Sorts the cards right away, switching back to manual restores the order the cards had
+    /// before the board was sorted, with cards added since at the end
+    pub fn set_sort_mode(&mut self, sort_mode: BoardSortMode) {
+        match (self.sort_mode, sort_mode) {
+            (BoardSortMode::Manual, BoardSortMode::Manual) => {}
+            (BoardSortMode::Manual, _) => self.manual_order = self.cards.get_all_card_ids(),
+            (_, BoardSortMode::Manual) => {
+                self.cards.order_by_ids(&self.manual_order);
+                self.manual_order.clear();
+            }
+            _ => {}
+        }
+        self.sort_mode = sort_mode;
+        self.cards.sort_by_mode(sort_mode);
+    }
+
+    pub fn get_ordering(&self) -> BoardOrdering {
+        BoardOrdering {
+            card_ids: self.cards.get_all_card_ids(),
+            manual_order: self.manual_order.clone(),
+            sort_mode: self.sort_mode,
+        }
+    }
+
+    pub fn restore_ordering(&mut self, ordering: &BoardOrdering) {
+        self.sort_mode = ordering.sort_mode;
+        self.manual_order.clone_from(&ordering.manual_order);
+        self.cards.order_by_ids(&ordering.card_ids);
+    }
+
+    /// Adds the next occurrence of a recurring card of the board right after it, or where the
+    /// sort mode puts it. Returns the new card, None if the card does not recur
+    pub fn add_next_occurrence(&mut self, card_id: (u64, u64)) -> Option<Card> {
+        let next_card = self.cards.get_card_with_id(card_id)?.next_occurrence()?;
+        self.add_card_after(card_id, next_card.clone());
+        Some(next_card)
+    }
+
+    /// Adds a card right after another one, at the end if that one is not on the board, or
+    /// where the sort mode puts it
+    pub fn add_card_after(&mut self, card_id: (u64, u64), card: Card) {
+        match self.cards.get_card_index(card_id) {
+            Some(index) => self.cards.add_card_at_index(index + 1, card),
+            None => self.cards.add_card(card),
+        }
+        self.cards.sort_by_mode(self.sort_mode);
+    }
+
+    /// (done, total) checklist items across all cards of the board, None if no card has a
+    /// checklist
+    pub fn checklist_progress(&self) -> Option<(usize, usize)> {
+        self.cards
+            .get_all_cards()
+            .iter()
+            .filter_map(Card::checklist_progress)
+            .reduce(|(done, total), (card_done, card_total)| (done + card_done, total + card_total))
+    }
 }
 
 impl Default for Board {
     fn default() -> Self {
         Self {
+            archived_cards: Vec::new(),
             cards: Cards::default(),
             description: String::from("Default Board Description"),
             id: get_id(),
+            in_progress: false,
+            manual_order: Vec::new(),
             name: String::from("Default Board"),
+            sort_mode: BoardSortMode::Manual,
+            wip_limit: None,
+        }
+    }
+}
+
+/// A card taken off its board, kept with enough to put it back where it was
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct ArchivedCard {
+    pub card: Card,
+    pub date_archived: DateTime<Utc>,
+    /// Index the card had on its board
+    pub position: usize,
+    /// Ids of the cards whose blocked_by link to the card was removed when it was archived
+    pub unlinked_card_ids: Vec<(u64, u64)>,
+}
+
+impl ArchivedCard {
+    pub fn from_json(value: &Value) -> Result<Self, String> {
+        let card = Card::from_json(&value["card"])?;
+        let date_archived = match date_from_json(&value["date_archived"], "date_archived") {
+            Ok(Some(date_archived)) => date_archived,
+            _ => return Err("archived card date_archived is invalid".to_string()),
+        };
+        let position = match value["position"].as_u64() {
+            Some(position) => position as usize,
+            None => return Err("archived card position is invalid".to_string()),
+        };
+        let unlinked_card_ids = match value["unlinked_card_ids"].as_array() {
+            Some(unlinked_card_ids) => unlinked_card_ids
+                .iter()
+                .map(|card_id| match (card_id[0].as_u64(), card_id[1].as_u64()) {
+                    (Some(id_1), Some(id_2)) => Ok((id_1, id_2)),
+                    _ => Err("archived card unlinked_card_ids is invalid".to_string()),
+                })
+                .collect::<Result<Vec<(u64, u64)>, String>>()?,
+            None => return Err("archived card unlinked_card_ids is invalid".to_string()),
+        };
+        Ok(Self {
+            card,
+            date_archived,
+            position,
+            unlinked_card_ids,
+        })
+    }
+
+    /// Case insensitive match of the query against the name, description and tags of the card
+    pub fn matches_search(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.card.name.to_lowercase().contains(&query)
+            || self.card.description.to_lowercase().contains(&query)
+            || self
+                .card
+                .tags
+                .iter()
+                .any(|tag| tag.to_lowercase().contains(&query))
+    }
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    EnumIter,
+    EnumString,
+    Display,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum BoardSortMode {
+    #[default]
+    Manual,
+    /// Earliest first, cards without a due date last
+    #[strum(serialize = "DueDate", to_string = "Due Date")]
+    DueDate,
+    /// Highest first
+    Priority,
+    /// Oldest first
+    Created,
+    /// Most recently modified first
+    Modified,
+    Name,
+    /// Active, then stale, then complete
+    Status,
+}
+
+impl BoardSortMode {
+    pub fn all() -> Vec<BoardSortMode> {
+        BoardSortMode::iter().collect()
+    }
+
+    /// The modes a manually sorted board can be sorted by once
+    pub fn all_automatic() -> Vec<BoardSortMode> {
+        BoardSortMode::iter()
+            .filter(|sort_mode| *sort_mode != BoardSortMode::Manual)
+            .collect()
+    }
+
+    fn compare(self, card_1: &Card, card_2: &Card) -> Ordering {
+        let priority_rank = |priority: &CardPriority| match priority {
+            CardPriority::High => 0,
+            CardPriority::Medium => 1,
+            CardPriority::Low => 2,
+        };
+        let status_rank = |status: &CardStatus| match status {
+            CardStatus::Active => 0,
+            CardStatus::Stale => 1,
+            CardStatus::Complete => 2,
+        };
+        // Cards without the date go last
+        let compare_dates =
+            |date_1: Option<DateTime<Utc>>, date_2: Option<DateTime<Utc>>| match (date_1, date_2) {
+                (Some(date_1), Some(date_2)) => date_1.cmp(&date_2),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+        match self {
+            BoardSortMode::Manual => Ordering::Equal,
+            BoardSortMode::DueDate => compare_dates(card_1.due_date, card_2.due_date),
+            BoardSortMode::Priority => {
+                priority_rank(&card_1.priority).cmp(&priority_rank(&card_2.priority))
+            }
+            BoardSortMode::Created => compare_dates(card_1.date_created, card_2.date_created),
+            BoardSortMode::Modified => {
+                compare_dates(card_1.date_modified, card_2.date_modified).reverse()
+            }
+            BoardSortMode::Name => card_1.name.to_lowercase().cmp(&card_2.name.to_lowercase()),
+            BoardSortMode::Status => {
+                status_rank(&card_1.card_status).cmp(&status_rank(&card_2.card_status))
+            }
         }
     }
 }
 
+/// The sort mode and card order of a board, kept in the action history to undo sorting
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BoardOrdering {
+    pub card_ids: Vec<(u64, u64)>,
+    pub manual_order: Vec<(u64, u64)>,
+    pub sort_mode: BoardSortMode,
+}
+
 #[derive(Debug)]
 pub enum BoardSwapError {
     IndexOutOfBounds,
@@ -110,34 +389,57 @@ impl fmt::Display for BoardSwapError {
 
 impl std::error::Error for BoardSwapError {}
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Boards {
     boards: Vec<Board>,
+    /// Bumped by every method that can change the boards, used to tell if there are unsaved
+    /// changes without comparing the boards with a save
+    #[serde(skip)]
+    generation: u64,
 }
 
+impl PartialEq for Boards {
+    fn eq(&self, other: &Self) -> bool {
+        self.boards == other.boards
+    }
+}
+
+impl Eq for Boards {}
+
 impl Boards {
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+    fn changed(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
     pub fn add_board(&mut self, board: Board) {
+        self.changed();
         self.boards.push(board);
     }
     pub fn get_board_with_id(&self, board_id: (u64, u64)) -> Option<&Board> {
         self.boards.iter().find(|b| b.id == board_id)
     }
     pub fn get_mut_board_with_id(&mut self, board_id: (u64, u64)) -> Option<&mut Board> {
+        self.changed();
         self.boards.iter_mut().find(|b| b.id == board_id)
     }
     pub fn get_board_with_index(&self, index: usize) -> Option<&Board> {
         self.boards.get(index)
     }
     pub fn get_mut_board_with_index(&mut self, index: usize) -> Option<&mut Board> {
+        self.changed();
         self.boards.get_mut(index)
     }
     pub fn get_boards(&self) -> &Vec<Board> {
         &self.boards
     }
     pub fn get_mut_boards(&mut self) -> &mut Vec<Board> {
+        self.changed();
         &mut self.boards
     }
     pub fn set_boards(&mut self, boards: Boards) {
+        self.changed();
         self.boards = boards.boards;
     }
     pub fn is_empty(&self) -> bool {
@@ -153,9 +455,11 @@ impl Boards {
         self.boards.len()
     }
     pub fn remove_board_with_id(&mut self, board_id: (u64, u64)) {
+        self.changed();
         self.boards.retain(|b| b.id != board_id);
     }
     pub fn reset(&mut self) {
+        self.changed();
         self.boards.clear();
     }
     pub fn find_board_with_card_id(&self, card_id: (u64, u64)) -> Option<(usize, &Board)> {
@@ -164,18 +468,267 @@ impl Boards {
             .enumerate()
             .find(|(_, b)| b.cards.get_card_with_id(card_id).is_some())
     }
+    /// Sorts the cards of every board that is not sorted manually, returns the ids of the
+    /// boards whose card order changed
+    pub fn keep_sorted(&mut self) -> Vec<(u64, u64)> {
+        let mut resorted_board_ids = Vec::new();
+        for board in self.boards.iter_mut() {
+            if board.sort_mode == BoardSortMode::Manual {
+                continue;
+            }
+            let card_ids = board.cards.get_all_card_ids();
+            board.cards.sort_by_mode(board.sort_mode);
+            if board.cards.get_all_card_ids() != card_ids {
+                resorted_board_ids.push(board.id);
+            }
+        }
+        if !resorted_board_ids.is_empty() {
+            self.changed();
+        }
+        resorted_board_ids
+    }
     pub fn swap(&mut self, index_1: usize, index_2: usize) -> Result<(), BoardSwapError> {
         if index_1 >= self.boards.len() || index_2 >= self.boards.len() {
             return Err(BoardSwapError::IndexOutOfBounds);
         }
+        self.changed();
         self.boards.swap(index_1, index_2);
         Ok(())
     }
+    pub fn find_card_with_id(&self, card_id: (u64, u64)) -> Option<&Card> {
+        self.boards
+            .iter()
+            .find_map(|b| b.cards.get_card_with_id(card_id))
+    }
+    /// A card is blocked while any card it is blocked by is not complete, links to cards that
+    /// no longer exist are ignored
+    pub fn is_card_blocked(&self, card: &Card) -> bool {
+        !self.get_open_blockers(card).is_empty()
+    }
+    /// The cards blocking this card that are not complete yet
+    pub fn get_open_blockers(&self, card: &Card) -> Vec<&Card> {
+        card.blocked_by
+            .iter()
+            .filter_map(|blocker_id| self.find_card_with_id(*blocker_id))
+            .filter(|blocker| blocker.card_status != CardStatus::Complete)
+            .collect()
+    }
+    /// Blocked cards can not be moved into a board that is in progress, returns why the move
+    /// is refused or None if it is allowed
+    pub fn get_blocked_move_error(
+        &self,
+        card_id: (u64, u64),
+        to_board_id: (u64, u64),
+    ) -> Option<String> {
+        let to_board = self.get_board_with_id(to_board_id)?;
+        if !to_board.in_progress {
+            return None;
+        }
+        let card = self.find_card_with_id(card_id)?;
+        let open_blockers = self.get_open_blockers(card);
+        if open_blockers.is_empty() {
+            return None;
+        }
+        let blocker_names: Vec<String> = open_blockers
+            .iter()
+            .map(|blocker| format!("\"{}\"", blocker.name))
+            .collect();
+        Some(format!(
+            "Card \"{}\" is blocked by {} and can not be moved to \"{}\" which is in progress",
+            card.name,
+            blocker_names.join(", "),
+            to_board.name
+        ))
+    }
+    /// Error for moving or adding a card to a board that is already at its WIP limit
+    pub fn get_wip_limit_error(&self, to_board_id: (u64, u64)) -> Option<String> {
+        let to_board = self.get_board_with_id(to_board_id)?;
+        if !to_board.is_at_wip_limit() {
+            return None;
+        }
+        Some(format!(
+            "Board \"{}\" is at its WIP limit of {} cards",
+            to_board.name,
+            to_board.wip_limit.unwrap_or_default()
+        ))
+    }
+    /// The cards that have this card in their blocked_by list
+    pub fn get_cards_blocked_by(&self, card_id: (u64, u64)) -> Vec<&Card> {
+        self.boards
+            .iter()
+            .flat_map(|b| b.cards.get_all_cards())
+            .filter(|c| c.blocked_by.contains(&card_id))
+            .collect()
+    }
+    /// True if making `card_id` blocked by `blocker_id` would make the card (indirectly) block
+    /// itself
+    pub fn would_create_dependency_cycle(
+        &self,
+        card_id: (u64, u64),
+        blocker_id: (u64, u64),
+    ) -> bool {
+        let mut to_visit = vec![blocker_id];
+        let mut visited = Vec::new();
+        while let Some(current_id) = to_visit.pop() {
+            if current_id == card_id {
+                return true;
+            }
+            if visited.contains(&current_id) {
+                continue;
+            }
+            visited.push(current_id);
+            if let Some(current_card) = self.find_card_with_id(current_id) {
+                to_visit.extend(current_card.blocked_by.iter().copied());
+            }
+        }
+        false
+    }
+    /// Removes `card_id` from the blocked_by list of every card, returns the ids of the cards
+    /// that were changed so the links can be restored
+    pub fn remove_blocked_by_links(&mut self, card_id: (u64, u64)) -> Vec<(u64, u64)> {
+        let mut unlinked_card_ids = Vec::new();
+        for board in self.boards.iter_mut() {
+            for card in board.cards.get_mut_all_cards() {
+                if card.blocked_by.contains(&card_id) {
+                    card.blocked_by.retain(|blocker_id| *blocker_id != card_id);
+                    card.mark_modified();
+                    unlinked_card_ids.push(card.id);
+                }
+            }
+        }
+        if !unlinked_card_ids.is_empty() {
+            self.changed();
+        }
+        unlinked_card_ids
+    }
+    pub fn restore_blocked_by_links(
+        &mut self,
+        card_id: (u64, u64),
+        unlinked_card_ids: &[(u64, u64)],
+    ) {
+        if !unlinked_card_ids.is_empty() {
+            self.changed();
+        }
+        for board in self.boards.iter_mut() {
+            for card in board.cards.get_mut_all_cards() {
+                if unlinked_card_ids.contains(&card.id) && !card.blocked_by.contains(&card_id) {
+                    card.blocked_by.push(card_id);
+                    card.mark_modified();
+                }
+            }
+        }
+    }
+    /// Moves a card into the archive of its board, links from cards it blocked are removed
+    /// and kept with it. Returns the archived card, None if the board has no such card
+    pub fn archive_card(
+        &mut self,
+        board_id: (u64, u64),
+        card_id: (u64, u64),
+    ) -> Option<ArchivedCard> {
+        let board = self.get_mut_board_with_id(board_id)?;
+        let position = board.cards.get_card_index(card_id)?;
+        let card = board.cards.remove_card_with_id(card_id)?;
+        board
+            .manual_order
+            .retain(|manual_card_id| *manual_card_id != card_id);
+        let unlinked_card_ids = self.remove_blocked_by_links(card_id);
+        let archived_card = ArchivedCard {
+            card,
+            date_archived: Utc::now(),
+            position,
+            unlinked_card_ids,
+        };
+        self.put_in_archive(board_id, archived_card.clone());
+        Some(archived_card)
+    }
+    /// Puts an already archived card (back) into the archive, used to redo archiving
+    pub fn put_in_archive(&mut self, board_id: (u64, u64), archived_card: ArchivedCard) {
+        if let Some(board) = self.get_mut_board_with_id(board_id) {
+            board.cards.remove_card_with_id(archived_card.card.id);
+            board
+                .manual_order
+                .retain(|card_id| *card_id != archived_card.card.id);
+            self.remove_blocked_by_links(archived_card.card.id);
+            if let Some(board) = self.get_mut_board_with_id(board_id) {
+                board.archived_cards.push(archived_card);
+            }
+        }
+    }
+    /// Takes a card out of the archive and puts it back at its old position on the board, or
+    /// where the board's sort mode puts it. Returns the archived card, None if it is not in the
+    /// archive of the board
+    pub fn restore_archived_card(
+        &mut self,
+        board_id: (u64, u64),
+        card_id: (u64, u64),
+    ) -> Option<ArchivedCard> {
+        let board = self.get_mut_board_with_id(board_id)?;
+        let archive_index = board
+            .archived_cards
+            .iter()
+            .position(|archived_card| archived_card.card.id == card_id)?;
+        let archived_card = board.archived_cards.remove(archive_index);
+        let position = archived_card.position.min(board.cards.len());
+        board
+            .cards
+            .add_card_at_index(position, archived_card.card.clone());
+        board.cards.sort_by_mode(board.sort_mode);
+        self.restore_blocked_by_links(card_id, &archived_card.unlinked_card_ids);
+        Some(archived_card)
+    }
+    /// Archived cards of every board that match the search, most recently archived first
+    pub fn search_archived_cards(&self, query: &str) -> Vec<(&Board, &ArchivedCard)> {
+        let mut archived_cards: Vec<(&Board, &ArchivedCard)> = self
+            .boards
+            .iter()
+            .flat_map(|board| {
+                board
+                    .archived_cards
+                    .iter()
+                    .filter(|archived_card| archived_card.matches_search(query))
+                    .map(move |archived_card| (board, archived_card))
+            })
+            .collect();
+        archived_cards.sort_by_key(|(_, archived_card)| Reverse(archived_card.date_archived));
+        archived_cards
+    }
+    /// Archives the cards that have been complete for at least `days` days, returns the board
+    /// id and archived card for each of them
+    pub fn archive_completed_cards(&mut self, days: u16) -> Vec<((u64, u64), ArchivedCard)> {
+        let archive_before = Utc::now() - chrono::Duration::days(days as i64);
+        let completed_card_ids: Vec<((u64, u64), (u64, u64))> = self
+            .boards
+            .iter()
+            .flat_map(|board| {
+                board
+                    .cards
+                    .get_all_cards()
+                    .iter()
+                    .filter(|card| {
+                        card.card_status == CardStatus::Complete
+                            && card
+                                .date_completed
+                                .is_some_and(|date_completed| date_completed <= archive_before)
+                    })
+                    .map(|card| (board.id, card.id))
+            })
+            .collect();
+        completed_card_ids
+            .into_iter()
+            .filter_map(|(board_id, card_id)| {
+                self.archive_card(board_id, card_id)
+                    .map(|archived_card| (board_id, archived_card))
+            })
+            .collect()
+    }
 }
 
 impl From<Vec<Board>> for Boards {
     fn from(boards: Vec<Board>) -> Self {
-        Self { boards }
+        Self {
+            boards,
+            generation: 0,
+        }
     }
 }
 
@@ -202,6 +755,17 @@ impl CardStatus {
     }
 }
 
+impl FromStr for CardStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CardStatus::all()
+            .into_iter()
+            .find(|status| status.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("Invalid card status \"{}\"", s))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum CardPriority {
     High,
@@ -225,18 +789,182 @@ impl CardPriority {
     }
 }
 
+impl FromStr for CardPriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CardPriority::all()
+            .into_iter()
+            .find(|priority| priority.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("Invalid card priority \"{}\"", s))
+    }
+}
+
+/// How often a card comes back once it is completed, the next due date is counted from the
+/// current one
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardRecurrence {
+    Daily,
+    Weekly,
+    Monthly,
+    EveryDays(u16),
+}
+
+impl fmt::Display for CardRecurrence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardRecurrence::Daily => write!(f, "Daily"),
+            CardRecurrence::Weekly => write!(f, "Weekly"),
+            CardRecurrence::Monthly => write!(f, "Monthly"),
+            CardRecurrence::EveryDays(days) => write!(f, "Every {} Days", days),
+        }
+    }
+}
+
+impl CardRecurrence {
+    /// The fixed recurrences, custom intervals are made with `EveryDays`
+    pub fn all() -> Vec<CardRecurrence> {
+        vec![
+            CardRecurrence::Daily,
+            CardRecurrence::Weekly,
+            CardRecurrence::Monthly,
+        ]
+    }
+
+    /// The first due date after `now` that is a whole number of intervals after `due_date`,
+    /// at least one interval later
+    pub fn next_due_date(self, due_date: DateTime<Utc>, now: DateTime<Utc>) -> DateTime<Utc> {
+        let mut next_due_date = due_date;
+        let mut intervals = 0;
+        while intervals == 0 || next_due_date <= now {
+            intervals += 1;
+            next_due_date = match self {
+                CardRecurrence::Daily => due_date + chrono::Duration::days(intervals),
+                CardRecurrence::Weekly => due_date + chrono::Duration::weeks(intervals),
+                CardRecurrence::Monthly => due_date
+                    .checked_add_months(chrono::Months::new(intervals as u32))
+                    .unwrap_or(DateTime::<Utc>::MAX_UTC),
+                CardRecurrence::EveryDays(days) => {
+                    due_date + chrono::Duration::days(intervals * days.max(1) as i64)
+                }
+            };
+        }
+        next_due_date
+    }
+}
+
+impl FromStr for CardRecurrence {
+    type Err = String;
+
+    /// Accepts the names of the fixed recurrences or a number of days like "10" or "10d"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(recurrence) = CardRecurrence::all()
+            .into_iter()
+            .find(|recurrence| recurrence.to_string().eq_ignore_ascii_case(s))
+        {
+            return Ok(recurrence);
+        }
+        match s
+            .strip_suffix(['d', 'D'])
+            .unwrap_or(s)
+            .trim()
+            .parse::<u16>()
+        {
+            Ok(days) if days > 0 => Ok(CardRecurrence::EveryDays(days)),
+            _ => Err(format!(
+                "Invalid card recurrence \"{}\", expected daily, weekly, monthly or a number of days",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChecklistItem {
+    pub text: String,
+    pub done: bool,
+}
+
+impl ChecklistItem {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            done: false,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    pub author: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub edited_at: Option<DateTime<Utc>>,
+    pub id: (u64, u64),
+}
+
+impl Comment {
+    pub fn new(author: &str, body: &str) -> Self {
+        Self {
+            author: author.to_string(),
+            body: body.to_string(),
+            created_at: Utc::now(),
+            edited_at: None,
+            id: get_id(),
+        }
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, String> {
+        let id = match value["id"].as_array().map(|id| id.as_slice()) {
+            Some([id_1, id_2]) => match (id_1.as_u64(), id_2.as_u64()) {
+                (Some(id_1), Some(id_2)) => (id_1, id_2),
+                _ => return Err("card comment id is invalid for card".to_string()),
+            },
+            _ => return Err("card comment id is invalid for card".to_string()),
+        };
+        let author = match value["author"].as_str() {
+            Some(author) => author.to_string(),
+            None => return Err("card comment author is invalid for card".to_string()),
+        };
+        let body = match value["body"].as_str() {
+            Some(body) => body.to_string(),
+            None => return Err("card comment body is invalid for card".to_string()),
+        };
+        let created_at = match date_from_json(&value["created_at"], "comment created_at")? {
+            Some(created_at) => created_at,
+            None => return Err("card comment created_at is invalid for card".to_string()),
+        };
+        let edited_at = date_from_json(&value["edited_at"], "comment edited_at")?;
+        Ok(Self {
+            author,
+            body,
+            created_at,
+            edited_at,
+            id,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Card {
+    /// Ids of the cards that have to be completed before this one, the cards this one blocks
+    /// are found by looking for it in the other cards' lists
+    pub blocked_by: Vec<(u64, u64)>,
     pub card_status: CardStatus,
-    pub comments: Vec<String>,
-    pub date_completed: String,
-    pub date_created: String,
-    pub date_modified: String,
+    pub checklist: Vec<ChecklistItem>,
+    pub comments: Vec<Comment>,
+    pub date_completed: Option<DateTime<Utc>>,
+    pub date_created: Option<DateTime<Utc>>,
+    pub date_modified: Option<DateTime<Utc>>,
     pub description: String,
-    pub due_date: String,
+    pub due_date: Option<DateTime<Utc>>,
     pub id: (u64, u64),
     pub name: String,
     pub priority: CardPriority,
+    /// Completing a recurring card adds a fresh copy of it with the next due date
+    pub recurrence: Option<CardRecurrence>,
+    pub revision: u64,
     pub tags: Vec<String>,
 }
 
@@ -244,11 +972,10 @@ impl Card {
     pub fn new(
         name: &str,
         description: &str,
-        due_date: &str,
+        due_date: Option<DateTime<Utc>>,
         priority: CardPriority,
         tags: Vec<String>,
-        comments: Vec<String>,
-        date_time_format: DateTimeFormat,
+        comments: Vec<Comment>,
     ) -> Self {
         let name = if name.is_empty() { FIELD_NOT_SET } else { name };
         let description = if description.is_empty() {
@@ -256,11 +983,6 @@ impl Card {
         } else {
             description
         };
-        let due_date = if due_date.is_empty() {
-            FIELD_NOT_SET
-        } else {
-            due_date
-        };
         let priority = if priority.to_string().is_empty() {
             CardPriority::Low
         } else {
@@ -273,27 +995,85 @@ impl Card {
             comments
         };
 
-        let corrected_date_time_format = DateTimeFormat::add_time_to_date_format(date_time_format);
-
         Self {
             id: get_id(),
             name: name.to_string(),
             description: description.to_string(),
-            date_created: chrono::Local::now()
-                .format(corrected_date_time_format.to_parser_string())
-                .to_string(),
-            date_modified: chrono::Local::now()
-                .format(corrected_date_time_format.to_parser_string())
-                .to_string(),
-            due_date: due_date.to_string(),
-            date_completed: FIELD_NA.to_string(),
+            blocked_by: Vec::new(),
+            date_created: Some(Utc::now()),
+            date_modified: Some(Utc::now()),
+            due_date,
+            date_completed: None,
             priority,
             card_status: CardStatus::Active,
+            checklist: Vec::new(),
+            recurrence: None,
+            revision: 0,
             tags,
             comments,
         }
     }
 
+    /// Bumps the revision and modified date, cloud sync uses them to find the cards that
+    /// changed since the last sync
+    pub fn mark_modified(&mut self) {
+        self.revision += 1;
+        self.date_modified = Some(Utc::now());
+    }
+
+    /// Replaces the comment with the same id, returns false if the card has no such comment
+    pub fn replace_comment(&mut self, comment: Comment) -> bool {
+        match self.comments.iter_mut().find(|c| c.id == comment.id) {
+            Some(existing_comment) => {
+                *existing_comment = comment;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remove_comment_with_id(&mut self, comment_id: (u64, u64)) -> Option<Comment> {
+        let index = self.comments.iter().position(|c| c.id == comment_id)?;
+        Some(self.comments.remove(index))
+    }
+
+    /// A fresh copy of a recurring card with the next due date, cards without a due date are
+    /// due one interval after now. None if the card does not recur
+    pub fn next_occurrence(&self) -> Option<Card> {
+        let recurrence = self.recurrence?;
+        let now = Utc::now();
+        Some(Card {
+            blocked_by: Vec::new(),
+            card_status: CardStatus::Active,
+            checklist: self
+                .checklist
+                .iter()
+                .map(|item| ChecklistItem::new(&item.text))
+                .collect(),
+            comments: Vec::new(),
+            date_completed: None,
+            date_created: Some(now),
+            date_modified: Some(now),
+            description: self.description.clone(),
+            due_date: Some(recurrence.next_due_date(self.due_date.unwrap_or(now), now)),
+            id: get_id(),
+            name: self.name.clone(),
+            priority: self.priority.clone(),
+            recurrence: Some(recurrence),
+            revision: 0,
+            tags: self.tags.clone(),
+        })
+    }
+
+    /// (done, total) items of the checklist, None if the card has no checklist
+    pub fn checklist_progress(&self) -> Option<(usize, usize)> {
+        if self.checklist.is_empty() {
+            return None;
+        }
+        let done = self.checklist.iter().filter(|item| item.done).count();
+        Some((done, self.checklist.len()))
+    }
+
     pub fn from_json(value: &Value) -> Result<Self, String> {
         let id = match value["id"].as_array() {
             Some(id) => {
@@ -329,22 +1109,10 @@ impl Card {
             Some(description) => description,
             None => return Err("card description is invalid for card".to_string()),
         };
-        let date_created = match value["date_created"].as_str() {
-            Some(date_created) => date_created,
-            None => return Err("card date_created is invalid for card".to_string()),
-        };
-        let date_modified = match value["date_modified"].as_str() {
-            Some(date_modified) => date_modified,
-            None => return Err("card date_modified is invalid for card".to_string()),
-        };
-        let due_date = match value["due_date"].as_str() {
-            Some(due_date) => due_date,
-            None => return Err("card due_date is invalid for card".to_string()),
-        };
-        let date_completed = match value["date_completed"].as_str() {
-            Some(date_completed) => date_completed,
-            None => return Err("card date_completed is invalid for card".to_string()),
-        };
+        let date_created = date_from_json(&value["date_created"], "date_created")?;
+        let date_modified = date_from_json(&value["date_modified"], "date_modified")?;
+        let due_date = date_from_json(&value["due_date"], "due_date")?;
+        let date_completed = date_from_json(&value["date_completed"], "date_completed")?;
         let priority = match value["priority"].as_str() {
             Some(priority) => match priority {
                 "Low" => CardPriority::Low,
@@ -363,6 +1131,10 @@ impl Card {
             },
             None => return Err("card card_status is invalid for card".to_string()),
         };
+        let revision = match value["revision"].as_u64() {
+            Some(revision) => revision,
+            None => return Err("card revision is invalid for card".to_string()),
+        };
         let tags = match value["tags"].as_array() {
             Some(tags) => tags
                 .iter()
@@ -373,21 +1145,59 @@ impl Card {
         let comments = match value["comments"].as_array() {
             Some(comments) => comments
                 .iter()
-                .map(|c| c.as_str().unwrap().to_string())
-                .collect(),
+                .map(Comment::from_json)
+                .collect::<Result<Vec<Comment>, String>>()?,
             None => return Err("card comments is invalid for card".to_string()),
         };
+        let checklist = match value["checklist"].as_array() {
+            Some(checklist) => checklist
+                .iter()
+                .map(
+                    |item| match (item["text"].as_str(), item["done"].as_bool()) {
+                        (Some(text), Some(done)) => Ok(ChecklistItem {
+                            text: text.to_string(),
+                            done,
+                        }),
+                        _ => Err("card checklist item is invalid for card".to_string()),
+                    },
+                )
+                .collect::<Result<Vec<ChecklistItem>, String>>()?,
+            None => return Err("card checklist is invalid for card".to_string()),
+        };
+        let recurrence = match &value["recurrence"] {
+            Value::Null => None,
+            recurrence => Some(
+                serde_json::from_value::<CardRecurrence>(recurrence.clone())
+                    .map_err(|_| "card recurrence is invalid for card".to_string())?,
+            ),
+        };
+        let blocked_by = match value["blocked_by"].as_array() {
+            Some(blocked_by) => blocked_by
+                .iter()
+                .map(
+                    |blocker_id| match (blocker_id[0].as_u64(), blocker_id[1].as_u64()) {
+                        (Some(id_1), Some(id_2)) => Ok((id_1, id_2)),
+                        _ => Err("card blocked_by is invalid for card".to_string()),
+                    },
+                )
+                .collect::<Result<Vec<(u64, u64)>, String>>()?,
+            None => return Err("card blocked_by is invalid for card".to_string()),
+        };
 
         Ok(Self {
             id,
             name: name.to_string(),
             description: description.to_string(),
-            date_created: date_created.to_string(),
-            date_modified: date_modified.to_string(),
-            due_date: due_date.to_string(),
-            date_completed: date_completed.to_string(),
+            blocked_by,
+            date_created,
+            date_modified,
+            due_date,
+            date_completed,
             priority,
             card_status,
+            checklist,
+            recurrence,
+            revision,
             tags,
             comments,
         })
@@ -397,20 +1207,20 @@ impl Card {
 impl Default for Card {
     fn default() -> Self {
         Self {
+            blocked_by: Vec::new(),
             card_status: CardStatus::Active,
+            checklist: Vec::new(),
             comments: Vec::new(),
-            date_completed: FIELD_NOT_SET.to_string(),
-            date_created: chrono::Local::now()
-                .format(DateTimeFormat::default().to_parser_string())
-                .to_string(),
-            date_modified: chrono::Local::now()
-                .format(DateTimeFormat::default().to_parser_string())
-                .to_string(),
+            date_completed: None,
+            date_created: Some(Utc::now()),
+            date_modified: Some(Utc::now()),
             description: String::from("Default Card Description"),
-            due_date: FIELD_NOT_SET.to_string(),
+            due_date: None,
             id: get_id(),
             name: String::from("Default Card"),
             priority: CardPriority::Low,
+            recurrence: None,
+            revision: 0,
             tags: Vec::new(),
         }
     }
@@ -477,6 +1287,23 @@ impl Cards {
     pub fn swap(&mut self, index_1: usize, index_2: usize) {
         self.cards.swap(index_1, index_2);
     }
+    /// Stable, cards that compare equal keep their current order
+    pub fn sort_by_mode(&mut self, sort_mode: BoardSortMode) {
+        if sort_mode != BoardSortMode::Manual {
+            self.cards
+                .sort_by(|card_1, card_2| sort_mode.compare(card_1, card_2));
+        }
+    }
+    /// Puts the cards in the order of `card_ids`, cards missing from it keep their current
+    /// order after the others
+    pub fn order_by_ids(&mut self, card_ids: &[(u64, u64)]) {
+        self.cards.sort_by_key(|card| {
+            card_ids
+                .iter()
+                .position(|card_id| *card_id == card.id)
+                .unwrap_or(card_ids.len())
+        });
+    }
 }
 
 impl From<Vec<Card>> for Cards {
@@ -493,6 +1320,18 @@ impl FromIterator<Card> for Cards {
     }
 }
 
+/// Card dates are stored as RFC 3339 timestamps or null when not set, saves using the older
+/// formatted strings are migrated in io::migrations before they get here
+fn date_from_json(value: &Value, field_name: &str) -> Result<Option<DateTime<Utc>>, String> {
+    match value {
+        Value::Null => Ok(None),
+        Value::String(date_string) => DateTime::parse_from_rfc3339(date_string)
+            .map(|date_time| Some(date_time.with_timezone(&Utc)))
+            .map_err(|_| format!("card {} is invalid for card", field_name)),
+        _ => Err(format!("card {} is invalid for card", field_name)),
+    }
+}
+
 fn get_id() -> (u64, u64) {
     Uuid::new_v4().as_u64_pair()
 }
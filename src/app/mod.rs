@@ -3,15 +3,26 @@ use crate::{
         actions::Action,
         app_helper::{
             handle_edit_keybinding_mode, handle_general_actions, handle_mouse_action,
-            handle_user_input_mode, prepare_config_for_new_app,
+            handle_user_input_mode, prepare_config_for_new_app, refresh_card_filter,
+            restore_card_filter,
+        },
+        board_template::BoardTemplate,
+        card_template::CardTemplate,
+        kanban::{
+            ArchivedCard, Board, BoardOrdering, BoardSortMode, Boards, Card, CardPriority,
+            CardRecurrence, CardStatus, Comment,
         },
-        kanban::{Board, Boards, Card, CardPriority, CardStatus},
         state::{AppStatus, Focus, KeyBindingEnum, KeyBindings},
+        workspace::WorkspaceSettings,
     },
     constants::{
-        DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_NO_OF_BOARDS_PER_PAGE,
-        DEFAULT_NO_OF_CARDS_PER_BOARD, DEFAULT_TICKRATE, DEFAULT_VIEW, FIELD_NA,
-        IO_EVENT_WAIT_TIME, MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD, MAX_TICKRATE,
+        DEFAULT_AUTO_SAVE_INTERVAL, DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_COMMENT_AUTHOR,
+        DEFAULT_NO_OF_BOARDS_PER_PAGE, DEFAULT_NO_OF_CARDS_PER_BOARD,
+        DEFAULT_SAVE_RETENTION_DAILY_DAYS, DEFAULT_SAVE_RETENTION_VERSIONS_PER_DAY,
+        DEFAULT_TICKRATE, DEFAULT_UNDO_HISTORY_LENGTH, DEFAULT_VIEW, DEFAULT_WORKSPACE_NAME,
+        FIELD_NA, IO_EVENT_WAIT_TIME, MAX_AUTO_ARCHIVE_COMPLETED_DAYS, MAX_AUTO_SAVE_INTERVAL,
+        MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD, MAX_SAVE_RETENTION_DAILY_DAYS,
+        MAX_SAVE_RETENTION_VERSIONS_PER_DAY, MAX_TICKRATE, MAX_UNDO_HISTORY_LENGTH,
         MAX_WARNING_DUE_DATE_DAYS, MIN_NO_BOARDS_PER_PAGE, MIN_NO_CARDS_PER_BOARD, MIN_TICKRATE,
         MIN_WARNING_DUE_DATE_DAYS,
     },
@@ -19,17 +30,23 @@ use crate::{
     io::{
         data_handler::{self, get_available_local_save_files, get_default_save_directory},
         io_handler::refresh_visible_boards_and_cards,
+        journal::StorageMode,
         logger::{get_logs, RUST_KANBAN_LOGGER},
+        sync::SyncBackendType,
         IoEvent,
     },
     ui::{
         text_box::TextBox,
         theme::Theme,
-        widgets::{date_time_picker::CalenderType, Widgets},
+        widgets::{date_time_picker::CalenderType, toast::ToastType, Widgets},
         PopUp, TextColorOptions, TextModifierOptions, View,
     },
-    util::{send_error_toast, send_error_toast_with_duration, send_info_toast, send_warning_toast},
+    util::{
+        send_error_toast, send_error_toast_with_duration, send_info_toast, send_warning_toast,
+        update_current_board_and_card,
+    },
 };
+use chrono::{DateTime, Utc};
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, warn};
 use ratatui::widgets::TableState;
@@ -37,7 +54,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use state::AppState;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt::{self, Display, Formatter},
     path::PathBuf,
     str::FromStr,
@@ -49,8 +66,14 @@ use strum_macros::EnumIter;
 
 pub mod actions;
 pub mod app_helper;
+pub mod board_template;
+pub mod card_filter;
+pub mod card_template;
+pub mod inbuilt_board_templates;
 pub mod kanban;
+pub mod save_diff;
 pub mod state;
+pub mod workspace;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum AppReturn {
@@ -60,8 +83,8 @@ pub enum AppReturn {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ActionHistory {
-    /// card, board_id
-    DeleteCard(Card, (u64, u64)),
+    /// card, board_id, ids of the cards whose blocked_by link to the card was removed
+    DeleteCard(Card, (u64, u64), Vec<(u64, u64)>),
     /// card, board_id
     CreateCard(Card, (u64, u64)),
     /// board
@@ -74,11 +97,131 @@ pub enum ActionHistory {
     CreateBoard(Board),
     /// old_card, new_card, board_id
     EditCard(Card, Card, (u64, u64)),
+    /// old_comment, new_comment, card_id, board_id
+    EditComment(Comment, Comment, (u64, u64), (u64, u64)),
+    /// comment, comment_index, card_id, board_id
+    DeleteComment(Comment, usize, (u64, u64), (u64, u64)),
+    /// board_id, ordering before, ordering after
+    SortBoard((u64, u64), BoardOrdering, BoardOrdering),
+    /// old_board, new_board, both stored without their cards
+    EditBoard(Board, Board),
+    /// board_id, moved_from_index, moved_to_index
+    MoveBoard((u64, u64), usize, usize),
+    /// old_query, new_query, no query means no filter
+    ChangeFilter(Option<String>, Option<String>),
+    /// save_name, boards before the save was loaded, boards of the save
+    LoadSave(String, Boards, Boards),
+    /// board_id and archived card for every card that was archived
+    ArchiveCards(Vec<((u64, u64), ArchivedCard)>),
+    /// board_id, archived card
+    RestoreArchivedCard((u64, u64), ArchivedCard),
+    /// board_id, archived card, index in the archive of the board
+    DeleteArchivedCard((u64, u64), ArchivedCard, usize),
+    /// old_card, completed_card, next occurrence of the card, board_id
+    CompleteRecurringCard(Card, Card, Box<Card>, (u64, u64)),
+    /// template_name, boards before the template was applied, boards created from the template
+    ApplyBoardTemplate(String, Boards, Boards),
+    /// card_name, save_name, boards before the card was restored, boards after
+    RestoreCardFromSave(String, String, Boards, Boards),
 }
 
-#[derive(Default)]
+impl ActionHistory {
+    /// Short description for the action history popup, names of boards and cards that are only
+    /// stored by id are looked up in `boards`
+    pub fn describe(&self, boards: &Boards) -> String {
+        let board_name = |board_id: &(u64, u64)| {
+            boards
+                .get_board_with_id(*board_id)
+                .map_or(FIELD_NA.to_string(), |board| board.name.clone())
+        };
+        let card_name = |card_id: &(u64, u64)| {
+            boards
+                .find_card_with_id(*card_id)
+                .map_or(FIELD_NA.to_string(), |card| card.name.clone())
+        };
+        match self {
+            ActionHistory::DeleteCard(card, _, _) => format!("Delete Card '{}'", card.name),
+            ActionHistory::CreateCard(card, _) => format!("Create Card '{}'", card.name),
+            ActionHistory::DeleteBoard(board) => format!("Delete Board '{}'", board.name),
+            ActionHistory::MoveCardBetweenBoards(card, _, moved_to_board_id, _, _) => format!(
+                "Move Card '{}' to '{}'",
+                card.name,
+                board_name(moved_to_board_id)
+            ),
+            ActionHistory::MoveCardWithinBoard(board_id, _, _) => {
+                format!("Move Card within '{}'", board_name(board_id))
+            }
+            ActionHistory::CreateBoard(board) => format!("Create Board '{}'", board.name),
+            ActionHistory::EditCard(old_card, new_card, _) => {
+                if old_card.card_status != new_card.card_status {
+                    format!(
+                        "Change Status of '{}' to {}",
+                        new_card.name, new_card.card_status
+                    )
+                } else if old_card.priority != new_card.priority {
+                    format!(
+                        "Change Priority of '{}' to {}",
+                        new_card.name, new_card.priority
+                    )
+                } else {
+                    format!("Edit Card '{}'", new_card.name)
+                }
+            }
+            ActionHistory::EditComment(_, _, card_id, _) => {
+                format!("Edit Comment on '{}'", card_name(card_id))
+            }
+            ActionHistory::DeleteComment(_, _, card_id, _) => {
+                format!("Delete Comment on '{}'", card_name(card_id))
+            }
+            ActionHistory::SortBoard(board_id, _, _) => {
+                format!("Sort Board '{}'", board_name(board_id))
+            }
+            ActionHistory::EditBoard(_, new_board) => format!("Edit Board '{}'", new_board.name),
+            ActionHistory::MoveBoard(board_id, moved_from_index, moved_to_index) => {
+                let direction = if moved_to_index < moved_from_index {
+                    "left"
+                } else {
+                    "right"
+                };
+                format!("Move Board '{}' to the {}", board_name(board_id), direction)
+            }
+            ActionHistory::ChangeFilter(_, Some(new_query)) => {
+                format!("Filter by \"{}\"", new_query)
+            }
+            ActionHistory::ChangeFilter(_, None) => "Clear Filter".to_string(),
+            ActionHistory::LoadSave(save_name, _, _) => format!("Load Save '{}'", save_name),
+            ActionHistory::ArchiveCards(archived_cards) => match archived_cards.as_slice() {
+                [(_, archived_card)] => format!("Archive Card '{}'", archived_card.card.name),
+                _ => format!("Archive {} Cards", archived_cards.len()),
+            },
+            ActionHistory::RestoreArchivedCard(_, archived_card) => {
+                format!("Restore Card '{}'", archived_card.card.name)
+            }
+            ActionHistory::DeleteArchivedCard(_, archived_card, _) => {
+                format!("Permanently Delete Card '{}'", archived_card.card.name)
+            }
+            ActionHistory::CompleteRecurringCard(_, completed_card, _, _) => {
+                format!("Complete Recurring Card '{}'", completed_card.name)
+            }
+            ActionHistory::ApplyBoardTemplate(template_name, _, _) => {
+                format!("Start From Board Template '{}'", template_name)
+            }
+            ActionHistory::RestoreCardFromSave(card_name, save_name, _, _) => {
+                format!("Restore Card '{}' From '{}'", card_name, save_name)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActionHistoryEntry {
+    pub action: ActionHistory,
+    pub time: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ActionHistoryManager {
-    pub history: Vec<ActionHistory>,
+    pub history: Vec<ActionHistoryEntry>,
     pub history_index: usize,
 }
 
@@ -87,13 +230,72 @@ impl ActionHistoryManager {
         if self.history_index != self.history.len() {
             self.history.truncate(self.history_index);
         }
-        self.history.push(action);
+        self.history.push(ActionHistoryEntry {
+            action,
+            time: Utc::now(),
+        });
         self.history_index += 1;
     }
     pub fn reset(&mut self) {
         self.history.clear();
         self.history_index = 0;
     }
+    /// The history to keep with a save, filter changes are left out as filters are not saved and
+    /// the oldest entries are dropped first to keep at most `max_len` of them and `max_size` bytes
+    /// of json. Actions holding whole copies of the boards (loading a save, applying a board
+    /// template, restoring a card from a save) are not kept either, and neither are the entries
+    /// that can only be undone or redone through them
+    pub fn to_saved_history(&self, max_len: usize, max_size: usize) -> ActionHistoryManager {
+        let replaces_boards = |action: &ActionHistory| {
+            matches!(
+                action,
+                ActionHistory::LoadSave(..)
+                    | ActionHistory::ApplyBoardTemplate(..)
+                    | ActionHistory::RestoreCardFromSave(..)
+            )
+        };
+        let undo_start = self.history[..self.history_index]
+            .iter()
+            .rposition(|entry| replaces_boards(&entry.action))
+            .map_or(0, |index| index + 1);
+        let redo_end = self.history[self.history_index..]
+            .iter()
+            .position(|entry| replaces_boards(&entry.action))
+            .map_or(self.history.len(), |index| self.history_index + index);
+        let mut history = Vec::new();
+        let mut history_index = 0;
+        for (index, entry) in self.history[..redo_end].iter().enumerate().skip(undo_start) {
+            if matches!(entry.action, ActionHistory::ChangeFilter(..)) {
+                continue;
+            }
+            history.push(entry.clone());
+            if index < self.history_index {
+                history_index += 1;
+            }
+        }
+        let start = history.len().saturating_sub(max_len).min(history_index);
+        history.drain(..start);
+        history.truncate(max_len);
+        let mut history_index = history_index - start;
+        let mut size: usize = history
+            .iter()
+            .map(|entry| serde_json::to_string(entry).map_or(0, |json| json.len()))
+            .sum();
+        // Oldest entries go first, then the ones that can only be redone, newest first
+        while size > max_size && !history.is_empty() {
+            let entry = if history_index > 0 {
+                history_index -= 1;
+                history.remove(0)
+            } else {
+                history.pop().unwrap()
+            };
+            size -= serde_json::to_string(&entry).map_or(0, |json| json.len());
+        }
+        ActionHistoryManager {
+            history,
+            history_index,
+        }
+    }
 }
 
 /// Hashmap of board id to a vector of card id's
@@ -113,6 +315,9 @@ pub struct App<'a> {
     pub last_io_event_time: Option<Instant>,
     pub all_themes: Vec<Theme>,
     pub current_theme: Theme,
+    pub card_templates: Vec<CardTemplate>,
+    /// Inbuilt templates first, followed by the ones saved in the config directory
+    pub board_templates: Vec<BoardTemplate>,
     pub action_history_manager: ActionHistoryManager,
     pub main_menu: MainMenu,
     pub widgets: Widgets<'a>,
@@ -128,7 +333,7 @@ impl App<'_> {
         let all_themes = Theme::all_default_themes();
         let mut theme = Theme::default();
         let (config, config_errors, toasts) = prepare_config_for_new_app();
-        let default_theme = config.default_theme.clone();
+        let default_theme = config.workspace_default_theme();
         let theme_in_all = all_themes.iter().find(|t| t.name == default_theme);
         if let Some(theme_in_all) = theme_in_all {
             theme = theme_in_all.clone();
@@ -153,6 +358,8 @@ impl App<'_> {
             last_io_event_time: None,
             all_themes,
             current_theme: theme,
+            card_templates: Vec::new(),
+            board_templates: BoardTemplate::all_default_templates(),
             action_history_manager: ActionHistoryManager::default(),
             main_menu: MainMenu::default(),
             widgets,
@@ -166,12 +373,48 @@ impl App<'_> {
     }
 
     pub async fn do_action(&mut self, key: Key) -> AppReturn {
-        if self.state.app_status == AppStatus::UserInput {
+        let app_return = if self.state.app_status == AppStatus::UserInput {
             handle_user_input_mode(self, key).await
         } else if self.state.app_status == AppStatus::KeyBindMode {
             handle_edit_keybinding_mode(self, key).await
         } else {
             handle_general_actions(self, key).await
+        };
+        self.keep_boards_sorted();
+        app_return
+    }
+    /// Adding, editing and moving cards can put the cards of a sorted board out of order, so
+    /// sorted boards are sorted again after every action
+    fn keep_boards_sorted(&mut self) {
+        let mut resorted_board_ids = self.boards.keep_sorted();
+        resorted_board_ids.extend(self.filtered_boards.keep_sorted());
+        let boards = if self.filtered_boards.is_empty() {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        let no_of_cards_to_show = self.config.no_of_cards_to_show as usize;
+        for board_id in resorted_board_ids {
+            let (Some(board), Some(visible_cards)) = (
+                boards.get_board_with_id(board_id),
+                self.visible_boards_and_cards.get_mut(&board_id),
+            ) else {
+                continue;
+            };
+            // Scroll just far enough to keep the current card visible
+            let first_visible_index = self
+                .state
+                .current_card_id
+                .filter(|_| self.state.current_board_id == Some(board_id))
+                .and_then(|card_id| board.cards.get_card_index(card_id))
+                .map_or(0, |index| (index + 1).saturating_sub(no_of_cards_to_show));
+            *visible_cards = board
+                .cards
+                .get_all_card_ids()
+                .into_iter()
+                .skip(first_visible_index)
+                .take(no_of_cards_to_show)
+                .collect();
         }
     }
     pub async fn dispatch(&mut self, action: IoEvent) {
@@ -198,11 +441,25 @@ impl App<'_> {
 
     pub async fn handle_mouse(&mut self, mouse_action: Mouse) -> AppReturn {
         if self.config.enable_mouse_support {
-            handle_mouse_action(self, mouse_action).await
+            let app_return = handle_mouse_action(self, mouse_action).await;
+            self.keep_boards_sorted();
+            app_return
         } else {
             AppReturn::Continue
         }
     }
+    /// The configured display name, falls back to the email of the logged in user
+    pub fn get_comment_author(&self) -> String {
+        let display_name = self.config.display_name.trim();
+        if !display_name.is_empty() {
+            return display_name.to_string();
+        }
+        if let Some(email_id) = &self.state.user_login_data.email_id {
+            return email_id.clone();
+        }
+        DEFAULT_COMMENT_AUTHOR.to_string()
+    }
+
     pub fn get_first_keybinding(&self, keybinding_enum: KeyBindingEnum) -> Option<String> {
         self.config
             .keybindings
@@ -224,6 +481,46 @@ impl App<'_> {
     pub fn loaded(&mut self) {
         self.is_loading = false;
     }
+    /// Whether the boards differ from the latest save on disk, the generation only skips the
+    /// comparison when nothing touched the boards since they last matched it
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.state.saved_boards_generation != Some(self.boards.generation())
+            && self.boards != self.state.saved_boards
+    }
+    /// Called once the boards were written as the latest save, or loaded from it
+    pub fn mark_boards_saved(&mut self) {
+        self.state.saved_boards = self.boards.clone();
+        self.state.saved_boards_generation = Some(self.boards.generation());
+    }
+    /// Called when the latest save on disk changed without the boards being saved, e.g. when
+    /// it was deleted or the app started without loading it
+    pub fn set_saved_boards(&mut self, saved_boards: Boards) {
+        self.state.saved_boards = saved_boards;
+        self.state.saved_boards_generation = None;
+    }
+    /// Checked on every tick, true once unsaved changes have been left alone for the auto-save
+    /// interval so a burst of changes is saved only once. A failed save is retried after
+    /// another interval
+    pub fn auto_save_due(&mut self) -> bool {
+        let generation = self.boards.generation();
+        if generation != self.state.boards_changed.0 {
+            self.state.boards_changed = (generation, Instant::now());
+            if self.boards == self.state.saved_boards {
+                self.state.saved_boards_generation = Some(generation);
+            }
+            return false;
+        }
+        let interval = Duration::from_secs(self.config.auto_save_interval as u64);
+        if interval.is_zero()
+            || self.state.latest_save_load_error.is_some()
+            || !self.has_unsaved_changes()
+            || self.state.boards_changed.1.elapsed() < interval
+        {
+            return false;
+        }
+        self.state.boards_changed.1 = Instant::now();
+        true
+    }
     pub fn get_current_focus(&self) -> &Focus {
         &self.state.focus
     }
@@ -238,11 +535,15 @@ impl App<'_> {
             send_error_toast(&mut self.widgets.toast_widget, "No more actions to undo");
         } else {
             let history_index = self.action_history_manager.history_index - 1;
-            let history = self.action_history_manager.history[history_index].clone();
+            let history = self.action_history_manager.history[history_index]
+                .action
+                .clone();
             match history {
-                ActionHistory::DeleteCard(card, board_id) => {
+                ActionHistory::DeleteCard(card, board_id, unlinked_card_ids) => {
                     if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
                         board.cards.add_card(card.clone());
+                        self.boards
+                            .restore_blocked_by_links(card.id, &unlinked_card_ids);
                         self.action_history_manager.history_index -= 1;
                         refresh_visible_boards_and_cards(self);
                         send_info_toast(
@@ -405,6 +706,259 @@ impl App<'_> {
                         refresh_visible_boards_and_cards(self);
                     }
                 }
+                ActionHistory::EditComment(old_comment, _, card_id, board_id) => {
+                    let comment_restored = self
+                        .boards
+                        .get_mut_board_with_id(board_id)
+                        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+                        .is_some_and(|card| {
+                            let replaced = card.replace_comment(old_comment);
+                            if replaced {
+                                card.mark_modified();
+                            }
+                            replaced
+                        });
+                    if comment_restored {
+                        self.action_history_manager.history_index -= 1;
+                        send_info_toast(&mut self.widgets.toast_widget, "Undo Edit Comment");
+                        refresh_visible_boards_and_cards(self);
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            "Could not undo edit comment as the card or comment was not found",
+                        );
+                    }
+                }
+                ActionHistory::DeleteComment(comment, comment_index, card_id, board_id) => {
+                    let comment_restored = self
+                        .boards
+                        .get_mut_board_with_id(board_id)
+                        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+                        .map(|card| {
+                            let comment_index = comment_index.min(card.comments.len());
+                            card.comments.insert(comment_index, comment);
+                            card.mark_modified();
+                        })
+                        .is_some();
+                    if comment_restored {
+                        self.action_history_manager.history_index -= 1;
+                        send_info_toast(&mut self.widgets.toast_widget, "Undo Delete Comment");
+                        refresh_visible_boards_and_cards(self);
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            "Could not undo delete comment as the card was not found",
+                        );
+                    }
+                }
+                ActionHistory::SortBoard(board_id, old_ordering, _) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
+                        board.restore_ordering(&old_ordering);
+                        let board_name = board.name.clone();
+                        self.action_history_manager.history_index -= 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Undo Sort Board '{}'", board_name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!(
+                                "Could not undo sort board as the board with id '{:?}' was not found",
+                                board_id
+                            ),
+                        );
+                    }
+                }
+                ActionHistory::EditBoard(old_board, _) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(old_board.id) {
+                        board.set_details(&old_board);
+                        self.action_history_manager.history_index -= 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Undo Edit Board '{}'", old_board.name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!(
+                                "Could not undo edit board as the board with id '{:?}' was not found",
+                                old_board.id
+                            ),
+                        );
+                    }
+                }
+                ActionHistory::MoveBoard(board_id, moved_from_index, _) => {
+                    let board_index = self.boards.get_board_index(board_id);
+                    let board_name = self
+                        .boards
+                        .get_board_with_id(board_id)
+                        .map(|board| board.name.clone());
+                    match (board_index, board_name) {
+                        (Some(board_index), Some(board_name))
+                            if self.boards.swap(board_index, moved_from_index).is_ok() =>
+                        {
+                            self.action_history_manager.history_index -= 1;
+                            refresh_visible_boards_and_cards(self);
+                            send_info_toast(
+                                &mut self.widgets.toast_widget,
+                                &format!("Undo Move Board '{}'", board_name),
+                            );
+                        }
+                        _ => {
+                            send_error_toast(
+                                &mut self.widgets.toast_widget,
+                                &format!(
+                                    "Could not undo move board as the board with id '{:?}' was not found",
+                                    board_id
+                                ),
+                            );
+                        }
+                    }
+                }
+                ActionHistory::ChangeFilter(old_query, _) => {
+                    match restore_card_filter(self, old_query.as_deref()) {
+                        Ok(_) => {
+                            self.action_history_manager.history_index -= 1;
+                            send_info_toast(&mut self.widgets.toast_widget, "Undo Filter Change");
+                        }
+                        Err(err) => {
+                            send_error_toast(
+                                &mut self.widgets.toast_widget,
+                                &format!("Could not undo filter change: {}", err),
+                            );
+                        }
+                    }
+                }
+                ActionHistory::LoadSave(save_name, old_boards, _) => {
+                    self.boards.set_boards(old_boards);
+                    update_current_board_and_card(&mut self.state, None, None);
+                    self.action_history_manager.history_index -= 1;
+                    refresh_visible_boards_and_cards(self);
+                    send_info_toast(
+                        &mut self.widgets.toast_widget,
+                        &format!("Undo Load Save '{}'", save_name),
+                    );
+                }
+                ActionHistory::RestoreCardFromSave(card_name, save_name, old_boards, _) => {
+                    self.boards.set_boards(old_boards);
+                    update_current_board_and_card(&mut self.state, None, None);
+                    self.action_history_manager.history_index -= 1;
+                    refresh_visible_boards_and_cards(self);
+                    send_info_toast(
+                        &mut self.widgets.toast_widget,
+                        &format!("Undo Restore Card '{}' From '{}'", card_name, save_name),
+                    );
+                }
+                ActionHistory::ApplyBoardTemplate(template_name, old_boards, _) => {
+                    self.boards.set_boards(old_boards);
+                    update_current_board_and_card(&mut self.state, None, None);
+                    self.action_history_manager.history_index -= 1;
+                    refresh_visible_boards_and_cards(self);
+                    send_info_toast(
+                        &mut self.widgets.toast_widget,
+                        &format!("Undo Start From Board Template '{}'", template_name),
+                    );
+                }
+                ActionHistory::ArchiveCards(archived_cards) => {
+                    let missing_board_id = archived_cards
+                        .iter()
+                        .map(|(board_id, _)| *board_id)
+                        .find(|board_id| self.boards.get_board_with_id(*board_id).is_none());
+                    if let Some(board_id) = missing_board_id {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!(
+                                "Could not undo archive as the board with id '{:?}' was not found",
+                                board_id
+                            ),
+                        );
+                    } else {
+                        for (board_id, archived_card) in archived_cards.iter().rev() {
+                            self.boards
+                                .restore_archived_card(*board_id, archived_card.card.id);
+                        }
+                        self.action_history_manager.history_index -= 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!(
+                                "Undo {}",
+                                ActionHistory::ArchiveCards(archived_cards).describe(&self.boards)
+                            ),
+                        );
+                    }
+                }
+                ActionHistory::RestoreArchivedCard(board_id, archived_card) => {
+                    if self.boards.get_board_with_id(board_id).is_some() {
+                        let card_name = archived_card.card.name.clone();
+                        self.boards.put_in_archive(board_id, archived_card);
+                        self.action_history_manager.history_index -= 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Undo Restore Card '{}'", card_name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Could not undo restore card '{}' as the board with id '{:?}' was not found", archived_card.card.name, board_id)
+                        );
+                    }
+                }
+                ActionHistory::CompleteRecurringCard(old_card, _, next_card, board_id) => {
+                    let card_restored =
+                        self.boards
+                            .get_mut_board_with_id(board_id)
+                            .is_some_and(|board| {
+                                let Some(card) = board.cards.get_mut_card_with_id(old_card.id)
+                                else {
+                                    return false;
+                                };
+                                *card = old_card.clone();
+                                board.cards.remove_card_with_id(next_card.id);
+                                board
+                                    .manual_order
+                                    .retain(|card_id| *card_id != next_card.id);
+                                true
+                            });
+                    if card_restored {
+                        self.action_history_manager.history_index -= 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Undo Complete Recurring Card '{}'", old_card.name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Could not undo complete card '{}' as the card or the board with id '{:?}' was not found", old_card.name, board_id)
+                        );
+                    }
+                }
+                ActionHistory::DeleteArchivedCard(board_id, archived_card, archive_index) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
+                        let card_name = archived_card.card.name.clone();
+                        let archive_index = archive_index.min(board.archived_cards.len());
+                        board.archived_cards.insert(archive_index, archived_card);
+                        self.action_history_manager.history_index -= 1;
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Undo Permanently Delete Card '{}'", card_name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Could not undo delete card '{}' as the board with id '{:?}' was not found", archived_card.card.name, board_id)
+                        );
+                    }
+                }
+            }
+            if self.state.card_filter.is_some() {
+                refresh_card_filter(self);
+                refresh_visible_boards_and_cards(self);
             }
         }
     }
@@ -414,11 +968,14 @@ impl App<'_> {
             send_error_toast(&mut self.widgets.toast_widget, "No more actions to redo");
         } else {
             let history_index = self.action_history_manager.history_index;
-            let history = self.action_history_manager.history[history_index].clone();
+            let history = self.action_history_manager.history[history_index]
+                .action
+                .clone();
             match history {
-                ActionHistory::DeleteCard(card, board_id) => {
+                ActionHistory::DeleteCard(card, board_id, _) => {
                     if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
                         board.cards.remove_card_with_id(card.id);
+                        self.boards.remove_blocked_by_links(card.id);
                         refresh_visible_boards_and_cards(self);
                         self.action_history_manager.history_index += 1;
                         send_info_toast(
@@ -582,9 +1139,300 @@ impl App<'_> {
                         refresh_visible_boards_and_cards(self);
                     }
                 }
+                ActionHistory::EditComment(_, new_comment, card_id, board_id) => {
+                    let comment_restored = self
+                        .boards
+                        .get_mut_board_with_id(board_id)
+                        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+                        .is_some_and(|card| {
+                            let replaced = card.replace_comment(new_comment);
+                            if replaced {
+                                card.mark_modified();
+                            }
+                            replaced
+                        });
+                    if comment_restored {
+                        self.action_history_manager.history_index += 1;
+                        send_info_toast(&mut self.widgets.toast_widget, "Redo Edit Comment");
+                        refresh_visible_boards_and_cards(self);
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            "Could not redo edit comment as the card or comment was not found",
+                        );
+                    }
+                }
+                ActionHistory::DeleteComment(comment, _, card_id, board_id) => {
+                    let comment_removed = self
+                        .boards
+                        .get_mut_board_with_id(board_id)
+                        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+                        .and_then(|card| {
+                            let removed_comment = card.remove_comment_with_id(comment.id);
+                            if removed_comment.is_some() {
+                                card.mark_modified();
+                            }
+                            removed_comment
+                        })
+                        .is_some();
+                    if comment_removed {
+                        self.action_history_manager.history_index += 1;
+                        send_info_toast(&mut self.widgets.toast_widget, "Redo Delete Comment");
+                        refresh_visible_boards_and_cards(self);
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            "Could not redo delete comment as the card or comment was not found",
+                        );
+                    }
+                }
+                ActionHistory::SortBoard(board_id, _, new_ordering) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
+                        board.restore_ordering(&new_ordering);
+                        let board_name = board.name.clone();
+                        self.action_history_manager.history_index += 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Redo Sort Board '{}'", board_name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!(
+                                "Could not redo sort board as the board with id '{:?}' was not found",
+                                board_id
+                            ),
+                        );
+                    }
+                }
+                ActionHistory::EditBoard(_, new_board) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(new_board.id) {
+                        board.set_details(&new_board);
+                        self.action_history_manager.history_index += 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Redo Edit Board '{}'", new_board.name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!(
+                                "Could not redo edit board as the board with id '{:?}' was not found",
+                                new_board.id
+                            ),
+                        );
+                    }
+                }
+                ActionHistory::MoveBoard(board_id, _, moved_to_index) => {
+                    let board_index = self.boards.get_board_index(board_id);
+                    let board_name = self
+                        .boards
+                        .get_board_with_id(board_id)
+                        .map(|board| board.name.clone());
+                    match (board_index, board_name) {
+                        (Some(board_index), Some(board_name))
+                            if self.boards.swap(board_index, moved_to_index).is_ok() =>
+                        {
+                            self.action_history_manager.history_index += 1;
+                            refresh_visible_boards_and_cards(self);
+                            send_info_toast(
+                                &mut self.widgets.toast_widget,
+                                &format!("Redo Move Board '{}'", board_name),
+                            );
+                        }
+                        _ => {
+                            send_error_toast(
+                                &mut self.widgets.toast_widget,
+                                &format!(
+                                    "Could not redo move board as the board with id '{:?}' was not found",
+                                    board_id
+                                ),
+                            );
+                        }
+                    }
+                }
+                ActionHistory::ChangeFilter(_, new_query) => {
+                    match restore_card_filter(self, new_query.as_deref()) {
+                        Ok(_) => {
+                            self.action_history_manager.history_index += 1;
+                            send_info_toast(&mut self.widgets.toast_widget, "Redo Filter Change");
+                        }
+                        Err(err) => {
+                            send_error_toast(
+                                &mut self.widgets.toast_widget,
+                                &format!("Could not redo filter change: {}", err),
+                            );
+                        }
+                    }
+                }
+                ActionHistory::LoadSave(save_name, _, new_boards) => {
+                    self.boards.set_boards(new_boards);
+                    update_current_board_and_card(&mut self.state, None, None);
+                    self.action_history_manager.history_index += 1;
+                    refresh_visible_boards_and_cards(self);
+                    send_info_toast(
+                        &mut self.widgets.toast_widget,
+                        &format!("Redo Load Save '{}'", save_name),
+                    );
+                }
+                ActionHistory::RestoreCardFromSave(card_name, save_name, _, new_boards) => {
+                    self.boards.set_boards(new_boards);
+                    update_current_board_and_card(&mut self.state, None, None);
+                    self.action_history_manager.history_index += 1;
+                    refresh_visible_boards_and_cards(self);
+                    send_info_toast(
+                        &mut self.widgets.toast_widget,
+                        &format!("Redo Restore Card '{}' From '{}'", card_name, save_name),
+                    );
+                }
+                ActionHistory::ApplyBoardTemplate(template_name, _, new_boards) => {
+                    self.boards.set_boards(new_boards);
+                    update_current_board_and_card(&mut self.state, None, None);
+                    self.action_history_manager.history_index += 1;
+                    refresh_visible_boards_and_cards(self);
+                    send_info_toast(
+                        &mut self.widgets.toast_widget,
+                        &format!("Redo Start From Board Template '{}'", template_name),
+                    );
+                }
+                ActionHistory::ArchiveCards(archived_cards) => {
+                    let missing_board_id = archived_cards
+                        .iter()
+                        .map(|(board_id, _)| *board_id)
+                        .find(|board_id| self.boards.get_board_with_id(*board_id).is_none());
+                    if let Some(board_id) = missing_board_id {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!(
+                                "Could not redo archive as the board with id '{:?}' was not found",
+                                board_id
+                            ),
+                        );
+                    } else {
+                        let description = ActionHistory::ArchiveCards(archived_cards.clone())
+                            .describe(&self.boards);
+                        for (board_id, archived_card) in archived_cards {
+                            self.boards.put_in_archive(board_id, archived_card);
+                        }
+                        self.action_history_manager.history_index += 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Redo {}", description),
+                        );
+                    }
+                }
+                ActionHistory::RestoreArchivedCard(board_id, archived_card) => {
+                    if self
+                        .boards
+                        .restore_archived_card(board_id, archived_card.card.id)
+                        .is_some()
+                    {
+                        self.action_history_manager.history_index += 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Redo Restore Card '{}'", archived_card.card.name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Could not redo restore card '{}' as it was not found in the archive of the board with id '{:?}'", archived_card.card.name, board_id)
+                        );
+                    }
+                }
+                ActionHistory::DeleteArchivedCard(board_id, archived_card, _) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
+                        board.archived_cards.retain(|board_archived_card| {
+                            board_archived_card.card.id != archived_card.card.id
+                        });
+                        self.action_history_manager.history_index += 1;
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Redo Permanently Delete Card '{}'", archived_card.card.name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Could not redo delete card '{}' as the board with id '{:?}' was not found", archived_card.card.name, board_id)
+                        );
+                    }
+                }
+                ActionHistory::CompleteRecurringCard(_, completed_card, next_card, board_id) => {
+                    let card_completed =
+                        self.boards
+                            .get_mut_board_with_id(board_id)
+                            .is_some_and(|board| {
+                                let Some(card) =
+                                    board.cards.get_mut_card_with_id(completed_card.id)
+                                else {
+                                    return false;
+                                };
+                                *card = completed_card.clone();
+                                board.add_card_after(completed_card.id, *next_card);
+                                true
+                            });
+                    if card_completed {
+                        self.action_history_manager.history_index += 1;
+                        refresh_visible_boards_and_cards(self);
+                        send_info_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Redo Complete Recurring Card '{}'", completed_card.name),
+                        );
+                    } else {
+                        send_error_toast(
+                            &mut self.widgets.toast_widget,
+                            &format!("Could not redo complete card '{}' as the card or the board with id '{:?}' was not found", completed_card.name, board_id)
+                        );
+                    }
+                }
+            }
+            if self.state.card_filter.is_some() {
+                refresh_card_filter(self);
+                refresh_visible_boards_and_cards(self);
             }
         }
     }
+    /// Undoes or redoes actions until `history_index` actions are applied, stops early if an
+    /// action can not be undone or redone. Only errors are shown for the individual steps
+    pub fn jump_to_history_index(&mut self, history_index: usize) {
+        let history_index = history_index.min(self.action_history_manager.history.len());
+        let toasts_before = self.widgets.toast_widget.toasts.len();
+        let start_index = self.action_history_manager.history_index;
+        while self.action_history_manager.history_index != history_index {
+            let previous_index = self.action_history_manager.history_index;
+            if previous_index > history_index {
+                self.undo();
+            } else {
+                self.redo();
+            }
+            if self.action_history_manager.history_index == previous_index {
+                break;
+            }
+        }
+        let step_toasts = self.widgets.toast_widget.toasts.split_off(toasts_before);
+        self.widgets.toast_widget.toasts.extend(
+            step_toasts
+                .into_iter()
+                .filter(|toast| toast.toast_type != ToastType::Info),
+        );
+        let end_index = self.action_history_manager.history_index;
+        let plural = |count: usize| if count == 1 { "action" } else { "actions" };
+        let info_msg = match end_index.cmp(&start_index) {
+            std::cmp::Ordering::Less => {
+                let count = start_index - end_index;
+                format!("Undid {} {}", count, plural(count))
+            }
+            std::cmp::Ordering::Greater => {
+                let count = end_index - start_index;
+                format!("Redid {} {}", count, plural(count))
+            }
+            std::cmp::Ordering::Equal => "Already at that point in the history".to_string(),
+        };
+        send_info_toast(&mut self.widgets.toast_widget, &info_msg);
+    }
     fn select_next(current_index: Option<usize>, items_len: usize) -> usize {
         match current_index {
             Some(i) => {
@@ -655,6 +1503,65 @@ impl App<'_> {
         let i = Self::select_previous(self.state.app_list_states.load_save.selected(), items_len);
         self.state.app_list_states.load_save.select(Some(i));
     }
+    pub fn save_diff_next(&mut self) {
+        let items_len = self
+            .state
+            .save_diff
+            .as_ref()
+            .map_or(0, |save_diff| save_diff.entries.len());
+        if items_len == 0 {
+            return;
+        }
+        let i = Self::select_next(self.state.app_list_states.save_diff.selected(), items_len);
+        self.state.app_list_states.save_diff.select(Some(i));
+    }
+    pub fn save_diff_prv(&mut self) {
+        let items_len = self
+            .state
+            .save_diff
+            .as_ref()
+            .map_or(0, |save_diff| save_diff.entries.len());
+        if items_len == 0 {
+            return;
+        }
+        let i = Self::select_previous(self.state.app_list_states.save_diff.selected(), items_len);
+        self.state.app_list_states.save_diff.select(Some(i));
+    }
+    /// What the selected save can be compared with, `None` is the current boards
+    fn save_diff_targets(&self) -> Vec<Option<String>> {
+        let save_files = get_available_local_save_files(&self.config).unwrap_or_default();
+        let selected_save = self
+            .state
+            .app_list_states
+            .load_save
+            .selected()
+            .and_then(|index| save_files.get(index))
+            .cloned();
+        let mut targets = vec![None];
+        targets.extend(
+            save_files
+                .into_iter()
+                .filter(|save_file| Some(save_file) != selected_save.as_ref())
+                .map(Some),
+        );
+        targets
+    }
+    pub fn save_diff_target_next(&mut self) {
+        let targets = self.save_diff_targets();
+        let current_index = targets
+            .iter()
+            .position(|target| *target == self.state.save_diff_target);
+        let i = Self::select_next(current_index, targets.len());
+        self.state.save_diff_target = targets[i].clone();
+    }
+    pub fn save_diff_target_prv(&mut self) {
+        let targets = self.save_diff_targets();
+        let current_index = targets
+            .iter()
+            .position(|target| *target == self.state.save_diff_target);
+        let i = Self::select_previous(current_index, targets.len());
+        self.state.save_diff_target = targets[i].clone();
+    }
     pub fn edit_keybindings_next(&mut self) {
         let keybinding_count = self.config.keybindings.iter().count();
         let i = Self::select_next(
@@ -787,28 +1694,120 @@ impl App<'_> {
             );
             self.state
                 .app_list_states
-                .command_palette_board_search
-                .select(Some(i));
-        }
+                .command_palette_board_search
+                .select(Some(i));
+        }
+    }
+    pub fn select_card_status_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state.app_list_states.card_status_selector.selected(),
+            CardStatus::all().len(),
+        );
+        self.state
+            .app_list_states
+            .card_status_selector
+            .select(Some(i));
+    }
+    pub fn select_card_status_next(&mut self) {
+        let i = Self::select_next(
+            self.state.app_list_states.card_status_selector.selected(),
+            CardStatus::all().len(),
+        );
+        self.state
+            .app_list_states
+            .card_status_selector
+            .select(Some(i));
+    }
+    pub fn select_card_recurrence_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state
+                .app_list_states
+                .card_recurrence_selector
+                .selected(),
+            CardRecurrence::all().len() + 1,
+        );
+        self.state
+            .app_list_states
+            .card_recurrence_selector
+            .select(Some(i));
+    }
+    pub fn select_card_recurrence_next(&mut self) {
+        let i = Self::select_next(
+            self.state
+                .app_list_states
+                .card_recurrence_selector
+                .selected(),
+            CardRecurrence::all().len() + 1,
+        );
+        self.state
+            .app_list_states
+            .card_recurrence_selector
+            .select(Some(i));
+    }
+    pub fn select_card_template_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state.app_list_states.card_template_selector.selected(),
+            self.card_templates.len() + 1,
+        );
+        self.state
+            .app_list_states
+            .card_template_selector
+            .select(Some(i));
+    }
+    pub fn select_card_template_next(&mut self) {
+        let i = Self::select_next(
+            self.state.app_list_states.card_template_selector.selected(),
+            self.card_templates.len() + 1,
+        );
+        self.state
+            .app_list_states
+            .card_template_selector
+            .select(Some(i));
+    }
+    pub fn select_board_template_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state
+                .app_list_states
+                .board_template_selector
+                .selected(),
+            self.board_templates.len(),
+        );
+        self.state
+            .app_list_states
+            .board_template_selector
+            .select(Some(i));
+    }
+    pub fn select_board_template_next(&mut self) {
+        let i = Self::select_next(
+            self.state
+                .app_list_states
+                .board_template_selector
+                .selected(),
+            self.board_templates.len(),
+        );
+        self.state
+            .app_list_states
+            .board_template_selector
+            .select(Some(i));
     }
-    pub fn select_card_status_prv(&mut self) {
+    pub fn select_workspace_prv(&mut self) {
         let i = Self::select_previous(
-            self.state.app_list_states.card_status_selector.selected(),
-            CardStatus::all().len(),
+            self.state.app_list_states.workspace_selector.selected(),
+            self.config.workspace_names().len() + 1,
         );
         self.state
             .app_list_states
-            .card_status_selector
+            .workspace_selector
             .select(Some(i));
     }
-    pub fn select_card_status_next(&mut self) {
+    pub fn select_workspace_next(&mut self) {
         let i = Self::select_next(
-            self.state.app_list_states.card_status_selector.selected(),
-            CardStatus::all().len(),
+            self.state.app_list_states.workspace_selector.selected(),
+            self.config.workspace_names().len() + 1,
         );
         self.state
             .app_list_states
-            .card_status_selector
+            .workspace_selector
             .select(Some(i));
     }
     pub fn select_change_theme_next(&mut self) {
@@ -941,6 +1940,191 @@ impl App<'_> {
                 .select(Some(i));
         }
     }
+    fn get_board_sort_mode_options_len(&self) -> usize {
+        if self.state.z_stack.last() == Some(&PopUp::SortBoardOnce) {
+            BoardSortMode::all_automatic().len()
+        } else {
+            BoardSortMode::all().len()
+        }
+    }
+    pub fn board_sort_mode_selector_next(&mut self) {
+        let i = Self::select_next(
+            self.state
+                .app_list_states
+                .board_sort_mode_selector
+                .selected(),
+            self.get_board_sort_mode_options_len(),
+        );
+        self.state
+            .app_list_states
+            .board_sort_mode_selector
+            .select(Some(i));
+    }
+    pub fn board_sort_mode_selector_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state
+                .app_list_states
+                .board_sort_mode_selector
+                .selected(),
+            self.get_board_sort_mode_options_len(),
+        );
+        self.state
+            .app_list_states
+            .board_sort_mode_selector
+            .select(Some(i));
+    }
+    pub fn action_history_next(&mut self) {
+        let i = Self::select_next(
+            self.state.app_list_states.action_history.selected(),
+            self.action_history_manager.history.len() + 1,
+        );
+        self.state.app_list_states.action_history.select(Some(i));
+    }
+    pub fn action_history_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state.app_list_states.action_history.selected(),
+            self.action_history_manager.history.len() + 1,
+        );
+        self.state.app_list_states.action_history.select(Some(i));
+    }
+    fn get_matching_archived_cards_len(&self) -> usize {
+        let query = self.state.text_buffers.archive_search.get_joined_lines();
+        self.boards.search_archived_cards(&query).len()
+    }
+    pub fn archived_cards_next(&mut self) {
+        let archived_cards_len = self.get_matching_archived_cards_len();
+        if archived_cards_len > 0 {
+            let i = Self::select_next(
+                self.state.app_list_states.archived_cards.selected(),
+                archived_cards_len,
+            );
+            self.state.app_list_states.archived_cards.select(Some(i));
+        }
+    }
+    pub fn archived_cards_prv(&mut self) {
+        let archived_cards_len = self.get_matching_archived_cards_len();
+        if archived_cards_len > 0 {
+            let i = Self::select_previous(
+                self.state.app_list_states.archived_cards.selected(),
+                archived_cards_len,
+            );
+            self.state.app_list_states.archived_cards.select(Some(i));
+        }
+    }
+    pub fn saved_filters_next(&mut self) {
+        let saved_filters_len = self.config.saved_filters.len();
+        if saved_filters_len > 0 {
+            let i = Self::select_next(
+                self.state.app_list_states.saved_filters_list.selected(),
+                saved_filters_len,
+            );
+            self.state
+                .app_list_states
+                .saved_filters_list
+                .select(Some(i));
+        }
+    }
+    pub fn saved_filters_prv(&mut self) {
+        let saved_filters_len = self.config.saved_filters.len();
+        if saved_filters_len > 0 {
+            let i = Self::select_previous(
+                self.state.app_list_states.saved_filters_list.selected(),
+                saved_filters_len,
+            );
+            self.state
+                .app_list_states
+                .saved_filters_list
+                .select(Some(i));
+        }
+    }
+    fn get_card_view_checklist_len(&self) -> usize {
+        if let Some((_, card)) = &self.state.card_being_edited {
+            return card.checklist.len();
+        }
+        if let (Some(board_id), Some(card_id)) =
+            (self.state.current_board_id, self.state.current_card_id)
+        {
+            if let Some(board) = self.boards.get_board_with_id(board_id) {
+                if let Some(card) = board.cards.get_card_with_id(card_id) {
+                    return card.checklist.len();
+                }
+            }
+        }
+        0
+    }
+    pub fn card_view_checklist_next(&mut self) {
+        let checklist_len = self.get_card_view_checklist_len();
+        if checklist_len > 0 {
+            let i = Self::select_next(
+                self.state
+                    .app_list_states
+                    .card_view_checklist_list
+                    .selected(),
+                checklist_len,
+            );
+            self.state
+                .app_list_states
+                .card_view_checklist_list
+                .select(Some(i));
+        }
+    }
+    pub fn card_view_checklist_prv(&mut self) {
+        let checklist_len = self.get_card_view_checklist_len();
+        if checklist_len > 0 {
+            let i = Self::select_previous(
+                self.state
+                    .app_list_states
+                    .card_view_checklist_list
+                    .selected(),
+                checklist_len,
+            );
+            self.state
+                .app_list_states
+                .card_view_checklist_list
+                .select(Some(i));
+        }
+    }
+    fn get_card_view_blocked_by_len(&self) -> usize {
+        if let Some((_, card)) = &self.state.card_being_edited {
+            return card.blocked_by.len();
+        }
+        self.state
+            .current_card_id
+            .and_then(|card_id| self.boards.find_card_with_id(card_id))
+            .map_or(0, |card| card.blocked_by.len())
+    }
+    pub fn card_view_blocked_by_next(&mut self) {
+        let blocked_by_len = self.get_card_view_blocked_by_len();
+        if blocked_by_len > 0 {
+            let i = Self::select_next(
+                self.state
+                    .app_list_states
+                    .card_view_blocked_by_list
+                    .selected(),
+                blocked_by_len,
+            );
+            self.state
+                .app_list_states
+                .card_view_blocked_by_list
+                .select(Some(i));
+        }
+    }
+    pub fn card_view_blocked_by_prv(&mut self) {
+        let blocked_by_len = self.get_card_view_blocked_by_len();
+        if blocked_by_len > 0 {
+            let i = Self::select_previous(
+                self.state
+                    .app_list_states
+                    .card_view_blocked_by_list
+                    .selected(),
+                blocked_by_len,
+            );
+            self.state
+                .app_list_states
+                .card_view_blocked_by_list
+                .select(Some(i));
+        }
+    }
     pub fn change_date_format_popup_next(&mut self) {
         let i = Self::select_next(
             self.state.app_list_states.date_format_selector.selected(),
@@ -1057,9 +2241,85 @@ impl App<'_> {
             PopUp::CardStatusSelector => {
                 self.state.set_focus(Focus::ChangeCardStatusPopup);
             }
+            PopUp::CardRecurrenceSelector => {
+                let recurrence = match &self.state.card_being_edited {
+                    Some((_, card_being_edited)) => card_being_edited.recurrence,
+                    None => self
+                        .state
+                        .current_card_id
+                        .and_then(|card_id| self.boards.find_card_with_id(card_id))
+                        .and_then(|card| card.recurrence),
+                };
+                // The list starts with None, custom intervals are shown in the days field
+                let selected_index = match recurrence {
+                    Some(CardRecurrence::EveryDays(_)) => None,
+                    Some(recurrence) => CardRecurrence::all()
+                        .iter()
+                        .position(|r| *r == recurrence)
+                        .map(|index| index + 1),
+                    None => Some(0),
+                };
+                let days = match recurrence {
+                    Some(CardRecurrence::EveryDays(days)) => days.to_string(),
+                    _ => String::new(),
+                };
+                self.state
+                    .app_list_states
+                    .card_recurrence_selector
+                    .select(selected_index);
+                self.state.text_buffers.card_recurrence_days =
+                    TextBox::from_string_with_newline_sep(days, true);
+                if selected_index.is_some() {
+                    self.state.set_focus(Focus::ChangeCardRecurrencePopup);
+                } else {
+                    self.state.set_focus(Focus::TextInput);
+                }
+            }
+            PopUp::ChangeBoardSortMode | PopUp::SortBoardOnce => {
+                let current_sort_mode = self
+                    .state
+                    .current_board_id
+                    .and_then(|board_id| self.boards.get_board_with_id(board_id))
+                    .map(|board| board.sort_mode);
+                let selected_index = if popup == PopUp::ChangeBoardSortMode {
+                    BoardSortMode::all()
+                        .iter()
+                        .position(|sort_mode| Some(*sort_mode) == current_sort_mode)
+                } else {
+                    None
+                };
+                self.state
+                    .app_list_states
+                    .board_sort_mode_selector
+                    .select(Some(selected_index.unwrap_or(0)));
+                self.state.set_focus(Focus::ChangeBoardSortModePopup);
+            }
             PopUp::CardPrioritySelector => {
                 self.state.set_focus(Focus::ChangeCardPriorityPopup);
             }
+            PopUp::ActionHistory => {
+                // Newest action first, the current point in the history is selected
+                let selected_index = self.action_history_manager.history.len()
+                    - self.action_history_manager.history_index;
+                self.state
+                    .app_list_states
+                    .action_history
+                    .select(Some(selected_index));
+                self.state.set_focus(Focus::ActionHistoryPopup);
+            }
+            PopUp::ArchivedCards => {
+                self.state.text_buffers.archive_search.reset();
+                let has_archived_cards = self
+                    .boards
+                    .get_boards()
+                    .iter()
+                    .any(|board| !board.archived_cards.is_empty());
+                self.state
+                    .app_list_states
+                    .archived_cards
+                    .select(has_archived_cards.then_some(0));
+                self.state.set_focus(Focus::ArchivedCardsList);
+            }
             PopUp::EditGeneralConfig => {
                 self.state.set_focus(Focus::EditGeneralConfigPopup);
             }
@@ -1067,6 +2327,111 @@ impl App<'_> {
                 self.state.set_focus(Focus::TextInput);
                 self.state.app_status = AppStatus::UserInput;
             }
+            PopUp::EditBoard => {
+                let current_board = self
+                    .state
+                    .current_board_id
+                    .and_then(|board_id| self.boards.get_board_with_id(board_id));
+                self.state.text_buffers.board_name = TextBox::from_string_with_newline_sep(
+                    current_board.map_or(String::new(), |board| board.name.clone()),
+                    true,
+                );
+                self.state.text_buffers.board_description = TextBox::from_string_with_newline_sep(
+                    current_board.map_or(String::new(), |board| board.description.clone()),
+                    false,
+                );
+                self.state.text_buffers.board_wip_limit = TextBox::from_string_with_newline_sep(
+                    current_board
+                        .and_then(|board| board.wip_limit)
+                        .map_or(String::new(), |wip_limit| wip_limit.to_string()),
+                    true,
+                );
+                self.state.set_focus(Focus::NewBoardName);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::ImportBoardsPrompt => {
+                self.state.text_buffers.import_file_path.reset();
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::CardTemplatePicker => {
+                // The list starts with the option to not use a template
+                let selected_index = self
+                    .state
+                    .new_card_template
+                    .as_ref()
+                    .and_then(|new_card_template| {
+                        self.card_templates
+                            .iter()
+                            .position(|card_template| card_template.name == new_card_template.name)
+                    })
+                    .map_or(0, |index| index + 1);
+                self.state
+                    .app_list_states
+                    .card_template_selector
+                    .select(Some(selected_index));
+                self.state.set_focus(Focus::SelectCardTemplatePopup);
+                self.state.app_status = AppStatus::Initialized;
+            }
+            PopUp::SaveCardTemplatePrompt => {
+                let card_name = self
+                    .state
+                    .current_card_id
+                    .and_then(|card_id| self.boards.find_card_with_id(card_id))
+                    .map(|card| card.name.clone())
+                    .unwrap_or_default();
+                self.state.text_buffers.card_template_name =
+                    TextBox::from_string_with_newline_sep(card_name, true);
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::BoardTemplatePicker => {
+                self.state
+                    .app_list_states
+                    .board_template_selector
+                    .select(Some(0));
+                self.state.set_focus(Focus::SelectBoardTemplatePopup);
+                self.state.app_status = AppStatus::Initialized;
+            }
+            PopUp::SaveBoardTemplatePrompt => {
+                self.state.text_buffers.board_template_name.reset();
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::WorkspacePicker => {
+                // The current workspace, after the new workspace item
+                let active_workspace = self.config.active_workspace().to_string();
+                let current_index = self
+                    .config
+                    .workspace_names()
+                    .iter()
+                    .position(|workspace| *workspace == active_workspace)
+                    .map_or(0, |index| index + 1);
+                self.state
+                    .app_list_states
+                    .workspace_selector
+                    .select(Some(current_index));
+                self.state.set_focus(Focus::SelectWorkspacePopup);
+                self.state.app_status = AppStatus::Initialized;
+            }
+            PopUp::CreateWorkspacePrompt => {
+                self.state.text_buffers.workspace_name.reset();
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::FilterPrompt => {
+                self.state.text_buffers.filter_query.reset();
+                if let Some(card_filter) = &self.state.card_filter {
+                    self.state
+                        .text_buffers
+                        .filter_query
+                        .insert_str(card_filter.query());
+                }
+                self.state.text_buffers.filter_name.reset();
+                self.state.app_list_states.saved_filters_list.select(None);
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
             PopUp::DateTimePicker => {
                 self.widgets.date_time_picker.open_date_picker();
             }
@@ -1079,7 +2444,21 @@ impl App<'_> {
     pub fn close_popup(&mut self) {
         if let Some(popup) = self.state.z_stack.pop() {
             match popup {
-                PopUp::CustomHexColorPromptBG | PopUp::CustomHexColorPromptFG => {
+                PopUp::CustomHexColorPromptBG
+                | PopUp::CustomHexColorPromptFG
+                | PopUp::ImportBoardsPrompt
+                | PopUp::FilterPrompt
+                | PopUp::ArchivedCards
+                | PopUp::SaveCardTemplatePrompt
+                | PopUp::SaveBoardTemplatePrompt
+                | PopUp::CreateWorkspacePrompt => {
+                    self.state.app_status = AppStatus::Initialized;
+                }
+                PopUp::EditBoard => {
+                    // The fields are shared with the new board form
+                    self.state.text_buffers.board_name.reset();
+                    self.state.text_buffers.board_description.reset();
+                    self.state.text_buffers.board_wip_limit.reset();
                     self.state.app_status = AppStatus::Initialized;
                 }
                 PopUp::ViewCard => {
@@ -1102,6 +2481,27 @@ impl App<'_> {
                 PopUp::DateTimePicker => {
                     self.widgets.date_time_picker.close_date_picker();
                 }
+                PopUp::CardRecurrenceSelector => {
+                    self.state.text_buffers.card_recurrence_days.reset();
+                    if self.state.card_being_edited.is_none() {
+                        self.state.app_status = AppStatus::Initialized;
+                    }
+                }
+                PopUp::SyncConflicts => {
+                    let sync_cancelled = self
+                        .state
+                        .pending_sync
+                        .as_ref()
+                        .is_some_and(|sync| sync.current_conflict < sync.conflicts.len());
+                    if sync_cancelled {
+                        self.state.pending_sync = None;
+                        warn!("Sync cancelled, nothing was changed");
+                        send_warning_toast(
+                            &mut self.widgets.toast_widget,
+                            "Sync cancelled, nothing was changed",
+                        );
+                    }
+                }
                 _ => {}
             }
         }
@@ -1203,6 +2603,8 @@ pub enum MainMenuItem {
     Help,
     LoadSaveLocal,
     LoadSaveCloud,
+    StartFromBoardTemplate,
+    SwitchWorkspace,
     Quit,
 }
 
@@ -1214,6 +2616,8 @@ impl Display for MainMenuItem {
             MainMenuItem::Help => write!(f, "Help"),
             MainMenuItem::LoadSaveLocal => write!(f, "Load a Save (local)"),
             MainMenuItem::LoadSaveCloud => write!(f, "Load a Save (cloud)"),
+            MainMenuItem::StartFromBoardTemplate => write!(f, "Start From a Board Template"),
+            MainMenuItem::SwitchWorkspace => write!(f, "Switch Workspace"),
             MainMenuItem::Quit => write!(f, "Quit"),
         }
     }
@@ -1233,6 +2637,8 @@ impl Default for MainMenu {
                 MainMenuItem::Config,
                 MainMenuItem::Help,
                 MainMenuItem::LoadSaveLocal,
+                MainMenuItem::StartFromBoardTemplate,
+                MainMenuItem::SwitchWorkspace,
                 MainMenuItem::Quit,
             ],
             logged_in: false,
@@ -1249,6 +2655,8 @@ impl MainMenu {
                 MainMenuItem::Help,
                 MainMenuItem::LoadSaveLocal,
                 MainMenuItem::LoadSaveCloud,
+                MainMenuItem::StartFromBoardTemplate,
+                MainMenuItem::SwitchWorkspace,
                 MainMenuItem::Quit,
             ];
             self.items.clone_from(&return_vec);
@@ -1259,6 +2667,8 @@ impl MainMenu {
                 MainMenuItem::Config,
                 MainMenuItem::Help,
                 MainMenuItem::LoadSaveLocal,
+                MainMenuItem::StartFromBoardTemplate,
+                MainMenuItem::SwitchWorkspace,
                 MainMenuItem::Quit,
             ];
             self.items.clone_from(&return_vec);
@@ -1274,7 +2684,9 @@ impl MainMenu {
                 2 => MainMenuItem::Help,
                 3 => MainMenuItem::LoadSaveLocal,
                 4 => MainMenuItem::LoadSaveCloud,
-                5 => MainMenuItem::Quit,
+                5 => MainMenuItem::StartFromBoardTemplate,
+                6 => MainMenuItem::SwitchWorkspace,
+                7 => MainMenuItem::Quit,
                 _ => MainMenuItem::Quit,
             }
         } else {
@@ -1283,7 +2695,9 @@ impl MainMenu {
                 1 => MainMenuItem::Config,
                 2 => MainMenuItem::Help,
                 3 => MainMenuItem::LoadSaveLocal,
-                4 => MainMenuItem::Quit,
+                4 => MainMenuItem::StartFromBoardTemplate,
+                5 => MainMenuItem::SwitchWorkspace,
+                6 => MainMenuItem::Quit,
                 _ => MainMenuItem::Quit,
             }
         }
@@ -1390,22 +2804,50 @@ impl Display for DateTimeFormat {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AppConfig {
     pub always_load_last_save: bool,
+    /// Days a card has to be complete before it is archived automatically, 0 turns it off
+    pub auto_archive_completed_days: u16,
     pub auto_login: bool,
+    /// Seconds the boards have to be left unchanged before they are saved automatically, 0
+    /// turns it off
+    pub auto_save_interval: u16,
+    /// Workspace the app opens, its saves are kept apart from the other workspaces
+    pub current_workspace: String,
     pub date_time_format: DateTimeFormat,
     pub default_theme: String,
     pub default_view: View,
     pub disable_animations: bool,
+    pub display_name: String,
     pub disable_scroll_bar: bool,
     pub enable_mouse_support: bool,
+    /// Block moving cards into boards that are at their WIP limit instead of only warning
+    pub enforce_wip_limits: bool,
     pub keybindings: KeyBindings,
     pub no_of_boards_to_show: u16,
     pub no_of_cards_to_show: u16,
     pub date_picker_calender_format: CalenderType,
     pub save_directory: PathBuf,
     pub save_on_exit: bool,
+    /// Days every day keeps its own saves for, older saves are thinned to one per week, 0 keeps
+    /// every day
+    pub save_retention_daily_days: u16,
+    /// Newest save versions kept for each day, 0 keeps every version
+    pub save_retention_versions_per_day: u16,
+    /// Filter queries saved by name from the filter prompt or the cli
+    pub saved_filters: BTreeMap<String, String>,
     pub show_line_numbers: bool,
+    /// A new save file for every save, or a single journal file every save is appended to
+    pub storage_mode: StorageMode,
+    pub sync_backend: SyncBackendType,
+    pub sync_directory: PathBuf,
     pub tickrate: u16,
+    /// Most undo history entries kept with each save, 0 turns off saving the history
+    pub undo_history_length: u16,
     pub warning_delta: u16,
+    /// Workspace given with `--workspace`, used instead of `current_workspace` without being saved
+    #[serde(skip)]
+    pub workspace_override: Option<String>,
+    /// Every workspace other than the default one by name
+    pub workspaces: BTreeMap<String, WorkspaceSettings>,
 }
 
 impl Default for AppConfig {
@@ -1414,22 +2856,36 @@ impl Default for AppConfig {
         let default_theme = Theme::default();
         Self {
             always_load_last_save: true,
+            auto_archive_completed_days: 0,
             auto_login: true,
+            auto_save_interval: DEFAULT_AUTO_SAVE_INTERVAL,
+            current_workspace: DEFAULT_WORKSPACE_NAME.to_string(),
             date_time_format: DateTimeFormat::default(),
             default_theme: default_theme.name,
             default_view,
             disable_animations: false,
+            display_name: String::new(),
             disable_scroll_bar: false,
             enable_mouse_support: true,
+            enforce_wip_limits: false,
             keybindings: KeyBindings::default(),
             no_of_boards_to_show: DEFAULT_NO_OF_BOARDS_PER_PAGE,
             no_of_cards_to_show: DEFAULT_NO_OF_CARDS_PER_BOARD,
             date_picker_calender_format: CalenderType::default(),
             save_directory: get_default_save_directory(),
             save_on_exit: true,
+            save_retention_daily_days: DEFAULT_SAVE_RETENTION_DAILY_DAYS,
+            save_retention_versions_per_day: DEFAULT_SAVE_RETENTION_VERSIONS_PER_DAY,
+            saved_filters: BTreeMap::new(),
             show_line_numbers: true,
+            storage_mode: StorageMode::default(),
+            sync_backend: SyncBackendType::default(),
+            sync_directory: PathBuf::new(),
             tickrate: DEFAULT_TICKRATE,
+            undo_history_length: DEFAULT_UNDO_HISTORY_LENGTH,
             warning_delta: DEFAULT_CARD_WARNING_DUE_DATE_DAYS,
+            workspace_override: None,
+            workspaces: BTreeMap::new(),
         }
     }
 }
@@ -1446,21 +2902,39 @@ impl AppConfig {
                     ConfigEnum::DefaultView => (self.default_view.to_string(), 1),
                     ConfigEnum::AlwaysLoadLastSave => (self.always_load_last_save.to_string(), 2),
                     ConfigEnum::SaveOnExit => (self.save_on_exit.to_string(), 3),
-                    ConfigEnum::DisableScrollBar => (self.disable_scroll_bar.to_string(), 4),
-                    ConfigEnum::DisableAnimations => (self.disable_animations.to_string(), 5),
-                    ConfigEnum::AutoLogin => (self.auto_login.to_string(), 6),
-                    ConfigEnum::ShowLineNumbers => (self.show_line_numbers.to_string(), 7),
-                    ConfigEnum::EnableMouseSupport => (self.enable_mouse_support.to_string(), 8),
-                    ConfigEnum::WarningDelta => (self.warning_delta.to_string(), 9),
-                    ConfigEnum::Tickrate => (self.tickrate.to_string(), 10),
-                    ConfigEnum::NoOfCardsToShow => (self.no_of_cards_to_show.to_string(), 11),
-                    ConfigEnum::NoOfBoardsToShow => (self.no_of_boards_to_show.to_string(), 12),
+                    ConfigEnum::AutoSaveInterval => (self.auto_save_interval.to_string(), 4),
+                    ConfigEnum::DisableScrollBar => (self.disable_scroll_bar.to_string(), 5),
+                    ConfigEnum::DisableAnimations => (self.disable_animations.to_string(), 6),
+                    ConfigEnum::AutoLogin => (self.auto_login.to_string(), 7),
+                    ConfigEnum::SyncBackend => (self.sync_backend.to_string(), 8),
+                    ConfigEnum::SyncDirectory => {
+                        (self.sync_directory.to_string_lossy().to_string(), 9)
+                    }
+                    ConfigEnum::DisplayName => (self.display_name.clone(), 10),
+                    ConfigEnum::ShowLineNumbers => (self.show_line_numbers.to_string(), 11),
+                    ConfigEnum::EnableMouseSupport => (self.enable_mouse_support.to_string(), 12),
+                    ConfigEnum::WarningDelta => (self.warning_delta.to_string(), 13),
+                    ConfigEnum::Tickrate => (self.tickrate.to_string(), 14),
+                    ConfigEnum::NoOfCardsToShow => (self.no_of_cards_to_show.to_string(), 15),
+                    ConfigEnum::NoOfBoardsToShow => (self.no_of_boards_to_show.to_string(), 16),
                     ConfigEnum::DatePickerCalenderFormat => {
-                        (self.date_picker_calender_format.to_string(), 13)
+                        (self.date_picker_calender_format.to_string(), 17)
+                    }
+                    ConfigEnum::DefaultTheme => (self.default_theme.clone(), 18),
+                    ConfigEnum::DateFormat => (self.date_time_format.to_string(), 19),
+                    ConfigEnum::EnforceWipLimits => (self.enforce_wip_limits.to_string(), 20),
+                    ConfigEnum::UndoHistoryLength => (self.undo_history_length.to_string(), 21),
+                    ConfigEnum::AutoArchiveCompletedDays => {
+                        (self.auto_archive_completed_days.to_string(), 22)
                     }
-                    ConfigEnum::DefaultTheme => (self.default_theme.clone(), 14),
-                    ConfigEnum::DateFormat => (self.date_time_format.to_string(), 15),
-                    ConfigEnum::Keybindings => ("".to_string(), 16),
+                    ConfigEnum::SaveRetentionVersionsPerDay => {
+                        (self.save_retention_versions_per_day.to_string(), 23)
+                    }
+                    ConfigEnum::SaveRetentionDailyDays => {
+                        (self.save_retention_daily_days.to_string(), 24)
+                    }
+                    ConfigEnum::StorageMode => (self.storage_mode.to_string(), 25),
+                    ConfigEnum::Keybindings => ("".to_string(), 26),
                 };
                 (enum_variant.to_string(), value.to_string(), index)
             })
@@ -1476,13 +2950,17 @@ impl AppConfig {
     pub fn get_value_as_string(&self, config_enum: ConfigEnum) -> String {
         match config_enum {
             ConfigEnum::AlwaysLoadLastSave => self.always_load_last_save.to_string(),
+            ConfigEnum::AutoArchiveCompletedDays => self.auto_archive_completed_days.to_string(),
             ConfigEnum::AutoLogin => self.auto_login.to_string(),
+            ConfigEnum::AutoSaveInterval => self.auto_save_interval.to_string(),
             ConfigEnum::DateFormat => self.date_time_format.to_string(),
             ConfigEnum::DefaultTheme => self.default_theme.clone(),
             ConfigEnum::DefaultView => self.default_view.to_string(),
             ConfigEnum::DisableAnimations => self.disable_animations.to_string(),
             ConfigEnum::DisableScrollBar => self.disable_scroll_bar.to_string(),
+            ConfigEnum::DisplayName => self.display_name.clone(),
             ConfigEnum::EnableMouseSupport => self.enable_mouse_support.to_string(),
+            ConfigEnum::EnforceWipLimits => self.enforce_wip_limits.to_string(),
             ConfigEnum::Keybindings => {
                 // This should never be called
                 debug!("Keybindings should not be called from get_value_as_str");
@@ -1493,8 +2971,16 @@ impl AppConfig {
             ConfigEnum::DatePickerCalenderFormat => self.date_picker_calender_format.to_string(),
             ConfigEnum::SaveDirectory => self.save_directory.to_string_lossy().to_string(),
             ConfigEnum::SaveOnExit => self.save_on_exit.to_string(),
+            ConfigEnum::SaveRetentionDailyDays => self.save_retention_daily_days.to_string(),
+            ConfigEnum::SaveRetentionVersionsPerDay => {
+                self.save_retention_versions_per_day.to_string()
+            }
             ConfigEnum::ShowLineNumbers => self.show_line_numbers.to_string(),
+            ConfigEnum::StorageMode => self.storage_mode.to_string(),
+            ConfigEnum::SyncBackend => self.sync_backend.to_string(),
+            ConfigEnum::SyncDirectory => self.sync_directory.to_string_lossy().to_string(),
             ConfigEnum::Tickrate => self.tickrate.to_string(),
+            ConfigEnum::UndoHistoryLength => self.undo_history_length.to_string(),
             ConfigEnum::WarningDelta => self.warning_delta.to_string(),
         }
     }
@@ -1506,12 +2992,21 @@ impl AppConfig {
             ConfigEnum::DisableAnimations => (!self.disable_animations).to_string(),
             ConfigEnum::DisableScrollBar => (!self.disable_scroll_bar).to_string(),
             ConfigEnum::EnableMouseSupport => (!self.enable_mouse_support).to_string(),
+            ConfigEnum::EnforceWipLimits => (!self.enforce_wip_limits).to_string(),
             ConfigEnum::SaveOnExit => (!self.save_on_exit).to_string(),
             ConfigEnum::ShowLineNumbers => (!self.show_line_numbers).to_string(),
             ConfigEnum::DatePickerCalenderFormat => match self.date_picker_calender_format {
                 CalenderType::MondayFirst => CalenderType::SundayFirst.to_string(),
                 CalenderType::SundayFirst => CalenderType::MondayFirst.to_string(),
             },
+            ConfigEnum::SyncBackend => match self.sync_backend {
+                SyncBackendType::Supabase => SyncBackendType::LocalDirectory.to_string(),
+                SyncBackendType::LocalDirectory => SyncBackendType::Supabase.to_string(),
+            },
+            ConfigEnum::StorageMode => match self.storage_mode {
+                StorageMode::Snapshots => StorageMode::Journal.to_string(),
+                StorageMode::Journal => StorageMode::Snapshots.to_string(),
+            },
             _ => {
                 debug!("Invalid config enum to toggle: {}", config_enum);
                 "".to_string()
@@ -1601,6 +3096,9 @@ impl AppConfig {
             KeyBindingEnum::ClearAllToasts => {
                 self.keybindings.clear_all_toasts = value.to_vec();
             }
+            KeyBindingEnum::CompareSaves => {
+                self.keybindings.compare_saves = value.to_vec();
+            }
             KeyBindingEnum::DeleteBoard => {
                 self.keybindings.delete_board = value.to_vec();
             }
@@ -1670,6 +3168,9 @@ impl AppConfig {
             KeyBindingEnum::TakeUserInput => {
                 self.keybindings.take_user_input = value.to_vec();
             }
+            KeyBindingEnum::ToggleChecklistItem => {
+                self.keybindings.toggle_checklist_item = value.to_vec();
+            }
             KeyBindingEnum::ToggleCommandPalette => {
                 self.keybindings.toggle_command_palette = value.to_vec();
             }
@@ -1865,6 +3366,11 @@ impl AppConfig {
             ConfigEnum::EnableMouseSupport,
             default_config.enable_mouse_support,
         );
+        let enforce_wip_limits = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::EnforceWipLimits,
+            default_config.enforce_wip_limits,
+        );
         let warning_delta = AppConfig::get_u16_or_default(
             &serde_json_object,
             ConfigEnum::WarningDelta,
@@ -1879,6 +3385,41 @@ impl AppConfig {
             Some(MIN_TICKRATE),
             Some(MAX_TICKRATE),
         );
+        let auto_save_interval = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::AutoSaveInterval,
+            default_config.auto_save_interval,
+            None,
+            Some(MAX_AUTO_SAVE_INTERVAL),
+        );
+        let auto_archive_completed_days = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::AutoArchiveCompletedDays,
+            default_config.auto_archive_completed_days,
+            None,
+            Some(MAX_AUTO_ARCHIVE_COMPLETED_DAYS),
+        );
+        let save_retention_daily_days = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::SaveRetentionDailyDays,
+            default_config.save_retention_daily_days,
+            None,
+            Some(MAX_SAVE_RETENTION_DAILY_DAYS),
+        );
+        let save_retention_versions_per_day = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::SaveRetentionVersionsPerDay,
+            default_config.save_retention_versions_per_day,
+            None,
+            Some(MAX_SAVE_RETENTION_VERSIONS_PER_DAY),
+        );
+        let undo_history_length = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::UndoHistoryLength,
+            default_config.undo_history_length,
+            None,
+            Some(MAX_UNDO_HISTORY_LENGTH),
+        );
         let no_of_cards_to_show = AppConfig::get_u16_or_default(
             &serde_json_object,
             ConfigEnum::NoOfCardsToShow,
@@ -1936,24 +3477,93 @@ impl AppConfig {
                     CalenderType::default()
                 }
             };
+        let sync_backend = match serde_json_object[ConfigEnum::SyncBackend.to_json_key()].as_str() {
+            Some(sync_backend) => SyncBackendType::from_str(sync_backend).unwrap_or_else(|_| {
+                error!(
+                    "Invalid sync backend: {}, Resetting to default sync backend",
+                    sync_backend
+                );
+                SyncBackendType::default()
+            }),
+            None => default_config.sync_backend,
+        };
+        let storage_mode = match serde_json_object[ConfigEnum::StorageMode.to_json_key()].as_str() {
+            Some(storage_mode) => StorageMode::from_str(storage_mode).unwrap_or_else(|_| {
+                error!(
+                    "Invalid storage mode: {}, Resetting to default storage mode",
+                    storage_mode
+                );
+                StorageMode::default()
+            }),
+            None => default_config.storage_mode,
+        };
+        let sync_directory =
+            match serde_json_object[ConfigEnum::SyncDirectory.to_json_key()].as_str() {
+                Some(path) => PathBuf::from(path),
+                None => default_config.sync_directory,
+            };
+        let display_name = match serde_json_object[ConfigEnum::DisplayName.to_json_key()].as_str() {
+            Some(display_name) => display_name.to_string(),
+            None => default_config.display_name,
+        };
+        // Saved filters are managed from the filter prompt so they are not part of ConfigEnum,
+        // filters that no longer parse are kept so they can be fixed or deleted
+        let saved_filters = match serde_json_object["saved_filters"].as_object() {
+            Some(saved_filters) => saved_filters
+                .iter()
+                .filter_map(|(name, query)| {
+                    query
+                        .as_str()
+                        .map(|query| (name.to_string(), query.to_string()))
+                })
+                .collect(),
+            None => default_config.saved_filters,
+        };
+        // Workspaces are managed from the workspace switcher and the cli like saved filters, they
+        // are checked once the config is loaded
+        let workspaces = match serde_json_object.get("workspaces") {
+            Some(workspaces) => serde_json::from_value(workspaces.clone()).unwrap_or_else(|e| {
+                error!("Invalid workspaces: {}, Resetting to no workspaces", e);
+                default_config.workspaces
+            }),
+            None => default_config.workspaces,
+        };
+        let current_workspace = match serde_json_object["current_workspace"].as_str() {
+            Some(current_workspace) => current_workspace.to_string(),
+            None => default_config.current_workspace,
+        };
         Ok(Self {
             save_directory,
             default_view,
             always_load_last_save,
             save_on_exit,
+            auto_save_interval,
             disable_scroll_bar,
             auto_login,
             warning_delta,
             keybindings,
             tickrate,
+            undo_history_length,
+            auto_archive_completed_days,
+            save_retention_daily_days,
+            save_retention_versions_per_day,
             no_of_cards_to_show,
             no_of_boards_to_show,
             date_picker_calender_format,
             enable_mouse_support,
+            enforce_wip_limits,
             default_theme,
             date_time_format: date_format,
             show_line_numbers,
             disable_animations,
+            storage_mode,
+            sync_backend,
+            sync_directory,
+            display_name,
+            saved_filters,
+            current_workspace,
+            workspace_override: None,
+            workspaces,
         })
     }
 }
@@ -1961,21 +3571,31 @@ impl AppConfig {
 #[derive(PartialEq, Copy, Clone, EnumIter)]
 pub enum ConfigEnum {
     AlwaysLoadLastSave,
+    AutoArchiveCompletedDays,
     AutoLogin,
+    AutoSaveInterval,
     DateFormat,
     DefaultTheme,
     DefaultView,
     DisableAnimations,
     DisableScrollBar,
+    DisplayName,
     EnableMouseSupport,
+    EnforceWipLimits,
     Keybindings,
     NoOfBoardsToShow,
     NoOfCardsToShow,
     DatePickerCalenderFormat,
     SaveDirectory,
     SaveOnExit,
+    SaveRetentionDailyDays,
+    SaveRetentionVersionsPerDay,
     ShowLineNumbers,
+    StorageMode,
+    SyncBackend,
+    SyncDirectory,
     Tickrate,
+    UndoHistoryLength,
     WarningDelta,
 }
 
@@ -1983,21 +3603,35 @@ impl fmt::Display for ConfigEnum {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ConfigEnum::AlwaysLoadLastSave => write!(f, "Auto Load Last Save"),
+            ConfigEnum::AutoArchiveCompletedDays => {
+                write!(f, "Auto Archive Completed Cards After Days")
+            }
             ConfigEnum::AutoLogin => write!(f, "Auto Login"),
+            ConfigEnum::AutoSaveInterval => write!(f, "Auto Save After Seconds Unchanged"),
             ConfigEnum::DateFormat => write!(f, "Date Format"),
             ConfigEnum::DefaultTheme => write!(f, "Default Theme"),
             ConfigEnum::DefaultView => write!(f, "Select Default View"),
             ConfigEnum::DisableAnimations => write!(f, "Disable Animations"),
             ConfigEnum::DisableScrollBar => write!(f, "Disable Scroll Bar"),
+            ConfigEnum::DisplayName => write!(f, "Display Name"),
             ConfigEnum::EnableMouseSupport => write!(f, "Enable Mouse Support"),
+            ConfigEnum::EnforceWipLimits => write!(f, "Enforce WIP Limits"),
             ConfigEnum::Keybindings => write!(f, "Edit Keybindings"),
             ConfigEnum::NoOfBoardsToShow => write!(f, "Number of Boards to Show"),
             ConfigEnum::NoOfCardsToShow => write!(f, "Number of Cards to Show"),
             ConfigEnum::DatePickerCalenderFormat => write!(f, "Date Picker Calender Format"),
             ConfigEnum::SaveDirectory => write!(f, "Save Directory"),
             ConfigEnum::SaveOnExit => write!(f, "Auto Save on Exit"),
+            ConfigEnum::SaveRetentionDailyDays => {
+                write!(f, "Keep Daily Saves for Days (Weekly After)")
+            }
+            ConfigEnum::SaveRetentionVersionsPerDay => write!(f, "Save Versions to Keep per Day"),
             ConfigEnum::ShowLineNumbers => write!(f, "Show Line Numbers"),
+            ConfigEnum::StorageMode => write!(f, "Storage Mode"),
+            ConfigEnum::SyncBackend => write!(f, "Sync Backend"),
+            ConfigEnum::SyncDirectory => write!(f, "Sync Directory"),
             ConfigEnum::Tickrate => write!(f, "Tickrate"),
+            ConfigEnum::UndoHistoryLength => write!(f, "Undo History Length"),
             ConfigEnum::WarningDelta => write!(f, "Number of Days to Warn Before Due Date"),
         }
     }
@@ -2008,22 +3642,32 @@ impl FromStr for ConfigEnum {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "Auto Load Last Save" => Ok(ConfigEnum::AlwaysLoadLastSave),
+            "Auto Archive Completed Cards After Days" => Ok(ConfigEnum::AutoArchiveCompletedDays),
             "Auto Login" => Ok(ConfigEnum::AutoLogin),
+            "Auto Save After Seconds Unchanged" => Ok(ConfigEnum::AutoSaveInterval),
             "Auto Save on Exit" => Ok(ConfigEnum::SaveOnExit),
             "Date Format" => Ok(ConfigEnum::DateFormat),
             "Default Theme" => Ok(ConfigEnum::DefaultTheme),
             "Disable Animations" => Ok(ConfigEnum::DisableAnimations),
             "Disable Scroll Bar" => Ok(ConfigEnum::DisableScrollBar),
+            "Display Name" => Ok(ConfigEnum::DisplayName),
             "Edit Keybindings" => Ok(ConfigEnum::Keybindings),
             "Enable Mouse Support" => Ok(ConfigEnum::EnableMouseSupport),
+            "Enforce WIP Limits" => Ok(ConfigEnum::EnforceWipLimits),
             "Number of Boards to Show" => Ok(ConfigEnum::NoOfBoardsToShow),
             "Number of Cards to Show" => Ok(ConfigEnum::NoOfCardsToShow),
             "Date Picker Calender Format" => Ok(ConfigEnum::DatePickerCalenderFormat),
             "Number of Days to Warn Before Due Date" => Ok(ConfigEnum::WarningDelta),
             "Save Directory" => Ok(ConfigEnum::SaveDirectory),
+            "Keep Daily Saves for Days (Weekly After)" => Ok(ConfigEnum::SaveRetentionDailyDays),
+            "Save Versions to Keep per Day" => Ok(ConfigEnum::SaveRetentionVersionsPerDay),
             "Select Default View" => Ok(ConfigEnum::DefaultView),
             "Show Line Numbers" => Ok(ConfigEnum::ShowLineNumbers),
+            "Storage Mode" => Ok(ConfigEnum::StorageMode),
+            "Sync Backend" => Ok(ConfigEnum::SyncBackend),
+            "Sync Directory" => Ok(ConfigEnum::SyncDirectory),
             "Tickrate" => Ok(ConfigEnum::Tickrate),
+            "Undo History Length" => Ok(ConfigEnum::UndoHistoryLength),
             _ => Err(format!("Invalid ConfigEnum: {}", s)),
         }
     }
@@ -2033,28 +3677,38 @@ impl ConfigEnum {
     pub fn to_json_key(&self) -> &str {
         match self {
             ConfigEnum::AlwaysLoadLastSave => "always_load_last_save",
+            ConfigEnum::AutoArchiveCompletedDays => "auto_archive_completed_days",
             ConfigEnum::AutoLogin => "auto_login",
+            ConfigEnum::AutoSaveInterval => "auto_save_interval",
             ConfigEnum::DateFormat => "date_format",
             ConfigEnum::DefaultTheme => "default_theme",
             ConfigEnum::DefaultView => "default_view",
             ConfigEnum::DisableAnimations => "disable_animations",
             ConfigEnum::DisableScrollBar => "disable_scroll_bar",
+            ConfigEnum::DisplayName => "display_name",
             ConfigEnum::EnableMouseSupport => "enable_mouse_support",
+            ConfigEnum::EnforceWipLimits => "enforce_wip_limits",
             ConfigEnum::Keybindings => "keybindings",
             ConfigEnum::NoOfBoardsToShow => "no_of_boards_to_show",
             ConfigEnum::NoOfCardsToShow => "no_of_cards_to_show",
             ConfigEnum::DatePickerCalenderFormat => "date_picker_calender_format",
             ConfigEnum::SaveDirectory => "save_directory",
             ConfigEnum::SaveOnExit => "save_on_exit",
+            ConfigEnum::SaveRetentionDailyDays => "save_retention_daily_days",
+            ConfigEnum::SaveRetentionVersionsPerDay => "save_retention_versions_per_day",
             ConfigEnum::ShowLineNumbers => "show_line_numbers",
+            ConfigEnum::StorageMode => "storage_mode",
+            ConfigEnum::SyncBackend => "sync_backend",
+            ConfigEnum::SyncDirectory => "sync_directory",
             ConfigEnum::Tickrate => "tickrate",
+            ConfigEnum::UndoHistoryLength => "undo_history_length",
             ConfigEnum::WarningDelta => "warning_delta",
         }
     }
 
     pub fn validate_value(&self, value: &str) -> Result<(), String> {
         match self {
-            ConfigEnum::SaveDirectory => {
+            ConfigEnum::SaveDirectory | ConfigEnum::SyncDirectory => {
                 let path = PathBuf::from(value);
                 if path.try_exists().is_ok() && path.try_exists().unwrap() && path.is_dir() {
                     Ok(())
@@ -2075,6 +3729,7 @@ impl ConfigEnum {
             | ConfigEnum::DisableAnimations
             | ConfigEnum::DisableScrollBar
             | ConfigEnum::EnableMouseSupport
+            | ConfigEnum::EnforceWipLimits
             | ConfigEnum::SaveOnExit
             | ConfigEnum::ShowLineNumbers => {
                 let check = value.parse::<bool>();
@@ -2084,9 +3739,14 @@ impl ConfigEnum {
                     Err(format!("Invalid boolean: {}", value))
                 }
             }
-            ConfigEnum::NoOfBoardsToShow
+            ConfigEnum::AutoArchiveCompletedDays
+            | ConfigEnum::AutoSaveInterval
+            | ConfigEnum::NoOfBoardsToShow
             | ConfigEnum::NoOfCardsToShow
+            | ConfigEnum::SaveRetentionDailyDays
+            | ConfigEnum::SaveRetentionVersionsPerDay
             | ConfigEnum::Tickrate
+            | ConfigEnum::UndoHistoryLength
             | ConfigEnum::WarningDelta => {
                 let min_value = match self {
                     ConfigEnum::WarningDelta => MIN_WARNING_DUE_DATE_DAYS,
@@ -2100,6 +3760,11 @@ impl ConfigEnum {
                     ConfigEnum::Tickrate => MAX_TICKRATE,
                     ConfigEnum::NoOfCardsToShow => MAX_NO_CARDS_PER_BOARD,
                     ConfigEnum::NoOfBoardsToShow => MAX_NO_BOARDS_PER_PAGE,
+                    ConfigEnum::UndoHistoryLength => MAX_UNDO_HISTORY_LENGTH,
+                    ConfigEnum::AutoArchiveCompletedDays => MAX_AUTO_ARCHIVE_COMPLETED_DAYS,
+                    ConfigEnum::AutoSaveInterval => MAX_AUTO_SAVE_INTERVAL,
+                    ConfigEnum::SaveRetentionDailyDays => MAX_SAVE_RETENTION_DAILY_DAYS,
+                    ConfigEnum::SaveRetentionVersionsPerDay => MAX_SAVE_RETENTION_VERSIONS_PER_DAY,
                     _ => 0,
                 };
                 let check = value.parse::<u16>();
@@ -2121,6 +3786,8 @@ impl ConfigEnum {
                 // TODO: check if theme exists
                 Ok(())
             }
+            // An empty display name falls back to the email of the logged in user
+            ConfigEnum::DisplayName => Ok(()),
             ConfigEnum::DateFormat => {
                 let date_format = DateTimeFormat::from_human_readable_string(value);
                 if date_format.is_some() {
@@ -2137,6 +3804,20 @@ impl ConfigEnum {
                     Err(format!("Invalid CalenderFormat: {}", value))
                 }
             }
+            ConfigEnum::SyncBackend => {
+                if SyncBackendType::from_str(value).is_ok() {
+                    Ok(())
+                } else {
+                    Err(format!("Invalid SyncBackend: {}", value))
+                }
+            }
+            ConfigEnum::StorageMode => {
+                if StorageMode::from_str(value).is_ok() {
+                    Ok(())
+                } else {
+                    Err(format!("Invalid StorageMode: {}", value))
+                }
+            }
             ConfigEnum::Keybindings => {
                 debug!("Keybindings should not be called from validate_value");
                 // Keybindings are handled separately
@@ -2153,6 +3834,15 @@ impl ConfigEnum {
             ConfigEnum::SaveDirectory => {
                 config.save_directory = PathBuf::from(value);
             }
+            ConfigEnum::SyncBackend => {
+                config.sync_backend = SyncBackendType::from_str(value).unwrap();
+            }
+            ConfigEnum::StorageMode => {
+                config.storage_mode = StorageMode::from_str(value).unwrap();
+            }
+            ConfigEnum::SyncDirectory => {
+                config.sync_directory = PathBuf::from(value);
+            }
             ConfigEnum::DefaultView => {
                 config.default_view = View::from_string(value).unwrap();
             }
@@ -2177,6 +3867,9 @@ impl ConfigEnum {
             ConfigEnum::EnableMouseSupport => {
                 config.enable_mouse_support = value.parse::<bool>().unwrap();
             }
+            ConfigEnum::EnforceWipLimits => {
+                config.enforce_wip_limits = value.parse::<bool>().unwrap();
+            }
             ConfigEnum::WarningDelta => {
                 config.warning_delta = value.parse::<u16>().unwrap();
             }
@@ -2189,9 +3882,27 @@ impl ConfigEnum {
             ConfigEnum::NoOfBoardsToShow => {
                 config.no_of_boards_to_show = value.parse::<u16>().unwrap();
             }
+            ConfigEnum::UndoHistoryLength => {
+                config.undo_history_length = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::AutoArchiveCompletedDays => {
+                config.auto_archive_completed_days = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::AutoSaveInterval => {
+                config.auto_save_interval = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::SaveRetentionDailyDays => {
+                config.save_retention_daily_days = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::SaveRetentionVersionsPerDay => {
+                config.save_retention_versions_per_day = value.parse::<u16>().unwrap();
+            }
             ConfigEnum::DefaultTheme => {
                 config.default_theme = value.to_string();
             }
+            ConfigEnum::DisplayName => {
+                config.display_name = value.to_string();
+            }
             ConfigEnum::DateFormat => {
                 config.date_time_format =
                     DateTimeFormat::from_human_readable_string(value).unwrap();
@@ -2214,3 +3925,98 @@ pub async fn handle_exit(app: &mut App<'_>) -> AppReturn {
     }
     AppReturn::Exit
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(actions: Vec<ActionHistory>, history_index: usize) -> ActionHistoryManager {
+        ActionHistoryManager {
+            history: actions
+                .into_iter()
+                .map(|action| ActionHistoryEntry {
+                    action,
+                    time: Utc::now(),
+                })
+                .collect(),
+            history_index,
+        }
+    }
+
+    fn create_board(name: &str) -> ActionHistory {
+        ActionHistory::CreateBoard(Board::new(name, ""))
+    }
+
+    fn board_names(action_history_manager: &ActionHistoryManager) -> Vec<&str> {
+        action_history_manager
+            .history
+            .iter()
+            .map(|entry| match &entry.action {
+                ActionHistory::CreateBoard(board) => board.name.as_str(),
+                action => panic!("unexpected {:?}", action),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn saved_history_leaves_out_whole_board_actions_and_what_depends_on_them() {
+        let actions = || {
+            vec![
+                create_board("A"),
+                ActionHistory::LoadSave(
+                    "kanban_01-01-2024_v1.json".to_string(),
+                    Boards::default(),
+                    Boards::default(),
+                ),
+                create_board("B"),
+                ActionHistory::ChangeFilter(None, Some("tag:x".to_string())),
+                create_board("C"),
+                ActionHistory::ApplyBoardTemplate(
+                    "Template".to_string(),
+                    Boards::default(),
+                    Boards::default(),
+                ),
+                create_board("D"),
+            ]
+        };
+
+        let saved_history = history(actions(), 5).to_saved_history(100, usize::MAX);
+        assert_eq!(board_names(&saved_history), vec!["B", "C"]);
+        assert_eq!(saved_history.history_index, 2);
+
+        let saved_history = history(actions(), 7).to_saved_history(100, usize::MAX);
+        assert_eq!(board_names(&saved_history), vec!["D"]);
+        assert_eq!(saved_history.history_index, 1);
+
+        let saved_history = history(actions(), 3).to_saved_history(1, usize::MAX);
+        assert_eq!(board_names(&saved_history), vec!["C"]);
+        assert_eq!(saved_history.history_index, 0);
+    }
+
+    #[test]
+    fn saved_history_is_kept_under_the_size_limit() {
+        let action_history_manager = history(
+            vec![
+                create_board("A"),
+                create_board("B"),
+                create_board("C"),
+                create_board("D"),
+            ],
+            2,
+        );
+        let entry_size = |index: usize| {
+            serde_json::to_string(&action_history_manager.history[index])
+                .unwrap()
+                .len()
+        };
+
+        let saved_history = action_history_manager
+            .to_saved_history(100, entry_size(1) + entry_size(2) + entry_size(3));
+        assert_eq!(board_names(&saved_history), vec!["B", "C", "D"]);
+        assert_eq!(saved_history.history_index, 1);
+
+        let saved_history = action_history_manager.to_saved_history(100, entry_size(2));
+        assert_eq!(board_names(&saved_history), vec!["C"]);
+        assert_eq!(saved_history.history_index, 0);
+    }
+}
@@ -0,0 +1,477 @@
+use crate::{
+    app::{
+        kanban::{Board, Boards, Card},
+        DateTimeFormat,
+    },
+    io::sync::merge::{get_card_versions, CardVersion},
+    util::format_date_time,
+};
+use std::{collections::HashSet, fmt};
+
+/// Label used in place of a save name when a save is compared with the boards in the app
+pub const CURRENT_BOARDS_LABEL: &str = "Current Boards";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveDiffChange {
+    BoardAdded,
+    BoardRemoved,
+    BoardEdited,
+    CardAdded,
+    CardRemoved,
+    CardArchived,
+    /// Moved to another board or to another position on its board, the card may have been
+    /// edited as well
+    CardMoved,
+    CardEdited,
+}
+
+impl fmt::Display for SaveDiffChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveDiffChange::BoardAdded => write!(f, "Board Added"),
+            SaveDiffChange::BoardRemoved => write!(f, "Board Removed"),
+            SaveDiffChange::BoardEdited => write!(f, "Board Edited"),
+            SaveDiffChange::CardAdded => write!(f, "Card Added"),
+            SaveDiffChange::CardRemoved => write!(f, "Card Removed"),
+            SaveDiffChange::CardArchived => write!(f, "Card Archived"),
+            SaveDiffChange::CardMoved => write!(f, "Card Moved"),
+            SaveDiffChange::CardEdited => write!(f, "Card Edited"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SaveDiffEntry {
+    pub change: SaveDiffChange,
+    /// Name of the board or card, the newer one if it was renamed
+    pub name: String,
+    /// Board the card is on, empty for board changes
+    pub board_name: String,
+    pub field_changes: Vec<FieldChange>,
+    /// The card as it is in the older save, restoring a card puts this version back
+    pub older_card: Option<CardVersion>,
+}
+
+/// Everything that changed from the boards of the older save to the boards of the newer one
+#[derive(Debug, Clone)]
+pub struct SaveDiff {
+    pub older_name: String,
+    pub newer_name: String,
+    pub entries: Vec<SaveDiffEntry>,
+}
+
+impl SaveDiff {
+    pub fn new(
+        older_name: &str,
+        older: &Boards,
+        newer_name: &str,
+        newer: &Boards,
+        date_time_format: DateTimeFormat,
+    ) -> Self {
+        let mut entries = Vec::new();
+
+        for board in newer.get_boards() {
+            match older.get_board_with_id(board.id) {
+                None => entries.push(board_entry(SaveDiffChange::BoardAdded, board, Vec::new())),
+                Some(older_board) => {
+                    let field_changes = get_board_field_changes(older_board, board);
+                    if !field_changes.is_empty() {
+                        entries.push(board_entry(
+                            SaveDiffChange::BoardEdited,
+                            board,
+                            field_changes,
+                        ));
+                    }
+                }
+            }
+        }
+        for board in older.get_boards() {
+            if newer.get_board_with_id(board.id).is_none() {
+                entries.push(board_entry(SaveDiffChange::BoardRemoved, board, Vec::new()));
+            }
+        }
+
+        let older_cards = get_card_versions(older);
+        let newer_cards = get_card_versions(newer);
+        for board in newer.get_boards() {
+            let moved_card_ids = older
+                .get_board_with_id(board.id)
+                .map(|older_board| get_moved_card_ids(older_board, board))
+                .unwrap_or_default();
+            for (card_index, card) in board.cards.get_all_cards().iter().enumerate() {
+                let Some(older_version) = older_cards.get(&card.id) else {
+                    entries.push(SaveDiffEntry {
+                        change: SaveDiffChange::CardAdded,
+                        name: card.name.clone(),
+                        board_name: board.name.clone(),
+                        field_changes: Vec::new(),
+                        older_card: None,
+                    });
+                    continue;
+                };
+                let mut field_changes = Vec::new();
+                if older_version.board_id != board.id {
+                    field_changes.push(FieldChange {
+                        field: "Board",
+                        old: older_version.board_name.clone(),
+                        new: board.name.clone(),
+                    });
+                } else if moved_card_ids.contains(&card.id) {
+                    field_changes.push(FieldChange {
+                        field: "Position",
+                        old: (older_version.card_index + 1).to_string(),
+                        new: (card_index + 1).to_string(),
+                    });
+                }
+                let moved = !field_changes.is_empty();
+                field_changes.extend(get_card_field_changes(
+                    (&older_version.card, older),
+                    (card, newer),
+                    date_time_format,
+                ));
+                if field_changes.is_empty() {
+                    continue;
+                }
+                let change = if moved {
+                    SaveDiffChange::CardMoved
+                } else {
+                    SaveDiffChange::CardEdited
+                };
+                entries.push(SaveDiffEntry {
+                    change,
+                    name: card.name.clone(),
+                    board_name: board.name.clone(),
+                    field_changes,
+                    older_card: Some(older_version.clone()),
+                });
+            }
+        }
+        for board in older.get_boards() {
+            for card in board.cards.get_all_cards() {
+                if newer_cards.contains_key(&card.id) {
+                    continue;
+                }
+                let archived = newer.get_boards().iter().any(|board| {
+                    board
+                        .archived_cards
+                        .iter()
+                        .any(|archived_card| archived_card.card.id == card.id)
+                });
+                entries.push(SaveDiffEntry {
+                    change: if archived {
+                        SaveDiffChange::CardArchived
+                    } else {
+                        SaveDiffChange::CardRemoved
+                    },
+                    name: card.name.clone(),
+                    board_name: board.name.clone(),
+                    field_changes: Vec::new(),
+                    older_card: older_cards.get(&card.id).cloned(),
+                });
+            }
+        }
+
+        Self {
+            older_name: older_name.to_string(),
+            newer_name: newer_name.to_string(),
+            entries,
+        }
+    }
+}
+
+fn board_entry(
+    change: SaveDiffChange,
+    board: &Board,
+    field_changes: Vec<FieldChange>,
+) -> SaveDiffEntry {
+    SaveDiffEntry {
+        change,
+        name: board.name.clone(),
+        board_name: String::new(),
+        field_changes,
+        older_card: None,
+    }
+}
+
+/// Cards that changed places on a board that has them in both saves. Only the cards outside the
+/// longest run kept in the same order count as moved, so a card moved to the top does not make
+/// every card it passed count as moved too
+fn get_moved_card_ids(older: &Board, newer: &Board) -> HashSet<(u64, u64)> {
+    let older_ids: HashSet<(u64, u64)> = older.cards.get_all_card_ids().into_iter().collect();
+    let newer_ids: HashSet<(u64, u64)> = newer.cards.get_all_card_ids().into_iter().collect();
+    let older_order: Vec<(u64, u64)> = older
+        .cards
+        .get_all_card_ids()
+        .into_iter()
+        .filter(|card_id| newer_ids.contains(card_id))
+        .collect();
+    let newer_order: Vec<(u64, u64)> = newer
+        .cards
+        .get_all_card_ids()
+        .into_iter()
+        .filter(|card_id| older_ids.contains(card_id))
+        .collect();
+    // longest common subsequence, lengths[i][j] is its length for older_order[i..] and
+    // newer_order[j..]
+    let mut lengths = vec![vec![0; newer_order.len() + 1]; older_order.len() + 1];
+    for i in (0..older_order.len()).rev() {
+        for j in (0..newer_order.len()).rev() {
+            lengths[i][j] = if older_order[i] == newer_order[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let mut moved_card_ids: HashSet<(u64, u64)> = newer_order.iter().copied().collect();
+    let (mut i, mut j) = (0, 0);
+    while i < older_order.len() && j < newer_order.len() {
+        if older_order[i] == newer_order[j] {
+            moved_card_ids.remove(&older_order[i]);
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    moved_card_ids
+}
+
+fn get_board_field_changes(older: &Board, newer: &Board) -> Vec<FieldChange> {
+    let wip_limit = |board: &Board| {
+        board
+            .wip_limit
+            .map_or("None".to_string(), |wip_limit| wip_limit.to_string())
+    };
+    collect_field_changes(vec![
+        ("Name", older.name.clone(), newer.name.clone()),
+        (
+            "Description",
+            older.description.clone(),
+            newer.description.clone(),
+        ),
+        (
+            "In Progress",
+            older.in_progress.to_string(),
+            newer.in_progress.to_string(),
+        ),
+        (
+            "Sort Mode",
+            older.sort_mode.to_string(),
+            newer.sort_mode.to_string(),
+        ),
+        ("WIP Limit", wip_limit(older), wip_limit(newer)),
+    ])
+}
+
+/// Revision and modified date are left out, they change with every edit and say nothing about
+/// what was changed
+fn get_card_field_changes(
+    (older, older_boards): (&Card, &Boards),
+    (newer, newer_boards): (&Card, &Boards),
+    date_time_format: DateTimeFormat,
+) -> Vec<FieldChange> {
+    let tags = |card: &Card| {
+        if card.tags.is_empty() {
+            "None".to_string()
+        } else {
+            card.tags.join(", ")
+        }
+    };
+    let checklist = |card: &Card| {
+        if card.checklist.is_empty() {
+            return "None".to_string();
+        }
+        card.checklist
+            .iter()
+            .map(|item| format!("[{}] {}", if item.done { "x" } else { " " }, item.text))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    let comments = |card: &Card| {
+        if card.comments.is_empty() {
+            return "None".to_string();
+        }
+        card.comments
+            .iter()
+            .map(|comment| format!("{}: {}", comment.author, comment.body))
+            .collect::<Vec<String>>()
+            .join("; ")
+    };
+    let blocked_by = |card: &Card, boards: &Boards| {
+        if card.blocked_by.is_empty() {
+            return "None".to_string();
+        }
+        card.blocked_by
+            .iter()
+            .map(|card_id| {
+                boards
+                    .find_card_with_id(*card_id)
+                    .map_or(format!("{:?}", card_id), |card| card.name.clone())
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    let recurrence = |card: &Card| {
+        card.recurrence
+            .as_ref()
+            .map_or("None".to_string(), |recurrence| recurrence.to_string())
+    };
+    collect_field_changes(vec![
+        ("Name", older.name.clone(), newer.name.clone()),
+        (
+            "Description",
+            older.description.clone(),
+            newer.description.clone(),
+        ),
+        (
+            "Status",
+            older.card_status.to_string(),
+            newer.card_status.to_string(),
+        ),
+        (
+            "Priority",
+            older.priority.to_string(),
+            newer.priority.to_string(),
+        ),
+        (
+            "Due Date",
+            format_date_time(older.due_date, date_time_format),
+            format_date_time(newer.due_date, date_time_format),
+        ),
+        (
+            "Completed",
+            format_date_time(older.date_completed, date_time_format),
+            format_date_time(newer.date_completed, date_time_format),
+        ),
+        ("Tags", tags(older), tags(newer)),
+        ("Checklist", checklist(older), checklist(newer)),
+        ("Comments", comments(older), comments(newer)),
+        (
+            "Blocked By",
+            blocked_by(older, older_boards),
+            blocked_by(newer, newer_boards),
+        ),
+        ("Recurrence", recurrence(older), recurrence(newer)),
+    ])
+}
+
+fn collect_field_changes(fields: Vec<(&'static str, String, String)>) -> Vec<FieldChange> {
+    fields
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .map(|(field, old, new)| FieldChange { field, old, new })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::CardPriority;
+
+    /// A "Todo" board with the cards in the given order
+    fn boards(card_names: &[&str]) -> Boards {
+        let mut board = Board::new("Todo", "");
+        for card_name in card_names {
+            board.cards.add_card(Card::new(
+                card_name,
+                "",
+                None,
+                CardPriority::Low,
+                vec![],
+                vec![],
+            ));
+        }
+        Boards::from(vec![board])
+    }
+
+    /// The same boards with the cards of the first board in the order of `card_names`
+    fn reordered(boards: &Boards, card_names: &[&str]) -> Boards {
+        let mut boards = boards.clone();
+        let board = &mut boards.get_mut_boards()[0];
+        let mut cards = board.cards.get_all_cards().clone();
+        cards.sort_by_key(|card| {
+            card_names
+                .iter()
+                .position(|card_name| *card_name == card.name)
+        });
+        cards.retain(|card| card_names.contains(&card.name.as_str()));
+        board.cards.set_cards(cards.into());
+        boards
+    }
+
+    fn diff(older: &Boards, newer: &Boards) -> SaveDiff {
+        SaveDiff::new("older", older, "newer", newer, DateTimeFormat::DayMonthYear)
+    }
+
+    fn card_names(boards: &Boards) -> Vec<String> {
+        boards.get_boards()[0]
+            .cards
+            .get_all_cards()
+            .iter()
+            .map(|card| card.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn card_moved_on_its_board_is_reported_with_its_positions() {
+        let older = boards(&["A", "B", "C", "D"]);
+        let newer = reordered(&older, &["D", "A", "B", "C"]);
+
+        let save_diff = diff(&older, &newer);
+        assert_eq!(save_diff.entries.len(), 1);
+        let entry = &save_diff.entries[0];
+        assert_eq!(entry.change, SaveDiffChange::CardMoved);
+        assert_eq!(entry.name, "D");
+        assert_eq!(
+            entry.field_changes,
+            vec![FieldChange {
+                field: "Position",
+                old: "4".to_string(),
+                new: "1".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn removed_cards_do_not_make_the_others_count_as_moved() {
+        let older = boards(&["A", "B", "C"]);
+        let newer = reordered(&older, &["B", "C"]);
+
+        let save_diff = diff(&older, &newer);
+        assert_eq!(save_diff.entries.len(), 1);
+        assert_eq!(save_diff.entries[0].change, SaveDiffChange::CardRemoved);
+        assert_eq!(save_diff.entries[0].name, "A");
+    }
+
+    #[test]
+    fn restored_cards_go_back_to_their_position() {
+        let older = boards(&["A", "B", "C"]);
+        let newer = reordered(&older, &["B", "C", "A"]);
+        let save_diff = diff(&older, &newer);
+        let older_card = save_diff.entries[0].older_card.as_ref().unwrap();
+        assert_eq!(older_card.card.name, "A");
+
+        let mut restored = newer.clone();
+        older_card.restore(&mut restored);
+        assert_eq!(card_names(&restored), vec!["A", "B", "C"]);
+
+        // C was the last of three cards, the board only has one left
+        let mut shorter = reordered(&older, &["B"]);
+        let older_c = get_card_versions(&older)
+            .into_values()
+            .find(|card_version| card_version.card.name == "C")
+            .unwrap();
+        older_c.restore(&mut shorter);
+        assert_eq!(card_names(&shorter), vec!["B", "C"]);
+    }
+}
@@ -1,11 +1,19 @@
 use crate::{
-    app::{actions::Action, kanban::Card, VisibleBoardsAndCards},
+    app::{
+        actions::Action,
+        card_filter::CardFilter,
+        card_template::CardTemplate,
+        kanban::{Boards, Card},
+        save_diff::SaveDiff,
+        VisibleBoardsAndCards,
+    },
     constants::{DEFAULT_VIEW, MOUSE_OUT_OF_BOUNDS_COORDINATES},
     inputs::{key::Key, mouse::Mouse},
-    io::io_handler::CloudData,
+    io::{io_handler::CloudData, sync::PendingSync},
     ui::{text_box::TextBox, theme::Theme, PopUp, View},
     util::get_term_bg_color,
 };
+use chrono::{DateTime, Local};
 use linked_hash_map::LinkedHashMap;
 use log::debug;
 use ratatui::widgets::{ListState, TableState};
@@ -25,6 +33,9 @@ pub struct AppState<'a> {
     pub app_list_states: AppListStates,
     pub app_status: AppStatus,
     pub app_table_states: AppTableStates,
+    /// Generation of `App::boards` last seen by the auto-save timer and when it was first seen,
+    /// the boards are saved once they have been left unchanged for the auto-save interval
+    pub boards_changed: (u64, Instant),
     pub card_being_edited: Option<((u64, u64), Card)>, // (board_id, card)
     pub card_drag_mode: bool,
     pub cloud_data: Option<Vec<CloudData>>,
@@ -36,20 +47,38 @@ pub struct AppState<'a> {
     pub edited_keybinding: Option<Vec<Key>>,
     pub encryption_key_from_arguments: Option<String>,
     pub filter_tags: Option<Vec<String>>,
+    /// The filter `App::filtered_boards` was built from
+    pub card_filter: Option<CardFilter>,
     pub focus: Focus,
     pub hovered_board: Option<(u64, u64)>,
     pub hovered_card_dimensions: Option<(u16, u16)>,
     pub hovered_card: Option<((u64, u64), (u64, u64))>,
     pub last_mouse_action: Option<Mouse>,
     pub last_reset_password_link_sent_time: Option<Instant>,
+    pub last_save_time: Option<DateTime<Local>>,
+    /// Why the latest save could not be loaded, saving is refused while it is set so the empty
+    /// boards the app started with do not replace it
+    pub latest_save_load_error: Option<String>,
     pub mouse_focus: Option<Focus>,
     pub mouse_list_index: Option<u16>,
+    /// Template picked in the new card form, its tags and priority are given to the new card
+    pub new_card_template: Option<CardTemplate>,
+    pub pending_sync: Option<PendingSync>,
     pub z_stack: ZStack,
     pub prev_focus: Option<Focus>,
     pub prev_view: Option<View>,
     pub preview_file_name: Option<String>,
     pub preview_visible_boards_and_cards: VisibleBoardsAndCards,
     pub previous_mouse_coordinates: (u16, u16),
+    /// The boards in the latest save on disk, unsaved changes are checked against them
+    pub saved_boards: Boards,
+    /// Generation of `App::boards` when they last matched `saved_boards`
+    pub saved_boards_generation: Option<u64>,
+    /// Changes between the selected save and `save_diff_target`, loaded while comparing saves
+    pub save_diff: Option<SaveDiff>,
+    pub save_diff_mode: bool,
+    /// Save the selected save is compared with, the current boards when not set
+    pub save_diff_target: Option<String>,
     pub term_background_color: (u8, u8, u8),
     pub theme_being_edited: Theme,
     pub current_view: View,
@@ -80,6 +109,7 @@ impl Default for AppState<'_> {
             app_list_states: AppListStates::default(),
             app_status: AppStatus::default(),
             app_table_states: AppTableStates::default(),
+            boards_changed: (0, Instant::now()),
             card_being_edited: None,
             card_drag_mode: false,
             cloud_data: None,
@@ -91,20 +121,30 @@ impl Default for AppState<'_> {
             edited_keybinding: None,
             encryption_key_from_arguments: None,
             filter_tags: None,
+            card_filter: None,
             focus: Focus::NoFocus,
             hovered_board: None,
             hovered_card_dimensions: None,
             hovered_card: None,
             last_mouse_action: None,
             last_reset_password_link_sent_time: None,
+            last_save_time: None,
+            latest_save_load_error: None,
             mouse_focus: None,
             mouse_list_index: None,
+            new_card_template: None,
+            pending_sync: None,
             z_stack: ZStack::default(),
             prev_focus: None,
             prev_view: None,
             preview_file_name: None,
             preview_visible_boards_and_cards: LinkedHashMap::new(),
             previous_mouse_coordinates: MOUSE_OUT_OF_BOUNDS_COORDINATES,
+            saved_boards: Boards::default(),
+            saved_boards_generation: None,
+            save_diff: None,
+            save_diff_mode: false,
+            save_diff_target: None,
             term_background_color: get_term_bg_color(),
             theme_being_edited: Theme::default(),
             current_view: DEFAULT_VIEW,
@@ -168,8 +208,16 @@ impl DerefMut for ZStack {
 
 #[derive(Debug, Clone, Default)]
 pub struct AppListStates {
+    pub action_history: ListState,
+    pub archived_cards: ListState,
+    pub board_sort_mode_selector: ListState,
+    pub board_template_selector: ListState,
     pub card_priority_selector: ListState,
+    pub card_recurrence_selector: ListState,
     pub card_status_selector: ListState,
+    pub card_template_selector: ListState,
+    pub card_view_blocked_by_list: ListState,
+    pub card_view_checklist_list: ListState,
     pub card_view_comment_list: ListState,
     pub card_view_list: ListState,
     pub card_view_tag_list: ListState,
@@ -181,10 +229,13 @@ pub struct AppListStates {
     pub default_view: ListState,
     pub edit_specific_style: [ListState; 3],
     pub filter_by_tag_list: ListState,
+    pub saved_filters_list: ListState,
     pub load_save: ListState,
     pub logs: ListState,
     pub main_menu: ListState,
+    pub save_diff: ListState,
     pub theme_selector: ListState,
+    pub workspace_selector: ListState,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -199,10 +250,12 @@ pub struct AppTableStates {
 pub struct TextBuffers<'a> {
     pub board_name: TextBox<'a>,
     pub board_description: TextBox<'a>,
+    pub board_wip_limit: TextBox<'a>,
     pub card_name: TextBox<'a>,
     pub card_description: TextBox<'a>,
     pub card_tags: Vec<TextBox<'a>>,
     pub card_comments: Vec<TextBox<'a>>,
+    pub card_checklist: Vec<TextBox<'a>>,
     pub email_id: TextBox<'a>,
     pub password: TextBox<'a>,
     pub confirm_password: TextBox<'a>,
@@ -211,6 +264,14 @@ pub struct TextBuffers<'a> {
     pub command_palette: TextBox<'a>,
     pub theme_editor_fg_hex: TextBox<'a>,
     pub theme_editor_bg_hex: TextBox<'a>,
+    pub import_file_path: TextBox<'a>,
+    pub filter_query: TextBox<'a>,
+    pub filter_name: TextBox<'a>,
+    pub archive_search: TextBox<'a>,
+    pub card_recurrence_days: TextBox<'a>,
+    pub card_template_name: TextBox<'a>,
+    pub board_template_name: TextBox<'a>,
+    pub workspace_name: TextBox<'a>,
 }
 
 impl Default for TextBuffers<'_> {
@@ -218,10 +279,12 @@ impl Default for TextBuffers<'_> {
         TextBuffers {
             board_name: TextBox::new(vec!["".to_string()], true),
             board_description: TextBox::new(vec!["".to_string()], false),
+            board_wip_limit: TextBox::new(vec!["".to_string()], true),
             card_name: TextBox::new(vec!["".to_string()], true),
             card_description: TextBox::new(vec!["".to_string()], false),
             card_tags: Vec::new(),
             card_comments: Vec::new(),
+            card_checklist: Vec::new(),
             email_id: TextBox::new(vec!["".to_string()], true),
             password: TextBox::new(vec!["".to_string()], true),
             confirm_password: TextBox::new(vec!["".to_string()], true),
@@ -230,6 +293,14 @@ impl Default for TextBuffers<'_> {
             command_palette: TextBox::new(vec!["".to_string()], true),
             theme_editor_fg_hex: TextBox::new(vec!["".to_string()], true),
             theme_editor_bg_hex: TextBox::new(vec!["".to_string()], true),
+            import_file_path: TextBox::new(vec!["".to_string()], true),
+            filter_query: TextBox::new(vec!["".to_string()], true),
+            filter_name: TextBox::new(vec!["".to_string()], true),
+            archive_search: TextBox::new(vec!["".to_string()], true),
+            card_recurrence_days: TextBox::new(vec!["".to_string()], true),
+            card_template_name: TextBox::new(vec!["".to_string()], true),
+            board_template_name: TextBox::new(vec!["".to_string()], true),
+            workspace_name: TextBox::new(vec!["".to_string()], true),
         }
     }
 }
@@ -244,7 +315,12 @@ impl TextBuffers<'_> {
         self.card_comments = card
             .comments
             .iter()
-            .map(|comment| TextBox::new(vec![comment.clone()], true))
+            .map(|comment| TextBox::new(vec![comment.body.clone()], true))
+            .collect();
+        self.card_checklist = card
+            .checklist
+            .iter()
+            .map(|item| TextBox::new(vec![item.text.clone()], true))
             .collect();
     }
 }
@@ -277,15 +353,23 @@ pub enum AppStatus {
 
 #[derive(Clone, PartialEq, Debug, Copy, Default)]
 pub enum Focus {
+    ActionHistoryPopup,
+    ArchivedCardsList,
     Body,
+    CardBlockedBy,
+    CardChecklist,
     CardComments,
     CardDescription,
     CardDueDate,
     CardName,
     CardPriority,
+    CardRecurrence,
     CardStatus,
     CardTags,
+    CardTemplate,
+    ChangeBoardSortModePopup,
     ChangeCardPriorityPopup,
+    ChangeCardRecurrencePopup,
     ChangeCardStatusPopup,
     ChangeDateFormatPopup,
     ChangeViewPopup,
@@ -302,17 +386,24 @@ pub enum Focus {
     EmailIDField,
     ExtraFocus, // Used in cases where defining a new focus is not necessary
     FilterByTagPopup,
+    FilterName,
     Help,
     LoadSave,
     Log,
     MainMenu,
     NewBoardDescription,
     NewBoardName,
+    NewBoardWipLimit,
     #[default]
     NoFocus,
     PasswordField,
     ResetPasswordLinkField,
+    SaveDiff,
+    SavedFilters,
+    SelectBoardTemplatePopup,
+    SelectCardTemplatePopup,
     SelectDefaultView,
+    SelectWorkspacePopup,
     SendResetPasswordLinkButton,
     StyleEditorBG,
     StyleEditorFG,
@@ -341,6 +432,7 @@ pub struct KeyBindings {
     pub change_card_priority_to_medium: Vec<Key>,
     pub change_card_priority_to_low: Vec<Key>,
     pub clear_all_toasts: Vec<Key>,
+    pub compare_saves: Vec<Key>,
     pub delete_board: Vec<Key>,
     pub delete_card: Vec<Key>,
     pub down: Vec<Key>,
@@ -364,6 +456,7 @@ pub struct KeyBindings {
     pub save_state: Vec<Key>,
     pub stop_user_input: Vec<Key>,
     pub take_user_input: Vec<Key>,
+    pub toggle_checklist_item: Vec<Key>,
     pub toggle_command_palette: Vec<Key>,
     pub undo: Vec<Key>,
     pub up: Vec<Key>,
@@ -379,6 +472,7 @@ pub enum KeyBindingEnum {
     ChangeCardPriorityToMedium,
     ChangeCardPriorityToLow,
     ClearAllToasts,
+    CompareSaves,
     DeleteBoard,
     DeleteCard,
     Down,
@@ -402,6 +496,7 @@ pub enum KeyBindingEnum {
     SaveState,
     StopUserInput,
     TakeUserInput,
+    ToggleChecklistItem,
     ToggleCommandPalette,
     Undo,
     Up,
@@ -456,6 +551,7 @@ impl KeyBindings {
                 KeyBindingEnum::ChangeCardPriorityToMedium => &self.change_card_priority_to_medium,
                 KeyBindingEnum::ChangeCardPriorityToLow => &self.change_card_priority_to_low,
                 KeyBindingEnum::ClearAllToasts => &self.clear_all_toasts,
+                KeyBindingEnum::CompareSaves => &self.compare_saves,
                 KeyBindingEnum::DeleteBoard => &self.delete_board,
                 KeyBindingEnum::DeleteCard => &self.delete_card,
                 KeyBindingEnum::Down => &self.down,
@@ -479,6 +575,7 @@ impl KeyBindings {
                 KeyBindingEnum::SaveState => &self.save_state,
                 KeyBindingEnum::StopUserInput => &self.stop_user_input,
                 KeyBindingEnum::TakeUserInput => &self.take_user_input,
+                KeyBindingEnum::ToggleChecklistItem => &self.toggle_checklist_item,
                 KeyBindingEnum::ToggleCommandPalette => &self.toggle_command_palette,
                 KeyBindingEnum::Undo => &self.undo,
                 KeyBindingEnum::Up => &self.up,
@@ -505,6 +602,7 @@ impl KeyBindings {
             KeyBindingEnum::ChangeCardPriorityToMedium => Action::ChangeCardPriorityToMedium,
             KeyBindingEnum::ChangeCardPriorityToLow => Action::ChangeCardPriorityToLow,
             KeyBindingEnum::ClearAllToasts => Action::ClearAllToasts,
+            KeyBindingEnum::CompareSaves => Action::CompareSaves,
             KeyBindingEnum::DeleteBoard => Action::DeleteBoard,
             KeyBindingEnum::DeleteCard => Action::Delete,
             KeyBindingEnum::Down => Action::Down,
@@ -528,6 +626,7 @@ impl KeyBindings {
             KeyBindingEnum::SaveState => Action::SaveState,
             KeyBindingEnum::StopUserInput => Action::StopUserInput,
             KeyBindingEnum::TakeUserInput => Action::TakeUserInput,
+            KeyBindingEnum::ToggleChecklistItem => Action::ToggleChecklistItem,
             KeyBindingEnum::ToggleCommandPalette => Action::ToggleCommandPalette,
             KeyBindingEnum::Undo => Action::Undo,
             KeyBindingEnum::Up => Action::Up,
@@ -560,6 +659,7 @@ impl KeyBindings {
                     self.change_card_priority_to_low = keybinding
                 }
                 KeyBindingEnum::ClearAllToasts => self.clear_all_toasts = keybinding,
+                KeyBindingEnum::CompareSaves => self.compare_saves = keybinding,
                 KeyBindingEnum::DeleteBoard => self.delete_board = keybinding,
                 KeyBindingEnum::DeleteCard => self.delete_card = keybinding,
                 KeyBindingEnum::Down => self.down = keybinding,
@@ -585,6 +685,7 @@ impl KeyBindings {
                 KeyBindingEnum::SaveState => self.save_state = keybinding,
                 KeyBindingEnum::StopUserInput => self.stop_user_input = keybinding,
                 KeyBindingEnum::TakeUserInput => self.take_user_input = keybinding,
+                KeyBindingEnum::ToggleChecklistItem => self.toggle_checklist_item = keybinding,
                 KeyBindingEnum::ToggleCommandPalette => self.toggle_command_palette = keybinding,
                 KeyBindingEnum::Undo => self.undo = keybinding,
                 KeyBindingEnum::Up => self.up = keybinding,
@@ -617,6 +718,7 @@ impl KeyBindings {
                 Some(self.change_card_priority_to_low.clone())
             }
             KeyBindingEnum::ClearAllToasts => Some(self.clear_all_toasts.clone()),
+            KeyBindingEnum::CompareSaves => Some(self.compare_saves.clone()),
             KeyBindingEnum::DeleteBoard => Some(self.delete_board.clone()),
             KeyBindingEnum::DeleteCard => Some(self.delete_card.clone()),
             KeyBindingEnum::Down => Some(self.down.clone()),
@@ -642,6 +744,7 @@ impl KeyBindings {
             KeyBindingEnum::SaveState => Some(self.save_state.clone()),
             KeyBindingEnum::StopUserInput => Some(self.stop_user_input.clone()),
             KeyBindingEnum::TakeUserInput => Some(self.take_user_input.clone()),
+            KeyBindingEnum::ToggleChecklistItem => Some(self.toggle_checklist_item.clone()),
             KeyBindingEnum::ToggleCommandPalette => Some(self.toggle_command_palette.clone()),
             KeyBindingEnum::Undo => Some(self.undo.clone()),
             KeyBindingEnum::Up => Some(self.up.clone()),
@@ -660,6 +763,7 @@ impl Default for KeyBindings {
             change_card_priority_to_medium: vec![Key::Char('5')],
             change_card_priority_to_low: vec![Key::Char('6')],
             clear_all_toasts: vec![Key::Char('t')],
+            compare_saves: vec![Key::Char('v')],
             delete_board: vec![Key::Char('D')],
             delete_card: vec![Key::Char('d'), Key::Delete],
             down: vec![Key::Down],
@@ -683,6 +787,7 @@ impl Default for KeyBindings {
             save_state: vec![Key::Ctrl('s')],
             stop_user_input: vec![Key::Ins],
             take_user_input: vec![Key::Char('i')],
+            toggle_checklist_item: vec![Key::Char('x')],
             toggle_command_palette: vec![Key::Ctrl('p')],
             undo: vec![Key::Ctrl('z')],
             up: vec![Key::Up],
@@ -0,0 +1,174 @@
+use crate::{
+    app::AppConfig,
+    constants::{DEFAULT_WORKSPACE_NAME, WORKSPACE_DIR_NAME},
+    io::io_handler::make_file_system_safe_name,
+    ui::View,
+};
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// View and theme a workspace opens with instead of the ones in the config, kept in the config
+/// under the name of the workspace
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct WorkspaceSettings {
+    #[serde(default)]
+    pub default_view: Option<View>,
+    #[serde(default)]
+    pub default_theme: Option<String>,
+}
+
+impl WorkspaceSettings {
+    /// Shown next to the workspace name in lists, empty if nothing is overridden
+    pub fn summary(&self) -> String {
+        let mut summary = Vec::new();
+        if let Some(default_view) = &self.default_view {
+            summary.push(format!("view: {}", default_view));
+        }
+        if let Some(default_theme) = &self.default_theme {
+            summary.push(format!("theme: {}", default_theme));
+        }
+        summary.join(", ")
+    }
+}
+
+/// Folder the saves of the workspace are kept in, None if the name has nothing a folder name can
+/// be made of
+pub fn get_workspace_folder_name(name: &str) -> Option<String> {
+    let folder_name = make_file_system_safe_name(name);
+    // Names like ".." would point at the save directory or outside it
+    if folder_name.trim_matches('.').is_empty() {
+        None
+    } else {
+        Some(folder_name)
+    }
+}
+
+impl AppConfig {
+    /// Drops workspaces whose names were edited into something without a save folder, and goes
+    /// back to the default workspace if the current one is gone
+    pub fn check_workspaces(&mut self) {
+        self.workspaces.retain(|name, _| {
+            let valid = !name.eq_ignore_ascii_case(DEFAULT_WORKSPACE_NAME)
+                && get_workspace_folder_name(name).is_some();
+            if !valid {
+                error!("Invalid workspace name: {}, Ignoring the workspace", name);
+            }
+            valid
+        });
+        match self.find_workspace(&self.current_workspace) {
+            Some(workspace) => self.current_workspace = workspace,
+            None => {
+                error!(
+                    "Unknown workspace: {}, Resetting to the default workspace",
+                    self.current_workspace
+                );
+                self.current_workspace = DEFAULT_WORKSPACE_NAME.to_string();
+            }
+        }
+    }
+
+    /// The workspace given with `--workspace` if any, otherwise the current one
+    pub fn active_workspace(&self) -> &str {
+        self.workspace_override
+            .as_deref()
+            .unwrap_or(&self.current_workspace)
+    }
+
+    /// The default workspace first, then the others by name
+    pub fn workspace_names(&self) -> Vec<String> {
+        std::iter::once(DEFAULT_WORKSPACE_NAME.to_string())
+            .chain(self.workspaces.keys().cloned())
+            .collect()
+    }
+
+    /// Name of the workspace as it was created, names are matched ignoring case
+    pub fn find_workspace(&self, name: &str) -> Option<String> {
+        self.workspace_names()
+            .into_iter()
+            .find(|workspace| workspace.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// The default workspace keeps its saves in the save directory itself, so saves from before
+    /// there were workspaces stay where they are. Other workspaces get a folder in it
+    pub fn workspace_save_directory(&self) -> PathBuf {
+        let workspace = self.active_workspace();
+        if workspace == DEFAULT_WORKSPACE_NAME {
+            self.save_directory.clone()
+        } else {
+            self.save_directory
+                .join(WORKSPACE_DIR_NAME)
+                .join(get_workspace_folder_name(workspace).unwrap_or_default())
+        }
+    }
+
+    pub fn workspace_default_view(&self) -> View {
+        self.workspaces
+            .get(self.active_workspace())
+            .and_then(|settings| settings.default_view)
+            .unwrap_or(self.default_view)
+    }
+
+    pub fn workspace_default_theme(&self) -> String {
+        self.workspaces
+            .get(self.active_workspace())
+            .and_then(|settings| settings.default_theme.clone())
+            .unwrap_or_else(|| self.default_theme.clone())
+    }
+
+    /// Adds a workspace without switching to it, returns the trimmed name
+    pub fn add_workspace(&mut self, name: &str) -> Result<String, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Workspace name cannot be empty".to_string());
+        }
+        let Some(folder_name) = get_workspace_folder_name(name) else {
+            return Err(format!("\"{}\" cannot be used as a workspace name", name));
+        };
+        // Workspaces whose names only differ in unsafe characters would share a save folder
+        if let Some(existing) = self.workspace_names().into_iter().find(|workspace| {
+            workspace.eq_ignore_ascii_case(name)
+                || make_file_system_safe_name(workspace).eq_ignore_ascii_case(&folder_name)
+        }) {
+            return Err(format!("Workspace \"{}\" already exists", existing));
+        }
+        self.workspaces
+            .insert(name.to_string(), WorkspaceSettings::default());
+        Ok(name.to_string())
+    }
+
+    /// Settings of a workspace other than the default one, which uses the config itself
+    pub fn workspace_settings_mut(&mut self, name: &str) -> Result<&mut WorkspaceSettings, String> {
+        let workspace = self
+            .find_workspace(name)
+            .ok_or_else(|| format!("Workspace \"{}\" not found", name.trim()))?;
+        self.workspaces.get_mut(&workspace).ok_or_else(|| {
+            format!(
+                "The {} workspace uses the default view and theme of the config",
+                DEFAULT_WORKSPACE_NAME
+            )
+        })
+    }
+
+    /// Makes the workspace the one the app opens, clears `--workspace`
+    pub fn switch_workspace(&mut self, name: &str) -> Result<String, String> {
+        let workspace = self
+            .find_workspace(name)
+            .ok_or_else(|| format!("Workspace \"{}\" not found", name.trim()))?;
+        self.current_workspace.clone_from(&workspace);
+        self.workspace_override = None;
+        Ok(workspace)
+    }
+
+    /// Uses the workspace for this run only, for `--workspace`
+    pub fn override_workspace(&mut self, name: &str) -> Result<(), String> {
+        let workspace = self.find_workspace(name).ok_or_else(|| {
+            format!(
+                "Workspace \"{}\" not found, create it with `rust-kanban workspace add`",
+                name.trim()
+            )
+        })?;
+        self.workspace_override = Some(workspace);
+        Ok(())
+    }
+}
@@ -0,0 +1,1262 @@
+use crate::{
+    app::{
+        board_template::BoardTemplate,
+        card_filter::CardFilter,
+        card_template::CardTemplate,
+        kanban::{Board, BoardSortMode, Boards, Card, CardPriority, CardRecurrence, CardStatus},
+        AppConfig, DateTimeFormat,
+    },
+    io::{
+        data_handler::{
+            compact_local_saves, get_config, get_local_kanban_state, get_saved_board_templates,
+            get_saved_card_templates, get_saved_themes, import_boards_from_file,
+            save_board_template, save_card_template, save_kanban_state_locally, write_config,
+            write_file_atomically,
+        },
+        exchange::{merge_imported_boards, ExchangeFormat},
+        importers::ImportSource,
+        io_handler::get_latest_save_file,
+    },
+    ui::{theme::Theme, View},
+    util::{format_date_time, format_file_size, parse_date_time_string, print_info, print_warn},
+};
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use serde_json::json;
+use std::{fs, path::PathBuf, str::FromStr};
+
+/// Commands that work on the latest local save without starting the UI
+#[derive(Subcommand, Debug)]
+pub enum CliCommand {
+    /// Manage boards in the latest local save
+    Board {
+        #[command(subcommand)]
+        command: BoardCommand,
+    },
+    /// Manage cards in the latest local save
+    Card {
+        #[command(subcommand)]
+        command: CardCommand,
+    },
+    /// Manage saved card filters
+    Filter {
+        #[command(subcommand)]
+        command: FilterCommand,
+    },
+    /// Manage card templates
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommand,
+    },
+    /// Manage board templates and start fresh from one
+    BoardTemplate {
+        #[command(subcommand)]
+        command: BoardTemplateCommand,
+    },
+    /// Manage workspaces, every workspace keeps its own saves
+    Workspace {
+        #[command(subcommand)]
+        command: WorkspaceCommand,
+    },
+    /// Remove the local saves the retention policy in the config does not keep and compact the journal
+    CompactSaves,
+    /// Export all boards as Markdown or CSV
+    Export {
+        #[arg(long, value_enum)]
+        format: ExchangeFormat,
+        /// File to write to, prints to stdout if not given
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Import boards from a Markdown, CSV, Trello or GitHub Projects file, boards with an
+    /// existing name are merged
+    Import {
+        #[arg(long)]
+        file: PathBuf,
+        /// Detected from the file extension if not given
+        #[arg(long, value_enum)]
+        format: Option<ExchangeFormat>,
+        /// Tool a json file was exported from, detected from the file content if not given
+        #[arg(long, value_enum, conflicts_with = "format")]
+        source: Option<ImportSource>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BoardCommand {
+    /// Create a new board
+    Add {
+        #[arg(long)]
+        name: String,
+        #[arg(long, default_value = "")]
+        description: String,
+        /// Most cards the board should hold
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        wip_limit: Option<u32>,
+    },
+    /// Change the name, description or WIP limit of a board
+    Edit {
+        #[arg(long)]
+        board: String,
+        #[arg(long)]
+        name: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        wip_limit: Option<u32>,
+        /// Remove the WIP limit of the board
+        #[arg(long, conflicts_with = "wip_limit")]
+        no_wip_limit: bool,
+    },
+    /// List all boards
+    List {
+        #[arg(long, default_value = "false")]
+        json: bool,
+    },
+    /// Change how the cards of a board are sorted
+    Sort {
+        #[arg(long)]
+        board: String,
+        /// manual, due-date, priority, created, modified, name or status
+        #[arg(long)]
+        mode: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CardCommand {
+    /// Create a new card in a board
+    Add {
+        #[arg(long)]
+        board: String,
+        /// Taken from the template if not given
+        #[arg(long, required_unless_present = "template")]
+        name: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long)]
+        due: Option<String>,
+        #[arg(long)]
+        priority: Option<String>,
+        /// Added to the tags of the template
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+        /// Saved card template to start from, the other options override it
+        #[arg(long)]
+        template: Option<String>,
+        /// How often the card comes back once completed: daily, weekly, monthly or a number
+        /// of days
+        #[arg(long)]
+        recurrence: Option<String>,
+    },
+    /// Set how often a card comes back once it is completed
+    Recur {
+        #[arg(long)]
+        card: String,
+        /// daily, weekly, monthly, a number of days or none
+        #[arg(long)]
+        every: String,
+        /// Board the card is in, only needed if the card name is not unique
+        #[arg(long)]
+        board: Option<String>,
+    },
+    /// Move a card to another board
+    Move {
+        #[arg(long)]
+        card: String,
+        #[arg(long)]
+        to: String,
+        /// Board the card is currently in, only needed if the card name is not unique
+        #[arg(long)]
+        board: Option<String>,
+    },
+    /// Mark a card as complete
+    Done {
+        #[arg(long)]
+        card: String,
+        /// Board the card is in, only needed if the card name is not unique
+        #[arg(long)]
+        board: Option<String>,
+    },
+    /// Move a card to the archive of its board
+    Archive {
+        #[arg(long)]
+        card: String,
+        /// Board the card is in, only needed if the card name is not unique
+        #[arg(long)]
+        board: Option<String>,
+    },
+    /// Put an archived card back where it was on its board
+    Restore {
+        #[arg(long)]
+        card: String,
+        /// Board the card was archived from, only needed if the card name is not unique
+        #[arg(long)]
+        board: Option<String>,
+    },
+    /// List archived cards, most recently archived first
+    Archived {
+        /// Only list cards whose name, description or tags contain this text
+        #[arg(long)]
+        search: Option<String>,
+        #[arg(long, default_value = "false")]
+        json: bool,
+    },
+    /// List cards, optionally only for a single board
+    List {
+        #[arg(long)]
+        board: Option<String>,
+        /// Only list cards matching a filter query, e.g. "tag:bug due:<7d -status:complete"
+        #[arg(long, allow_hyphen_values = true)]
+        filter: Option<String>,
+        /// Only list cards matching a saved filter
+        #[arg(long, conflicts_with = "filter")]
+        saved_filter: Option<String>,
+        #[arg(long, default_value = "false")]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum FilterCommand {
+    /// List saved filters
+    List {
+        #[arg(long, default_value = "false")]
+        json: bool,
+    },
+    /// Save a filter query under a name, replacing any filter with the same name
+    Save {
+        #[arg(long)]
+        name: String,
+        #[arg(long, allow_hyphen_values = true)]
+        query: String,
+    },
+    /// Delete a saved filter
+    Delete {
+        #[arg(long)]
+        name: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TemplateCommand {
+    /// List saved card templates
+    List {
+        #[arg(long, default_value = "false")]
+        json: bool,
+    },
+    /// Save a card as a template, replacing any template with the same name
+    Save {
+        #[arg(long)]
+        card: String,
+        /// Name of the template, the card name if not given
+        #[arg(long)]
+        name: Option<String>,
+        /// Board the card is in, only needed if the card name is not unique
+        #[arg(long)]
+        board: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BoardTemplateCommand {
+    /// List the inbuilt and saved board templates
+    List {
+        #[arg(long, default_value = "false")]
+        json: bool,
+    },
+    /// Save the boards of the latest save as a template, cards are left out
+    Save {
+        #[arg(long)]
+        name: String,
+        #[arg(long, default_value = "")]
+        description: String,
+    },
+    /// Replace the boards of the latest save with empty boards from a template, the previous
+    /// boards are kept in the older save
+    Apply {
+        #[arg(long)]
+        template: String,
+        /// Needed if the latest save already has boards
+        #[arg(long, default_value = "false")]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum WorkspaceCommand {
+    /// List workspaces, the current one is marked with *
+    List {
+        #[arg(long, default_value = "false")]
+        json: bool,
+    },
+    /// Create a workspace without switching to it, it starts without any boards
+    Add {
+        #[arg(long)]
+        name: String,
+        /// View the workspace opens with instead of the default view of the config
+        #[arg(long)]
+        view: Option<String>,
+        /// Theme the workspace opens with instead of the default theme of the config
+        #[arg(long)]
+        theme: Option<String>,
+    },
+    /// Change the view or theme a workspace opens with
+    Edit {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        view: Option<String>,
+        #[arg(long)]
+        theme: Option<String>,
+        /// Use the default view of the config again
+        #[arg(long, conflicts_with = "view")]
+        clear_view: bool,
+        /// Use the default theme of the config again
+        #[arg(long, conflicts_with = "theme")]
+        clear_theme: bool,
+    },
+    /// Make a workspace the one the app opens
+    Switch {
+        #[arg(long)]
+        name: String,
+    },
+}
+
+/// only to be used as a cli argument function
+/// `workspace` is the workspace given with `--workspace`, the current one is used otherwise
+pub fn run_cli_command(command: CliCommand, workspace: Option<String>) -> Result<(), String> {
+    let mut config = get_config(true).unwrap_or_else(|e| {
+        print_warn(&format!("Could not read config ({}), using defaults", e));
+        AppConfig::default()
+    });
+    if let Some(workspace) = workspace {
+        config.override_workspace(&workspace)?;
+    }
+    // Boards are only loaded by the commands that use them, so the others still work when the
+    // latest save can not be loaded. Boards returned here were changed and are saved
+    let modified_boards = match command {
+        CliCommand::Board { command } => {
+            let mut boards = load_latest_boards(&config)?;
+            handle_board_command(command, &mut boards)?.then_some(boards)
+        }
+        CliCommand::Card { command } => {
+            let mut boards = load_latest_boards(&config)?;
+            handle_card_command(command, &mut boards, &config)?.then_some(boards)
+        }
+        CliCommand::Filter { command } => {
+            handle_filter_command(command, &mut config)?;
+            None
+        }
+        CliCommand::Template { command } => {
+            handle_template_command(command, &config)?;
+            None
+        }
+        CliCommand::BoardTemplate { command } => handle_board_template_command(command, &config)?,
+        CliCommand::Workspace { command } => {
+            handle_workspace_command(command, &mut config)?;
+            None
+        }
+        CliCommand::CompactSaves => {
+            let report = compact_local_saves(&config)?;
+            print_info(&format!(
+                "Removed {} save files, {} freed",
+                report.files_removed,
+                format_file_size(report.bytes_removed)
+            ));
+            None
+        }
+        CliCommand::Export { format, output } => {
+            let boards = load_latest_boards(&config)?;
+            let exported = format.export(boards.get_boards(), config.date_time_format);
+            match output {
+                Some(output) => {
+                    write_file_atomically(&output, exported)?;
+                    print_info(&format!("Exported boards as {} to {:?}", format, output));
+                }
+                None => print!("{}", exported),
+            }
+            None
+        }
+        CliCommand::Import {
+            file,
+            format,
+            source,
+        } => {
+            let import_report =
+                import_boards_from_file(&file, format, source, config.date_time_format)?;
+            for card_error in &import_report.card_errors {
+                print_warn(card_error);
+            }
+            let mut boards = load_latest_boards(&config)?;
+            let (boards_added, cards_added) =
+                merge_imported_boards(&mut boards, import_report.boards);
+            print_info(&format!(
+                "Imported {} boards and {} cards from {:?}",
+                boards_added, cards_added, file
+            ));
+            (boards_added > 0 || cards_added > 0).then_some(boards)
+        }
+    };
+    if let Some(mut boards) = modified_boards {
+        boards.keep_sorted();
+        let save_directory = config.workspace_save_directory();
+        if !save_directory.exists() {
+            if let Err(e) = fs::create_dir_all(&save_directory) {
+                return Err(format!("Error creating save directory: {}", e));
+            }
+        }
+        save_kanban_state_locally(boards.get_boards().clone(), &config)?;
+        print_info("Kanban state saved");
+    }
+    Ok(())
+}
+
+fn load_latest_boards(config: &AppConfig) -> Result<Boards, String> {
+    match get_latest_save_file(config) {
+        Ok(latest_save_file) => get_local_kanban_state(latest_save_file, false, config),
+        Err(_) => Ok(Boards::default()),
+    }
+}
+
+fn handle_board_command(command: BoardCommand, boards: &mut Boards) -> Result<bool, String> {
+    match command {
+        BoardCommand::Add {
+            name,
+            description,
+            wip_limit,
+        } => {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err("Board name cannot be empty".to_string());
+            }
+            if find_board_index(boards, name).is_some() {
+                return Err(format!("Board \"{}\" already exists", name));
+            }
+            boards.add_board(Board {
+                wip_limit,
+                ..Board::new(name, description.trim())
+            });
+            print_info(&format!("Created board \"{}\"", name));
+            Ok(true)
+        }
+        BoardCommand::Edit {
+            board,
+            name,
+            description,
+            wip_limit,
+            no_wip_limit,
+        } => {
+            let board_index = find_board_index(boards, &board)
+                .ok_or_else(|| format!("Board \"{}\" not found", board))?;
+            if let Some(name) = &name {
+                let name = name.trim();
+                if name.is_empty() {
+                    return Err("Board name cannot be empty".to_string());
+                }
+                if find_board_index(boards, name).is_some_and(|index| index != board_index) {
+                    return Err(format!("Board \"{}\" already exists", name));
+                }
+            }
+            if name.is_none() && description.is_none() && wip_limit.is_none() && !no_wip_limit {
+                return Err(
+                    "Nothing to change, give --name, --description, --wip-limit or --no-wip-limit"
+                        .to_string(),
+                );
+            }
+            let board = &mut boards.get_mut_boards()[board_index];
+            if let Some(name) = name {
+                board.name = name.trim().to_string();
+            }
+            if let Some(description) = description {
+                board.description = description.trim().to_string();
+            }
+            if wip_limit.is_some() || no_wip_limit {
+                board.wip_limit = wip_limit;
+            }
+            if board.is_over_wip_limit() {
+                print_warn(&format!(
+                    "Board \"{}\" already has {} cards which is over its WIP limit",
+                    board.name,
+                    board.cards.len()
+                ));
+            }
+            print_info(&format!("Edited board \"{}\"", board.name));
+            Ok(true)
+        }
+        BoardCommand::List { json } => {
+            if json {
+                let boards_json: Vec<serde_json::Value> = boards
+                    .get_boards()
+                    .iter()
+                    .map(|board| {
+                        json!({
+                            "name": board.name,
+                            "description": board.description,
+                            "cards": board.cards.len(),
+                            "in_progress": board.in_progress,
+                            "sort_mode": board.sort_mode,
+                            "wip_limit": board.wip_limit,
+                        })
+                    })
+                    .collect();
+                print_json(&boards_json)?;
+            } else {
+                for board in boards.get_boards() {
+                    let markers = if board.in_progress {
+                        " [in progress]".to_string()
+                    } else {
+                        String::new()
+                    };
+                    let markers = if board.sort_mode == BoardSortMode::Manual {
+                        markers
+                    } else {
+                        format!("{} [by {}]", markers, board.sort_mode)
+                    };
+                    let card_count = match board.wip_limit {
+                        Some(wip_limit) => format!("{}/{} cards", board.cards.len(), wip_limit),
+                        None => format!("{} cards", board.cards.len()),
+                    };
+                    if let Some((done, total)) = board.checklist_progress() {
+                        println!(
+                            "{}{} ({}, {}/{} checklist items done)",
+                            board.name, markers, card_count, done, total
+                        );
+                    } else {
+                        println!("{}{} ({})", board.name, markers, card_count);
+                    }
+                }
+            }
+            Ok(false)
+        }
+        BoardCommand::Sort { board, mode } => {
+            let board_index = find_board_index(boards, &board)
+                .ok_or_else(|| format!("Board \"{}\" not found", board))?;
+            let sort_mode =
+                BoardSortMode::from_str(&mode.replace(['-', '_', ' '], "")).map_err(|_| {
+                    let sort_modes: Vec<String> = BoardSortMode::all()
+                        .iter()
+                        .map(|sort_mode| sort_mode.to_string())
+                        .collect();
+                    format!(
+                        "Invalid sort mode \"{}\", expected one of {}",
+                        mode,
+                        sort_modes.join(", ")
+                    )
+                })?;
+            let board = &mut boards.get_mut_boards()[board_index];
+            board.set_sort_mode(sort_mode);
+            if sort_mode == BoardSortMode::Manual {
+                print_info(&format!("Board \"{}\" is now sorted manually", board.name));
+            } else {
+                print_info(&format!(
+                    "Board \"{}\" is now sorted by {}",
+                    board.name, sort_mode
+                ));
+            }
+            Ok(true)
+        }
+    }
+}
+
+fn handle_filter_command(command: FilterCommand, config: &mut AppConfig) -> Result<(), String> {
+    match command {
+        FilterCommand::List { json } => {
+            if json {
+                print_json(&config.saved_filters)?;
+            } else if config.saved_filters.is_empty() {
+                print_info("No saved filters");
+            } else {
+                for (name, query) in &config.saved_filters {
+                    println!("{}: {}", name, query);
+                }
+            }
+        }
+        FilterCommand::Save { name, query } => {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err("Filter name cannot be empty".to_string());
+            }
+            let card_filter = CardFilter::parse(&query)?;
+            config
+                .saved_filters
+                .insert(name.to_string(), card_filter.query().to_string());
+            write_config(config)?;
+            print_info(&format!("Saved filter \"{}\"", name));
+        }
+        FilterCommand::Delete { name } => {
+            if config.saved_filters.remove(&name).is_none() {
+                return Err(format!("Saved filter \"{}\" not found", name));
+            }
+            write_config(config)?;
+            print_info(&format!("Deleted saved filter \"{}\"", name));
+        }
+    }
+    Ok(())
+}
+
+fn handle_workspace_command(
+    command: WorkspaceCommand,
+    config: &mut AppConfig,
+) -> Result<(), String> {
+    match command {
+        WorkspaceCommand::List { json } => {
+            let active_workspace = config.active_workspace();
+            if json {
+                let workspaces: Vec<_> = config
+                    .workspace_names()
+                    .into_iter()
+                    .map(|workspace| {
+                        let settings = config.workspaces.get(&workspace).cloned();
+                        json!({
+                            "name": workspace,
+                            "current": workspace == active_workspace,
+                            "settings": settings.unwrap_or_default(),
+                        })
+                    })
+                    .collect();
+                print_json(&workspaces)?;
+            } else {
+                for workspace in config.workspace_names() {
+                    let marker = if workspace == active_workspace {
+                        "*"
+                    } else {
+                        " "
+                    };
+                    let summary = config
+                        .workspaces
+                        .get(&workspace)
+                        .map(|settings| settings.summary())
+                        .unwrap_or_default();
+                    if summary.is_empty() {
+                        println!("{} {}", marker, workspace);
+                    } else {
+                        println!("{} {} ({})", marker, workspace, summary);
+                    }
+                }
+            }
+        }
+        WorkspaceCommand::Add { name, view, theme } => {
+            let default_view = view.as_deref().map(parse_workspace_view).transpose()?;
+            let default_theme = theme.as_deref().map(find_theme_name).transpose()?;
+            let workspace = config.add_workspace(&name)?;
+            let settings = config.workspace_settings_mut(&workspace)?;
+            settings.default_view = default_view;
+            settings.default_theme = default_theme;
+            write_config(config)?;
+            print_info(&format!("Created workspace \"{}\"", workspace));
+        }
+        WorkspaceCommand::Edit {
+            name,
+            view,
+            theme,
+            clear_view,
+            clear_theme,
+        } => {
+            let default_view = view.as_deref().map(parse_workspace_view).transpose()?;
+            let default_theme = theme.as_deref().map(find_theme_name).transpose()?;
+            let settings = config.workspace_settings_mut(&name)?;
+            if default_view.is_some() || clear_view {
+                settings.default_view = default_view;
+            }
+            if default_theme.is_some() || clear_theme {
+                settings.default_theme = default_theme;
+            }
+            let summary = settings.summary();
+            write_config(config)?;
+            if summary.is_empty() {
+                print_info(&format!(
+                    "Workspace \"{}\" uses the view and theme of the config",
+                    name.trim()
+                ));
+            } else {
+                print_info(&format!(
+                    "Workspace \"{}\" opens with {}",
+                    name.trim(),
+                    summary
+                ));
+            }
+        }
+        WorkspaceCommand::Switch { name } => {
+            let workspace = config.switch_workspace(&name)?;
+            write_config(config)?;
+            print_info(&format!("Switched to workspace \"{}\"", workspace));
+        }
+    }
+    Ok(())
+}
+
+/// Only the views that show boards, matched ignoring case
+fn parse_workspace_view(view_name: &str) -> Result<View, String> {
+    let views = View::views_with_kanban_board();
+    views
+        .iter()
+        .find(|view| view.to_string().eq_ignore_ascii_case(view_name.trim()))
+        .copied()
+        .ok_or_else(|| {
+            let view_names: Vec<String> = views.iter().map(|view| view.to_string()).collect();
+            format!(
+                "Invalid view \"{}\", expected one of: {}",
+                view_name,
+                view_names.join(", ")
+            )
+        })
+}
+
+/// Name of an inbuilt or saved theme, matched ignoring case
+fn find_theme_name(theme_name: &str) -> Result<String, String> {
+    Theme::all_default_themes()
+        .into_iter()
+        .chain(get_saved_themes().unwrap_or_default())
+        .find(|theme| theme.name.eq_ignore_ascii_case(theme_name.trim()))
+        .map(|theme| theme.name)
+        .ok_or_else(|| format!("Theme \"{}\" not found", theme_name))
+}
+
+fn handle_card_command(
+    command: CardCommand,
+    boards: &mut Boards,
+    config: &AppConfig,
+) -> Result<bool, String> {
+    match command {
+        CardCommand::Add {
+            board,
+            name,
+            description,
+            due,
+            priority,
+            tags,
+            template,
+            recurrence,
+        } => {
+            let board_index = find_board_index(boards, &board)
+                .ok_or_else(|| format!("Board \"{}\" not found", board))?;
+            let card_template = match template {
+                Some(template) => Some(find_card_template(&template)?),
+                None => None,
+            };
+            let name = match (name, &card_template) {
+                (Some(name), _) => name,
+                (None, Some(card_template)) => {
+                    let board = &boards.get_boards()[board_index];
+                    let existing_card_names: Vec<&str> = board
+                        .cards
+                        .get_all_cards()
+                        .iter()
+                        .map(|card| card.name.as_str())
+                        .collect();
+                    card_template.card_name_for_board(&existing_card_names, config.date_time_format)
+                }
+                (None, None) => return Err("Card name cannot be empty".to_string()),
+            };
+            let name = name.trim();
+            if name.is_empty() {
+                return Err("Card name cannot be empty".to_string());
+            }
+            check_wip_limit(boards, boards.get_boards()[board_index].id, config)?;
+            let board = &mut boards.get_mut_boards()[board_index];
+            if board.cards.get_all_cards().iter().any(|c| c.name == name) {
+                return Err(format!(
+                    "Card \"{}\" already exists in board \"{}\"",
+                    name, board.name
+                ));
+            }
+            let due_date = match (due, &card_template) {
+                (Some(due), _) => Some(parse_due_date(&due, config.date_time_format)?),
+                (None, Some(card_template)) => card_template.due_date(Utc::now())?,
+                (None, None) => None,
+            };
+            let priority = match (priority, &card_template) {
+                (Some(priority), _) => CardPriority::from_str(&priority)?,
+                (None, Some(card_template)) => card_template.priority.clone(),
+                (None, None) => CardPriority::Low,
+            };
+            let description = description
+                .or_else(|| {
+                    card_template
+                        .as_ref()
+                        .map(|card_template| card_template.description.clone())
+                })
+                .unwrap_or_default();
+            let mut all_tags = card_template
+                .as_ref()
+                .map_or(vec![], |card_template| card_template.tags.clone());
+            all_tags.extend(tags);
+            let mut tags: Vec<String> = Vec::new();
+            for tag in all_tags {
+                let tag = tag.trim().to_string();
+                if !tag.is_empty() && !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            let recurrence = match recurrence {
+                Some(recurrence) => Some(CardRecurrence::from_str(&recurrence)?),
+                None => None,
+            };
+            let mut card = Card::new(name, description.trim(), due_date, priority, tags, vec![]);
+            card.recurrence = recurrence;
+            board.cards.add_card(card);
+            print_info(&format!("Created card \"{}\" in \"{}\"", name, board.name));
+            Ok(true)
+        }
+        CardCommand::Move { card, to, board } => {
+            let (from_index, card_id) = find_card(boards, &card, board.as_deref())?;
+            let to_index = find_board_index(boards, &to)
+                .ok_or_else(|| format!("Board \"{}\" not found", to))?;
+            if from_index == to_index {
+                return Err(format!("Card \"{}\" is already in \"{}\"", card, to));
+            }
+            if let Some(error) =
+                boards.get_blocked_move_error(card_id, boards.get_boards()[to_index].id)
+            {
+                return Err(error);
+            }
+            check_wip_limit(boards, boards.get_boards()[to_index].id, config)?;
+            let mut moved_card = boards.get_mut_boards()[from_index]
+                .cards
+                .remove_card_with_id(card_id)
+                .ok_or_else(|| format!("Card \"{}\" not found", card))?;
+            moved_card.mark_modified();
+            let to_board = &mut boards.get_mut_boards()[to_index];
+            to_board.cards.add_card(moved_card);
+            print_info(&format!("Moved card \"{}\" to \"{}\"", card, to_board.name));
+            Ok(true)
+        }
+        CardCommand::Done { card, board } => {
+            let (board_index, card_id) = find_card(boards, &card, board.as_deref())?;
+            let found_card = boards.get_mut_boards()[board_index]
+                .cards
+                .get_mut_card_with_id(card_id)
+                .ok_or_else(|| format!("Card \"{}\" not found", card))?;
+            if found_card.card_status == CardStatus::Complete {
+                print_info(&format!("Card \"{}\" is already complete", card));
+                return Ok(false);
+            }
+            found_card.card_status = CardStatus::Complete;
+            found_card.date_completed = Some(Utc::now());
+            found_card.mark_modified();
+            print_info(&format!("Marked card \"{}\" as complete", card));
+            if let Some(next_card) =
+                boards.get_mut_boards()[board_index].add_next_occurrence(card_id)
+            {
+                print_info(&format!(
+                    "Added the next \"{}\", due {}",
+                    card,
+                    format_date_time(next_card.due_date, config.date_time_format)
+                ));
+            }
+            Ok(true)
+        }
+        CardCommand::Recur { card, every, board } => {
+            let (board_index, card_id) = find_card(boards, &card, board.as_deref())?;
+            let recurrence = if every.trim().eq_ignore_ascii_case("none") {
+                None
+            } else {
+                Some(CardRecurrence::from_str(&every)?)
+            };
+            let found_card = boards.get_mut_boards()[board_index]
+                .cards
+                .get_mut_card_with_id(card_id)
+                .ok_or_else(|| format!("Card \"{}\" not found", card))?;
+            found_card.recurrence = recurrence;
+            found_card.mark_modified();
+            match recurrence {
+                Some(recurrence) => {
+                    print_info(&format!("Card \"{}\" now recurs: {}", card, recurrence))
+                }
+                None => print_info(&format!("Card \"{}\" no longer recurs", card)),
+            }
+            Ok(true)
+        }
+        CardCommand::Archive { card, board } => {
+            let (board_index, card_id) = find_card(boards, &card, board.as_deref())?;
+            let board_id = boards.get_boards()[board_index].id;
+            boards
+                .archive_card(board_id, card_id)
+                .ok_or_else(|| format!("Card \"{}\" not found", card))?;
+            print_info(&format!("Archived card \"{}\"", card));
+            Ok(true)
+        }
+        CardCommand::Restore { card, board } => {
+            let (board_index, card_id) = find_archived_card(boards, &card, board.as_deref())?;
+            let board_id = boards.get_boards()[board_index].id;
+            check_wip_limit(boards, board_id, config)?;
+            boards
+                .restore_archived_card(board_id, card_id)
+                .ok_or_else(|| format!("Archived card \"{}\" not found", card))?;
+            print_info(&format!("Restored card \"{}\"", card));
+            Ok(true)
+        }
+        CardCommand::Archived { search, json } => {
+            let archived_cards = boards.search_archived_cards(search.as_deref().unwrap_or(""));
+            if json {
+                let mut cards_json = Vec::new();
+                for (board, archived_card) in archived_cards {
+                    let mut card_json =
+                        serde_json::to_value(&archived_card.card).map_err(|e| e.to_string())?;
+                    card_json["board"] = json!(board.name);
+                    card_json["date_archived"] = json!(archived_card.date_archived);
+                    cards_json.push(card_json);
+                }
+                print_json(&cards_json)?;
+            } else {
+                for (board, archived_card) in archived_cards {
+                    println!(
+                        "{} [{}] {} from {}",
+                        format_date_time(
+                            Some(archived_card.date_archived),
+                            DateTimeFormat::add_time_to_date_format(config.date_time_format)
+                        ),
+                        archived_card.card.card_status,
+                        archived_card.card.name,
+                        board.name
+                    );
+                }
+            }
+            Ok(false)
+        }
+        CardCommand::List {
+            board,
+            filter,
+            saved_filter,
+            json,
+        } => {
+            let selected_boards: Vec<&Board> = match board {
+                Some(board) => {
+                    let board_index = find_board_index(boards, &board)
+                        .ok_or_else(|| format!("Board \"{}\" not found", board))?;
+                    vec![&boards.get_boards()[board_index]]
+                }
+                None => boards.get_boards().iter().collect(),
+            };
+            let card_filter = match (filter, saved_filter) {
+                (Some(filter), _) => Some(CardFilter::parse(&filter)?),
+                (None, Some(saved_filter)) => {
+                    let query = config
+                        .saved_filters
+                        .get(&saved_filter)
+                        .ok_or_else(|| format!("Saved filter \"{}\" not found", saved_filter))?;
+                    Some(CardFilter::parse(query)?)
+                }
+                (None, None) => None,
+            };
+            if json {
+                let mut cards_json = Vec::new();
+                for board in selected_boards {
+                    for card in get_listed_cards(board, card_filter.as_ref()) {
+                        let mut card_json =
+                            serde_json::to_value(card).map_err(|e| e.to_string())?;
+                        card_json["board"] = json!(board.name);
+                        cards_json.push(card_json);
+                    }
+                }
+                print_json(&cards_json)?;
+            } else {
+                for board in selected_boards {
+                    let listed_cards = get_listed_cards(board, card_filter.as_ref());
+                    if card_filter.is_some() && listed_cards.is_empty() {
+                        continue;
+                    }
+                    println!("{}", board.name);
+                    for card in listed_cards {
+                        let mut line = format!(
+                            "  [{}] {} ({} priority)",
+                            card.card_status, card.name, card.priority
+                        );
+                        if card.due_date.is_some() {
+                            line.push_str(&format!(
+                                " due: {}",
+                                format_date_time(
+                                    card.due_date,
+                                    DateTimeFormat::add_time_to_date_format(
+                                        config.date_time_format
+                                    )
+                                )
+                            ));
+                        }
+                        if !card.tags.is_empty() {
+                            line.push_str(&format!(" tags: {}", card.tags.join(", ")));
+                        }
+                        if let Some((done, total)) = card.checklist_progress() {
+                            line.push_str(&format!(" checklist: {}/{}", done, total));
+                        }
+                        if let Some(recurrence) = card.recurrence {
+                            line.push_str(&format!(" recurs: {}", recurrence));
+                        }
+                        let open_blockers = boards.get_open_blockers(card);
+                        if !open_blockers.is_empty() {
+                            let blocker_names: Vec<&str> = open_blockers
+                                .iter()
+                                .map(|blocker| blocker.name.as_str())
+                                .collect();
+                            line.push_str(&format!(" blocked by: {}", blocker_names.join(", ")));
+                        }
+                        println!("{}", line);
+                    }
+                }
+            }
+            Ok(false)
+        }
+    }
+}
+
+fn handle_template_command(command: TemplateCommand, config: &AppConfig) -> Result<(), String> {
+    match command {
+        TemplateCommand::List { json } => {
+            let card_templates = get_saved_card_templates();
+            if json {
+                print_json(&card_templates)?;
+            } else if card_templates.is_empty() {
+                print_info("No card templates saved");
+            } else {
+                for card_template in &card_templates {
+                    println!(
+                        "{}: \"{}\" {}",
+                        card_template.name,
+                        card_template.card_name,
+                        card_template.summary()
+                    );
+                }
+            }
+        }
+        TemplateCommand::Save { card, name, board } => {
+            let boards = &load_latest_boards(config)?;
+            let (board_index, card_id) = find_card(boards, &card, board.as_deref())?;
+            let found_card = boards.get_boards()[board_index]
+                .cards
+                .get_card_with_id(card_id)
+                .ok_or_else(|| format!("Card \"{}\" not found", card))?;
+            let name = name.unwrap_or_else(|| found_card.name.clone());
+            if name.trim().is_empty() {
+                return Err("Template name cannot be empty".to_string());
+            }
+            let card_template = CardTemplate::from_card(&name, found_card);
+            let template_path = save_card_template(&card_template)?;
+            print_info(&format!(
+                "Saved card template \"{}\" to {}",
+                card_template.name, template_path
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the boards created from a template, to be saved
+fn handle_board_template_command(
+    command: BoardTemplateCommand,
+    config: &AppConfig,
+) -> Result<Option<Boards>, String> {
+    let default_templates = BoardTemplate::all_default_templates();
+    match command {
+        BoardTemplateCommand::List { json } => {
+            let saved_templates = get_saved_board_templates();
+            if json {
+                print_json(&json!({
+                    "inbuilt": default_templates,
+                    "saved": saved_templates,
+                }))?;
+            } else {
+                for board_template in &default_templates {
+                    println!(
+                        "{} (inbuilt): {}",
+                        board_template.name,
+                        board_template.summary()
+                    );
+                }
+                for board_template in &saved_templates {
+                    println!("{}: {}", board_template.name, board_template.summary());
+                }
+            }
+            Ok(None)
+        }
+        BoardTemplateCommand::Save { name, description } => {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err("Template name cannot be empty".to_string());
+            }
+            if default_templates
+                .iter()
+                .any(|board_template| board_template.name.eq_ignore_ascii_case(name))
+            {
+                return Err(format!(
+                    "\"{}\" is an inbuilt board template, pick another name",
+                    name
+                ));
+            }
+            let boards = load_latest_boards(config)?;
+            if boards.is_empty() {
+                return Err("There are no boards to save as a template".to_string());
+            }
+            let board_template = BoardTemplate::from_boards(name, &description, &boards);
+            let template_path = save_board_template(&board_template)?;
+            print_info(&format!(
+                "Saved board template \"{}\" to {}",
+                board_template.name, template_path
+            ));
+            Ok(None)
+        }
+        BoardTemplateCommand::Apply { template, force } => {
+            let board_template = default_templates
+                .into_iter()
+                .chain(get_saved_board_templates())
+                .find(|board_template| board_template.name.eq_ignore_ascii_case(template.trim()))
+                .ok_or_else(|| format!("Board template \"{}\" not found", template))?;
+            let mut boards = load_latest_boards(config)?;
+            if !boards.is_empty() && !force {
+                return Err(format!(
+                    "The latest save already has {} boards, use --force to replace them",
+                    boards.len()
+                ));
+            }
+            boards.set_boards(board_template.create_boards());
+            print_info(&format!(
+                "Created {} boards from board template \"{}\"",
+                boards.len(),
+                board_template.name
+            ));
+            Ok(Some(boards))
+        }
+    }
+}
+
+fn find_card_template(template_name: &str) -> Result<CardTemplate, String> {
+    get_saved_card_templates()
+        .into_iter()
+        .find(|card_template| {
+            card_template
+                .name
+                .eq_ignore_ascii_case(template_name.trim())
+        })
+        .ok_or_else(|| format!("Card template \"{}\" not found", template_name))
+}
+
+fn get_listed_cards<'a>(board: &'a Board, card_filter: Option<&CardFilter>) -> Vec<&'a Card> {
+    board
+        .cards
+        .get_all_cards()
+        .iter()
+        .filter(|card| card_filter.is_none_or(|card_filter| card_filter.matches(card)))
+        .collect()
+}
+
+/// Errors if the board is at its WIP limit and WIP limits are enforced, otherwise only warns
+fn check_wip_limit(
+    boards: &Boards,
+    to_board_id: (u64, u64),
+    config: &AppConfig,
+) -> Result<(), String> {
+    let Some(error) = boards.get_wip_limit_error(to_board_id) else {
+        return Ok(());
+    };
+    if config.enforce_wip_limits {
+        return Err(error);
+    }
+    print_warn(&error);
+    Ok(())
+}
+
+fn find_board_index(boards: &Boards, board_name: &str) -> Option<usize> {
+    boards
+        .get_boards()
+        .iter()
+        .position(|board| board.name.eq_ignore_ascii_case(board_name.trim()))
+}
+
+/// Returns the board index and card id of the card with the given name, a single card that is not
+/// complete is preferred over complete ones with the same name. Errors if the name is ambiguous
+fn find_card(
+    boards: &Boards,
+    card_name: &str,
+    board_name: Option<&str>,
+) -> Result<(usize, (u64, u64)), String> {
+    let board_filter = match board_name {
+        Some(board_name) => Some(
+            find_board_index(boards, board_name)
+                .ok_or_else(|| format!("Board \"{}\" not found", board_name))?,
+        ),
+        None => None,
+    };
+    let matches: Vec<(usize, &Card)> = boards
+        .get_boards()
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| board_filter.is_none_or(|filter| filter == *index))
+        .flat_map(|(index, board)| {
+            board
+                .cards
+                .get_all_cards()
+                .iter()
+                .filter(|card| card.name == card_name.trim())
+                .map(move |card| (index, card))
+        })
+        .collect();
+    // Completing a recurring card leaves it next to its next occurrence with the same name
+    let open_matches: Vec<&(usize, &Card)> = matches
+        .iter()
+        .filter(|(_, card)| card.card_status != CardStatus::Complete)
+        .collect();
+    match (matches.as_slice(), open_matches.as_slice()) {
+        ([], _) => Err(format!("Card \"{}\" not found", card_name)),
+        ([(index, card)], _) | (_, [(index, card)]) => Ok((*index, card.id)),
+        _ => Err(format!(
+            "Multiple cards named \"{}\" found, use --board to pick one",
+            card_name
+        )),
+    }
+}
+
+/// Returns the board index and card id of the archived card with the given name, errors if the
+/// name is ambiguous
+fn find_archived_card(
+    boards: &Boards,
+    card_name: &str,
+    board_name: Option<&str>,
+) -> Result<(usize, (u64, u64)), String> {
+    let board_filter = match board_name {
+        Some(board_name) => Some(
+            find_board_index(boards, board_name)
+                .ok_or_else(|| format!("Board \"{}\" not found", board_name))?,
+        ),
+        None => None,
+    };
+    let matches: Vec<(usize, (u64, u64))> = boards
+        .get_boards()
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| board_filter.is_none_or(|filter| filter == *index))
+        .flat_map(|(index, board)| {
+            board
+                .archived_cards
+                .iter()
+                .filter(|archived_card| archived_card.card.name == card_name.trim())
+                .map(move |archived_card| (index, archived_card.card.id))
+        })
+        .collect();
+    match matches.len() {
+        0 => Err(format!("Archived card \"{}\" not found", card_name)),
+        1 => Ok(matches[0]),
+        _ => Err(format!(
+            "Multiple archived cards named \"{}\" found, use --board to pick one",
+            card_name
+        )),
+    }
+}
+
+fn parse_due_date(
+    due_date: &str,
+    date_time_format: DateTimeFormat,
+) -> Result<DateTime<Utc>, String> {
+    parse_date_time_string(due_date, date_time_format).map_err(|_| {
+        format!(
+            "Invalid due date \"{}\", expected a format like {}",
+            due_date,
+            date_time_format.to_human_readable_string()
+        )
+    })
+}
+
+fn print_json<T: serde::Serialize>(value: &T) -> Result<(), String> {
+    let json_string = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    println!("{}", json_string);
+    Ok(())
+}
@@ -1,20 +1,42 @@
 use crate::{
     app::{
+        board_template::BoardTemplate,
+        card_template::CardTemplate,
         kanban::{Board, Boards},
-        AppConfig,
+        ActionHistoryManager, AppConfig, DateTimeFormat,
     },
     constants::{
-        CONFIG_DIR_NAME, CONFIG_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME, SAVE_FILE_REGEX,
+        BOARD_TEMPLATE_DIR_NAME, BOARD_TEMPLATE_FILE_NAME, CARD_TEMPLATE_DIR_NAME,
+        CARD_TEMPLATE_FILE_NAME, CONFIG_DIR_NAME, CONFIG_FILE_NAME, JOURNAL_FILE_NAME,
+        MAX_SAVED_UNDO_HISTORY_SIZE, SAVE_BACKUP_DIR_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME,
+        SAVE_FILE_REGEX, SAVE_HISTORY_DIR_NAME, SAVE_SCHEMA_VERSION, TEMP_FILE_SUFFIX,
         THEME_DIR_NAME, THEME_FILE_NAME,
     },
     inputs::key::Key,
-    io::io_handler::{get_config_dir, make_file_system_safe_name, prepare_config_dir},
+    io::{
+        exchange::ExchangeFormat,
+        importers::{import_from_json, ImportReport, ImportSource},
+        io_handler::{get_config_dir, make_file_system_safe_name, prepare_config_dir},
+        journal::{
+            compact_journal, read_journal_as_save, recover_journal, save_boards_to_journal,
+            StorageMode,
+        },
+        migrations::migrate_save,
+    },
     ui::theme::Theme,
 };
+use chrono::{Datelike, IsoWeek, NaiveDate};
 use log::{debug, error, info};
 use regex::Regex;
-use serde::{Deserialize, Serialize};
-use std::{cmp::Ordering, collections::HashMap, env, fs, path::PathBuf};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+    env,
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 pub fn get_config(ignore_overlapped_keybindings: bool) -> Result<AppConfig, String> {
     let config_dir_status = get_config_dir();
@@ -24,7 +46,7 @@ pub fn get_config(ignore_overlapped_keybindings: bool) -> Result<AppConfig, Stri
         return Err(config_dir_status.unwrap_err());
     };
     let config_path = config_dir.join(CONFIG_FILE_NAME);
-    let config = match fs::read_to_string(config_path) {
+    let mut config = match fs::read_to_string(config_path) {
         Ok(config_json_string) => {
             let serde_value = serde_json::from_str(&config_json_string);
             if let Ok(app_config) = serde_value {
@@ -54,6 +76,7 @@ pub fn get_config(ignore_overlapped_keybindings: bool) -> Result<AppConfig, Stri
             AppConfig::default()
         }
     };
+    config.check_workspaces();
     let config_keybindings = config.keybindings.clone();
     if ignore_overlapped_keybindings {
         return Ok(config);
@@ -88,7 +111,7 @@ pub fn write_config(config: &AppConfig) -> Result<(), String> {
     let config_str = serde_json::to_string_pretty(&config).unwrap();
     prepare_config_dir()?;
     let config_dir = get_config_dir()?;
-    let write_result = fs::write(config_dir.join(CONFIG_FILE_NAME), config_str);
+    let write_result = write_file_atomically(&config_dir.join(CONFIG_FILE_NAME), config_str);
     match write_result {
         Ok(_) => Ok(()),
         Err(e) => {
@@ -109,8 +132,13 @@ pub fn reset_config() {
     }
 }
 
-pub fn save_kanban_state_locally(boards: Vec<Board>, config: &AppConfig) -> Result<(), String> {
-    let files = fs::read_dir(&config.save_directory);
+/// Saves the boards as a new version in the save directory, or to the journal when the storage
+/// mode is journal. Returns the name of the save file
+pub fn save_kanban_state_locally(boards: Vec<Board>, config: &AppConfig) -> Result<String, String> {
+    if config.storage_mode == StorageMode::Journal {
+        return save_boards_to_journal(&boards, config);
+    }
+    let files = fs::read_dir(config.workspace_save_directory());
     if files.is_err() {
         return Err("Error reading save directory".to_string());
     }
@@ -157,33 +185,205 @@ pub fn save_kanban_state_locally(boards: Vec<Board>, config: &AppConfig) -> Resu
         chrono::Local::now().format("%d-%m-%Y"),
         version
     );
-    match export_kanban_to_json(&boards, config, file_name) {
-        Ok(_) => Ok(()),
+    match export_kanban_to_json(&boards, config, file_name.clone()) {
+        Ok(_) => {
+            apply_save_retention_policy(config);
+            Ok(file_name)
+        }
         Err(e) => Err(e),
     }
 }
 
+/// Files and bytes removed by compacting the save directory, undo histories of removed saves
+/// are included
+#[derive(Debug, Default)]
+pub struct SaveCompactionReport {
+    pub bytes_removed: u64,
+    pub files_removed: usize,
+}
+
+/// Removes the saves the retention policy in the config does not keep. Every day keeps its
+/// newest versions, days older than the daily days only keep the newest save of their week and
+/// the latest save is always kept. A journal is compacted into a single snapshot
+pub fn compact_local_saves(config: &AppConfig) -> Result<SaveCompactionReport, String> {
+    let Some(save_files) = get_available_local_save_files(config) else {
+        return Err("Error reading save directory".to_string());
+    };
+    let mut report = SaveCompactionReport {
+        bytes_removed: compact_journal(config)?.unwrap_or(0),
+        files_removed: 0,
+    };
+    let mut saves: Vec<(String, NaiveDate, u32)> = save_files
+        .into_iter()
+        .filter_map(|save_file| {
+            parse_save_file_name(&save_file).map(|(date, version)| (save_file, date, version))
+        })
+        .collect();
+    // newest first, so the first save seen for a day or week is the one to keep
+    saves.sort_by_key(|(_, date, version)| Reverse((*date, *version)));
+    let weekly_before = (config.save_retention_daily_days > 0).then(|| {
+        chrono::Local::now().date_naive()
+            - chrono::Duration::days(config.save_retention_daily_days as i64)
+    });
+    let mut versions_kept_per_day: HashMap<NaiveDate, u16> = HashMap::new();
+    let mut weeks_kept: Vec<IsoWeek> = Vec::new();
+    for (index, (save_file, date, _)) in saves.iter().enumerate() {
+        let is_weekly = weekly_before.is_some_and(|weekly_before| *date < weekly_before);
+        let keep = if index == 0 {
+            true
+        } else if is_weekly {
+            !weeks_kept.contains(&date.iso_week())
+        } else {
+            let versions_kept = versions_kept_per_day.get(date).copied().unwrap_or(0);
+            config.save_retention_versions_per_day == 0
+                || versions_kept < config.save_retention_versions_per_day
+        };
+        if keep {
+            *versions_kept_per_day.entry(*date).or_insert(0) += 1;
+            // saves kept by the daily window do not stand in for the older part of their week
+            if is_weekly && !weeks_kept.contains(&date.iso_week()) {
+                weeks_kept.push(date.iso_week());
+            }
+            continue;
+        }
+        for file_path in [
+            config.workspace_save_directory().join(save_file),
+            get_action_history_path(save_file, config),
+        ] {
+            if !file_path.exists() {
+                continue;
+            }
+            let file_size = fs::metadata(&file_path).map_or(0, |metadata| metadata.len());
+            fs::remove_file(&file_path)
+                .map_err(|e| format!("Error removing {:?}: {}", file_path, e))?;
+            report.files_removed += 1;
+            report.bytes_removed += file_size;
+        }
+    }
+    Ok(report)
+}
+
+fn apply_save_retention_policy(config: &AppConfig) {
+    if config.save_retention_versions_per_day == 0 && config.save_retention_daily_days == 0 {
+        return;
+    }
+    match compact_local_saves(config) {
+        Ok(report) if report.files_removed > 0 => {
+            info!(
+                "Removed {} old save files ({} bytes)",
+                report.files_removed, report.bytes_removed
+            );
+        }
+        Ok(_) => {}
+        Err(e) => error!("Error applying the save retention policy: {}", e),
+    }
+}
+
+/// Date and version of a `kanban_DD-MM-YYYY_vN.json` save file name
+fn parse_save_file_name(save_file_name: &str) -> Option<(NaiveDate, u32)> {
+    let mut parts = save_file_name.trim_end_matches(".json").split('_');
+    let date = NaiveDate::parse_from_str(parts.nth(1)?, "%d-%m-%Y").ok()?;
+    let version = parts.next()?.strip_prefix('v')?.parse::<u32>().ok()?;
+    Some((date, version))
+}
+
+fn get_action_history_path(save_file_name: &str, config: &AppConfig) -> PathBuf {
+    config
+        .workspace_save_directory()
+        .join(SAVE_HISTORY_DIR_NAME)
+        .join(save_file_name)
+}
+
+/// Keeps the undo history next to a save file, bounded by the undo history length in the config
+/// and `MAX_SAVED_UNDO_HISTORY_SIZE`. An empty history or a length of 0 removes any history kept
+/// for the save
+pub fn save_action_history(
+    action_history_manager: &ActionHistoryManager,
+    save_file_name: &str,
+    config: &AppConfig,
+) -> Result<(), String> {
+    let saved_history = action_history_manager.to_saved_history(
+        config.undo_history_length as usize,
+        MAX_SAVED_UNDO_HISTORY_SIZE,
+    );
+    if saved_history.history.is_empty() {
+        return delete_action_history(save_file_name, config);
+    }
+    let history_dir = config
+        .workspace_save_directory()
+        .join(SAVE_HISTORY_DIR_NAME);
+    if let Err(e) = fs::create_dir_all(&history_dir) {
+        debug!("Error creating undo history directory: {}", e);
+        return Err(format!("Error creating undo history directory: {}", e));
+    }
+    let history_json = serde_json::to_string(&saved_history).map_err(|e| e.to_string())?;
+    write_file_atomically(
+        &get_action_history_path(save_file_name, config),
+        history_json,
+    )
+    .map_err(|e| format!("Error saving undo history: {}", e))
+}
+
+/// Returns the undo history kept with a save file, if there is one
+pub fn get_action_history(
+    save_file_name: &str,
+    config: &AppConfig,
+) -> Result<Option<ActionHistoryManager>, String> {
+    let history_path = get_action_history_path(save_file_name, config);
+    if !history_path.exists() {
+        return Ok(None);
+    }
+    let history_json = fs::read_to_string(&history_path)
+        .map_err(|e| format!("Error reading undo history: {}", e))?;
+    let action_history_manager: ActionHistoryManager = serde_json::from_str(&history_json)
+        .map_err(|e| format!("Error parsing undo history: {}", e))?;
+    if action_history_manager.history_index > action_history_manager.history.len() {
+        return Err("Error parsing undo history: history index out of range".to_string());
+    }
+    Ok(Some(action_history_manager))
+}
+
+pub fn delete_action_history(save_file_name: &str, config: &AppConfig) -> Result<(), String> {
+    let history_path = get_action_history_path(save_file_name, config);
+    if !history_path.exists() {
+        return Ok(());
+    }
+    fs::remove_file(history_path).map_err(|e| format!("Error deleting undo history: {}", e))
+}
+
 pub fn get_local_kanban_state(
     file_name: String,
     preview_mode: bool,
     config: &AppConfig,
 ) -> Result<Boards, String> {
-    let file_path = config.save_directory.join(file_name);
+    let file_path = config.workspace_save_directory().join(&file_name);
     if !preview_mode {
         info!("Loading local save file: {:?}", file_path);
     }
-    let file = fs::File::open(file_path);
-    if file.is_err() {
-        debug!("Error opening save file: {}", file.err().unwrap());
-        return Err("Error opening save file".to_string());
-    }
-    let file = file.unwrap();
-    let serde_object = serde_json::from_reader(file);
-    if serde_object.is_err() {
-        debug!("Error parsing save file: {}", serde_object.err().unwrap());
-        return Err("Error parsing save file".to_string());
+    let mut serde_object: serde_json::Value = if file_name == JOURNAL_FILE_NAME {
+        read_journal_as_save(config)?
+    } else {
+        let file = fs::File::open(&file_path);
+        if file.is_err() {
+            debug!("Error opening save file: {}", file.err().unwrap());
+            return Err("Error opening save file".to_string());
+        }
+        let file = file.unwrap();
+        let serde_object = serde_json::from_reader(file);
+        if serde_object.is_err() {
+            debug!("Error parsing save file: {}", serde_object.err().unwrap());
+            return Err("Error parsing save file".to_string());
+        }
+        serde_object.unwrap()
+    };
+    let schema_version = migrate_save(&mut serde_object, config.date_time_format)?;
+    if schema_version < SAVE_SCHEMA_VERSION && !preview_mode {
+        info!(
+            "Migrated save file {} from schema version {} to {}",
+            file_name, schema_version, SAVE_SCHEMA_VERSION
+        );
+        backup_save_file(&file_path, schema_version, config)?;
     }
-    let serde_object: serde_json::Value = serde_object.unwrap();
     let boards = serde_object.get("boards");
     if boards.is_none() {
         debug!("Error parsing save file, no boards found");
@@ -204,8 +404,51 @@ pub fn get_local_kanban_state(
     Ok(Boards::from(parsed_boards))
 }
 
+/// Keeps a copy of a save written with an older schema version, so nothing is lost if the
+/// migrated boards get saved and the migration turns out to be wrong
+pub fn backup_save_file(
+    file_path: &Path,
+    schema_version: u32,
+    config: &AppConfig,
+) -> Result<(), String> {
+    let backup_dir = config.workspace_save_directory().join(SAVE_BACKUP_DIR_NAME);
+    let file_stem = file_path
+        .file_stem()
+        .map(|file_stem| file_stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| SAVE_FILE_NAME.to_string());
+    let extension = file_path
+        .extension()
+        .map_or("json".to_string(), |extension| {
+            extension.to_string_lossy().to_string()
+        });
+    let backup_path = backup_dir.join(format!(
+        "{}_schema_v{}.{}",
+        file_stem, schema_version, extension
+    ));
+    if backup_path.exists() {
+        return Ok(());
+    }
+    if let Err(e) = fs::create_dir_all(&backup_dir) {
+        debug!("Error creating save backup directory: {}", e);
+        return Err(format!("Error creating save backup directory: {}", e));
+    }
+    match fs::copy(file_path, &backup_path) {
+        Ok(_) => {
+            info!("Backed up save file to {:?}", backup_path);
+            Ok(())
+        }
+        Err(e) => {
+            debug!("Error backing up save file: {}", e);
+            Err(format!(
+                "Error backing up save file before migrating: {}",
+                e
+            ))
+        }
+    }
+}
+
 pub fn get_available_local_save_files(config: &AppConfig) -> Option<Vec<String>> {
-    let read_dir_status = fs::read_dir(&config.save_directory);
+    let read_dir_status = fs::read_dir(config.workspace_save_directory());
     match read_dir_status {
         Ok(files) => {
             let mut save_files = Vec::new();
@@ -286,15 +529,128 @@ pub fn export_kanban_to_json(
         boards: boards.to_vec(),
         export_date: date,
         kanban_version: version.to_string(),
+        schema_version: SAVE_SCHEMA_VERSION,
     };
-    let file_path = config.save_directory.join(file_name);
-    let write_status = fs::write(
-        file_path.clone(),
+    let file_path = config.workspace_save_directory().join(file_name);
+    let write_status = write_file_atomically(
+        &file_path,
         serde_json::to_string_pretty(&export_struct).unwrap(),
     );
     match write_status {
         Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Writes to a temporary file next to the path and renames it over the path, so a crash while
+/// writing leaves the previous file in place instead of a half written one
+pub fn write_file_atomically(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), String> {
+    let mut temp_file_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_file_name.push(TEMP_FILE_SUFFIX);
+    let temp_file_path = path.with_file_name(temp_file_name);
+    File::create(&temp_file_path)
+        .and_then(|mut temp_file| {
+            temp_file.write_all(contents.as_ref())?;
+            temp_file.sync_all()
+        })
+        .map_err(|e| format!("Error writing {:?}: {}", temp_file_path, e))?;
+    fs::rename(&temp_file_path, path).map_err(|e| format!("Error writing {:?}: {}", path, e))
+}
+
+/// Cleans up after saving was interrupted by a crash, temporary files that never replaced their
+/// save are removed and a journal that ends in an unfinished entry is compacted without it.
+/// Returns what was recovered, for the user to be told
+pub fn recover_interrupted_saves(config: &AppConfig) -> Result<Vec<String>, String> {
+    let mut recovered = Vec::new();
+    let mut removed_temp_files = 0;
+    for dir in [
+        config.workspace_save_directory(),
+        config
+            .workspace_save_directory()
+            .join(SAVE_HISTORY_DIR_NAME),
+    ] {
+        let Ok(files) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for file in files.flatten() {
+            if !file
+                .file_name()
+                .to_string_lossy()
+                .ends_with(TEMP_FILE_SUFFIX)
+            {
+                continue;
+            }
+            fs::remove_file(file.path())
+                .map_err(|e| format!("Error removing {:?}: {}", file.path(), e))?;
+            removed_temp_files += 1;
+        }
+    }
+    if removed_temp_files > 0 {
+        recovered.push(format!(
+            "Removed {} unfinished save files left by a crash",
+            removed_temp_files
+        ));
+    }
+    if recover_journal(config)? {
+        recovered.push("Dropped the unfinished last entry of the journal".to_string());
+    }
+    Ok(recovered)
+}
+
+/// Exports the boards to the save directory in the given format, returns the path of the exported file
+pub fn export_kanban_to_format(
+    boards: &[Board],
+    config: &AppConfig,
+    format: ExchangeFormat,
+) -> Result<String, String> {
+    let file_name = format!(
+        "{}_export_{}.{}",
+        SAVE_FILE_NAME,
+        chrono::Local::now().format("%d-%m-%Y_%H-%M-%S"),
+        format.file_extension()
+    );
+    let file_path = config.workspace_save_directory().join(file_name);
+    write_file_atomically(&file_path, format.export(boards, config.date_time_format))?;
+    Ok(file_path.to_string_lossy().to_string())
+}
+
+/// Reads boards from a Markdown, CSV, Trello or GitHub Projects file, the format is guessed
+/// from the file extension (and for json from its content) if not given
+pub fn import_boards_from_file(
+    file_path: &Path,
+    format: Option<ExchangeFormat>,
+    source: Option<ImportSource>,
+    date_time_format: DateTimeFormat,
+) -> Result<ImportReport, String> {
+    let is_json = file_path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    let format = format.or_else(|| ExchangeFormat::from_path(file_path));
+    if format.is_none() && source.is_none() && !is_json {
+        return Err(format!(
+            "Could not detect the format of {:?}, expected a .md, .csv or .json file",
+            file_path
+        ));
+    }
+    let content = match fs::read_to_string(file_path) {
+        Ok(content) => content,
+        Err(e) => {
+            debug!("Error reading import file: {}", e);
+            return Err(format!("Error reading {:?}: {}", file_path, e));
+        }
+    };
+    match format {
+        Some(format) if source.is_none() => {
+            info!("Importing boards from {:?} as {}", file_path, format);
+            Ok(ImportReport {
+                boards: format.import(&content, date_time_format)?,
+                card_errors: vec![],
+            })
+        }
+        _ => {
+            info!("Importing boards from {:?}", file_path);
+            import_from_json(&content, source)
+        }
     }
 }
 
@@ -366,16 +722,92 @@ pub fn save_theme(theme: Theme) -> Result<String, String> {
         make_file_system_safe_name(&theme.name)
     );
     let theme_path = theme_dir.join(theme_name);
-    let write_status = fs::write(
-        theme_path.clone(),
-        serde_json::to_string_pretty(&theme).unwrap(),
-    );
-    if let Err(write_status) = write_status {
-        return Err(write_status.to_string());
-    }
+    write_file_atomically(&theme_path, serde_json::to_string_pretty(&theme).unwrap())?;
     Ok(theme_path.to_str().unwrap().to_string())
 }
 
+/// Templates that can not be read are skipped, sorted by name
+pub fn get_saved_card_templates() -> Vec<CardTemplate> {
+    let mut card_templates: Vec<CardTemplate> =
+        read_config_json_files(CARD_TEMPLATE_DIR_NAME, CARD_TEMPLATE_FILE_NAME);
+    card_templates.sort_by_key(|card_template| card_template.name.to_lowercase());
+    card_templates
+}
+
+/// Replaces any saved template with the same name, returns the path it was saved to
+pub fn save_card_template(card_template: &CardTemplate) -> Result<String, String> {
+    write_config_json_file(
+        CARD_TEMPLATE_DIR_NAME,
+        CARD_TEMPLATE_FILE_NAME,
+        &card_template.name,
+        card_template,
+    )
+}
+
+/// Only the user defined templates, see BoardTemplate::all_default_templates for the inbuilt ones
+pub fn get_saved_board_templates() -> Vec<BoardTemplate> {
+    let mut board_templates: Vec<BoardTemplate> =
+        read_config_json_files(BOARD_TEMPLATE_DIR_NAME, BOARD_TEMPLATE_FILE_NAME);
+    board_templates.sort_by_key(|board_template| board_template.name.to_lowercase());
+    board_templates
+}
+
+/// Replaces any saved template with the same name, returns the path it was saved to
+pub fn save_board_template(board_template: &BoardTemplate) -> Result<String, String> {
+    write_config_json_file(
+        BOARD_TEMPLATE_DIR_NAME,
+        BOARD_TEMPLATE_FILE_NAME,
+        &board_template.name,
+        board_template,
+    )
+}
+
+/// Reads every `<file_name>_*.json` file in a sub directory of the config directory, files that
+/// cannot be read are logged and skipped
+fn read_config_json_files<T: DeserializeOwned>(dir_name: &str, file_name: &str) -> Vec<T> {
+    let Ok(config_dir) = get_config_dir() else {
+        return Vec::new();
+    };
+    let Ok(files) = fs::read_dir(config_dir.join(dir_name)) else {
+        return Vec::new();
+    };
+    let file_prefix = format!("{}_", file_name);
+    files
+        .flatten()
+        .filter(|file| {
+            let file_name = file.file_name().to_string_lossy().to_string();
+            file_name.starts_with(&file_prefix) && file_name.ends_with(".json")
+        })
+        .filter_map(|file| {
+            let value = fs::read_to_string(file.path())
+                .map_err(|e| e.to_string())
+                .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()));
+            if let Err(e) = &value {
+                error!("Error reading {:?}: {}", file.path(), e);
+            }
+            value.ok()
+        })
+        .collect()
+}
+
+fn write_config_json_file<T: Serialize>(
+    dir_name: &str,
+    file_name: &str,
+    name: &str,
+    value: &T,
+) -> Result<String, String> {
+    let dir = get_config_dir()?.join(dir_name);
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path = dir.join(format!(
+        "{}_{}.json",
+        file_name,
+        make_file_system_safe_name(name)
+    ));
+    let json = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    write_file_atomically(&path, json)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
 fn write_default_config() {
     let config = AppConfig::default();
     let write_config_status = write_config(&config);
@@ -389,4 +821,5 @@ pub struct ExportStruct {
     pub boards: Vec<Board>,
     pub export_date: String,
     pub kanban_version: String,
+    pub schema_version: u32,
 }
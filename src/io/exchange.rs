@@ -0,0 +1,653 @@
+use crate::{
+    app::{
+        kanban::{Board, Boards, Card, CardPriority, CardStatus},
+        DateTimeFormat,
+    },
+    constants::{FIELD_NA, FIELD_NOT_SET},
+    util::{format_date_time, parse_date_time_string},
+};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use std::{fmt, path::Path, str::FromStr};
+
+const CSV_HEADERS: [&str; 8] = [
+    "board",
+    "board_description",
+    "name",
+    "description",
+    "status",
+    "priority",
+    "due_date",
+    "tags",
+];
+const CSV_TAG_SEPARATOR: char = ';';
+
+/// Formats boards can be exported to and imported from, apart from the internal save format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExchangeFormat {
+    Markdown,
+    Csv,
+}
+
+impl fmt::Display for ExchangeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExchangeFormat::Markdown => write!(f, "Markdown"),
+            ExchangeFormat::Csv => write!(f, "CSV"),
+        }
+    }
+}
+
+impl ExchangeFormat {
+    pub fn file_extension(&self) -> &str {
+        match self {
+            ExchangeFormat::Markdown => "md",
+            ExchangeFormat::Csv => "csv",
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "md" | "markdown" => Some(ExchangeFormat::Markdown),
+            "csv" => Some(ExchangeFormat::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn export(&self, boards: &[Board], date_time_format: DateTimeFormat) -> String {
+        match self {
+            ExchangeFormat::Markdown => boards_to_markdown(boards, date_time_format),
+            ExchangeFormat::Csv => boards_to_csv(boards, date_time_format),
+        }
+    }
+
+    /// Due dates are read in `date_time_format` first, the format they are exported in
+    pub fn import(
+        &self,
+        content: &str,
+        date_time_format: DateTimeFormat,
+    ) -> Result<Vec<Board>, String> {
+        match self {
+            ExchangeFormat::Markdown => boards_from_markdown(content, date_time_format),
+            ExchangeFormat::Csv => boards_from_csv(content, date_time_format),
+        }
+    }
+}
+
+/// Adds the imported boards to the existing ones, cards of boards with an existing name are
+/// merged into that board, cards that already exist in that board (same name) are skipped.
+/// Returns the number of boards and cards that were added
+pub fn merge_imported_boards(boards: &mut Boards, imported_boards: Vec<Board>) -> (usize, usize) {
+    let mut boards_added = 0;
+    let mut cards_added = 0;
+    for imported_board in imported_boards {
+        let existing_board = boards
+            .get_mut_boards()
+            .iter_mut()
+            .find(|board| board.name == imported_board.name);
+        if let Some(existing_board) = existing_board {
+            for card in imported_board.cards.get_all_cards() {
+                if existing_board
+                    .cards
+                    .get_all_cards()
+                    .iter()
+                    .any(|c| c.name == card.name)
+                {
+                    continue;
+                }
+                existing_board.cards.add_card(card.clone());
+                cards_added += 1;
+            }
+        } else {
+            cards_added += imported_board.cards.len();
+            boards_added += 1;
+            boards.add_board(imported_board);
+        }
+    }
+    (boards_added, cards_added)
+}
+
+fn is_field_set(value: &str) -> bool {
+    !value.is_empty() && value != FIELD_NOT_SET && value != FIELD_NA
+}
+
+pub fn boards_to_markdown(boards: &[Board], date_time_format: DateTimeFormat) -> String {
+    let date_time_format = DateTimeFormat::add_time_to_date_format(date_time_format);
+    let mut markdown = String::new();
+    for board in boards {
+        markdown.push_str(&format!("## {}\n\n", board.name));
+        if is_field_set(&board.description) {
+            markdown.push_str(&format!("{}\n\n", board.description.trim()));
+        }
+        for card in board.cards.get_all_cards() {
+            let checkbox = if card.card_status == CardStatus::Complete {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            markdown.push_str(&format!("- {} {}\n", checkbox, card.name));
+            if card.due_date.is_some() {
+                markdown.push_str(&format!(
+                    "  - Due: {}\n",
+                    format_date_time(card.due_date, date_time_format)
+                ));
+            }
+            markdown.push_str(&format!("  - Priority: {}\n", card.priority));
+            if card.card_status == CardStatus::Stale {
+                markdown.push_str(&format!("  - Status: {}\n", card.card_status));
+            }
+            if !card.tags.is_empty() {
+                markdown.push_str(&format!("  - Tags: {}\n", card.tags.join(", ")));
+            }
+            if is_field_set(&card.description) {
+                let mut description_lines = card.description.trim().lines();
+                if let Some(first_line) = description_lines.next() {
+                    markdown.push_str(&format!("  - Description: {}\n", first_line));
+                }
+                for line in description_lines {
+                    if line.trim().is_empty() {
+                        markdown.push('\n');
+                    } else {
+                        markdown.push_str(&format!("    {}\n", line));
+                    }
+                }
+            }
+        }
+        markdown.push('\n');
+    }
+    markdown.trim_end().to_string() + "\n"
+}
+
+pub fn boards_from_markdown(
+    content: &str,
+    date_time_format: DateTimeFormat,
+) -> Result<Vec<Board>, String> {
+    let mut boards: Vec<Board> = Vec::new();
+    let mut board_description: Vec<String> = Vec::new();
+    // the card being parsed and whether the last field was the description
+    let mut current_card: Option<(Card, bool)> = None;
+    // blank lines only belong to a description if it continues after them
+    let mut description_blank_lines = 0;
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let blank_lines_before = if line.trim().is_empty() {
+            0
+        } else {
+            std::mem::take(&mut description_blank_lines)
+        };
+        if let Some(board_name) = line.strip_prefix("## ") {
+            finish_markdown_card(&mut boards, &mut current_card);
+            finish_markdown_board_description(&mut boards, &mut board_description);
+            let board_name = board_name.trim();
+            if board_name.is_empty() {
+                return Err(format!("Line {}: board name is empty", line_number));
+            }
+            boards.push(Board::new(board_name, ""));
+        } else if let Some(card_line) = line
+            .strip_prefix("- [ ] ")
+            .map(|name| (name, false))
+            .or_else(|| line.strip_prefix("- [x] ").map(|name| (name, true)))
+            .or_else(|| line.strip_prefix("- [X] ").map(|name| (name, true)))
+        {
+            finish_markdown_card(&mut boards, &mut current_card);
+            finish_markdown_board_description(&mut boards, &mut board_description);
+            if boards.is_empty() {
+                return Err(format!(
+                    "Line {}: card found before any board heading (## Board name)",
+                    line_number
+                ));
+            }
+            let (card_name, completed) = card_line;
+            let card_name = card_name.trim();
+            if card_name.is_empty() {
+                return Err(format!("Line {}: card name is empty", line_number));
+            }
+            let mut card = Card::new(card_name, "", None, CardPriority::Low, vec![], vec![]);
+            if completed {
+                set_card_status(&mut card, CardStatus::Complete);
+            }
+            current_card = Some((card, false));
+        } else if let Some(field_line) = line.strip_prefix("  - ") {
+            let Some((card, in_description)) = current_card.as_mut() else {
+                return Err(format!(
+                    "Line {}: card field found outside of a card",
+                    line_number
+                ));
+            };
+            let (key, value) = field_line.split_once(':').ok_or_else(|| {
+                format!(
+                    "Line {}: expected a card field like \"Priority: High\"",
+                    line_number
+                )
+            })?;
+            let value = value.trim();
+            *in_description = false;
+            match key.trim().to_lowercase().as_str() {
+                "due" => {
+                    card.due_date = Some(
+                        convert_due_date(value, date_time_format)
+                            .map_err(|e| format!("Line {}: {}", line_number, e))?,
+                    );
+                }
+                "priority" => {
+                    card.priority = CardPriority::from_str(value)
+                        .map_err(|e| format!("Line {}: {}", line_number, e))?;
+                }
+                "status" => {
+                    let status = CardStatus::from_str(value)
+                        .map_err(|e| format!("Line {}: {}", line_number, e))?;
+                    set_card_status(card, status);
+                }
+                "tags" => {
+                    card.tags = split_tags(value, ',');
+                }
+                "description" => {
+                    card.description = value.to_string();
+                    *in_description = true;
+                }
+                _ => {
+                    return Err(format!(
+                        "Line {}: unknown card field \"{}\"",
+                        line_number,
+                        key.trim()
+                    ));
+                }
+            }
+        } else if line.trim().is_empty() {
+            if let Some((_, true)) = current_card {
+                description_blank_lines += 1;
+            }
+        } else if let Some(continuation) = line.strip_prefix("    ") {
+            match current_card.as_mut() {
+                Some((card, true)) => {
+                    for _ in 0..=blank_lines_before {
+                        card.description.push('\n');
+                    }
+                    card.description.push_str(continuation);
+                }
+                _ => {
+                    return Err(format!(
+                        "Line {}: unexpected indented line, only card descriptions can span multiple lines",
+                        line_number
+                    ));
+                }
+            }
+        } else if line.starts_with("# ") {
+            if let Some((_, in_description)) = current_card.as_mut() {
+                *in_description = false;
+            }
+        } else if current_card.is_none() && !boards.is_empty() {
+            board_description.push(line.trim().to_string());
+        } else {
+            return Err(format!(
+                "Line {}: could not parse \"{}\"",
+                line_number, line
+            ));
+        }
+    }
+    finish_markdown_card(&mut boards, &mut current_card);
+    finish_markdown_board_description(&mut boards, &mut board_description);
+
+    if boards.is_empty() {
+        return Err("No boards found, boards start with a \"## Board name\" heading".to_string());
+    }
+    Ok(boards)
+}
+
+fn finish_markdown_card(boards: &mut [Board], current_card: &mut Option<(Card, bool)>) {
+    if let Some((mut card, _)) = current_card.take() {
+        if card.description.is_empty() {
+            card.description = FIELD_NOT_SET.to_string();
+        }
+        if let Some(board) = boards.last_mut() {
+            board.cards.add_card(card);
+        }
+    }
+}
+
+fn finish_markdown_board_description(boards: &mut [Board], board_description: &mut Vec<String>) {
+    if board_description.is_empty() {
+        return;
+    }
+    if let Some(board) = boards.last_mut() {
+        if board.description.is_empty() {
+            board.description = board_description.join("\n");
+        }
+    }
+    board_description.clear();
+}
+
+pub fn boards_to_csv(boards: &[Board], date_time_format: DateTimeFormat) -> String {
+    let date_time_format = DateTimeFormat::add_time_to_date_format(date_time_format);
+    let mut csv = CSV_HEADERS.join(",");
+    csv.push('\n');
+    for board in boards {
+        let board_description = if is_field_set(&board.description) {
+            board.description.as_str()
+        } else {
+            ""
+        };
+        if board.cards.is_empty() {
+            let row = [
+                board.name.as_str(),
+                board_description,
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+            ];
+            csv.push_str(&csv_row(&row));
+            continue;
+        }
+        for card in board.cards.get_all_cards() {
+            let description = if is_field_set(&card.description) {
+                card.description.as_str()
+            } else {
+                ""
+            };
+            let due_date = if card.due_date.is_some() {
+                format_date_time(card.due_date, date_time_format)
+            } else {
+                String::new()
+            };
+            let status = card.card_status.to_string();
+            let priority = card.priority.to_string();
+            let tags = card.tags.join(&CSV_TAG_SEPARATOR.to_string());
+            let row = [
+                board.name.as_str(),
+                board_description,
+                card.name.as_str(),
+                description,
+                status.as_str(),
+                priority.as_str(),
+                due_date.as_str(),
+                tags.as_str(),
+            ];
+            csv.push_str(&csv_row(&row));
+        }
+    }
+    csv
+}
+
+/// Columns are matched by their header name so spreadsheets can reorder or leave out columns,
+/// only the board and name columns are required
+pub fn boards_from_csv(
+    content: &str,
+    date_time_format: DateTimeFormat,
+) -> Result<Vec<Board>, String> {
+    let mut rows = parse_csv(content)?.into_iter();
+    let headers: Vec<String> = match rows.next() {
+        Some(headers) => headers.iter().map(|h| h.trim().to_lowercase()).collect(),
+        None => return Err("CSV file is empty".to_string()),
+    };
+    let column = |name: &str| headers.iter().position(|h| h == name);
+    let board_column = column("board").ok_or("CSV file has no \"board\" column")?;
+    let name_column = column("name").ok_or("CSV file has no \"name\" column")?;
+    let board_description_column = column("board_description");
+    let description_column = column("description");
+    let status_column = column("status");
+    let priority_column = column("priority");
+    let due_date_column = column("due_date");
+    let tags_column = column("tags");
+
+    let mut boards: Vec<Board> = Vec::new();
+    for (index, row) in rows.enumerate() {
+        // header is row 1
+        let row_number = index + 2;
+        if row.iter().all(|value| value.trim().is_empty()) {
+            continue;
+        }
+        let get = |column: Option<usize>| {
+            column
+                .and_then(|column| row.get(column))
+                .map(|value| value.trim())
+                .unwrap_or("")
+        };
+        let board_name = get(Some(board_column));
+        if board_name.is_empty() {
+            return Err(format!("Row {}: board is empty", row_number));
+        }
+        let board_index = match boards.iter().position(|b| b.name == board_name) {
+            Some(board_index) => board_index,
+            None => {
+                boards.push(Board::new(board_name, get(board_description_column)));
+                boards.len() - 1
+            }
+        };
+        let card_name = get(Some(name_column));
+        if card_name.is_empty() {
+            continue;
+        }
+        let priority = match get(priority_column) {
+            "" => CardPriority::Low,
+            priority => CardPriority::from_str(priority)
+                .map_err(|e| format!("Row {}: {}", row_number, e))?,
+        };
+        let due_date = match get(due_date_column) {
+            "" => None,
+            due_date => Some(
+                convert_due_date(due_date, date_time_format)
+                    .map_err(|e| format!("Row {}: {}", row_number, e))?,
+            ),
+        };
+        let mut card = Card::new(
+            card_name,
+            get(description_column),
+            due_date,
+            priority,
+            split_tags(get(tags_column), CSV_TAG_SEPARATOR),
+            vec![],
+        );
+        match get(status_column) {
+            "" => {}
+            status => {
+                let status = CardStatus::from_str(status)
+                    .map_err(|e| format!("Row {}: {}", row_number, e))?;
+                set_card_status(&mut card, status);
+            }
+        }
+        boards[board_index].cards.add_card(card);
+    }
+    if boards.is_empty() {
+        return Err("No boards found in CSV file".to_string());
+    }
+    Ok(boards)
+}
+
+fn csv_row(values: &[&str]) -> String {
+    let mut row = values
+        .iter()
+        .map(|value| {
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    row.push('\n');
+    row
+}
+
+/// Minimal RFC 4180 parser, supports quoted values with escaped quotes and line breaks
+fn parse_csv(content: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut value = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    value.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                value.push(c);
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut value)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut value));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => value.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("CSV file has an unterminated quoted value".to_string());
+    }
+    if !value.is_empty() || !row.is_empty() {
+        row.push(value);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+fn split_tags(tags: &str, separator: char) -> Vec<String> {
+    tags.split(separator)
+        .map(|tag| tag.trim().trim_start_matches('#').to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+fn convert_due_date(
+    due_date: &str,
+    date_time_format: DateTimeFormat,
+) -> Result<DateTime<Utc>, String> {
+    parse_date_time_string(due_date, date_time_format)
+        .map_err(|_| format!("Invalid due date \"{}\"", due_date))
+}
+
+fn set_card_status(card: &mut Card, status: CardStatus) {
+    if status == CardStatus::Complete {
+        card.date_completed = Some(Utc::now());
+    }
+    card.card_status = status;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_card(name: &str, description: &str) -> Card {
+        Card::new(name, description, None, CardPriority::Low, vec![], vec![])
+    }
+
+    fn round_trip(boards: &[Board]) -> Vec<Board> {
+        let markdown = boards_to_markdown(boards, DateTimeFormat::DayMonthYear);
+        boards_from_markdown(&markdown, DateTimeFormat::DayMonthYear)
+            .unwrap_or_else(|e| panic!("{}\n{}", e, markdown))
+    }
+
+    fn descriptions(boards: &[Board]) -> Vec<(String, String)> {
+        boards
+            .iter()
+            .flat_map(|board| board.cards.get_all_cards())
+            .map(|card| (card.name.clone(), card.description.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn multi_line_descriptions_survive_a_round_trip() {
+        let mut board = Board::new("Todo", "Things to do");
+        board.cards.add_card(new_card(
+            "Paragraphs",
+            "First line\nSecond line\n\nAfter a blank line\n\n\nAfter two blank lines",
+        ));
+        board
+            .cards
+            .add_card(new_card("Indented", "Steps:\n  - one\n  - two"));
+        board
+            .cards
+            .add_card(new_card("Single line", "Just one line"));
+        board.cards.add_card(new_card("No description", ""));
+        let mut done = Board::new("Done", "");
+        let mut card = new_card("Last", "Ends the file\n\nWith a blank line inside");
+        card.tags = vec!["tag".to_string()];
+        done.cards.add_card(card);
+        let boards = vec![board, done];
+
+        let imported = round_trip(&boards);
+        assert_eq!(descriptions(&imported), descriptions(&boards));
+        assert_eq!(imported[0].description, "Things to do");
+        assert_eq!(
+            imported[1].cards.get_all_cards()[0].tags,
+            vec!["tag".to_string()]
+        );
+    }
+
+    #[test]
+    fn empty_description_lines_are_written_as_bare_empty_lines() {
+        let mut board = Board::new("Todo", "");
+        board
+            .cards
+            .add_card(new_card("Card", "First paragraph\n\nSecond paragraph"));
+
+        let markdown = boards_to_markdown(&[board], DateTimeFormat::DayMonthYear);
+        assert!(markdown.contains("  - Description: First paragraph\n\n    Second paragraph\n"));
+        assert!(markdown.lines().all(|line| line == line.trim_end()));
+    }
+
+    #[test]
+    fn blank_lines_after_a_description_do_not_belong_to_it() {
+        let markdown = "## Todo\n\n- [ ] First\n  - Description: Only line\n\n\n- [ ] Second\n  - Description: Other\n\n";
+        let boards = boards_from_markdown(markdown, DateTimeFormat::DayMonthYear).unwrap();
+        assert_eq!(
+            descriptions(&boards),
+            vec![
+                ("First".to_string(), "Only line".to_string()),
+                ("Second".to_string(), "Other".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn whitespace_only_description_lines_are_empty_lines() {
+        // an editor can leave the indentation on an empty line of a description
+        let markdown = "## Todo\n- [ ] Card\n  - Description: First\n    \n    Second\n";
+        let boards = boards_from_markdown(markdown, DateTimeFormat::DayMonthYear).unwrap();
+        assert_eq!(
+            boards[0].cards.get_all_cards()[0].description,
+            "First\n\nSecond"
+        );
+    }
+
+    #[test]
+    fn due_dates_are_read_in_the_configured_format_first() {
+        let markdown = "## Todo\n- [ ] Card\n  - Due: 03/04/2024\n";
+        let due_date = |date_time_format| {
+            let boards = boards_from_markdown(markdown, date_time_format).unwrap();
+            let due_date = boards[0].cards.get_all_cards()[0].due_date.unwrap();
+            due_date.with_timezone(&chrono::Local).date_naive()
+        };
+        assert_eq!(
+            due_date(DateTimeFormat::MonthDayYearTime),
+            chrono::NaiveDate::from_ymd_opt(2024, 3, 4).unwrap()
+        );
+        assert_eq!(
+            due_date(DateTimeFormat::DayMonthYear),
+            chrono::NaiveDate::from_ymd_opt(2024, 4, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn indented_lines_only_continue_descriptions() {
+        let markdown = "## Todo\n- [ ] Card\n  - Description: First\n  - Tags: a\n\n    Second\n";
+        assert!(boards_from_markdown(markdown, DateTimeFormat::DayMonthYear)
+            .unwrap_err()
+            .starts_with("Line 6:"));
+    }
+}
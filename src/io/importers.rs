@@ -0,0 +1,565 @@
+use crate::{
+    app::kanban::{Board, Card, CardPriority, CardStatus, ChecklistItem, Comment},
+    constants::DEFAULT_COMMENT_AUTHOR,
+    util::naive_local_to_utc,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use clap::ValueEnum;
+use serde_json::Value;
+use std::{collections::HashMap, fmt};
+
+/// Tools whose JSON exports can be imported as boards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportSource {
+    Trello,
+    GithubProjects,
+}
+
+impl fmt::Display for ImportSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImportSource::Trello => write!(f, "Trello"),
+            ImportSource::GithubProjects => write!(f, "GitHub Projects"),
+        }
+    }
+}
+
+impl ImportSource {
+    /// Guesses the source from the shape of the json
+    pub fn detect(value: &Value) -> Option<Self> {
+        if value["lists"].is_array() && value["cards"].is_array() {
+            Some(ImportSource::Trello)
+        } else if is_github_projects_export(value) {
+            Some(ImportSource::GithubProjects)
+        } else {
+            None
+        }
+    }
+}
+
+/// `gh project item-list` writes the items with a `totalCount`, every item has the issue, pull
+/// request or draft it tracks in `content`
+fn is_github_projects_export(value: &Value) -> bool {
+    let items = match (value["items"].as_array(), value.as_array()) {
+        (Some(items), _) if items.is_empty() => return value["totalCount"].is_number(),
+        (Some(items), _) | (None, Some(items)) => items,
+        (None, None) => return false,
+    };
+    !items.is_empty() && items.iter().all(|item| item["content"].is_object())
+}
+
+/// Boards built by an importer, cards that could not be fully mapped are listed in card_errors
+/// instead of failing the whole import
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub boards: Vec<Board>,
+    pub card_errors: Vec<String>,
+}
+
+pub fn import_from_json(
+    content: &str,
+    source: Option<ImportSource>,
+) -> Result<ImportReport, String> {
+    let value: Value = match serde_json::from_str(content) {
+        Ok(value) => value,
+        Err(e) => return Err(format!("Error parsing json: {}", e)),
+    };
+    let source = match source.or_else(|| ImportSource::detect(&value)) {
+        Some(source) => source,
+        None => {
+            return Err(
+                "Unrecognized json format, expected a Trello or GitHub Projects export".to_string(),
+            )
+        }
+    };
+    log::info!("Importing boards from a {} export", source);
+    match source {
+        ImportSource::Trello => import_trello(&value),
+        ImportSource::GithubProjects => import_github_projects(&value),
+    }
+}
+
+/// Maps a Trello board export, lists become boards, labels become tags, checklists become the
+/// card's checklist and comments keep their author and date. Archived lists and cards are skipped
+fn import_trello(value: &Value) -> Result<ImportReport, String> {
+    let lists = value["lists"]
+        .as_array()
+        .ok_or("Trello export has no lists")?;
+    let cards = value["cards"]
+        .as_array()
+        .ok_or("Trello export has no cards")?;
+    let trello_board_name = value["name"].as_str().unwrap_or("Trello");
+
+    let mut report = ImportReport::default();
+    let mut open_lists: Vec<&Value> = lists
+        .iter()
+        .filter(|list| !list["closed"].as_bool().unwrap_or(false))
+        .collect();
+    open_lists.sort_by(|a, b| {
+        let a_pos = a["pos"].as_f64().unwrap_or(0.0);
+        let b_pos = b["pos"].as_f64().unwrap_or(0.0);
+        a_pos.total_cmp(&b_pos)
+    });
+    let mut board_index_for_list: HashMap<&str, usize> = HashMap::new();
+    for list in open_lists {
+        let (Some(list_id), Some(list_name)) = (list["id"].as_str(), list["name"].as_str()) else {
+            report
+                .card_errors
+                .push("Skipped a list without an id or name".to_string());
+            continue;
+        };
+        board_index_for_list.insert(list_id, report.boards.len());
+        report.boards.push(Board::new(
+            list_name,
+            &format!("Imported from Trello board \"{}\"", trello_board_name),
+        ));
+    }
+
+    let mut checklists_for_card: HashMap<&str, Vec<ChecklistItem>> = HashMap::new();
+    if let Some(checklists) = value["checklists"].as_array() {
+        for checklist in checklists {
+            let Some(card_id) = checklist["idCard"].as_str() else {
+                continue;
+            };
+            let checklist_name = checklist["name"].as_str().unwrap_or("Checklist");
+            let entries = checklist_items(checklist).into_iter().map(|mut item| {
+                item.text = format!("{}: {}", checklist_name, item.text);
+                item
+            });
+            checklists_for_card
+                .entry(card_id)
+                .or_default()
+                .extend(entries);
+        }
+    }
+
+    let mut comments_for_card: HashMap<&str, Vec<Comment>> = HashMap::new();
+    if let Some(actions) = value["actions"].as_array() {
+        // Trello lists actions newest first
+        for action in actions.iter().rev() {
+            if action["type"].as_str() != Some("commentCard") {
+                continue;
+            }
+            let (Some(card_id), Some(text)) = (
+                action["data"]["card"]["id"].as_str(),
+                action["data"]["text"].as_str(),
+            ) else {
+                continue;
+            };
+            let author = action["memberCreator"]["fullName"]
+                .as_str()
+                .unwrap_or(DEFAULT_COMMENT_AUTHOR);
+            let mut comment = Comment::new(author, text);
+            if let Some(created_at) = action["date"]
+                .as_str()
+                .and_then(|date| convert_external_date(date).ok())
+            {
+                comment.created_at = created_at;
+            }
+            comments_for_card.entry(card_id).or_default().push(comment);
+        }
+    }
+
+    for (index, trello_card) in cards.iter().enumerate() {
+        if trello_card["closed"].as_bool().unwrap_or(false) {
+            continue;
+        }
+        let card_label = card_label(trello_card["name"].as_str(), index);
+        let Some(name) = trello_card["name"]
+            .as_str()
+            .filter(|n| !n.trim().is_empty())
+        else {
+            report
+                .card_errors
+                .push(format!("{}: missing name, card skipped", card_label));
+            continue;
+        };
+        let Some(board_index) = trello_card["idList"]
+            .as_str()
+            .and_then(|list_id| board_index_for_list.get(list_id))
+        else {
+            report.card_errors.push(format!(
+                "{}: list not found or archived, card skipped",
+                card_label
+            ));
+            continue;
+        };
+
+        let tags = match trello_card["labels"].as_array() {
+            Some(labels) => labels
+                .iter()
+                .filter_map(|label| {
+                    label["name"]
+                        .as_str()
+                        .filter(|name| !name.is_empty())
+                        .or_else(|| label["color"].as_str())
+                        .map(|name| name.to_string())
+                })
+                .collect(),
+            None => vec![],
+        };
+        let due_date = match trello_card["due"].as_str() {
+            Some(due) => match convert_external_date(due) {
+                Ok(due_date) => Some(due_date),
+                Err(e) => {
+                    report
+                        .card_errors
+                        .push(format!("{}: {}, due date skipped", card_label, e));
+                    None
+                }
+            },
+            None => None,
+        };
+        let card_id = trello_card["id"].as_str().unwrap_or_default();
+        let comments = comments_for_card.remove(card_id).unwrap_or_default();
+
+        let mut card = Card::new(
+            name.trim(),
+            trello_card["desc"].as_str().unwrap_or_default().trim(),
+            due_date,
+            CardPriority::Low,
+            tags,
+            comments,
+        );
+        card.checklist = checklists_for_card.remove(card_id).unwrap_or_default();
+        if trello_card["dueComplete"].as_bool().unwrap_or(false) {
+            mark_card_complete(&mut card);
+        }
+        report.boards[*board_index].cards.add_card(card);
+    }
+    Ok(report)
+}
+
+fn checklist_items(checklist: &Value) -> Vec<ChecklistItem> {
+    let Some(items) = checklist["checkItems"].as_array() else {
+        return vec![];
+    };
+    let mut items: Vec<&Value> = items.iter().collect();
+    items.sort_by(|a, b| {
+        let a_pos = a["pos"].as_f64().unwrap_or(0.0);
+        let b_pos = b["pos"].as_f64().unwrap_or(0.0);
+        a_pos.total_cmp(&b_pos)
+    });
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let name = item["name"].as_str()?;
+            Some(ChecklistItem {
+                text: name.to_string(),
+                done: item["state"].as_str() == Some("complete"),
+            })
+        })
+        .collect()
+}
+
+/// Maps a GitHub Projects item dump (e.g. from `gh project item-list --format json`), every
+/// status becomes a board, items without a status end up in a "No Status" board
+fn import_github_projects(value: &Value) -> Result<ImportReport, String> {
+    let items = match value["items"].as_array() {
+        Some(items) => items,
+        None => value
+            .as_array()
+            .ok_or("GitHub Projects export has no items")?,
+    };
+    let mut report = ImportReport::default();
+    for (index, item) in items.iter().enumerate() {
+        let title = item["title"]
+            .as_str()
+            .or_else(|| item["content"]["title"].as_str());
+        let card_label = card_label(title, index);
+        let Some(title) = title.filter(|t| !t.trim().is_empty()) else {
+            report
+                .card_errors
+                .push(format!("{}: missing title, item skipped", card_label));
+            continue;
+        };
+        let status = item["status"]
+            .as_str()
+            .filter(|s| !s.trim().is_empty())
+            .unwrap_or("No Status")
+            .trim();
+        let board_index = match report.boards.iter().position(|b| b.name == status) {
+            Some(board_index) => board_index,
+            None => {
+                report
+                    .boards
+                    .push(Board::new(status, "Imported from GitHub Projects"));
+                report.boards.len() - 1
+            }
+        };
+
+        let mut description = item["content"]["body"]
+            .as_str()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        if let Some(url) = item["content"]["url"].as_str() {
+            if !description.is_empty() {
+                description.push_str("\n\n");
+            }
+            description.push_str(url);
+        }
+        let tags = match item["labels"].as_array() {
+            Some(labels) => labels
+                .iter()
+                .filter_map(|label| label.as_str().or_else(|| label["name"].as_str()))
+                .map(|label| label.to_string())
+                .collect(),
+            None => vec![],
+        };
+        let priority = match item["priority"].as_str() {
+            Some(priority) => match map_github_priority(priority) {
+                Some(priority) => priority,
+                None => {
+                    report.card_errors.push(format!(
+                        "{}: unknown priority \"{}\", using Low",
+                        card_label, priority
+                    ));
+                    CardPriority::Low
+                }
+            },
+            None => CardPriority::Low,
+        };
+        let due_date = match github_due_date_field(item) {
+            Some(due) => match convert_external_date(due) {
+                Ok(due_date) => Some(due_date),
+                Err(e) => {
+                    report
+                        .card_errors
+                        .push(format!("{}: {}, due date skipped", card_label, e));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let mut card = Card::new(title.trim(), &description, due_date, priority, tags, vec![]);
+        if status.eq_ignore_ascii_case("done") {
+            mark_card_complete(&mut card);
+        }
+        report.boards[board_index].cards.add_card(card);
+    }
+    Ok(report)
+}
+
+/// Project fields are custom, so look for the commonly used names of a date field
+fn github_due_date_field(item: &Value) -> Option<&str> {
+    let fields = item.as_object()?;
+    fields
+        .iter()
+        .find(|(key, _)| {
+            matches!(
+                key.to_lowercase().replace([' ', '_', '-'], "").as_str(),
+                "due" | "duedate" | "targetdate" | "enddate"
+            )
+        })
+        .and_then(|(_, value)| value.as_str())
+}
+
+fn map_github_priority(priority: &str) -> Option<CardPriority> {
+    match priority.trim().to_lowercase().as_str() {
+        "high" | "urgent" | "p0" | "p1" => Some(CardPriority::High),
+        "medium" | "p2" => Some(CardPriority::Medium),
+        "low" | "p3" | "p4" => Some(CardPriority::Low),
+        _ => None,
+    }
+}
+
+fn card_label(name: Option<&str>, index: usize) -> String {
+    match name {
+        Some(name) if !name.trim().is_empty() => format!("Card \"{}\"", name.trim()),
+        _ => format!("Card #{}", index + 1),
+    }
+}
+
+/// Parses an RFC 3339 timestamp, or a plain YYYY-MM-DD date as local midnight
+fn convert_external_date(date: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(date) {
+        return Ok(date_time.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        if let Some(date_time) = naive_local_to_utc(date.and_time(NaiveTime::MIN)) {
+            return Ok(date_time);
+        }
+    }
+    Err(format!("invalid date \"{}\"", date))
+}
+
+fn mark_card_complete(card: &mut Card) {
+    card.card_status = CardStatus::Complete;
+    card.date_completed = Some(Utc::now());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn card_names(board: &Board) -> Vec<&str> {
+        board
+            .cards
+            .get_all_cards()
+            .iter()
+            .map(|card| card.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn trello_exports_are_imported() {
+        let export = json!({
+            "name": "Project",
+            "lists": [
+                { "id": "l2", "name": "Done", "pos": 2, "closed": false },
+                { "id": "l1", "name": "Todo", "pos": 1, "closed": false },
+                { "id": "l3", "name": "Old", "pos": 3, "closed": true },
+            ],
+            "cards": [
+                {
+                    "id": "c1",
+                    "idList": "l1",
+                    "name": "Write docs",
+                    "desc": "For the importer",
+                    "labels": [{ "name": "docs", "color": "blue" }, { "name": "", "color": "red" }],
+                    "due": "2024-03-04T12:00:00.000Z",
+                    "dueComplete": false,
+                },
+                { "id": "c2", "idList": "l2", "name": "Ship", "dueComplete": true },
+                { "id": "c3", "idList": "l3", "name": "Archived list" },
+                { "id": "c4", "idList": "l1", "name": "Archived card", "closed": true },
+            ],
+            "checklists": [{
+                "idCard": "c1",
+                "name": "Steps",
+                "checkItems": [
+                    { "name": "Second", "pos": 2, "state": "incomplete" },
+                    { "name": "First", "pos": 1, "state": "complete" },
+                ],
+            }],
+            "actions": [{
+                "type": "commentCard",
+                "date": "2024-03-01T09:00:00.000Z",
+                "data": { "card": { "id": "c1" }, "text": "Looks good" },
+                "memberCreator": { "fullName": "Reviewer" },
+            }],
+        });
+        assert_eq!(ImportSource::detect(&export), Some(ImportSource::Trello));
+
+        let report = import_from_json(&export.to_string(), None).unwrap();
+        let board_names: Vec<&str> = report.boards.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(board_names, vec!["Todo", "Done"]);
+        assert_eq!(card_names(&report.boards[0]), vec!["Write docs"]);
+        assert_eq!(card_names(&report.boards[1]), vec!["Ship"]);
+        assert_eq!(
+            report.card_errors,
+            vec!["Card \"Archived list\": list not found or archived, card skipped".to_string()]
+        );
+
+        let card = &report.boards[0].cards.get_all_cards()[0];
+        assert_eq!(card.description, "For the importer");
+        assert_eq!(card.tags, vec!["docs".to_string(), "red".to_string()]);
+        assert_eq!(
+            card.due_date,
+            Some(convert_external_date("2024-03-04T12:00:00Z").unwrap())
+        );
+        assert_eq!(
+            card.checklist,
+            vec![
+                ChecklistItem {
+                    text: "Steps: First".to_string(),
+                    done: true
+                },
+                ChecklistItem {
+                    text: "Steps: Second".to_string(),
+                    done: false
+                },
+            ]
+        );
+        assert_eq!(card.comments.len(), 1);
+        assert_eq!(card.comments[0].author, "Reviewer");
+        assert_eq!(card.comments[0].body, "Looks good");
+        assert_eq!(
+            report.boards[1].cards.get_all_cards()[0].card_status,
+            CardStatus::Complete
+        );
+    }
+
+    #[test]
+    fn github_projects_exports_are_imported() {
+        let export = json!({
+            "items": [
+                {
+                    "id": "PVTI_1",
+                    "title": "Fix login",
+                    "status": "In Progress",
+                    "priority": "P1",
+                    "labels": ["bug"],
+                    "due date": "2024-03-04",
+                    "content": {
+                        "type": "Issue",
+                        "title": "Fix login",
+                        "body": "It fails",
+                        "url": "https://github.com/owner/repo/issues/1",
+                    },
+                },
+                {
+                    "id": "PVTI_2",
+                    "title": "Release",
+                    "status": "Done",
+                    "content": { "type": "DraftIssue", "title": "Release", "body": "" },
+                },
+                {
+                    "id": "PVTI_3",
+                    "content": { "type": "DraftIssue", "title": "Plan", "body": "" },
+                },
+            ],
+            "totalCount": 3,
+        });
+        assert_eq!(
+            ImportSource::detect(&export),
+            Some(ImportSource::GithubProjects)
+        );
+        assert_eq!(
+            ImportSource::detect(&export["items"]),
+            Some(ImportSource::GithubProjects)
+        );
+
+        let report = import_from_json(&export.to_string(), None).unwrap();
+        assert!(report.card_errors.is_empty());
+        let board_names: Vec<&str> = report.boards.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(board_names, vec!["In Progress", "Done", "No Status"]);
+        let card = &report.boards[0].cards.get_all_cards()[0];
+        assert_eq!(card.name, "Fix login");
+        assert_eq!(
+            card.description,
+            "It fails\n\nhttps://github.com/owner/repo/issues/1"
+        );
+        assert_eq!(card.priority, CardPriority::High);
+        assert_eq!(card.tags, vec!["bug".to_string()]);
+        assert_eq!(
+            card.due_date,
+            Some(convert_external_date("2024-03-04").unwrap())
+        );
+        assert_eq!(
+            report.boards[1].cards.get_all_cards()[0].card_status,
+            CardStatus::Complete
+        );
+        assert_eq!(card_names(&report.boards[2]), vec!["Plan"]);
+    }
+
+    #[test]
+    fn unrecognized_documents_are_rejected() {
+        for document in [
+            json!([1, 2, 3]),
+            json!([{ "name": "Not a project item" }]),
+            json!({ "items": [{ "title": "No content" }] }),
+            json!({ "items": [] }),
+            json!({ "boards": [] }),
+            json!({ "lists": [] }),
+        ] {
+            assert_eq!(ImportSource::detect(&document), None, "{}", document);
+            assert!(import_from_json(&document.to_string(), None)
+                .unwrap_err()
+                .starts_with("Unrecognized json format"));
+        }
+    }
+}
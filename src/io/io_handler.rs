@@ -1,27 +1,37 @@
 use crate::{
     app::{
-        app_helper::handle_go_to_previous_view, kanban::Boards, state::UserLoginData, App,
-        AppConfig, VisibleBoardsAndCards,
+        app_helper::handle_go_to_previous_view,
+        kanban::{Board, Boards},
+        save_diff::{SaveDiff, CURRENT_BOARDS_LABEL},
+        state::UserLoginData,
+        ActionHistory, ActionHistoryManager, App, AppConfig, DateTimeFormat, VisibleBoardsAndCards,
     },
     constants::{
         CONFIG_DIR_NAME, CONFIG_FILE_NAME, EMAIL_REGEX, ENCRYPTION_KEY_FILE_NAME,
-        MAX_PASSWORD_LENGTH, MIN_PASSWORD_LENGTH, MIN_TIME_BETWEEN_SENDING_RESET_LINK,
-        REFRESH_TOKEN_FILE_NAME, REFRESH_TOKEN_SEPARATOR, SAVE_DIR_NAME, SUPABASE_ANON_KEY,
-        SUPABASE_URL,
+        JOURNAL_FILE_NAME, MAX_PASSWORD_LENGTH, MIN_PASSWORD_LENGTH,
+        MIN_TIME_BETWEEN_SENDING_RESET_LINK, REFRESH_TOKEN_FILE_NAME, REFRESH_TOKEN_SEPARATOR,
+        SAVE_DIR_NAME, SAVE_SCHEMA_VERSION, SUPABASE_ANON_KEY, SUPABASE_URL,
     },
     io::{
         data_handler::{
-            get_available_local_save_files, get_default_save_directory, get_local_kanban_state,
-            get_saved_themes, save_kanban_state_locally,
+            delete_action_history, get_action_history, get_available_local_save_files,
+            get_default_save_directory, get_local_kanban_state, get_saved_board_templates,
+            get_saved_card_templates, get_saved_themes, recover_interrupted_saves,
+            save_action_history, save_kanban_state_locally, write_config,
+        },
+        journal::get_journal_path,
+        migrations::migrate_save,
+        sync::{
+            get_sync_base, merge::merge_boards, save_sync_base, PendingSync, SyncBackend,
+            SyncBackendClient, SyncBackendType,
         },
         IoEvent,
     },
-    ui::{TextColorOptions, View},
+    ui::{PopUp, TextColorOptions, View},
     util::{
-        print_debug, print_error, print_info, send_error_toast, send_error_toast_with_duration,
-        send_info_toast, send_info_toast_with_duration, send_warning_toast,
-        send_warning_toast_with_duration, update_current_board_and_card,
-        update_current_visible_boards_and_cards,
+        send_error_toast, send_error_toast_with_duration, send_info_toast,
+        send_info_toast_with_duration, send_warning_toast, send_warning_toast_with_duration,
+        update_current_board_and_card, update_current_visible_boards_and_cards,
     },
 };
 use aes_gcm::{
@@ -29,7 +39,7 @@ use aes_gcm::{
     AeadCore, Aes256Gcm, Key, KeyInit,
 };
 use base64::Engine;
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{Local, NaiveDate, NaiveDateTime};
 use eyre::{anyhow, Result};
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, info, warn};
@@ -62,6 +72,7 @@ impl IoAsyncHandler<'_> {
             IoEvent::ResetVisibleBoardsandCards => self.refresh_visible_boards_and_cards().await,
             IoEvent::AutoSave => self.auto_save().await,
             IoEvent::LoadLocalPreview => self.load_local_preview().await,
+            IoEvent::LoadSaveDiff => self.load_save_diff().await,
             IoEvent::Login(email_id, password) => self.cloud_login(email_id, password).await,
             IoEvent::Logout => self.cloud_logout().await,
             IoEvent::SignUp(email_id, password, confirm_password) => {
@@ -76,10 +87,12 @@ impl IoAsyncHandler<'_> {
                     .await
             }
             IoEvent::SyncLocalData => self.sync_local_data().await,
+            IoEvent::ApplySyncResolution => self.apply_sync_resolution().await,
             IoEvent::GetCloudData => self.get_cloud_data().await,
             IoEvent::LoadSaveCloud => self.load_save_file_cloud().await,
             IoEvent::LoadCloudPreview => self.preview_cloud_save().await,
             IoEvent::DeleteCloudSave => self.delete_cloud_save().await,
+            IoEvent::SwitchWorkspace(workspace) => self.switch_workspace(workspace).await,
         };
 
         let mut app = self.app.lock().await;
@@ -97,7 +110,7 @@ impl IoAsyncHandler<'_> {
     async fn do_initialize(&mut self) -> Result<()> {
         info!("🚀 Initialize the application");
         let mut app = self.app.lock().await;
-        let default_ui_view = app.config.default_view;
+        let default_ui_view = app.config.workspace_default_view();
         let prepare_config_dir_status = prepare_config_dir();
         if prepare_config_dir_status.is_err() {
             error!("Cannot create config directory");
@@ -114,23 +127,15 @@ impl IoAsyncHandler<'_> {
             );
         }
         prepare_boards(&mut app);
+        auto_archive_completed_cards(&mut app);
         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
         let saved_themes = get_saved_themes();
         if let Some(saved_themes) = saved_themes {
             app.all_themes.extend(saved_themes);
         }
-        let default_theme = app.config.default_theme.clone();
-        for theme in &app.all_themes {
-            if theme.name == default_theme {
-                app.current_theme = theme.clone();
-                break;
-            }
-        }
-        if let Some(bg_color) = app.current_theme.general_style.bg {
-            app.state.term_background_color = TextColorOptions::from(bg_color).to_rgb();
-        } else {
-            app.state.term_background_color = (0, 0, 0)
-        }
+        app.card_templates = get_saved_card_templates();
+        app.board_templates.extend(get_saved_board_templates());
+        apply_default_theme(&mut app);
         app.set_view(default_ui_view);
         info!("👍 Application initialized");
         app.initialized();
@@ -145,8 +150,12 @@ impl IoAsyncHandler<'_> {
         send_info_toast(&mut app.widgets.toast_widget, "Application initialized");
         if app.config.auto_login {
             send_info_toast(&mut app.widgets.toast_widget, "Attempting to auto login");
-            let user_login_data =
-                test_refresh_token_on_disk(app.state.encryption_key_from_arguments.clone()).await;
+            let sync_backend = SyncBackendClient::from_config(&app.config, false);
+            let user_login_data = test_refresh_token_on_disk(
+                &sync_backend,
+                app.state.encryption_key_from_arguments.clone(),
+            )
+            .await;
             if user_login_data.is_err() {
                 let refresh_token_file_path = get_config_dir();
                 if refresh_token_file_path.is_err() {
@@ -190,13 +199,24 @@ impl IoAsyncHandler<'_> {
     async fn save_local_data(&mut self) -> Result<()> {
         info!("🚀 Saving local data");
         let mut app = self.app.lock().await;
-        if save_required(&mut app) {
+        if let Some(err) = app.state.latest_save_load_error.clone() {
+            error!("Cannot save, the latest save could not be loaded: {}", err);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Cannot save, the latest save could not be loaded: {}", err),
+            );
+            return Ok(());
+        }
+        if app.has_unsaved_changes() {
             let board_data = app.boards.get_boards();
             let status = save_kanban_state_locally(board_data.to_vec(), &app.config);
             match status {
-                Ok(_) => {
+                Ok(save_file_name) => {
+                    app.mark_boards_saved();
+                    app.state.last_save_time = Some(Local::now());
                     info!("👍 Local data saved");
                     send_info_toast(&mut app.widgets.toast_widget, "👍 Local data saved");
+                    save_action_history_with_save_file(&mut app, &save_file_name);
                 }
                 Err(err) => {
                     debug!("Cannot save local data: {:?}", err);
@@ -207,13 +227,16 @@ impl IoAsyncHandler<'_> {
         } else {
             warn!("No changes to save");
             send_warning_toast(&mut app.widgets.toast_widget, "No changes to save");
+            if let Ok(save_file_name) = get_latest_save_file(&app.config) {
+                save_action_history_with_save_file(&mut app, &save_file_name);
+            }
             Ok(())
         }
     }
 
     async fn load_save_file_local(&mut self) -> Result<()> {
         let mut app = self.app.lock().await;
-        let default_view = app.config.default_view;
+        let default_view = app.config.workspace_default_view();
         let save_file_index = app.state.app_list_states.load_save.selected().unwrap_or(0);
         let local_files = get_available_local_save_files(&app.config);
         let local_files = if let Some(local_files) = local_files {
@@ -239,17 +262,28 @@ impl IoAsyncHandler<'_> {
         let board_data = get_local_kanban_state(save_file_name.clone(), false, &app.config);
         match board_data {
             Ok(boards) => {
-                app.boards.set_boards(boards);
-                app.action_history_manager.reset();
+                let previous_boards = app.boards.clone();
+                app.boards.set_boards(boards.clone());
+                app.state.latest_save_load_error = None;
+                app.action_history_manager
+                    .new_action(ActionHistory::LoadSave(
+                        save_file_name.clone(),
+                        previous_boards,
+                        boards,
+                    ));
                 info!("👍 Save file {:?} loaded", save_file_name);
                 send_info_toast(
                     &mut app.widgets.toast_widget,
                     &format!("👍 Save file {:?} loaded", save_file_name),
                 );
+                auto_archive_completed_cards(&mut app);
             }
             Err(err) => {
-                debug!("Cannot load save file: {:?}", err);
-                send_error_toast(&mut app.widgets.toast_widget, "Cannot load save file");
+                error!("Cannot load save file: {}", err);
+                send_error_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("Cannot load save file: {}", err),
+                );
             }
         }
         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
@@ -289,7 +323,7 @@ impl IoAsyncHandler<'_> {
         }
         let file_name = file_list[selected].clone();
         info!("🚀 Deleting save file: {}", file_name);
-        let path = app.config.save_directory.join(file_name);
+        let path = app.config.workspace_save_directory().join(&file_name);
         if !Path::new(&path).exists() {
             error!("Cannot delete save file: file not found");
             send_error_toast(
@@ -308,6 +342,11 @@ impl IoAsyncHandler<'_> {
         } else {
             info!("👍 Save file deleted");
             send_info_toast(&mut app.widgets.toast_widget, "👍 Save file deleted");
+            if let Err(err) = delete_action_history(&file_name, &app.config) {
+                debug!("Cannot delete undo history of the save file: {}", err);
+            }
+            let latest_saved_boards = get_latest_saved_boards(&app.config);
+            app.set_saved_boards(latest_saved_boards);
         }
         let file_list = get_available_local_save_files(&app.config);
         let file_list = if let Some(file_list) = file_list {
@@ -337,10 +376,64 @@ impl IoAsyncHandler<'_> {
 
     async fn auto_save(&mut self) -> Result<()> {
         let mut app = self.app.lock().await;
-        match auto_save(&mut app).await {
-            Ok(_) => Ok(()),
-            Err(err) => Err(anyhow!(err)),
+        if let Err(err) = auto_save(&mut app).await {
+            error!("Cannot auto save: {}", err);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Cannot auto save: {}", err),
+            );
         }
+        Ok(())
+    }
+
+    /// Saves the boards of the current workspace before loading the latest save of the other one
+    async fn switch_workspace(&mut self, workspace: String) -> Result<()> {
+        let mut app = self.app.lock().await;
+        // Nothing to save when the boards of this workspace could not be loaded
+        if app.state.latest_save_load_error.is_some() {
+            info!("Not saving the boards of a workspace whose latest save could not be loaded");
+        } else if let Err(err) = auto_save(&mut app).await {
+            error!("Cannot save before switching workspace: {}", err);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Cannot save before switching workspace: {}", err),
+            );
+            return Ok(());
+        }
+        let mut new_config = app.config.clone();
+        let workspace = match new_config.switch_workspace(&workspace) {
+            Ok(workspace) => workspace,
+            Err(err) => {
+                error!("{}", err);
+                send_error_toast(&mut app.widgets.toast_widget, &err);
+                return Ok(());
+            }
+        };
+        if let Err(err) = write_config(&new_config) {
+            error!("Cannot switch workspace: {}", err);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Cannot switch workspace: {}", err),
+            );
+            return Ok(());
+        }
+        app.config = new_config;
+        app.action_history_manager = ActionHistoryManager::default();
+        app.state.pending_sync = None;
+        // Cloud saves are listed per workspace
+        app.state.cloud_data = None;
+        let default_view = app.config.workspace_default_view();
+        app.set_view(default_view);
+        prepare_boards(&mut app);
+        auto_archive_completed_cards(&mut app);
+        apply_default_theme(&mut app);
+        app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
+        info!("Switched to workspace {}", workspace);
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Switched to workspace {}", workspace),
+        );
+        Ok(())
     }
 
     async fn load_local_preview(&mut self) -> Result<()> {
@@ -418,8 +511,94 @@ impl IoAsyncHandler<'_> {
         Ok(())
     }
 
+    /// Compares the selected save with `save_diff_target`, the older of the two saves is always
+    /// the one the changes are made from and the current boards count as newer than any save
+    async fn load_save_diff(&mut self) -> Result<()> {
+        let mut app = self.app.lock().await;
+        let Some(save_file_index) = app.state.app_list_states.load_save.selected() else {
+            return Ok(());
+        };
+        app.state.save_diff = None;
+        let local_files = get_available_local_save_files(&app.config).unwrap_or_default();
+        if save_file_index >= local_files.len() {
+            error!("Cannot compare saves: No such file");
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                "Cannot compare saves: No such file",
+            );
+            return Ok(());
+        }
+        let save_file_name = local_files[save_file_index].clone();
+        let target_index = app.state.save_diff_target.as_ref().and_then(|target| {
+            local_files
+                .iter()
+                .position(|save_file| save_file == target)
+                .filter(|target_index| *target_index != save_file_index)
+        });
+        if target_index.is_none() {
+            app.state.save_diff_target = None;
+        }
+        let load_save = |save_file_name: &str| {
+            get_local_kanban_state(save_file_name.to_string(), true, &app.config).map_err(|err| {
+                error!("Cannot compare saves: {}", err);
+                format!("Cannot compare saves: {}", err)
+            })
+        };
+        let save_diff = match target_index {
+            Some(target_index) => {
+                let (older_name, newer_name) = if target_index < save_file_index {
+                    (local_files[target_index].as_str(), save_file_name.as_str())
+                } else {
+                    (save_file_name.as_str(), local_files[target_index].as_str())
+                };
+                load_save(older_name).and_then(|older_boards| {
+                    load_save(newer_name).map(|newer_boards| {
+                        SaveDiff::new(
+                            older_name,
+                            &older_boards,
+                            newer_name,
+                            &newer_boards,
+                            app.config.date_time_format,
+                        )
+                    })
+                })
+            }
+            None => load_save(&save_file_name).map(|save_boards| {
+                SaveDiff::new(
+                    &save_file_name,
+                    &save_boards,
+                    CURRENT_BOARDS_LABEL,
+                    &app.boards,
+                    app.config.date_time_format,
+                )
+            }),
+        };
+        match save_diff {
+            Ok(save_diff) => {
+                let selected_entry = app
+                    .state
+                    .app_list_states
+                    .save_diff
+                    .selected()
+                    .unwrap_or(0)
+                    .min(save_diff.entries.len().saturating_sub(1));
+                app.state
+                    .app_list_states
+                    .save_diff
+                    .select(if save_diff.entries.is_empty() {
+                        None
+                    } else {
+                        Some(selected_entry)
+                    });
+                app.state.save_diff = Some(save_diff);
+            }
+            Err(err) => send_error_toast(&mut app.widgets.toast_widget, &err),
+        }
+        Ok(())
+    }
+
     async fn cloud_login(&mut self, email_id: String, password: String) -> Result<()> {
-        {
+        let sync_backend = {
             let mut app = self.app.lock().await;
             if app.state.user_login_data.auth_token.is_some() {
                 error!("Already logged in, Please logout first");
@@ -441,9 +620,10 @@ impl IoAsyncHandler<'_> {
                 send_error_toast(&mut app.widgets.toast_widget, "Password cannot be empty");
                 return Ok(());
             }
-        }
+            SyncBackendClient::from_config(&app.config, false)
+        };
 
-        let login_for_user_status = login_for_user(&email_id, &password, false).await;
+        let login_for_user_status = sync_backend.login(&email_id, &password).await;
         if let Err(err) = login_for_user_status {
             debug!("Error logging in: {:?}", err);
             let mut app = self.app.lock().await;
@@ -452,7 +632,7 @@ impl IoAsyncHandler<'_> {
                 send_error_toast(&mut app.widgets.toast_widget, "Invalid login credentials");
             } else {
                 error!("Error logging in");
-                send_error_toast(&mut app.widgets.toast_widget, "Error logging in");
+                send_error_toast(&mut app.widgets.toast_widget, &err);
             }
             return Ok(());
         }
@@ -484,7 +664,7 @@ impl IoAsyncHandler<'_> {
     }
 
     async fn cloud_logout(&mut self) -> Result<()> {
-        {
+        let (sync_backend, auth_token) = {
             let mut app = self.app.lock().await;
             if app.state.user_login_data.auth_token.is_none() {
                 error!("Not logged in");
@@ -494,41 +674,25 @@ impl IoAsyncHandler<'_> {
                 info!("Logging out, please wait...");
                 send_info_toast(&mut app.widgets.toast_widget, "Logging out, please wait...");
             }
-        }
-        let client = reqwest::Client::new();
-        let response = client
-            .post(format!("{}/auth/v1/logout", SUPABASE_URL))
-            .header("apikey", SUPABASE_ANON_KEY)
-            .header("Content-Type", "application/json")
-            .header(
-                "Authorization",
-                format!(
-                    "Bearer {}",
-                    self.app
-                        .lock()
-                        .await
-                        .state
-                        .user_login_data
-                        .auth_token
-                        .as_ref()
-                        .unwrap()
-                ),
+            (
+                SyncBackendClient::from_config(&app.config, false),
+                app.state.user_login_data.auth_token.clone().unwrap(),
             )
-            .send()
-            .await?;
+        };
+        let logout_status = sync_backend.logout(&auth_token).await;
 
-        let status = response.status();
-        if status == StatusCode::NO_CONTENT {
-            let mut app = self.app.lock().await;
+        let mut app = self.app.lock().await;
+        if let Err(err) = logout_status {
+            error!("Error logging out");
+            debug!("Error logging out: {}", err);
+            send_error_toast(&mut app.widgets.toast_widget, "Error logging out");
+        } else {
             app.state.user_login_data = UserLoginData::default();
             app.main_menu.logged_in = false;
             info!("👍 Logged out");
             send_info_toast(&mut app.widgets.toast_widget, "👍 Logged out");
-        } else {
-            error!("Error logging out");
-            let mut app = self.app.lock().await;
-            send_error_toast(&mut app.widgets.toast_widget, "Error logging out");
         }
+        drop(app);
         delete_refresh_token_from_disk().await?;
         Ok(())
     }
@@ -541,6 +705,19 @@ impl IoAsyncHandler<'_> {
     ) -> Result<()> {
         {
             let mut app = self.app.lock().await;
+            if app.config.sync_backend != SyncBackendType::Supabase {
+                let error_message = format!(
+                    "Sign up is only needed for the Supabase sync backend, with the {} backend login directly and generate an encryption key with the -g flag",
+                    app.config.sync_backend
+                );
+                error!("{}", error_message);
+                send_error_toast_with_duration(
+                    &mut app.widgets.toast_widget,
+                    &error_message,
+                    Duration::from_secs(10),
+                );
+                return Ok(());
+            }
             if app.state.user_login_data.auth_token.is_some() {
                 error!("Already logged in, Please logout first");
                 send_error_toast(
@@ -710,7 +887,7 @@ impl IoAsyncHandler<'_> {
                                     "Please keep this key safe, you will need it to decrypt your data, you will not be able to recover your data without it",
                                     Duration::from_secs(10),
                                 );
-                                let default_view = app.config.default_view;
+                                let default_view = app.config.workspace_default_view();
                                 app.set_view(default_view);
                             }
                         }
@@ -744,6 +921,14 @@ impl IoAsyncHandler<'_> {
     async fn send_reset_password_email(&mut self, email_id: String) -> Result<()> {
         {
             let mut app = self.app.lock().await;
+            if app.config.sync_backend != SyncBackendType::Supabase {
+                error!("Password reset is only available for the Supabase sync backend");
+                send_error_toast(
+                    &mut app.widgets.toast_widget,
+                    "Password reset is only available for the Supabase sync backend",
+                );
+                return Ok(());
+            }
             if let Some(reset_time) = app.state.last_reset_password_link_sent_time {
                 if reset_time.elapsed() < Duration::from_secs(MIN_TIME_BETWEEN_SENDING_RESET_LINK) {
                     let remaining_time = Duration::from_secs(MIN_TIME_BETWEEN_SENDING_RESET_LINK)
@@ -824,6 +1009,14 @@ impl IoAsyncHandler<'_> {
     ) -> Result<()> {
         {
             let mut app = self.app.lock().await;
+            if app.config.sync_backend != SyncBackendType::Supabase {
+                error!("Password reset is only available for the Supabase sync backend");
+                send_error_toast(
+                    &mut app.widgets.toast_widget,
+                    "Password reset is only available for the Supabase sync backend",
+                );
+                return Ok(());
+            }
             if reset_link.is_empty() {
                 error!("Reset link cannot be empty");
                 send_error_toast(&mut app.widgets.toast_widget, "Reset link cannot be empty");
@@ -1025,14 +1218,6 @@ impl IoAsyncHandler<'_> {
             }
         }
 
-        let save_ids = self.get_save_ids_for_user().await?;
-        let max_save_id = save_ids.iter().max();
-        let max_save_id = if let Some(max_save_id) = max_save_id {
-            max_save_id + 1
-        } else {
-            0
-        };
-
         let mut app = self.app.lock().await;
         let key = get_user_encryption_key(app.state.encryption_key_from_arguments.clone());
         if key.is_err() {
@@ -1048,43 +1233,196 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let key = key.unwrap();
-        let encrypt_result = encrypt_save(&app.boards, &key);
+        let auth_token = app.state.user_login_data.auth_token.clone().unwrap();
+        let user_id = app.state.user_login_data.user_id.clone().unwrap();
+        let workspace = app.config.active_workspace().to_string();
+        let sync_backend = SyncBackendClient::from_config(&app.config, false);
+        let local_boards = app.boards.clone();
+        let date_time_format = app.config.date_time_format;
+        drop(app);
+
+        let cloud_saves = sync_backend
+            .get_saves(&user_id, &auth_token, &workspace)
+            .await;
+        let cloud_saves = match cloud_saves {
+            Ok(cloud_saves) => cloud_saves,
+            Err(err) => {
+                error!("Error syncing local data");
+                debug!(
+                    "Error syncing local data: {}, could not get cloud saves",
+                    err
+                );
+                let mut app = self.app.lock().await;
+                send_error_toast(&mut app.widgets.toast_widget, "Error syncing local data");
+                return Ok(());
+            }
+        };
+        let latest_cloud_save = cloud_saves.into_iter().max_by_key(|save| save.save_id);
+        let latest_cloud_save = if let Some(latest_cloud_save) = latest_cloud_save {
+            latest_cloud_save
+        } else {
+            return self
+                .upload_synced_boards(
+                    &sync_backend,
+                    (&user_id, &auth_token, &workspace),
+                    &key,
+                    &local_boards,
+                    0,
+                )
+                .await;
+        };
+        let next_save_id = latest_cloud_save.save_id + 1;
+
+        let sync_base = get_sync_base(&user_id, &workspace, date_time_format);
+        if let Some(sync_base) = &sync_base {
+            if sync_base.save_id == latest_cloud_save.save_id {
+                // Nothing was synced from elsewhere since our last sync, so there is nothing to merge
+                if sync_base.boards == local_boards {
+                    info!("👍 Local data is already in sync with the cloud");
+                    let mut app = self.app.lock().await;
+                    send_info_toast(
+                        &mut app.widgets.toast_widget,
+                        "👍 Local data is already in sync with the cloud",
+                    );
+                    return Ok(());
+                }
+                return self
+                    .upload_synced_boards(
+                        &sync_backend,
+                        (&user_id, &auth_token, &workspace),
+                        &key,
+                        &local_boards,
+                        next_save_id,
+                    )
+                    .await;
+            }
+        }
+
+        let remote_boards = decrypt_save(
+            latest_cloud_save.board_data.clone(),
+            key.as_slice(),
+            &latest_cloud_save.nonce,
+            date_time_format,
+        );
+        let remote_boards = match remote_boards {
+            Ok(remote_boards) => remote_boards,
+            Err(err) => {
+                error!("Error syncing local data, Could not decrypt the latest cloud save. It must have been created with a different encryption key");
+                debug!("Error syncing local data: {}", err);
+                let mut app = self.app.lock().await;
+                send_error_toast_with_duration(
+                    &mut app.widgets.toast_widget,
+                    "Error syncing local data, Could not decrypt the latest cloud save. It must have been created with a different encryption key",
+                    Duration::from_secs(5),
+                );
+                return Ok(());
+            }
+        };
+        let base_boards = sync_base
+            .map(|sync_base| sync_base.boards)
+            .unwrap_or_default();
+        let sync_merge = merge_boards(&base_boards, &local_boards, &remote_boards);
+
+        let mut app = self.app.lock().await;
+        if app.boards != local_boards {
+            warn!("Boards changed while syncing, not applying the merge");
+            send_warning_toast(
+                &mut app.widgets.toast_widget,
+                "Boards changed while syncing, please sync again",
+            );
+            return Ok(());
+        }
+        if !sync_merge.conflicts.is_empty() {
+            let conflict_message = format!(
+                "{} cards were changed both locally and in the cloud, please pick the version to keep",
+                sync_merge.conflicts.len()
+            );
+            warn!("{}", conflict_message);
+            send_warning_toast(&mut app.widgets.toast_widget, &conflict_message);
+            app.state.pending_sync = Some(PendingSync {
+                conflicts: sync_merge.conflicts,
+                current_conflict: 0,
+                merged: sync_merge.merged,
+                remote_boards,
+                remote_save_id: latest_cloud_save.save_id,
+            });
+            app.set_popup(PopUp::SyncConflicts);
+            return Ok(());
+        }
+        if sync_merge.merged != local_boards {
+            app.boards.set_boards(sync_merge.merged.clone());
+            refresh_visible_boards_and_cards(&mut app);
+            info!("Local data updated with changes from the cloud");
+        }
+        if sync_merge.merged == remote_boards {
+            if let Err(err) = save_sync_base(
+                &user_id,
+                &workspace,
+                latest_cloud_save.save_id,
+                &remote_boards,
+            ) {
+                error!("Error saving sync base: {}", err);
+            }
+            info!("👍 Local data synced with the cloud");
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                "👍 Local data synced with the cloud",
+            );
+            return Ok(());
+        }
+        drop(app);
+        self.upload_synced_boards(
+            &sync_backend,
+            (&user_id, &auth_token, &workspace),
+            &key,
+            &sync_merge.merged,
+            next_save_id,
+        )
+        .await
+    }
+
+    /// Uploads the boards as a new cloud save of the workspace and makes them the base for its
+    /// next sync
+    async fn upload_synced_boards(
+        &mut self,
+        sync_backend: &SyncBackendClient,
+        (user_id, auth_token, workspace): (&str, &str, &str),
+        key: &[u8],
+        boards: &Boards,
+        save_id: usize,
+    ) -> Result<()> {
+        let encrypt_result = encrypt_save(boards, key);
         if encrypt_result.is_err() {
             error!("Error syncing local data");
             debug!(
                 "Error syncing local data: {:?}, could not encrypt",
                 encrypt_result.err()
             );
+            let mut app = self.app.lock().await;
             send_error_toast(&mut app.widgets.toast_widget, "Error syncing local data");
             return Ok(());
         }
         let (encrypted_board_data, nonce) = encrypt_result.unwrap();
-        let auth_token = app.state.user_login_data.auth_token.clone().unwrap();
-        let user_id = app.state.user_login_data.user_id.clone().unwrap();
-        drop(app);
-        let client = reqwest::Client::new();
-        let response = client
-            .post(format!("{}/rest/v1/user_data", SUPABASE_URL))
-            .header("apikey", SUPABASE_ANON_KEY)
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", auth_token))
-            .body(
-                json!(
-                    {
-                        "user_id": user_id,
-                        "board_data": encrypted_board_data,
-                        "save_id": max_save_id,
-                        "nonce": nonce
-                    }
-                )
-                .to_string(),
+        let upload_status = sync_backend
+            .upload_save(
+                user_id,
+                auth_token,
+                workspace,
+                save_id,
+                &encrypted_board_data,
+                &nonce,
             )
-            .send()
-            .await?;
+            .await;
 
-        let status = response.status();
         let mut app = self.app.lock().await;
-        if status == StatusCode::CREATED {
+        if let Err(err) = upload_status {
+            error!("Error syncing local data");
+            debug!("Error syncing local data: {}", err);
+            send_error_toast(&mut app.widgets.toast_widget, "Error syncing local data");
+        } else {
+            if let Err(err) = save_sync_base(user_id, workspace, save_id, boards) {
+                error!("Error saving sync base: {}", err);
+            }
             info!("👍 Local data synced to the cloud");
             send_info_toast(
                 &mut app.widgets.toast_widget,
@@ -1093,48 +1431,41 @@ impl IoAsyncHandler<'_> {
             if app.state.cloud_data.is_some() {
                 app.dispatch(IoEvent::GetCloudData).await;
             }
-        } else {
-            error!("Error syncing local data");
-            debug!("Error syncing local data: {:?}", response.text().await);
-            send_error_toast(&mut app.widgets.toast_widget, "Error syncing local data");
         }
         Ok(())
     }
 
-    async fn get_save_ids_for_user(&mut self) -> Result<Vec<usize>> {
-        {
-            let mut app = self.app.lock().await;
-            if app.state.user_login_data.auth_token.is_none() {
-                error!("Not logged in");
-                send_error_toast(&mut app.widgets.toast_widget, "Not logged in");
-                return Ok(vec![]);
-            }
-        }
-
-        let (user_id, access_token) = {
-            let app = self.app.lock().await;
-            let user_id = app.state.user_login_data.user_id.as_ref().unwrap().clone();
-            let access_token = app
-                .state
-                .user_login_data
-                .auth_token
-                .as_ref()
-                .unwrap()
-                .clone();
-            (user_id, access_token)
+    async fn apply_sync_resolution(&mut self) -> Result<()> {
+        let mut app = self.app.lock().await;
+        let pending_sync = if let Some(pending_sync) = app.state.pending_sync.take() {
+            pending_sync
+        } else {
+            debug!("No pending sync to apply");
+            return Ok(());
         };
-
-        let result = get_all_save_ids_for_user(user_id, &access_token).await;
-        if result.is_err() {
-            let error_string = format!("{:?}", result.err());
-            error!("{}", error_string);
-            let mut app = self.app.lock().await;
-            send_error_toast(&mut app.widgets.toast_widget, &error_string);
-            Ok(vec![])
+        let user_id = if let Some(user_id) = app.state.user_login_data.user_id.clone() {
+            user_id
         } else {
-            let save_ids = result.unwrap();
-            Ok(save_ids)
+            error!("Not logged in");
+            send_error_toast(&mut app.widgets.toast_widget, "Not logged in");
+            return Ok(());
+        };
+        app.boards.set_boards(pending_sync.merged);
+        refresh_visible_boards_and_cards(&mut app);
+        // With the cloud save we merged against as the base, syncing again just uploads the
+        // resolved boards unless someone else synced in the meantime
+        if let Err(err) = save_sync_base(
+            &user_id,
+            app.config.active_workspace(),
+            pending_sync.remote_save_id,
+            &pending_sync.remote_boards,
+        ) {
+            error!("Error saving sync base: {}", err);
+            send_error_toast(&mut app.widgets.toast_widget, "Error syncing local data");
+            return Ok(());
         }
+        drop(app);
+        self.sync_local_data().await
     }
 
     async fn get_cloud_data(&mut self) -> Result<()> {
@@ -1156,35 +1487,25 @@ impl IoAsyncHandler<'_> {
 
         let app = self.app.lock().await;
         let auth_token = app.state.user_login_data.auth_token.clone().unwrap();
+        let user_id = app.state.user_login_data.user_id.clone().unwrap();
+        let workspace = app.config.active_workspace().to_string();
+        let sync_backend = SyncBackendClient::from_config(&app.config, false);
         drop(app);
-        let client = reqwest::Client::new();
-        let response = client
-            .get(format!("{}/rest/v1/user_data", SUPABASE_URL))
-            .header("apikey", SUPABASE_ANON_KEY)
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", auth_token))
-            .send()
-            .await?;
+        let cloud_data = sync_backend
+            .get_saves(&user_id, &auth_token, &workspace)
+            .await;
 
         let mut app = self.app.lock().await;
-
-        let status = response.status();
-        if status == StatusCode::OK {
-            let body = response.json::<Vec<CloudData>>().await;
-            match body {
-                Ok(cloud_data) => {
-                    app.state.cloud_data = Some(cloud_data);
-                    info!("👍 Cloud data loaded");
-                    send_info_toast(&mut app.widgets.toast_widget, "👍 Cloud data loaded");
-                }
-                Err(e) => {
-                    error!("Error Refreshing cloud data: {}", e);
-                    send_error_toast(&mut app.widgets.toast_widget, "Error Refreshing cloud data");
-                }
+        match cloud_data {
+            Ok(cloud_data) => {
+                app.state.cloud_data = Some(cloud_data);
+                info!("👍 Cloud data loaded");
+                send_info_toast(&mut app.widgets.toast_widget, "👍 Cloud data loaded");
+            }
+            Err(e) => {
+                error!("Error Refreshing cloud data: {}", e);
+                send_error_toast(&mut app.widgets.toast_widget, "Error Refreshing cloud data");
             }
-        } else {
-            error!("Error Refreshing cloud data");
-            send_error_toast(&mut app.widgets.toast_widget, "Error Refreshing cloud data");
         }
         Ok(())
     }
@@ -1222,7 +1543,12 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let key = key.unwrap();
-        let decrypt_result = decrypt_save(save.board_data, key.as_slice(), &save.nonce);
+        let decrypt_result = decrypt_save(
+            save.board_data,
+            key.as_slice(),
+            &save.nonce,
+            app.config.date_time_format,
+        );
         if decrypt_result.is_err() {
             error!("Error loading save file, Could not decrypt save file. The save file must have been created with a different encryption key, either generate a new one with the -g flag or replace the current encryption key with the one used to create the save file");
             debug!("Error loading save file: {:?}", decrypt_result.err());
@@ -1289,7 +1615,7 @@ impl IoAsyncHandler<'_> {
 
     async fn load_save_file_cloud(&mut self) -> Result<()> {
         let mut app = self.app.lock().await;
-        let default_view = app.config.default_view;
+        let default_view = app.config.workspace_default_view();
         let save_file_index = app.state.app_list_states.load_save.selected().unwrap_or(0);
         let cloud_saves = app.state.cloud_data.clone();
         let local_files = if let Some(cloud_saves) = cloud_saves {
@@ -1327,6 +1653,7 @@ impl IoAsyncHandler<'_> {
             encrypted_board_data.to_string(),
             key.as_slice(),
             &local_files[save_file_index].nonce,
+            app.config.date_time_format,
         );
         if decrypt_result.is_err() {
             error!("Error loading save file, Could not decrypt save file. The save file must have been created with a different encryption key, either generate a new one with the -g flag or replace the current encryption key with the one used to create the save file");
@@ -1339,7 +1666,26 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let decrypt_result = decrypt_result.unwrap();
-        app.boards.set_boards(decrypt_result);
+        // Changes made after loading are merged against this save on the next sync
+        if let Some(user_id) = app.state.user_login_data.user_id.clone() {
+            if let Err(err) = save_sync_base(
+                &user_id,
+                app.config.active_workspace(),
+                save_file_number,
+                &decrypt_result,
+            ) {
+                error!("Error saving sync base: {}", err);
+            }
+        }
+        let previous_boards = app.boards.clone();
+        app.boards.set_boards(decrypt_result.clone());
+        app.state.latest_save_load_error = None;
+        app.action_history_manager
+            .new_action(ActionHistory::LoadSave(
+                format!("cloud_save_{}", save_file_number),
+                previous_boards,
+                decrypt_result,
+            ));
         info!("👍 Save file cloud_save_{} loaded", save_file_number);
         send_info_toast(
             &mut app.widgets.toast_widget,
@@ -1369,6 +1715,9 @@ impl IoAsyncHandler<'_> {
         let mut app = self.app.lock().await;
         let save_file_index = app.state.app_list_states.load_save.selected().unwrap_or(0);
         let user_access_token = app.state.user_login_data.auth_token.clone().unwrap();
+        let user_id = app.state.user_login_data.user_id.clone().unwrap();
+        let workspace = app.config.active_workspace().to_string();
+        let sync_backend = SyncBackendClient::from_config(&app.config, false);
         let cloud_saves = app.state.cloud_data.clone();
         let cloud_saves = if let Some(cloud_saves) = cloud_saves {
             cloud_saves
@@ -1391,13 +1740,15 @@ impl IoAsyncHandler<'_> {
         drop(app);
         let save_file_id = cloud_saves[save_file_index].id;
         let save_number = cloud_saves[save_file_index].save_id;
-        let delete_status =
-            delete_a_save_from_database(&user_access_token, false, save_file_id, Some(save_number))
-                .await;
+        let delete_status = sync_backend
+            .delete_save(&user_id, &user_access_token, &workspace, save_file_id)
+            .await;
         let mut app = self.app.lock().await;
-        if delete_status.is_err() {
+        if let Err(err) = delete_status {
+            error!("Error deleting cloud save: {}", err);
             send_error_toast(&mut app.widgets.toast_widget, "Error deleting cloud save");
         } else {
+            info!("👍 Cloud save cloud_save_{} deleted", save_number);
             send_info_toast(
                 &mut app.widgets.toast_widget,
                 &format!("👍 Cloud save cloud_save_{} deleted", save_number),
@@ -1468,6 +1819,32 @@ fn prepare_save_dir() -> bool {
 }
 
 fn prepare_boards(app: &mut App) {
+    let save_directory = app.config.workspace_save_directory();
+    if !save_directory.exists() {
+        if let Err(err) = std::fs::create_dir_all(&save_directory) {
+            error!("Cannot create save directory {:?}: {}", save_directory, err);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                "Cannot create save directory",
+            );
+        }
+    }
+    match recover_interrupted_saves(&app.config) {
+        Ok(recovered) => {
+            for message in recovered {
+                warn!("{}", message);
+                send_warning_toast(&mut app.widgets.toast_widget, &message);
+            }
+        }
+        Err(err) => {
+            error!("Cannot recover from an interrupted save: {}", err);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Cannot recover from an interrupted save: {}", err),
+            );
+        }
+    }
+    app.state.latest_save_load_error = None;
     let boards = if app.config.always_load_last_save {
         let latest_save_file_info = get_latest_save_file(&app.config);
         if let Ok(latest_save_file) = latest_save_file_info {
@@ -1479,12 +1856,32 @@ fn prepare_boards(app: &mut App) {
                         &mut app.widgets.toast_widget,
                         &format!("👍 Local data loaded from {:?}", latest_save_file),
                     );
+                    match get_action_history(&latest_save_file, &app.config) {
+                        Ok(Some(action_history_manager)) => {
+                            info!(
+                                "Restored {} undo history entries",
+                                action_history_manager.history.len()
+                            );
+                            app.action_history_manager = action_history_manager;
+                        }
+                        Ok(None) => {}
+                        Err(err) => {
+                            error!("Cannot restore undo history: {}", err);
+                            send_warning_toast(
+                                &mut app.widgets.toast_widget,
+                                &format!("Cannot restore undo history: {}", err),
+                            );
+                        }
+                    }
                     data
                 }
                 Err(err) => {
-                    debug!("Cannot get local data: {:?}", err);
-                    error!("👎 Cannot get local data, Data might be corrupted or is not in the correct format");
-                    send_error_toast(&mut app.widgets.toast_widget,"👎 Cannot get local data, Data might be corrupted or is not in the correct format");
+                    error!("👎 Cannot get local data: {}", err);
+                    send_error_toast(
+                        &mut app.widgets.toast_widget,
+                        &format!("👎 Cannot get local data: {}", err),
+                    );
+                    app.state.latest_save_load_error = Some(err);
                     Boards::default()
                 }
             }
@@ -1493,12 +1890,87 @@ fn prepare_boards(app: &mut App) {
         }
     } else {
         app.set_view(View::LoadLocalSave);
-        Boards::default()
+        app.boards.set_boards(Boards::default());
+        let latest_saved_boards = get_latest_saved_boards(&app.config);
+        app.set_saved_boards(latest_saved_boards);
+        app.state.last_save_time = None;
+        return;
     };
     app.boards.set_boards(boards);
+    app.mark_boards_saved();
+    app.state.last_save_time = None;
+}
+
+/// The boards in the latest save, empty if there is none or it can not be read
+fn get_latest_saved_boards(config: &AppConfig) -> Boards {
+    get_latest_save_file(config)
+        .ok()
+        .and_then(|latest_save_file| get_local_kanban_state(latest_save_file, true, config).ok())
+        .unwrap_or_default()
+}
+
+/// The default theme of the workspace, saved themes included
+fn apply_default_theme(app: &mut App) {
+    let default_theme = app.config.workspace_default_theme();
+    if let Some(theme) = app
+        .all_themes
+        .iter()
+        .find(|theme| theme.name == default_theme)
+    {
+        app.current_theme = theme.clone();
+    }
+    if let Some(bg_color) = app.current_theme.general_style.bg {
+        app.state.term_background_color = TextColorOptions::from(bg_color).to_rgb();
+    } else {
+        app.state.term_background_color = (0, 0, 0)
+    }
+}
+
+/// Archives the cards that have been complete for longer than the auto archive config allows,
+/// as a single undoable action
+fn auto_archive_completed_cards(app: &mut App) {
+    if app.config.auto_archive_completed_days == 0 {
+        return;
+    }
+    let archived_cards = app
+        .boards
+        .archive_completed_cards(app.config.auto_archive_completed_days);
+    if archived_cards.is_empty() {
+        return;
+    }
+    info!("Archived {} completed cards", archived_cards.len());
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Archived {} completed cards", archived_cards.len()),
+    );
+    app.action_history_manager
+        .new_action(ActionHistory::ArchiveCards(archived_cards));
+}
+
+/// The newest of the journal and the latest snapshot save. Both are checked whatever the storage
+/// mode is, so switching modes keeps loading the saves written before the switch
+pub(crate) fn get_latest_save_file(config: &AppConfig) -> Result<String, String> {
+    let latest_snapshot_file = get_latest_snapshot_file(config);
+    let journal_path = get_journal_path(config);
+    if !journal_path.exists() {
+        return latest_snapshot_file;
+    }
+    let Ok(latest_snapshot_file) = latest_snapshot_file else {
+        return Ok(JOURNAL_FILE_NAME.to_string());
+    };
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    let snapshot_path = config
+        .workspace_save_directory()
+        .join(&latest_snapshot_file);
+    if modified(&snapshot_path) > modified(&journal_path) {
+        Ok(latest_snapshot_file)
+    } else {
+        Ok(JOURNAL_FILE_NAME.to_string())
+    }
 }
 
-fn get_latest_save_file(config: &AppConfig) -> Result<String, String> {
+/// The save with the highest version of the latest day
+fn get_latest_snapshot_file(config: &AppConfig) -> Result<String, String> {
     let local_save_files = get_available_local_save_files(config);
     let local_save_files = if let Some(local_save_files) = local_save_files {
         local_save_files
@@ -1604,24 +2076,34 @@ pub fn make_file_system_safe_name(name: &str) -> String {
     safe_name
 }
 
+/// Saves the boards if they changed since the latest save, the undo history is kept up to date
+/// either way so it matches the save the boards are in
 pub async fn auto_save(app: &mut App<'_>) -> Result<(), String> {
-    if save_required(app) {
-        save_kanban_state_locally(app.boards.get_boards().to_vec(), &app.config)
+    if let Some(err) = &app.state.latest_save_load_error {
+        return Err(format!("the latest save could not be loaded: {}", err));
+    }
+    let save_file_name = if app.has_unsaved_changes() {
+        let save_file_name =
+            save_kanban_state_locally(app.boards.get_boards().to_vec(), &app.config)?;
+        app.mark_boards_saved();
+        app.state.last_save_time = Some(Local::now());
+        save_file_name
+    } else if let Ok(latest_save_file) = get_latest_save_file(&app.config) {
+        latest_save_file
     } else {
-        Ok(())
-    }
+        return Ok(());
+    };
+    save_action_history(&app.action_history_manager, &save_file_name, &app.config)
 }
 
-fn save_required(app: &mut App) -> bool {
-    let latest_save_file_info = get_latest_save_file(&app.config);
-    if let Ok(save_file_name) = latest_save_file_info {
-        let board_data = get_local_kanban_state(save_file_name, false, &app.config);
-        match board_data {
-            Ok(boards) => app.boards != boards,
-            Err(_) => true,
-        }
-    } else {
-        true
+fn save_action_history_with_save_file(app: &mut App, save_file_name: &str) {
+    if let Err(err) = save_action_history(&app.action_history_manager, save_file_name, &app.config)
+    {
+        error!("Cannot save undo history: {}", err);
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Cannot save undo history: {}", err),
+        );
     }
 }
 
@@ -1670,11 +2152,13 @@ fn check_for_safe_password(check_password: &str) -> PasswordStatus {
 
 fn encrypt_save(boards: &Boards, key: &[u8]) -> Result<(String, String), String> {
     let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
-    let boards_json = serde_json::to_string(&boards);
+    let boards_json = serde_json::to_value(boards);
     if boards_json.is_err() {
         return Err("Error serializing boards".to_string());
     }
-    let boards_json = boards_json.unwrap();
+    let mut boards_json = boards_json.unwrap();
+    boards_json["schema_version"] = serde_json::Value::from(SAVE_SCHEMA_VERSION);
+    let boards_json = boards_json.to_string();
     let key = Key::<Aes256Gcm>::from_slice(key);
     let cipher = Aes256Gcm::new(key);
     let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
@@ -1693,6 +2177,7 @@ fn decrypt_save(
     encrypted_boards: String,
     key: &[u8],
     encoded_nonce: &str,
+    date_time_format: DateTimeFormat,
 ) -> Result<Boards, String> {
     let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
     let encrypted_boards = base64_engine.decode(encrypted_boards);
@@ -1718,11 +2203,21 @@ fn decrypt_save(
         return Err("Error converting decrypted boards to string".to_string());
     }
     let decrypted_board_data = decrypted_board_data.unwrap();
-    let boards = serde_json::from_str(&decrypted_board_data);
+    let boards: Result<serde_json::Value, _> = serde_json::from_str(&decrypted_board_data);
     if boards.is_err() {
         return Err("Error deserializing boards".to_string());
     }
-    Ok(boards.unwrap())
+    let mut boards = boards.unwrap();
+    migrate_save(&mut boards, date_time_format)?;
+    let boards = match boards["boards"].as_array() {
+        Some(boards) => boards,
+        None => return Err("Error deserializing boards".to_string()),
+    };
+    let boards = boards
+        .iter()
+        .map(Board::from_json)
+        .collect::<Result<Vec<Board>, String>>()?;
+    Ok(Boards::from(boards))
 }
 
 pub fn save_user_encryption_key(key: &[u8]) -> Result<String> {
@@ -1772,439 +2267,6 @@ pub fn generate_new_encryption_key() -> Vec<u8> {
     Aes256Gcm::generate_key(&mut OsRng).to_vec()
 }
 
-pub async fn get_all_save_ids_for_user(user_id: String, access_token: &str) -> Result<Vec<usize>> {
-    let client = reqwest::Client::new();
-    let response = client
-        .get(format!(
-            "{}/rest/v1/user_data?user_id=eq.{}&select=save_id",
-            SUPABASE_URL, user_id
-        ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("Range", "0-9")
-        .send()
-        .await;
-    if response.is_err() {
-        debug!("Error getting save ids: {:?}", response.err());
-        return Err(anyhow!("Error getting save ids".to_string()));
-    }
-    let response = response.unwrap();
-    let status = response.status();
-    if status == StatusCode::OK {
-        let body = response.json::<serde_json::Value>().await;
-        match body {
-            Ok(save_instances) => {
-                let mut save_ids: Vec<usize> = Vec::new();
-                let save_instances_as_array = save_instances.as_array();
-                if save_instances_as_array.is_none() {
-                    return Err(anyhow!("Error getting save ids".to_string()));
-                }
-                let save_instances_as_array = save_instances_as_array.unwrap();
-                for save_instance in save_instances_as_array {
-                    let save_id = save_instance.get("save_id");
-                    if save_id.is_none() {
-                        return Err(anyhow!("Error getting save ids".to_string()));
-                    }
-                    let save_id = save_id.unwrap().as_u64();
-                    if save_id.is_none() {
-                        return Err(anyhow!("Error getting save ids".to_string()));
-                    }
-                    debug!("Found save_id: {:?}", save_id.unwrap() as usize);
-                    save_ids.push(save_id.unwrap() as usize);
-                }
-                debug!("Save instances: {:?}", save_instances);
-                Ok(save_ids)
-            }
-            Err(e) => {
-                debug!("Error getting save ids: {:?}", e);
-                Err(anyhow!("Error getting save ids".to_string()))
-            }
-        }
-    } else {
-        debug!("Status: {:?}", status);
-        debug!("Error getting save ids: {:?}", response.text().await);
-        Err(anyhow!("Error getting save ids".to_string()))
-    }
-}
-
-pub async fn get_all_save_ids_and_creation_dates_for_user(
-    user_id: String,
-    access_token: &str,
-    cli_mode: bool,
-) -> Result<Vec<(usize, String, usize)>> {
-    let client = reqwest::Client::new();
-    let response = client
-        .get(format!(
-            "{}/rest/v1/user_data?user_id=eq.{}&select=save_id,created_at,id",
-            SUPABASE_URL, user_id
-        ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
-        .await;
-    if let Err(e) = response {
-        debug!("Error getting save ids and created_at: {:?}", e);
-        return Err(anyhow!("Error getting save ids".to_string()));
-    }
-    let response = response.unwrap();
-    let status = response.status();
-    if status == StatusCode::OK {
-        let body = response.json::<serde_json::Value>().await;
-        match body {
-            Ok(save_instances) => {
-                let mut save_ids_and_creation_dates: Vec<(usize, String, usize)> = Vec::new();
-                let save_instances_as_array = save_instances.as_array();
-                if save_instances_as_array.is_none() {
-                    return Err(anyhow!("Error getting save ids".to_string()));
-                }
-                let save_instances_as_array = save_instances_as_array.unwrap().to_owned();
-                for save_instance in save_instances_as_array {
-                    let save_id = save_instance.get("save_id");
-                    if save_id.is_none() {
-                        return Err(anyhow!("Error getting save ids".to_string()));
-                    }
-                    let save_id = save_id.unwrap().as_u64();
-                    if save_id.is_none() {
-                        return Err(anyhow!("Error getting save ids".to_string()));
-                    }
-                    let save_id = save_id.unwrap() as usize;
-                    let created_at = save_instance.get("created_at");
-                    if created_at.is_none() {
-                        return Err(anyhow!("Error getting save ids".to_string()));
-                    }
-                    let created_at = created_at.unwrap().as_str();
-                    if created_at.is_none() {
-                        return Err(anyhow!("Error getting save ids".to_string()));
-                    }
-                    let created_at = created_at.unwrap().to_owned();
-                    let id = save_instance.get("id");
-                    if id.is_none() {
-                        return Err(anyhow!("Error getting save ids".to_string()));
-                    }
-                    let id = id.unwrap().as_u64();
-                    if id.is_none() {
-                        return Err(anyhow!("Error getting save ids".to_string()));
-                    }
-                    let id = id.unwrap() as usize;
-                    if cli_mode {
-                        print_debug(&format!("save_id: {:?}", save_id));
-                        print_debug(&format!("created_at: {:?}", created_at));
-                        print_debug(&format!("id: {:?}", id));
-                    } else {
-                        debug!("save_id: {:?}", save_id);
-                        debug!("created_at: {:?}", created_at);
-                        debug!("id: {:?}", id);
-                    }
-                    save_ids_and_creation_dates.push((save_id, created_at, id));
-                }
-                if cli_mode {
-                    print_debug(&format!("save_instances: {:?}", save_instances));
-                } else {
-                    debug!("save_instances: {:?}", save_instances);
-                }
-                Ok(save_ids_and_creation_dates)
-            }
-            Err(e) => {
-                if cli_mode {
-                    print_debug(&format!("Error getting save ids: {:?}", e));
-                } else {
-                    debug!("Error getting save ids: {:?}", e);
-                }
-                Err(anyhow!("Error getting save ids".to_string()))
-            }
-        }
-    } else {
-        if cli_mode {
-            print_debug(&format!("Status: {:?}", status));
-            print_debug(&format!(
-                "Error getting save ids: {:?}",
-                response.text().await
-            ));
-        } else {
-            debug!("Status: {:?}", status);
-            debug!("Error getting save ids: {:?}", response.text().await);
-        }
-        Err(anyhow!("Error getting save ids".to_string()))
-    }
-}
-
-pub async fn delete_a_save_from_database(
-    access_token: &str,
-    cli_mode: bool,
-    save_id: u64,
-    save_number: Option<usize>,
-) -> Result<String> {
-    let client = reqwest::Client::new();
-    let response = client
-        .delete(format!(
-            "{}/rest/v1/user_data?id=eq.{}",
-            SUPABASE_URL, save_id
-        ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
-        .await?;
-    let status = response.status();
-    if status == StatusCode::NO_CONTENT {
-        if cli_mode {
-            if save_number.is_some() {
-                print_info(&format!("👍 Cloud save {} deleted", save_number.unwrap()));
-            } else {
-                print_info("👍 Cloud save deleted");
-            }
-            Ok("👍 Cloud save deleted".to_string())
-        } else if save_number.is_some() {
-            info!("👍 Cloud save {} deleted", save_number.unwrap());
-            Ok(format!("👍 Cloud save {} deleted", save_number.unwrap()).to_string())
-        } else {
-            info!("👍 Cloud save deleted");
-            Ok("👍 Cloud save deleted".to_string())
-        }
-    } else {
-        let body = response.json::<serde_json::Value>().await;
-        if cli_mode {
-            print_error("Error deleting cloud save");
-            print_debug(&format!(
-                "status code {}, response body: {:?}",
-                status, body
-            ));
-        } else {
-            error!("Error deleting cloud save");
-            debug!("status code {}, response body: {:?}", status, body);
-        }
-        Err(anyhow!("Error deleting cloud save"))
-    }
-}
-
-pub async fn get_user_id_from_database(access_token: &str, cli_mode: bool) -> Result<String> {
-    let user_data_client = reqwest::Client::new();
-    let user_data_response = user_data_client
-        .get(format!("{}/auth/v1/user", SUPABASE_URL))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
-        .await?;
-    let user_data_status = user_data_response.status();
-    let user_data_body = user_data_response.json::<serde_json::Value>().await;
-    if user_data_status != StatusCode::OK {
-        if cli_mode {
-            print_error("Error retrieving user data");
-            print_debug(&format!(
-                "status code {}, response body: {:?}",
-                user_data_status, user_data_body
-            ));
-        } else {
-            error!("Error retrieving user data");
-            debug!(
-                "status code {}, response body: {:?}",
-                user_data_status, user_data_body
-            );
-        }
-        return Err(anyhow!("Error retrieving user data"));
-    }
-    let user_data_body = user_data_body.unwrap();
-    let user_id = user_data_body.get("id");
-    if user_id.is_none() {
-        if cli_mode {
-            print_error("Error retrieving user data");
-            print_debug(&format!(
-                "status code {}, response body: {:?}, could not find id",
-                user_data_status, user_data_body
-            ));
-        } else {
-            error!("Error retrieving user data");
-            debug!(
-                "status code {}, response body: {:?}, could not find id",
-                user_data_status, user_data_body
-            );
-        }
-        return Err(anyhow!("Error retrieving user data"));
-    }
-    let user_id = user_id.unwrap().as_str();
-    if cli_mode {
-        print_debug(&format!("user_id: {:?}", user_id));
-    } else {
-        debug!("user_id: {:?}", user_id);
-    }
-    Ok(user_id.unwrap().to_string())
-}
-
-pub async fn login_for_user(
-    email_id: &str,
-    password: &str,
-    cli_mode: bool,
-) -> Result<(String, String, String), String> {
-    let request_body = json!(
-        {
-            "email": email_id,
-            "password": password
-        }
-    );
-    let client = reqwest::Client::new();
-    let response = client
-        .post(format!(
-            "{}/auth/v1/token?grant_type=password",
-            SUPABASE_URL
-        ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .body(request_body.to_string())
-        .send()
-        .await;
-    if let Err(e) = response {
-        if cli_mode {
-            print_debug(&format!("Error logging in: {}", e));
-            print_error("Error logging in, Something went wrong, please try again later");
-        } else {
-            debug!("Error logging in: {}", e);
-            error!("Error logging in, Something went wrong, please try again later");
-        }
-        return Err("Error logging in, Something went wrong, please try again later".to_string());
-    }
-    let response = response.unwrap();
-    let status = response.status();
-    let body = response.json::<serde_json::Value>().await;
-    if status == StatusCode::OK {
-        match body {
-            Ok(body) => {
-                let access_token = body.get("access_token");
-                let refresh_token = body.get("refresh_token");
-                let access_token_result = match access_token {
-                    Some(access_token) => {
-                        let access_token = access_token.as_str().unwrap();
-                        if cli_mode {
-                            print_info("🚀 Login successful");
-                            print_debug(&format!("Access token: {}", access_token));
-                        } else {
-                            info!("🚀 Login successful");
-                            debug!("Access token: {}", access_token);
-                        }
-                        let user_id = get_user_id_from_database(access_token, cli_mode)
-                            .await
-                            .unwrap_or_else(|_| "Error getting user id".to_string());
-                        Ok((access_token.to_string(), user_id))
-                    }
-                    None => {
-                        if cli_mode {
-                            print_error("Error logging in");
-                            print_debug(&format!(
-                                "status code {}, response body: {:?}, could not find access token",
-                                status, body
-                            ));
-                        } else {
-                            error!("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer");
-                            debug!(
-                                "status code {}, response body: {:?}, could not find access token",
-                                status, body
-                            );
-                        }
-                        Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
-                    }
-                };
-                let refresh_token_result = match refresh_token {
-                    Some(refresh_token) => {
-                        let refresh_token = refresh_token.as_str().unwrap();
-                        if cli_mode {
-                            print_debug(&format!("Refresh token: {}", refresh_token));
-                        } else {
-                            debug!("Refresh token: {}", refresh_token);
-                        }
-                        Ok(refresh_token.to_string())
-                    }
-                    None => {
-                        if cli_mode {
-                            print_error("Error logging in");
-                            print_debug(&format!(
-                                "status code {}, response body: {:?}, could not find refresh token",
-                                status, body
-                            ));
-                        } else {
-                            error!("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer");
-                            debug!(
-                                "status code {}, response body: {:?}, could not find refresh token",
-                                status, body
-                            );
-                        }
-                        Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
-                    }
-                };
-
-                if access_token_result.is_err() || refresh_token_result.is_err() {
-                    Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
-                } else {
-                    let access_token_result = access_token_result.unwrap();
-                    let refresh_token_result = refresh_token_result.unwrap();
-                    Ok((
-                        access_token_result.0,
-                        access_token_result.1,
-                        refresh_token_result,
-                    ))
-                }
-            }
-            Err(e) => Err(format!("Error logging in: {}", e)),
-        }
-    } else if status == StatusCode::TOO_MANY_REQUESTS {
-        if cli_mode {
-            print_error("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢");
-            print_debug(&format!(
-                "status code {}, response body: {:?}",
-                status, body
-            ));
-        } else {
-            error!("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢");
-            debug!("status code {}, response body: {:?}", status, body);
-        }
-        Err("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢".to_string())
-    } else {
-        match body {
-            Ok(body) => {
-                let error_description = body.get("error_description");
-                match error_description {
-                    Some(error_description) => {
-                        let error_description = error_description.to_string();
-                        if cli_mode {
-                            print_error(&error_description);
-                            print_debug(&format!(
-                                "status code {}, response body: {:?}",
-                                status, body
-                            ));
-                        } else {
-                            error!("{}", error_description);
-                            debug!("status code {}, response body: {:?}", status, body);
-                        }
-                        Err(format!("Error logging in: {}", error_description))
-                    }
-                    None => {
-                        if cli_mode {
-                            print_error("Error logging in");
-                            print_debug(&format!(
-                                "status code {}, response body: {:?}",
-                                status, body
-                            ));
-                        } else {
-                            error!("Error logging in");
-                            debug!("status code {}, response body: {:?}", status, body);
-                        }
-                        Err("Error logging in".to_string())
-                    }
-                }
-            }
-            Err(e) => {
-                if cli_mode {
-                    print_error(&format!("Error logging in: {}", e));
-                } else {
-                    error!("Error logging in: {}", e);
-                }
-                Err(format!("Error logging in: {}", e))
-            }
-        }
-    }
-}
-
 async fn save_refresh_token_to_disk(
     refresh_token: &str,
     email_id: &str,
@@ -2339,123 +2401,14 @@ async fn delete_refresh_token_from_disk() -> Result<()> {
     Ok(())
 }
 
-async fn refresh_access_token(refresh_token: &str) -> Result<(String, String, String), String> {
-    let request_body = json!(
-        {
-            "grant_type": "refresh_token",
-            "refresh_token": refresh_token
-        }
-    );
-    let client = reqwest::Client::new();
-    let response = client
-        .post(format!(
-            "{}/auth/v1/token?grant_type=refresh_token",
-            SUPABASE_URL
-        ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .body(request_body.to_string())
-        .send()
-        .await;
-    if let Err(e) = response {
-        debug!("Error logging in: {}", e);
-        error!("Error logging in, Something went wrong, please try again later");
-        return Err("Error logging in, Something went wrong, please try again later".to_string());
-    }
-    let response = response.unwrap();
-    let status = response.status();
-    let body = response.json::<serde_json::Value>().await;
-    if status == StatusCode::OK {
-        match body {
-            Ok(body) => {
-                let access_token = body.get("access_token");
-                let refresh_token = body.get("refresh_token");
-                let access_token_result = match access_token {
-                    Some(access_token) => {
-                        let access_token = access_token.as_str().unwrap();
-                        info!("🚀 Login successful");
-                        debug!("Access token: {}", access_token);
-                        let user_id = get_user_id_from_database(access_token, false)
-                            .await
-                            .unwrap_or_else(|_| "Error getting user id".to_string());
-                        Ok((access_token.to_string(), user_id))
-                    }
-                    None => {
-                        error!("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer");
-                        debug!(
-                            "status code {}, response body: {:?}, could not find access token",
-                            status, body
-                        );
-                        Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
-                    }
-                };
-                let refresh_token_result = match refresh_token {
-                    Some(refresh_token) => {
-                        let refresh_token = refresh_token.as_str().unwrap();
-                        debug!("Refresh token: {}", refresh_token);
-                        Ok(refresh_token.to_string())
-                    }
-                    None => {
-                        error!("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer");
-                        debug!(
-                            "status code {}, response body: {:?}, could not find refresh token",
-                            status, body
-                        );
-                        Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
-                    }
-                };
-
-                if access_token_result.is_err() || refresh_token_result.is_err() {
-                    Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
-                } else {
-                    let access_token_result = access_token_result.unwrap();
-                    let refresh_token_result = refresh_token_result.unwrap();
-                    Ok((
-                        access_token_result.0,
-                        access_token_result.1,
-                        refresh_token_result,
-                    ))
-                }
-            }
-            Err(e) => Err(format!("Error logging in: {}", e)),
-        }
-    } else if status == StatusCode::TOO_MANY_REQUESTS {
-        error!("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢");
-        debug!("status code {}, response body: {:?}", status, body);
-        Err("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢".to_string())
-    } else {
-        match body {
-            Ok(body) => {
-                let error_description = body.get("error_description");
-                match error_description {
-                    Some(error_description) => {
-                        let error_description = error_description.to_string();
-                        error!("{}", error_description);
-                        debug!("status code {}, response body: {:?}", status, body);
-                        Err(format!("Error logging in: {}", error_description))
-                    }
-                    None => {
-                        error!("Error logging in");
-                        debug!("status code {}, response body: {:?}", status, body);
-                        Err("Error logging in".to_string())
-                    }
-                }
-            }
-            Err(e) => {
-                error!("Error logging in: {}", e);
-                Err(format!("Error logging in: {}", e))
-            }
-        }
-    }
-}
-
 async fn test_refresh_token_on_disk(
+    sync_backend: &SyncBackendClient,
     encryption_key_from_arguments: Option<String>,
 ) -> Result<UserLoginData> {
     let (refresh_token, email_id) =
         get_refresh_token_from_disk(encryption_key_from_arguments.clone())?;
     debug!("refresh_token: {:?}", refresh_token);
-    let status = refresh_access_token(&refresh_token).await;
+    let status = sync_backend.refresh_session(&refresh_token).await;
     if status.is_err() {
         return Err(anyhow!(status.err().unwrap()));
     }
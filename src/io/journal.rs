@@ -0,0 +1,515 @@
+use crate::{
+    app::{kanban::Board, AppConfig},
+    constants::{JOURNAL_COMPACTION_ENTRIES, JOURNAL_FILE_NAME, SAVE_SCHEMA_VERSION},
+    io::data_handler::{backup_save_file, write_file_atomically},
+};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+};
+use strum_macros::{Display, EnumString};
+
+/// How local saves are stored in the save directory
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, EnumString, Display,
+)]
+pub enum StorageMode {
+    /// Every save is a new `kanban_DD-MM-YYYY_vN.json` file
+    #[default]
+    Snapshots,
+    /// The whole workspace is one journal file, every save appends the boards that changed
+    Journal,
+}
+
+/// One line of the journal, a save is only applied when its whole line made it to disk
+#[derive(Serialize, Deserialize, Debug)]
+struct JournalEntry {
+    time: String,
+    operations: Vec<JournalOperation>,
+}
+
+/// Boards are kept as json so an old journal can go through the save migrations
+#[derive(Serialize, Deserialize, Debug)]
+enum JournalOperation {
+    /// Replaces every board, a journal always starts with one
+    Snapshot {
+        schema_version: u32,
+        kanban_version: String,
+        boards: Vec<Value>,
+    },
+    /// Replaces the board with the same id, or adds it after the others
+    SetBoard {
+        board: Value,
+    },
+    RemoveBoard {
+        board_id: (u64, u64),
+    },
+    OrderBoards {
+        board_ids: Vec<(u64, u64)>,
+    },
+}
+
+/// The boards a journal ends up with once every entry is applied
+struct ReplayedJournal {
+    schema_version: u32,
+    kanban_version: String,
+    boards: Vec<Value>,
+    entries: usize,
+    /// The last entry was cut off, the save writing it never finished
+    truncated: bool,
+    size: u64,
+}
+
+pub fn get_journal_path(config: &AppConfig) -> PathBuf {
+    config.workspace_save_directory().join(JOURNAL_FILE_NAME)
+}
+
+/// The journal in the same shape as a save file, ready for `migrate_save`
+pub fn read_journal_as_save(config: &AppConfig) -> Result<Value, String> {
+    let journal = read_journal(config)?;
+    if journal.truncated {
+        debug!("Ignoring the unfinished last entry of the journal");
+    }
+    Ok(json!({
+        "schema_version": journal.schema_version,
+        "kanban_version": journal.kanban_version,
+        "boards": journal.boards,
+    }))
+}
+
+/// Appends the boards that changed since the last save to the journal, the journal is started
+/// or compacted into a single snapshot instead when that is due. Returns the journal file name
+pub fn save_boards_to_journal(boards: &[Board], config: &AppConfig) -> Result<String, String> {
+    let boards = boards
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<Value>, _>>()
+        .map_err(|e| e.to_string())?;
+    if !get_journal_path(config).exists() {
+        info!("Starting a new journal");
+        write_snapshot(
+            config,
+            SAVE_SCHEMA_VERSION,
+            env!("CARGO_PKG_VERSION"),
+            boards,
+        )?;
+        return Ok(JOURNAL_FILE_NAME.to_string());
+    }
+    let journal = read_journal(config)?;
+    if journal.schema_version > SAVE_SCHEMA_VERSION {
+        return Err(format!(
+            "The journal uses schema version {} (written by rust-kanban {}), but rust-kanban {} only supports up to schema version {}. Please update rust-kanban to save to it",
+            journal.schema_version,
+            journal.kanban_version,
+            env!("CARGO_PKG_VERSION"),
+            SAVE_SCHEMA_VERSION
+        ));
+    }
+    if journal.schema_version < SAVE_SCHEMA_VERSION {
+        backup_save_file(&get_journal_path(config), journal.schema_version, config)?;
+    }
+    if journal.truncated
+        || journal.schema_version != SAVE_SCHEMA_VERSION
+        || journal.entries >= JOURNAL_COMPACTION_ENTRIES
+    {
+        info!("Compacting the journal ({} entries)", journal.entries);
+        write_snapshot(
+            config,
+            SAVE_SCHEMA_VERSION,
+            env!("CARGO_PKG_VERSION"),
+            boards,
+        )?;
+        return Ok(JOURNAL_FILE_NAME.to_string());
+    }
+    let operations = get_board_changes(&journal.boards, &boards);
+    if !operations.is_empty() {
+        append_entry(config, operations)?;
+    }
+    Ok(JOURNAL_FILE_NAME.to_string())
+}
+
+/// Rewrites the journal as a single snapshot of its boards, dropping an unfinished last entry.
+/// Returns the bytes saved, None if there is no journal
+pub fn compact_journal(config: &AppConfig) -> Result<Option<u64>, String> {
+    if !get_journal_path(config).exists() {
+        return Ok(None);
+    }
+    let journal = read_journal(config)?;
+    if journal.entries <= 1 && !journal.truncated {
+        return Ok(Some(0));
+    }
+    write_snapshot(
+        config,
+        journal.schema_version,
+        &journal.kanban_version,
+        journal.boards,
+    )?;
+    let compacted_size = fs::metadata(get_journal_path(config)).map_or(0, |m| m.len());
+    Ok(Some(journal.size.saturating_sub(compacted_size)))
+}
+
+/// Compacts the journal if its last entry was cut off by a crash, returns true if it was
+pub fn recover_journal(config: &AppConfig) -> Result<bool, String> {
+    if !get_journal_path(config).exists() {
+        return Ok(false);
+    }
+    if !read_journal(config)?.truncated {
+        return Ok(false);
+    }
+    compact_journal(config)?;
+    Ok(true)
+}
+
+fn read_journal(config: &AppConfig) -> Result<ReplayedJournal, String> {
+    let journal_path = get_journal_path(config);
+    let content = fs::read_to_string(&journal_path)
+        .map_err(|e| format!("Error reading {:?}: {}", journal_path, e))?;
+    let lines: Vec<&str> = content.split('\n').collect();
+    let mut journal: Option<ReplayedJournal> = None;
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: JournalEntry = match serde_json::from_str(line) {
+            Ok(entry) => entry,
+            // Only the last line is written without a newline after it, so only it can be cut off
+            Err(e) if index == lines.len() - 1 && journal.is_some() => {
+                debug!("Unfinished journal entry on line {}: {}", index + 1, e);
+                if let Some(journal) = journal.as_mut() {
+                    journal.truncated = true;
+                }
+                break;
+            }
+            Err(e) => {
+                return Err(format!(
+                    "Error parsing journal entry on line {}: {}",
+                    index + 1,
+                    e
+                ))
+            }
+        };
+        for operation in entry.operations {
+            match (operation, journal.as_mut()) {
+                (
+                    JournalOperation::Snapshot {
+                        schema_version,
+                        kanban_version,
+                        boards,
+                    },
+                    _,
+                ) => {
+                    let entries = journal.as_ref().map_or(0, |journal| journal.entries);
+                    journal = Some(ReplayedJournal {
+                        schema_version,
+                        kanban_version,
+                        boards,
+                        entries,
+                        truncated: false,
+                        size: 0,
+                    });
+                }
+                (_, None) => {
+                    return Err("Error parsing journal, it does not start with a snapshot".into())
+                }
+                (JournalOperation::SetBoard { board }, Some(journal)) => {
+                    let board_id = get_board_id(&board);
+                    match journal
+                        .boards
+                        .iter_mut()
+                        .find(|existing| get_board_id(existing) == board_id)
+                    {
+                        Some(existing) => *existing = board,
+                        None => journal.boards.push(board),
+                    }
+                }
+                (JournalOperation::RemoveBoard { board_id }, Some(journal)) => {
+                    journal
+                        .boards
+                        .retain(|board| get_board_id(board) != Some(board_id));
+                }
+                (JournalOperation::OrderBoards { board_ids }, Some(journal)) => {
+                    journal.boards.sort_by_key(|board| {
+                        get_board_id(board)
+                            .and_then(|board_id| board_ids.iter().position(|id| *id == board_id))
+                            .unwrap_or(board_ids.len())
+                    });
+                }
+            }
+        }
+        if let Some(journal) = journal.as_mut() {
+            journal.entries += 1;
+        }
+    }
+    let mut journal = journal.ok_or_else(|| "Error parsing journal, it is empty".to_string())?;
+    journal.size = content.len() as u64;
+    Ok(journal)
+}
+
+/// The operations that turn the saved boards into the current ones
+fn get_board_changes(saved_boards: &[Value], boards: &[Value]) -> Vec<JournalOperation> {
+    let board_ids: Vec<Option<(u64, u64)>> = boards.iter().map(get_board_id).collect();
+    let mut operations = Vec::new();
+    let mut order_after_changes = Vec::new();
+    for saved_board in saved_boards {
+        let saved_board_id = get_board_id(saved_board);
+        if board_ids.contains(&saved_board_id) {
+            order_after_changes.push(saved_board_id);
+        } else if let Some(board_id) = saved_board_id {
+            operations.push(JournalOperation::RemoveBoard { board_id });
+        }
+    }
+    for (board, board_id) in boards.iter().zip(&board_ids) {
+        let saved_board = saved_boards
+            .iter()
+            .find(|saved_board| get_board_id(saved_board) == *board_id);
+        if saved_board != Some(board) {
+            operations.push(JournalOperation::SetBoard {
+                board: board.clone(),
+            });
+        }
+        if saved_board.is_none() {
+            order_after_changes.push(*board_id);
+        }
+    }
+    if order_after_changes != board_ids {
+        operations.push(JournalOperation::OrderBoards {
+            board_ids: board_ids.into_iter().flatten().collect(),
+        });
+    }
+    operations
+}
+
+fn get_board_id(board: &Value) -> Option<(u64, u64)> {
+    serde_json::from_value(board["id"].clone()).ok()
+}
+
+fn write_snapshot(
+    config: &AppConfig,
+    schema_version: u32,
+    kanban_version: &str,
+    boards: Vec<Value>,
+) -> Result<(), String> {
+    let entry = JournalEntry {
+        time: chrono::Local::now().to_rfc3339(),
+        operations: vec![JournalOperation::Snapshot {
+            schema_version,
+            kanban_version: kanban_version.to_string(),
+            boards,
+        }],
+    };
+    let line = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+    write_file_atomically(&get_journal_path(config), format!("{}\n", line))
+}
+
+fn append_entry(config: &AppConfig, operations: Vec<JournalOperation>) -> Result<(), String> {
+    let journal_path = get_journal_path(config);
+    let entry = JournalEntry {
+        time: chrono::Local::now().to_rfc3339(),
+        operations,
+    };
+    let line = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+    let mut journal_file = OpenOptions::new()
+        .append(true)
+        .open(&journal_path)
+        .map_err(|e| format!("Error opening {:?}: {}", journal_path, e))?;
+    // One write for the whole line, a crash can only leave the last line unfinished
+    journal_file
+        .write_all(format!("{}\n", line).as_bytes())
+        .and_then(|_| journal_file.sync_data())
+        .map_err(|e| format!("Error writing {:?}: {}", journal_path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        app::kanban::{Card, CardPriority},
+        io::{data_handler::save_kanban_state_locally, io_handler::get_latest_save_file},
+    };
+    use uuid::Uuid;
+
+    /// A save directory of its own for every test, removed when the test is done
+    struct TestSaveDirectory(AppConfig);
+
+    impl TestSaveDirectory {
+        fn new() -> Self {
+            let path = std::env::temp_dir().join(format!("rust_kanban_journal_{}", Uuid::new_v4()));
+            fs::create_dir_all(&path).unwrap();
+            Self(AppConfig {
+                save_directory: path,
+                storage_mode: StorageMode::Journal,
+                ..AppConfig::default()
+            })
+        }
+
+        fn save(&self, boards: &[Board]) {
+            save_boards_to_journal(boards, &self.0).unwrap();
+        }
+
+        fn saved_boards(&self) -> Value {
+            read_journal_as_save(&self.0).unwrap()["boards"].clone()
+        }
+
+        fn lines(&self) -> usize {
+            fs::read_to_string(get_journal_path(&self.0))
+                .unwrap()
+                .lines()
+                .count()
+        }
+    }
+
+    impl Drop for TestSaveDirectory {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0.save_directory);
+        }
+    }
+
+    fn board(name: &str, card_names: &[&str]) -> Board {
+        let mut board = Board::new(name, "");
+        for card_name in card_names {
+            board.cards.add_card(Card::new(
+                card_name,
+                "",
+                None,
+                CardPriority::Low,
+                vec![],
+                vec![],
+            ));
+        }
+        board
+    }
+
+    fn to_values(boards: &[Board]) -> Vec<Value> {
+        boards
+            .iter()
+            .map(|board| serde_json::to_value(board).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn saves_are_appended_and_replayed() {
+        let save_directory = TestSaveDirectory::new();
+        let mut boards = vec![board("Todo", &["Write"]), board("Doing", &[])];
+        save_directory.save(&boards);
+
+        boards[1] = board("Doing", &["Review"]);
+        boards.push(board("Done", &["Ship"]));
+        save_directory.save(&boards);
+        boards.remove(0);
+        boards.swap(0, 1);
+        save_directory.save(&boards);
+        // Nothing changed, nothing is appended
+        save_directory.save(&boards);
+
+        assert_eq!(save_directory.lines(), 3);
+        assert_eq!(save_directory.saved_boards(), json!(to_values(&boards)));
+    }
+
+    #[test]
+    fn an_unfinished_last_entry_is_ignored_and_recovered() {
+        let save_directory = TestSaveDirectory::new();
+        let mut boards = vec![board("Todo", &["Write"])];
+        save_directory.save(&boards);
+        boards.push(board("Done", &[]));
+        save_directory.save(&boards);
+        let mut journal_file = OpenOptions::new()
+            .append(true)
+            .open(get_journal_path(&save_directory.0))
+            .unwrap();
+        journal_file
+            .write_all(br#"{"time":"2024-01-01T00:00:00+00:00","operations":[{"SetBo"#)
+            .unwrap();
+
+        assert_eq!(save_directory.saved_boards(), json!(to_values(&boards)));
+        assert!(recover_journal(&save_directory.0).unwrap());
+        assert_eq!(save_directory.lines(), 1);
+        assert_eq!(save_directory.saved_boards(), json!(to_values(&boards)));
+        assert!(!recover_journal(&save_directory.0).unwrap());
+    }
+
+    #[test]
+    fn a_broken_line_before_the_last_is_an_error() {
+        let save_directory = TestSaveDirectory::new();
+        save_directory.save(&[board("Todo", &[])]);
+        let journal_path = get_journal_path(&save_directory.0);
+        let content = fs::read_to_string(&journal_path).unwrap();
+        fs::write(&journal_path, format!("{{\"time\"\n{}", content)).unwrap();
+
+        assert!(read_journal_as_save(&save_directory.0)
+            .unwrap_err()
+            .contains("line 1"));
+    }
+
+    #[test]
+    fn compaction_keeps_the_boards() {
+        let save_directory = TestSaveDirectory::new();
+        let mut boards = vec![board("Todo", &[])];
+        save_directory.save(&boards);
+        for card_name in ["One", "Two", "Three"] {
+            boards[0] = board("Todo", &[card_name]);
+            boards.push(board(card_name, &[]));
+            save_directory.save(&boards);
+        }
+        let saved_boards = save_directory.saved_boards();
+
+        assert!(compact_journal(&save_directory.0).unwrap().unwrap() > 0);
+        assert_eq!(save_directory.lines(), 1);
+        assert_eq!(save_directory.saved_boards(), saved_boards);
+        assert_eq!(compact_journal(&save_directory.0), Ok(Some(0)));
+    }
+
+    #[test]
+    fn the_newest_of_the_journal_and_snapshots_is_loaded() {
+        let mut save_directory = TestSaveDirectory::new();
+        let saved_file =
+            |save_directory: &TestSaveDirectory| get_latest_save_file(&save_directory.0).unwrap();
+        // File times are not always more precise than a few milliseconds
+        let wait = || std::thread::sleep(std::time::Duration::from_millis(20));
+        save_directory.save(&[board("Todo", &[])]);
+        assert_eq!(saved_file(&save_directory), JOURNAL_FILE_NAME);
+
+        wait();
+        save_directory.0.storage_mode = StorageMode::Snapshots;
+        let snapshot_file =
+            save_kanban_state_locally(vec![board("Todo", &[])], &save_directory.0).unwrap();
+        assert_eq!(saved_file(&save_directory), snapshot_file);
+
+        wait();
+        save_directory.0.storage_mode = StorageMode::Journal;
+        save_directory.save(&[board("Done", &[])]);
+        assert_eq!(saved_file(&save_directory), JOURNAL_FILE_NAME);
+    }
+
+    #[test]
+    fn board_changes_only_hold_what_changed() {
+        let boards = to_values(&[board("Todo", &["Write"]), board("Done", &[])]);
+        assert!(get_board_changes(&boards, &boards).is_empty());
+
+        let reordered = vec![boards[1].clone(), boards[0].clone()];
+        let operations = get_board_changes(&boards, &reordered);
+        assert!(matches!(
+            operations.as_slice(),
+            [JournalOperation::OrderBoards { board_ids }]
+                if *board_ids == [get_board_id(&boards[1]).unwrap(), get_board_id(&boards[0]).unwrap()]
+        ));
+
+        let new_board = serde_json::to_value(board("Doing", &[])).unwrap();
+        let mut changed = vec![boards[0].clone(), new_board.clone()];
+        changed[0]["name"] = json!("Backlog");
+        let operations = get_board_changes(&boards, &changed);
+        assert!(matches!(
+            operations.as_slice(),
+            [
+                JournalOperation::RemoveBoard { board_id },
+                JournalOperation::SetBoard { board: backlog },
+                JournalOperation::SetBoard { board: doing },
+            ] if Some(*board_id) == get_board_id(&boards[1])
+                && *backlog == changed[0]
+                && *doing == new_board
+        ));
+    }
+}
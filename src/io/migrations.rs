@@ -0,0 +1,554 @@
+use crate::{
+    app::DateTimeFormat,
+    constants::{DEFAULT_COMMENT_AUTHOR, FIELD_NA, FIELD_NOT_SET, SAVE_SCHEMA_VERSION},
+    util::parse_date_time_string,
+};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// Migrations get the user's `DateTimeFormat` to read dates that older saves stored as text
+type Migration = fn(&mut Value, DateTimeFormat) -> Result<(), String>;
+
+/// `MIGRATIONS[n]` migrates a save from schema version `n + 1` to `n + 2`, when the save format
+/// changes bump `SAVE_SCHEMA_VERSION` and add the migration for it here
+const MIGRATIONS: [Migration; (SAVE_SCHEMA_VERSION - 1) as usize] = [
+    migrate_v1_to_v2,
+    migrate_v2_to_v3,
+    migrate_v3_to_v4,
+    migrate_v4_to_v5,
+    migrate_v5_to_v6,
+    migrate_v6_to_v7,
+    migrate_v7_to_v8,
+    migrate_v8_to_v9,
+    migrate_v9_to_v10,
+];
+
+const CARD_DATE_FIELDS: [&str; 4] = [
+    "date_created",
+    "date_modified",
+    "due_date",
+    "date_completed",
+];
+
+/// Saves written before the schema version was recorded are version 1
+pub fn get_schema_version(save: &Value) -> Result<u32, String> {
+    match &save["schema_version"] {
+        Value::Null => Ok(1),
+        schema_version => schema_version
+            .as_u64()
+            .and_then(|schema_version| u32::try_from(schema_version).ok())
+            .filter(|schema_version| *schema_version >= 1)
+            .ok_or_else(|| format!("Invalid save schema version {}", schema_version)),
+    }
+}
+
+/// Brings a save (or cloud save payload) up to `SAVE_SCHEMA_VERSION` by running every migration
+/// after its version in order. Returns the schema version the save was written with
+pub fn migrate_save(save: &mut Value, date_time_format: DateTimeFormat) -> Result<u32, String> {
+    let schema_version = get_schema_version(save)?;
+    if schema_version > SAVE_SCHEMA_VERSION {
+        let written_by = match save["kanban_version"].as_str() {
+            Some(kanban_version) => format!(" (written by rust-kanban {})", kanban_version),
+            None => String::new(),
+        };
+        return Err(format!(
+            "Save uses schema version {}{}, but rust-kanban {} only supports up to schema version {}. Please update rust-kanban to load it",
+            schema_version,
+            written_by,
+            env!("CARGO_PKG_VERSION"),
+            SAVE_SCHEMA_VERSION
+        ));
+    }
+    for version in schema_version..SAVE_SCHEMA_VERSION {
+        MIGRATIONS[(version - 1) as usize](save, date_time_format).map_err(|e| {
+            format!(
+                "Error migrating save to schema version {}: {}",
+                version + 1,
+                e
+            )
+        })?;
+        save["schema_version"] = Value::from(version + 1);
+    }
+    Ok(schema_version)
+}
+
+/// Cards used to be stored directly as an array instead of the `Cards` struct, and card dates
+/// were strings in the user's `DateTimeFormat` (local time) with `FIELD_NOT_SET` or `FIELD_NA`
+/// for missing dates. They are now RFC 3339 timestamps or null
+fn migrate_v1_to_v2(save: &mut Value, date_time_format: DateTimeFormat) -> Result<(), String> {
+    let boards = save["boards"]
+        .as_array_mut()
+        .ok_or("boards is not an array")?;
+    for board in boards {
+        if board["cards"].is_array() {
+            board["cards"] = serde_json::json!({ "cards": board["cards"].take() });
+        }
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let cards = board["cards"]["cards"]
+            .as_array_mut()
+            .ok_or_else(|| format!("cards of board \"{}\" is not an array", board_name))?;
+        for card in cards {
+            for field in CARD_DATE_FIELDS {
+                card[field] = migrate_v1_date(&card[field], date_time_format).ok_or_else(|| {
+                    format!(
+                        "{} of card \"{}\" in board \"{}\" is not a valid date",
+                        field,
+                        card["name"].as_str().unwrap_or_default(),
+                        board_name
+                    )
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Cards got a revision counter for cloud sync, existing cards start at 0
+fn migrate_v2_to_v3(save: &mut Value, _: DateTimeFormat) -> Result<(), String> {
+    let boards = save["boards"]
+        .as_array_mut()
+        .ok_or("boards is not an array")?;
+    for board in boards {
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let cards = board["cards"]["cards"]
+            .as_array_mut()
+            .ok_or_else(|| format!("cards of board \"{}\" is not an array", board_name))?;
+        for card in cards {
+            let card = card
+                .as_object_mut()
+                .ok_or_else(|| format!("a card in board \"{}\" is not an object", board_name))?;
+            card.entry("revision").or_insert(Value::from(0));
+        }
+    }
+    Ok(())
+}
+
+/// Cards got a checklist, existing cards start with an empty one
+fn migrate_v3_to_v4(save: &mut Value, _: DateTimeFormat) -> Result<(), String> {
+    let boards = save["boards"]
+        .as_array_mut()
+        .ok_or("boards is not an array")?;
+    for board in boards {
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let cards = board["cards"]["cards"]
+            .as_array_mut()
+            .ok_or_else(|| format!("cards of board \"{}\" is not an array", board_name))?;
+        for card in cards {
+            let card = card
+                .as_object_mut()
+                .ok_or_else(|| format!("a card in board \"{}\" is not an object", board_name))?;
+            card.entry("checklist").or_insert(Value::Array(vec![]));
+        }
+    }
+    Ok(())
+}
+
+/// Comments used to be plain strings, they are now objects with an id, author and timestamps.
+/// The author of old comments is unknown and they are dated to when their card was created
+fn migrate_v4_to_v5(save: &mut Value, _: DateTimeFormat) -> Result<(), String> {
+    let boards = save["boards"]
+        .as_array_mut()
+        .ok_or("boards is not an array")?;
+    for board in boards {
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let cards = board["cards"]["cards"]
+            .as_array_mut()
+            .ok_or_else(|| format!("cards of board \"{}\" is not an array", board_name))?;
+        for card in cards {
+            let created_at = match &card["date_created"] {
+                Value::String(date_created) => date_created.clone(),
+                _ => Utc::now().to_rfc3339(),
+            };
+            let card_name = card["name"].as_str().unwrap_or_default().to_string();
+            let Some(comments) = card["comments"].as_array_mut() else {
+                continue;
+            };
+            for comment in comments {
+                if comment.is_object() {
+                    continue;
+                }
+                let body = comment.as_str().ok_or_else(|| {
+                    format!(
+                        "a comment of card \"{}\" in board \"{}\" is not a string",
+                        card_name, board_name
+                    )
+                })?;
+                let (id_1, id_2) = Uuid::new_v4().as_u64_pair();
+                *comment = json!({
+                    "author": DEFAULT_COMMENT_AUTHOR,
+                    "body": body,
+                    "created_at": created_at,
+                    "edited_at": null,
+                    "id": [id_1, id_2],
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Cards got blocked_by links and boards an in progress flag, existing cards are not blocked
+/// and existing boards are not in progress
+fn migrate_v5_to_v6(save: &mut Value, _: DateTimeFormat) -> Result<(), String> {
+    let boards = save["boards"]
+        .as_array_mut()
+        .ok_or("boards is not an array")?;
+    for board in boards {
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let board_object = board
+            .as_object_mut()
+            .ok_or_else(|| format!("board \"{}\" is not an object", board_name))?;
+        board_object
+            .entry("in_progress")
+            .or_insert(Value::Bool(false));
+        let cards = board["cards"]["cards"]
+            .as_array_mut()
+            .ok_or_else(|| format!("cards of board \"{}\" is not an array", board_name))?;
+        for card in cards {
+            let card = card
+                .as_object_mut()
+                .ok_or_else(|| format!("a card in board \"{}\" is not an object", board_name))?;
+            card.entry("blocked_by").or_insert(Value::Array(vec![]));
+        }
+    }
+    Ok(())
+}
+
+fn migrate_v1_date(date: &Value, date_time_format: DateTimeFormat) -> Option<Value> {
+    let date_string = match date {
+        Value::Null => return Some(Value::Null),
+        Value::String(date_string) => date_string.trim(),
+        _ => return None,
+    };
+    if date_string.is_empty() || date_string == FIELD_NOT_SET || date_string == FIELD_NA {
+        return Some(Value::Null);
+    }
+    if DateTime::parse_from_rfc3339(date_string).is_ok() {
+        return Some(Value::from(date_string));
+    }
+    parse_date_time_string(date_string, date_time_format)
+        .ok()
+        .map(|date_time| Value::from(date_time.to_rfc3339()))
+}
+
+/// Boards got a sort mode, existing boards keep their manual order
+fn migrate_v6_to_v7(save: &mut Value, _: DateTimeFormat) -> Result<(), String> {
+    let boards = save["boards"]
+        .as_array_mut()
+        .ok_or("boards is not an array")?;
+    for board in boards {
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let board = board
+            .as_object_mut()
+            .ok_or_else(|| format!("board \"{}\" is not an object", board_name))?;
+        board.entry("sort_mode").or_insert(Value::from("Manual"));
+        board.entry("manual_order").or_insert(Value::Array(vec![]));
+    }
+    Ok(())
+}
+
+/// Boards got an optional WIP limit, existing boards have none
+fn migrate_v7_to_v8(save: &mut Value, _: DateTimeFormat) -> Result<(), String> {
+    let boards = save["boards"]
+        .as_array_mut()
+        .ok_or("boards is not an array")?;
+    for board in boards {
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let board = board
+            .as_object_mut()
+            .ok_or_else(|| format!("board \"{}\" is not an object", board_name))?;
+        board.entry("wip_limit").or_insert(Value::Null);
+    }
+    Ok(())
+}
+
+/// Boards got an archive for deleted and completed cards, existing boards start with it empty
+fn migrate_v8_to_v9(save: &mut Value, _: DateTimeFormat) -> Result<(), String> {
+    let boards = save["boards"]
+        .as_array_mut()
+        .ok_or("boards is not an array")?;
+    for board in boards {
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let board = board
+            .as_object_mut()
+            .ok_or_else(|| format!("board \"{}\" is not an object", board_name))?;
+        board
+            .entry("archived_cards")
+            .or_insert(Value::Array(vec![]));
+    }
+    Ok(())
+}
+
+/// Cards got an optional recurrence, existing cards, archived ones included, do not recur
+fn migrate_v9_to_v10(save: &mut Value, _: DateTimeFormat) -> Result<(), String> {
+    let boards = save["boards"]
+        .as_array_mut()
+        .ok_or("boards is not an array")?;
+    for board in boards {
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let cards = board["cards"]["cards"]
+            .as_array_mut()
+            .ok_or_else(|| format!("cards of board \"{}\" is not an array", board_name))?;
+        for card in cards {
+            let card = card
+                .as_object_mut()
+                .ok_or_else(|| format!("a card in board \"{}\" is not an object", board_name))?;
+            card.entry("recurrence").or_insert(Value::Null);
+        }
+        let archived_cards = board["archived_cards"]
+            .as_array_mut()
+            .ok_or_else(|| format!("archived cards of board \"{}\" is not an array", board_name))?;
+        for archived_card in archived_cards {
+            let card = archived_card["card"].as_object_mut().ok_or_else(|| {
+                format!(
+                    "an archived card in board \"{}\" is not an object",
+                    board_name
+                )
+            })?;
+            card.entry("recurrence").or_insert(Value::Null);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::{Board, BoardSortMode, Card, CardPriority};
+    use chrono::{Local, TimeZone};
+
+    const DATE_CREATED: &str = "2023-02-01T10:00:00+00:00";
+    const DUE_DATE: &str = "2023-02-15T00:00:00+00:00";
+
+    /// v1 saves stored dates as local time
+    fn local_date_time(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        Local
+            .with_ymd_and_hms(year, month, day, hour, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    /// A card as it was saved by `version`, v1 dates are in the default DD/MM/YYYY date format
+    fn sample_card(version: u32, id: [u64; 2]) -> Value {
+        let (date_created, due_date) = if version == 1 {
+            (json!("01/02/2023-10:00:00"), json!("15/02/2023"))
+        } else {
+            (json!(DATE_CREATED), json!(DUE_DATE))
+        };
+        let comment = if version < 5 {
+            json!("Looks good")
+        } else {
+            json!({
+                "author": "Reviewer",
+                "body": "Looks good",
+                "created_at": DATE_CREATED,
+                "edited_at": null,
+                "id": [7, 8],
+            })
+        };
+        let mut card = json!({
+            "id": id,
+            "name": "Write tests",
+            "description": "For the migrations",
+            "date_created": date_created,
+            "date_modified": date_created,
+            "due_date": due_date,
+            "date_completed": if version == 1 { json!(FIELD_NOT_SET) } else { Value::Null },
+            "priority": "High",
+            "card_status": "Active",
+            "tags": ["test"],
+            "comments": [comment],
+        });
+        if version >= 3 {
+            card["revision"] = json!(3);
+        }
+        if version >= 4 {
+            card["checklist"] = json!([{ "text": "Fixtures", "done": true }]);
+        }
+        if version >= 6 {
+            card["blocked_by"] = json!([]);
+        }
+        card
+    }
+
+    /// A save with one board holding one card (and one archived card once boards had an
+    /// archive) in the format written by `version`
+    fn sample_save(version: u32) -> Value {
+        let card = sample_card(version, [1, 2]);
+        let mut board = json!({
+            "id": [3, 4],
+            "name": "Todo",
+            "description": "Things to do",
+            "cards": if version == 1 { json!([card]) } else { json!({ "cards": [card] }) },
+        });
+        if version >= 6 {
+            board["in_progress"] = json!(true);
+        }
+        if version >= 7 {
+            board["sort_mode"] = json!("Manual");
+            board["manual_order"] = json!([]);
+        }
+        if version >= 8 {
+            board["wip_limit"] = json!(5);
+        }
+        if version >= 9 {
+            board["archived_cards"] = json!([{
+                "card": sample_card(version, [5, 6]),
+                "date_archived": DATE_CREATED,
+                "position": 1,
+                "unlinked_card_ids": [],
+            }]);
+        }
+        let mut save = json!({ "boards": [board] });
+        if version >= 2 {
+            save["schema_version"] = json!(version);
+        }
+        save
+    }
+
+    #[test]
+    fn saves_of_every_older_schema_version_are_migrated() {
+        for version in 1..SAVE_SCHEMA_VERSION {
+            let mut save = sample_save(version);
+            assert_eq!(
+                migrate_save(&mut save, DateTimeFormat::default()),
+                Ok(version),
+                "version {}",
+                version
+            );
+            assert_eq!(save["schema_version"], json!(SAVE_SCHEMA_VERSION));
+
+            let card = Card::from_json(&save["boards"][0]["cards"]["cards"][0])
+                .unwrap_or_else(|e| panic!("version {}: {}", version, e));
+            assert_eq!(card.id, (1, 2));
+            assert_eq!(card.name, "Write tests");
+            assert_eq!(card.priority, CardPriority::High);
+            assert_eq!(card.tags, vec!["test".to_string()]);
+            assert_eq!(card.revision, if version >= 3 { 3 } else { 0 });
+            assert_eq!(card.checklist.len(), usize::from(version >= 4));
+            assert_eq!(card.comments.len(), 1);
+            assert_eq!(card.comments[0].body, "Looks good");
+            let (expected_date_created, expected_due_date) = if version == 1 {
+                (
+                    local_date_time(2023, 2, 1, 10),
+                    local_date_time(2023, 2, 15, 0),
+                )
+            } else {
+                (
+                    DateTime::parse_from_rfc3339(DATE_CREATED).unwrap().into(),
+                    DateTime::parse_from_rfc3339(DUE_DATE).unwrap().into(),
+                )
+            };
+            assert_eq!(card.date_created, Some(expected_date_created));
+            assert_eq!(card.due_date, Some(expected_due_date));
+            assert_eq!(card.date_completed, None);
+            assert!(card.blocked_by.is_empty());
+            assert_eq!(card.recurrence, None);
+
+            let board = Board::from_json(&save["boards"][0])
+                .unwrap_or_else(|e| panic!("version {}: {}", version, e));
+            assert_eq!(board.id, (3, 4));
+            assert_eq!(board.name, "Todo");
+            assert_eq!(board.cards.get_all_cards(), &vec![card]);
+            assert_eq!(board.in_progress, version >= 6);
+            assert_eq!(board.sort_mode, BoardSortMode::Manual);
+            assert_eq!(board.wip_limit, (version >= 8).then_some(5));
+            assert_eq!(board.archived_cards.len(), usize::from(version >= 9));
+            if let Some(archived_card) = board.archived_cards.first() {
+                assert_eq!(archived_card.card.id, (5, 6));
+                assert_eq!(archived_card.card.recurrence, None);
+            }
+        }
+    }
+
+    #[test]
+    fn v1_dates_are_read_in_the_users_date_format() {
+        let v1_save = |date_created: &str, due_date: &str| {
+            let mut save = sample_save(1);
+            save["boards"][0]["cards"][0]["date_created"] = json!(date_created);
+            save["boards"][0]["cards"][0]["due_date"] = json!(due_date);
+            save
+        };
+        let migrated_dates = |mut save: Value, date_time_format| {
+            migrate_save(&mut save, date_time_format).unwrap();
+            let card = Card::from_json(&save["boards"][0]["cards"]["cards"][0]).unwrap();
+            (card.date_created.unwrap(), card.due_date.unwrap())
+        };
+
+        let save = v1_save("03/04/2024-09:00:00", "05/06/2024");
+        assert_eq!(
+            migrated_dates(save.clone(), DateTimeFormat::MonthDayYearTime),
+            (
+                local_date_time(2024, 3, 4, 9),
+                local_date_time(2024, 5, 6, 0)
+            )
+        );
+        assert_eq!(
+            migrated_dates(save, DateTimeFormat::MonthDayYear),
+            (
+                local_date_time(2024, 3, 4, 9),
+                local_date_time(2024, 5, 6, 0)
+            )
+        );
+        assert_eq!(
+            migrated_dates(
+                v1_save("03/04/2024-09:00:00", "05/06/2024"),
+                DateTimeFormat::DayMonthYearTime
+            ),
+            (
+                local_date_time(2024, 4, 3, 9),
+                local_date_time(2024, 6, 5, 0)
+            )
+        );
+        // dates that only fit another format are still read
+        assert_eq!(
+            migrated_dates(
+                v1_save("2024/03/04-09:00:00", "25/06/2024"),
+                DateTimeFormat::MonthDayYearTime
+            ),
+            (
+                local_date_time(2024, 3, 4, 9),
+                local_date_time(2024, 6, 25, 0)
+            )
+        );
+    }
+
+    #[test]
+    fn saves_of_the_current_schema_version_are_not_changed() {
+        let mut save = sample_save(SAVE_SCHEMA_VERSION - 1);
+        migrate_save(&mut save, DateTimeFormat::default()).unwrap();
+        let migrated_save = save.clone();
+        assert_eq!(
+            migrate_save(&mut save, DateTimeFormat::default()),
+            Ok(SAVE_SCHEMA_VERSION)
+        );
+        assert_eq!(save, migrated_save);
+    }
+
+    #[test]
+    fn saves_of_a_newer_schema_version_are_rejected() {
+        let mut save = json!({
+            "boards": [],
+            "kanban_version": "99.0.0",
+            "schema_version": SAVE_SCHEMA_VERSION + 1,
+        });
+        let unchanged_save = save.clone();
+        let error = migrate_save(&mut save, DateTimeFormat::default()).unwrap_err();
+        assert!(error.contains(&format!("schema version {}", SAVE_SCHEMA_VERSION + 1)));
+        assert!(error.contains("rust-kanban 99.0.0"));
+        assert_eq!(save, unchanged_save);
+    }
+
+    #[test]
+    fn invalid_schema_versions_are_rejected() {
+        for schema_version in [json!(0), json!(-1), json!("10"), json!(1.5)] {
+            let mut save = json!({ "boards": [], "schema_version": schema_version });
+            assert!(
+                migrate_save(&mut save, DateTimeFormat::default()).is_err(),
+                "{}",
+                schema_version
+            );
+        }
+    }
+}
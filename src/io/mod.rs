@@ -1,9 +1,15 @@
 pub mod data_handler;
+pub mod exchange;
+pub mod importers;
 pub mod io_handler;
+pub mod journal;
 pub mod logger;
+pub mod migrations;
+pub mod sync;
 
 #[derive(Debug, Clone)]
 pub enum IoEvent {
+    ApplySyncResolution,
     AutoSave,
     DeleteCloudSave,
     DeleteLocalSave,
@@ -12,6 +18,7 @@ pub enum IoEvent {
     LoadCloudPreview,
     LoadLocalPreview,
     LoadSaveCloud,
+    LoadSaveDiff,
     LoadSaveLocal,
     Login(String, String),
     Logout,
@@ -20,5 +27,6 @@ pub enum IoEvent {
     SaveLocalData,
     SendResetPasswordEmail(String),
     SignUp(String, String, String),
+    SwitchWorkspace(String),
     SyncLocalData,
 }
@@ -0,0 +1,434 @@
+use crate::{
+    app::workspace::get_workspace_folder_name,
+    constants::{DEFAULT_WORKSPACE_NAME, WORKSPACE_DIR_NAME},
+    io::{
+        io_handler::{make_file_system_safe_name, CloudData},
+        sync::{SyncBackend, UserSession},
+    },
+};
+use chrono::Utc;
+use log::{debug, info, warn};
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+use uuid::Uuid;
+
+const CLOUD_SAVE_FILE_PREFIX: &str = "cloud_save_";
+
+/// Stores cloud saves as json files in a directory, e.g. a network share, a mounted WebDAV
+/// folder or a folder synced by another tool, so saves can be kept on your own infrastructure.
+/// Every user gets a sub directory named after their email, the saves of the default workspace
+/// are kept in it and every other workspace gets a folder under `workspaces` in it, like in the
+/// save directory. There is no account server, the password is not checked and access is only
+/// controlled by the permissions of the directory, the saves themselves are still encrypted with
+/// the user's encryption key
+pub struct LocalDirectoryBackend {
+    sync_directory: PathBuf,
+}
+
+impl LocalDirectoryBackend {
+    pub fn new(sync_directory: PathBuf) -> Self {
+        Self { sync_directory }
+    }
+
+    fn get_user_directory(&self, user_id: &str) -> Result<PathBuf, String> {
+        if self.sync_directory.as_os_str().is_empty() {
+            return Err("Sync directory is not set, please set it in the config".to_string());
+        }
+        if !self.sync_directory.is_dir() {
+            return Err(format!(
+                "Sync directory {} does not exist",
+                self.sync_directory.display()
+            ));
+        }
+        if user_id.chars().all(|c| c == '.') || user_id != make_file_system_safe_name(user_id) {
+            return Err(format!("Invalid user id \"{}\"", user_id));
+        }
+        Ok(self.sync_directory.join(user_id))
+    }
+
+    fn get_workspace_directory(&self, user_id: &str, workspace: &str) -> Result<PathBuf, String> {
+        let user_directory = self.get_user_directory(user_id)?;
+        if workspace == DEFAULT_WORKSPACE_NAME {
+            return Ok(user_directory);
+        }
+        let folder_name = get_workspace_folder_name(workspace)
+            .ok_or_else(|| format!("Invalid workspace name \"{}\"", workspace))?;
+        Ok(user_directory.join(WORKSPACE_DIR_NAME).join(folder_name))
+    }
+
+    fn get_save_file_path(user_directory: &Path, id: u64) -> PathBuf {
+        user_directory.join(format!("{}{}.json", CLOUD_SAVE_FILE_PREFIX, id))
+    }
+}
+
+impl SyncBackend for LocalDirectoryBackend {
+    async fn login(&self, email_id: &str, _password: &str) -> Result<UserSession, String> {
+        let user_id = make_file_system_safe_name(&email_id.trim().to_lowercase());
+        let user_directory = self.get_user_directory(&user_id)?;
+        if !user_directory.exists() {
+            fs::create_dir(&user_directory)
+                .map_err(|e| format!("Error creating {}: {}", user_directory.display(), e))?;
+            info!("Created sync directory {}", user_directory.display());
+        }
+        info!("🚀 Login successful");
+        // Nothing to authenticate against, the user id doubles as the tokens
+        Ok((user_id.clone(), user_id.clone(), user_id))
+    }
+
+    async fn refresh_session(&self, refresh_token: &str) -> Result<UserSession, String> {
+        let user_directory = self.get_user_directory(refresh_token)?;
+        if !user_directory.is_dir() {
+            return Err(format!(
+                "User directory {} does not exist",
+                user_directory.display()
+            ));
+        }
+        Ok((
+            refresh_token.to_string(),
+            refresh_token.to_string(),
+            refresh_token.to_string(),
+        ))
+    }
+
+    async fn logout(&self, _access_token: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    async fn get_saves(
+        &self,
+        user_id: &str,
+        _access_token: &str,
+        workspace: &str,
+    ) -> Result<Vec<CloudData>, String> {
+        let user_directory = self.get_workspace_directory(user_id, workspace)?;
+        if !user_directory.exists() {
+            return Ok(vec![]);
+        }
+        let entries = fs::read_dir(&user_directory)
+            .map_err(|e| format!("Error reading {}: {}", user_directory.display(), e))?;
+        let mut saves = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_save_file = path
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .is_some_and(|file_name| {
+                    file_name.starts_with(CLOUD_SAVE_FILE_PREFIX) && file_name.ends_with(".json")
+                });
+            if !is_save_file {
+                continue;
+            }
+            let cloud_data = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| {
+                    serde_json::from_str::<CloudData>(&content).map_err(|e| e.to_string())
+                });
+            match cloud_data {
+                Ok(cloud_data) => saves.push(cloud_data),
+                Err(e) => warn!("Skipping invalid cloud save {}: {}", path.display(), e),
+            }
+        }
+        saves.sort_by_key(|save| save.save_id);
+        debug!(
+            "Found {} cloud saves in {}",
+            saves.len(),
+            user_directory.display()
+        );
+        Ok(saves)
+    }
+
+    async fn get_save_ids(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+    ) -> Result<Vec<usize>, String> {
+        Ok(self
+            .get_saves(user_id, access_token, workspace)
+            .await?
+            .iter()
+            .map(|save| save.save_id)
+            .collect())
+    }
+
+    async fn upload_save(
+        &self,
+        user_id: &str,
+        _access_token: &str,
+        workspace: &str,
+        save_id: usize,
+        board_data: &str,
+        nonce: &str,
+    ) -> Result<(), String> {
+        let user_directory = self.get_workspace_directory(user_id, workspace)?;
+        fs::create_dir_all(&user_directory)
+            .map_err(|e| format!("Error creating {}: {}", user_directory.display(), e))?;
+        let id = save_id as u64;
+        let save_file_path = Self::get_save_file_path(&user_directory, id);
+        let cloud_data = CloudData {
+            id,
+            created_at: Utc::now().format("%Y-%m-%dT%H:%M:%S%.6f+00:00").to_string(),
+            user_id: user_id.to_string(),
+            board_data: board_data.to_string(),
+            nonce: nonce.to_string(),
+            save_id,
+        };
+        let content = serde_json::to_string_pretty(&cloud_data).map_err(|e| e.to_string())?;
+        // Written to a temporary file of its own first so other clients never read a half
+        // written save, then linked to the save file name which fails if another client got
+        // there first
+        let temp_file_path = save_file_path.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        fs::write(&temp_file_path, &content)
+            .map_err(|e| format!("Error writing {}: {}", temp_file_path.display(), e))?;
+        let published = match fs::hard_link(&temp_file_path, &save_file_path) {
+            // Directories without hard links (e.g. some WebDAV mounts) get the temporary file
+            // renamed into place instead, only if there is no save yet. Another client can still
+            // get there between the check and the rename, which the hard link rules out
+            Err(e) if e.kind() == ErrorKind::Unsupported => {
+                if save_file_path.exists() {
+                    Err(ErrorKind::AlreadyExists.into())
+                } else {
+                    fs::rename(&temp_file_path, &save_file_path)
+                }
+            }
+            result => result,
+        };
+        if temp_file_path.exists() {
+            if let Err(e) = fs::remove_file(&temp_file_path) {
+                warn!("Error removing {}: {}", temp_file_path.display(), e);
+            }
+        }
+        match published {
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                return Err(format!("Cloud save {} already exists", save_id));
+            }
+            Err(e) => {
+                return Err(format!("Error writing {}: {}", save_file_path.display(), e));
+            }
+            Ok(()) => {}
+        }
+        info!("Saved cloud save to {}", save_file_path.display());
+        Ok(())
+    }
+
+    async fn delete_save(
+        &self,
+        user_id: &str,
+        _access_token: &str,
+        workspace: &str,
+        id: u64,
+    ) -> Result<(), String> {
+        let user_directory = self.get_workspace_directory(user_id, workspace)?;
+        let save_file_path = Self::get_save_file_path(&user_directory, id);
+        if !save_file_path.exists() {
+            return Err(format!("Cloud save {} not found", id));
+        }
+        fs::remove_file(&save_file_path)
+            .map_err(|e| format!("Error deleting {}: {}", save_file_path.display(), e))?;
+        info!("👍 Cloud save deleted");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER_ID: &str = "user@example.com";
+
+    /// A sync directory of its own for every test, removed when the test is done
+    struct TestSyncDirectory(PathBuf);
+
+    impl TestSyncDirectory {
+        fn new() -> Self {
+            let path = std::env::temp_dir().join(format!("rust_kanban_sync_{}", Uuid::new_v4()));
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn backend(&self) -> LocalDirectoryBackend {
+            LocalDirectoryBackend::new(self.0.clone())
+        }
+    }
+
+    impl Drop for TestSyncDirectory {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    async fn login(backend: &LocalDirectoryBackend) -> String {
+        backend.login(USER_ID, "").await.unwrap().0
+    }
+
+    #[tokio::test]
+    async fn uploaded_saves_are_listed_and_downloaded() {
+        let sync_directory = TestSyncDirectory::new();
+        let backend = sync_directory.backend();
+        let user_id = login(&backend).await;
+        backend
+            .upload_save(
+                &user_id,
+                &user_id,
+                DEFAULT_WORKSPACE_NAME,
+                2,
+                "second",
+                "nonce 2",
+            )
+            .await
+            .unwrap();
+        backend
+            .upload_save(
+                &user_id,
+                &user_id,
+                DEFAULT_WORKSPACE_NAME,
+                1,
+                "first",
+                "nonce 1",
+            )
+            .await
+            .unwrap();
+
+        let saves = backend
+            .get_saves(&user_id, &user_id, DEFAULT_WORKSPACE_NAME)
+            .await
+            .unwrap();
+        assert_eq!(saves.len(), 2);
+        assert_eq!(
+            (
+                saves[0].save_id,
+                saves[0].board_data.as_str(),
+                saves[0].nonce.as_str()
+            ),
+            (1, "first", "nonce 1")
+        );
+        assert_eq!(
+            (
+                saves[1].save_id,
+                saves[1].board_data.as_str(),
+                saves[1].nonce.as_str()
+            ),
+            (2, "second", "nonce 2")
+        );
+        assert_eq!(
+            backend
+                .get_save_ids(&user_id, &user_id, DEFAULT_WORKSPACE_NAME)
+                .await
+                .unwrap(),
+            vec![1, 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn uploading_an_existing_save_id_is_a_conflict() {
+        let sync_directory = TestSyncDirectory::new();
+        let backend = sync_directory.backend();
+        let user_id = login(&backend).await;
+        backend
+            .upload_save(
+                &user_id,
+                &user_id,
+                DEFAULT_WORKSPACE_NAME,
+                1,
+                "first",
+                "nonce",
+            )
+            .await
+            .unwrap();
+
+        let result = backend
+            .upload_save(
+                &user_id,
+                &user_id,
+                DEFAULT_WORKSPACE_NAME,
+                1,
+                "other",
+                "nonce",
+            )
+            .await;
+        assert_eq!(result, Err("Cloud save 1 already exists".to_string()));
+        let saves = backend
+            .get_saves(&user_id, &user_id, DEFAULT_WORKSPACE_NAME)
+            .await
+            .unwrap();
+        assert_eq!(saves.len(), 1);
+        assert_eq!(saves[0].board_data, "first");
+        let file_names: Vec<_> = fs::read_dir(sync_directory.0.join(&user_id))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(file_names, vec!["cloud_save_1.json"]);
+    }
+
+    #[tokio::test]
+    async fn workspaces_have_their_own_saves() {
+        let sync_directory = TestSyncDirectory::new();
+        let backend = sync_directory.backend();
+        let user_id = login(&backend).await;
+        backend
+            .upload_save(
+                &user_id,
+                &user_id,
+                DEFAULT_WORKSPACE_NAME,
+                1,
+                "default",
+                "nonce",
+            )
+            .await
+            .unwrap();
+        backend
+            .upload_save(&user_id, &user_id, "Work", 1, "work", "nonce")
+            .await
+            .unwrap();
+
+        let default_saves = backend
+            .get_saves(&user_id, &user_id, DEFAULT_WORKSPACE_NAME)
+            .await
+            .unwrap();
+        let work_saves = backend.get_saves(&user_id, &user_id, "Work").await.unwrap();
+        assert_eq!(default_saves.len(), 1);
+        assert_eq!(default_saves[0].board_data, "default");
+        assert_eq!(work_saves.len(), 1);
+        assert_eq!(work_saves[0].board_data, "work");
+        assert!(backend
+            .get_saves(&user_id, &user_id, "Home")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn deleted_saves_are_no_longer_listed() {
+        let sync_directory = TestSyncDirectory::new();
+        let backend = sync_directory.backend();
+        let user_id = login(&backend).await;
+        backend
+            .upload_save(
+                &user_id,
+                &user_id,
+                DEFAULT_WORKSPACE_NAME,
+                1,
+                "first",
+                "nonce",
+            )
+            .await
+            .unwrap();
+
+        backend
+            .delete_save(&user_id, &user_id, DEFAULT_WORKSPACE_NAME, 1)
+            .await
+            .unwrap();
+        assert!(backend
+            .get_saves(&user_id, &user_id, DEFAULT_WORKSPACE_NAME)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(backend
+            .delete_save(&user_id, &user_id, DEFAULT_WORKSPACE_NAME, 1)
+            .await
+            .is_err());
+    }
+}
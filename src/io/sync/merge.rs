@@ -0,0 +1,496 @@
+use crate::app::kanban::{Board, BoardSortMode, Boards, Card, Cards};
+use std::collections::{HashMap, HashSet};
+
+/// A card as it is on one side of a sync, along with the board it is on
+#[derive(Debug, Clone)]
+pub struct CardVersion {
+    pub board_id: (u64, u64),
+    pub board_name: String,
+    pub board_description: String,
+    pub board_in_progress: bool,
+    pub card: Card,
+    /// Index of the card on its board
+    pub card_index: usize,
+}
+
+impl CardVersion {
+    pub fn new(board: &Board, card_index: usize, card: &Card) -> Self {
+        Self {
+            board_id: board.id,
+            board_name: board.name.clone(),
+            board_description: board.description.clone(),
+            board_in_progress: board.in_progress,
+            card: card.clone(),
+            card_index,
+        }
+    }
+
+    /// Cards edited in the app bump their revision and modified date, the content is compared
+    /// as well so changes made without them (e.g. by hand or by other tools) are not lost
+    fn differs_from(&self, other: &CardVersion) -> bool {
+        self.board_id != other.board_id
+            || self.card.revision != other.card.revision
+            || self.card.date_modified != other.card.date_modified
+            || self.card != other.card
+    }
+
+    /// Puts the card back at its index on its board (or at the end if the board got shorter),
+    /// replacing any other copy of it in `boards` including archived ones. The board is
+    /// recreated if it no longer exists
+    pub fn restore(&self, boards: &mut Boards) {
+        for board in boards.get_mut_boards() {
+            board.cards.remove_card_with_id(self.card.id);
+            board
+                .archived_cards
+                .retain(|archived_card| archived_card.card.id != self.card.id);
+        }
+        if boards.get_board_with_id(self.board_id).is_none() {
+            boards.add_board(Board {
+                archived_cards: Vec::new(),
+                cards: Cards::default(),
+                description: self.board_description.clone(),
+                id: self.board_id,
+                in_progress: self.board_in_progress,
+                manual_order: Vec::new(),
+                name: self.board_name.clone(),
+                sort_mode: BoardSortMode::Manual,
+                wip_limit: None,
+            });
+        }
+        if let Some(board) = boards.get_mut_board_with_id(self.board_id) {
+            let card_index = self.card_index.min(board.cards.len());
+            board.cards.add_card_at_index(card_index, self.card.clone());
+        }
+    }
+}
+
+/// A card that was changed on both sides since the last sync, a missing version means the
+/// card was deleted on that side
+#[derive(Debug, Clone)]
+pub struct CardConflict {
+    pub card_id: (u64, u64),
+    pub local: Option<CardVersion>,
+    pub remote: Option<CardVersion>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    KeepLocal,
+    KeepRemote,
+}
+
+impl CardConflict {
+    pub fn resolve(&self, merged: &mut Boards, resolution: ConflictResolution) {
+        let kept_version = match resolution {
+            ConflictResolution::KeepLocal => &self.local,
+            ConflictResolution::KeepRemote => &self.remote,
+        };
+        match kept_version {
+            Some(kept_version) => kept_version.restore(merged),
+            None => {
+                for board in merged.get_mut_boards() {
+                    board.cards.remove_card_with_id(self.card_id);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SyncMerge {
+    /// Everything that could be merged, conflicting cards are left out until resolved
+    pub merged: Boards,
+    pub conflicts: Vec<CardConflict>,
+}
+
+enum CardMerge<'a> {
+    Keep(&'a CardVersion),
+    Drop,
+    Conflict,
+}
+
+/// Three way merge of the boards from the last sync (`base`) with the local and remote changes
+/// made since. Cards changed on only one side take that side's version, cards changed on both
+/// sides to something different are returned as conflicts
+pub fn merge_boards(base: &Boards, local: &Boards, remote: &Boards) -> SyncMerge {
+    let base_cards = get_card_versions(base);
+    let local_cards = get_card_versions(local);
+    let remote_cards = get_card_versions(remote);
+
+    let mut card_ids: Vec<(u64, u64)> = Vec::new();
+    let mut seen_card_ids = HashSet::new();
+    for boards in [local, remote, base] {
+        for board in boards.get_boards() {
+            for card in board.cards.get_all_cards() {
+                if seen_card_ids.insert(card.id) {
+                    card_ids.push(card.id);
+                }
+            }
+        }
+    }
+
+    let mut kept_cards: HashMap<(u64, u64), CardVersion> = HashMap::new();
+    let mut conflicts = Vec::new();
+    for card_id in &card_ids {
+        let local_version = local_cards.get(card_id);
+        let remote_version = remote_cards.get(card_id);
+        match merge_card(base_cards.get(card_id), local_version, remote_version) {
+            CardMerge::Keep(version) => {
+                kept_cards.insert(*card_id, version.clone());
+            }
+            CardMerge::Drop => {}
+            CardMerge::Conflict => conflicts.push(CardConflict {
+                card_id: *card_id,
+                local: local_version.cloned(),
+                remote: remote_version.cloned(),
+            }),
+        }
+    }
+
+    let mut merged = Boards::default();
+    let mut seen_board_ids = HashSet::new();
+    for board in local.get_boards().iter().chain(remote.get_boards()) {
+        if !seen_board_ids.insert(board.id) {
+            continue;
+        }
+        let base_board = base.get_board_with_id(board.id);
+        let local_board = local.get_board_with_id(board.id);
+        let remote_board = remote.get_board_with_id(board.id);
+        let has_kept_cards = kept_cards
+            .values()
+            .any(|version| version.board_id == board.id);
+        // A board deleted on one side stays deleted, unless cards were added to or changed in
+        // it on the other side
+        let deleted_on_one_side =
+            base_board.is_some() && (local_board.is_none() || remote_board.is_none());
+        if deleted_on_one_side && !has_kept_cards {
+            continue;
+        }
+        let details_board = match (local_board, remote_board) {
+            (Some(local_board), Some(remote_board)) => {
+                merge_board_details(base_board, local_board, remote_board)
+            }
+            _ => board,
+        };
+        merged.add_board(Board {
+            cards: Cards::default(),
+            ..details_board.clone()
+        });
+    }
+
+    // Keep the local order of cards, cards that only exist remotely go after them
+    for boards in [local, remote] {
+        for board in boards.get_boards() {
+            for card in board.cards.get_all_cards() {
+                if let Some(version) = kept_cards.remove(&card.id) {
+                    if let Some(merged_board) = merged.get_mut_board_with_id(version.board_id) {
+                        merged_board.cards.add_card(version.card);
+                    }
+                }
+            }
+        }
+    }
+
+    // Cards archived on either side stay archived, unless they are on a board after the merge
+    let merged_card_ids: HashSet<(u64, u64)> = merged
+        .get_boards()
+        .iter()
+        .flat_map(|board| board.cards.get_all_card_ids())
+        .collect();
+    for merged_board in merged.get_mut_boards() {
+        let mut archived_cards = Vec::new();
+        let mut seen_archived_card_ids = HashSet::new();
+        for boards in [local, remote] {
+            let Some(board) = boards.get_board_with_id(merged_board.id) else {
+                continue;
+            };
+            for archived_card in &board.archived_cards {
+                if !merged_card_ids.contains(&archived_card.card.id)
+                    && seen_archived_card_ids.insert(archived_card.card.id)
+                {
+                    archived_cards.push(archived_card.clone());
+                }
+            }
+        }
+        merged_board.archived_cards = archived_cards;
+    }
+
+    SyncMerge { merged, conflicts }
+}
+
+pub fn get_card_versions(boards: &Boards) -> HashMap<(u64, u64), CardVersion> {
+    let mut card_versions = HashMap::new();
+    for board in boards.get_boards() {
+        for (card_index, card) in board.cards.get_all_cards().iter().enumerate() {
+            card_versions.insert(card.id, CardVersion::new(board, card_index, card));
+        }
+    }
+    card_versions
+}
+
+fn merge_card<'a>(
+    base: Option<&'a CardVersion>,
+    local: Option<&'a CardVersion>,
+    remote: Option<&'a CardVersion>,
+) -> CardMerge<'a> {
+    match (base, local, remote) {
+        (None, Some(local), None) => CardMerge::Keep(local),
+        (None, None, Some(remote)) => CardMerge::Keep(remote),
+        (None, Some(local), Some(remote)) => {
+            if local.differs_from(remote) {
+                CardMerge::Conflict
+            } else {
+                CardMerge::Keep(local)
+            }
+        }
+        (Some(base), Some(changed), None) | (Some(base), None, Some(changed)) => {
+            if changed.differs_from(base) {
+                // Changed on one side and deleted on the other
+                CardMerge::Conflict
+            } else {
+                CardMerge::Drop
+            }
+        }
+        (Some(base), Some(local), Some(remote)) => {
+            match (local.differs_from(base), remote.differs_from(base)) {
+                (false, _) => CardMerge::Keep(remote),
+                (true, false) => CardMerge::Keep(local),
+                (true, true) => {
+                    if local.differs_from(remote) {
+                        CardMerge::Conflict
+                    } else {
+                        CardMerge::Keep(local)
+                    }
+                }
+            }
+        }
+        (_, None, None) => CardMerge::Drop,
+    }
+}
+
+/// Everything but the cards of a board is merged the same way as cards, except that local
+/// changes win when both sides changed a board. Returns the board to take the details from
+fn merge_board_details<'a>(base: Option<&Board>, local: &'a Board, remote: &'a Board) -> &'a Board {
+    let details = |board: &Board| {
+        (
+            board.name.clone(),
+            board.description.clone(),
+            board.in_progress,
+            board.sort_mode,
+            board.manual_order.clone(),
+            board.wip_limit,
+        )
+    };
+    let local_changed = base.is_none_or(|base| details(base) != details(local));
+    if local_changed {
+        local
+    } else {
+        remote
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::CardPriority;
+
+    fn new_card(name: &str) -> Card {
+        Card::new(name, "", None, CardPriority::Low, Vec::new(), Vec::new())
+    }
+
+    /// A "Todo" board with a "First" and a "Second" card
+    fn base_boards() -> Boards {
+        let mut board = Board::new("Todo", "");
+        board.cards.add_card(new_card("First"));
+        board.cards.add_card(new_card("Second"));
+        Boards::from(vec![board])
+    }
+
+    fn card_id(boards: &Boards, name: &str) -> (u64, u64) {
+        boards
+            .get_boards()
+            .iter()
+            .flat_map(|board| board.cards.get_all_cards())
+            .find(|card| card.name == name)
+            .unwrap()
+            .id
+    }
+
+    fn rename_card(boards: &Boards, name: &str, new_name: &str) -> Boards {
+        let id = card_id(boards, name);
+        let mut boards = boards.clone();
+        for board in boards.get_mut_boards() {
+            if let Some(card) = board.cards.get_mut_card_with_id(id) {
+                card.name = new_name.to_string();
+                card.mark_modified();
+            }
+        }
+        boards
+    }
+
+    fn remove_card(boards: &Boards, name: &str) -> Boards {
+        let id = card_id(boards, name);
+        let mut boards = boards.clone();
+        for board in boards.get_mut_boards() {
+            board.cards.remove_card_with_id(id);
+        }
+        boards
+    }
+
+    fn card_names(boards: &Boards) -> Vec<(String, String)> {
+        boards
+            .get_boards()
+            .iter()
+            .flat_map(|board| {
+                board
+                    .cards
+                    .get_all_cards()
+                    .iter()
+                    .map(|card| (board.name.clone(), card.name.clone()))
+            })
+            .collect()
+    }
+
+    fn names(names: &[(&str, &str)]) -> Vec<(String, String)> {
+        names
+            .iter()
+            .map(|(board, card)| (board.to_string(), card.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn card_edited_on_one_side_takes_the_edit() {
+        let base = base_boards();
+        let local = rename_card(&base, "First", "First edited locally");
+        let remote = rename_card(&base, "Second", "Second edited remotely");
+
+        let sync_merge = merge_boards(&base, &local, &remote);
+        assert!(sync_merge.conflicts.is_empty());
+        assert_eq!(
+            card_names(&sync_merge.merged),
+            names(&[
+                ("Todo", "First edited locally"),
+                ("Todo", "Second edited remotely")
+            ])
+        );
+    }
+
+    #[test]
+    fn card_edited_the_same_on_both_sides_is_not_a_conflict() {
+        let base = base_boards();
+        let edited = rename_card(&base, "First", "First edited");
+
+        let sync_merge = merge_boards(&base, &edited, &edited.clone());
+        assert!(sync_merge.conflicts.is_empty());
+        assert_eq!(
+            card_names(&sync_merge.merged),
+            names(&[("Todo", "First edited"), ("Todo", "Second")])
+        );
+    }
+
+    #[test]
+    fn card_edited_differently_on_both_sides_is_a_conflict() {
+        let base = base_boards();
+        let id = card_id(&base, "First");
+        let local = rename_card(&base, "First", "First edited locally");
+        let remote = rename_card(&base, "First", "First edited remotely");
+
+        let mut sync_merge = merge_boards(&base, &local, &remote);
+        assert_eq!(card_names(&sync_merge.merged), names(&[("Todo", "Second")]));
+        assert_eq!(sync_merge.conflicts.len(), 1);
+        let conflict = &sync_merge.conflicts[0];
+        assert_eq!(conflict.card_id, id);
+        assert_eq!(
+            conflict.local.as_ref().unwrap().card.name,
+            "First edited locally"
+        );
+        assert_eq!(
+            conflict.remote.as_ref().unwrap().card.name,
+            "First edited remotely"
+        );
+
+        conflict.resolve(&mut sync_merge.merged, ConflictResolution::KeepRemote);
+        assert_eq!(
+            card_names(&sync_merge.merged),
+            names(&[("Todo", "First edited remotely"), ("Todo", "Second")])
+        );
+    }
+
+    #[test]
+    fn card_deleted_on_one_side_and_edited_on_the_other_is_a_conflict() {
+        let base = base_boards();
+        let local = remove_card(&base, "First");
+        let remote = rename_card(&base, "First", "First edited remotely");
+
+        let sync_merge = merge_boards(&base, &local, &remote);
+        assert_eq!(card_names(&sync_merge.merged), names(&[("Todo", "Second")]));
+        assert_eq!(sync_merge.conflicts.len(), 1);
+        assert!(sync_merge.conflicts[0].local.is_none());
+        assert_eq!(
+            sync_merge.conflicts[0].remote.as_ref().unwrap().card.name,
+            "First edited remotely"
+        );
+    }
+
+    #[test]
+    fn card_deleted_on_one_side_and_unchanged_on_the_other_is_deleted() {
+        let base = base_boards();
+        let local = remove_card(&base, "First");
+
+        let sync_merge = merge_boards(&base, &local, &base.clone());
+        assert!(sync_merge.conflicts.is_empty());
+        assert_eq!(card_names(&sync_merge.merged), names(&[("Todo", "Second")]));
+    }
+
+    #[test]
+    fn board_deleted_on_one_side_is_kept_for_cards_added_on_the_other() {
+        let base = base_boards();
+        let board_id = base.get_boards()[0].id;
+        let mut local = base.clone();
+        local.remove_board_with_id(board_id);
+        let mut remote = base.clone();
+        remote
+            .get_mut_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .add_card(new_card("Added remotely"));
+
+        let sync_merge = merge_boards(&base, &local, &remote);
+        assert!(sync_merge.conflicts.is_empty());
+        assert_eq!(
+            card_names(&sync_merge.merged),
+            names(&[("Todo", "Added remotely")])
+        );
+
+        let sync_merge = merge_boards(&base, &local, &base.clone());
+        assert!(sync_merge.conflicts.is_empty());
+        assert!(sync_merge.merged.is_empty());
+    }
+
+    #[test]
+    fn first_sync_without_a_base_keeps_both_sides() {
+        let base = Boards::default();
+        let local = base_boards();
+        let mut remote = local.clone();
+        let remote_board = Board::new("Remote", "");
+        let remote_board_id = remote_board.id;
+        remote.add_board(remote_board);
+        remote
+            .get_mut_board_with_id(remote_board_id)
+            .unwrap()
+            .cards
+            .add_card(new_card("Remote card"));
+        let remote = rename_card(&remote, "Second", "Second edited remotely");
+
+        let sync_merge = merge_boards(&base, &local, &remote);
+        assert_eq!(
+            card_names(&sync_merge.merged),
+            names(&[("Todo", "First"), ("Remote", "Remote card")])
+        );
+        assert_eq!(sync_merge.conflicts.len(), 1);
+        assert_eq!(
+            sync_merge.conflicts[0].local.as_ref().unwrap().card.name,
+            "Second"
+        );
+    }
+}
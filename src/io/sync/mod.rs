@@ -0,0 +1,326 @@
+use crate::{
+    app::{
+        kanban::{Board, Boards},
+        workspace::get_workspace_folder_name,
+        AppConfig, DateTimeFormat,
+    },
+    constants::{
+        DEFAULT_WORKSPACE_NAME, SAVE_SCHEMA_VERSION, SYNC_BASE_FILE_NAME, WORKSPACE_DIR_NAME,
+    },
+    io::{
+        data_handler::write_file_atomically,
+        io_handler::{get_config_dir, CloudData},
+        migrations::migrate_save,
+        sync::{
+            local_directory::LocalDirectoryBackend,
+            merge::{CardConflict, ConflictResolution},
+            supabase::SupabaseBackend,
+        },
+    },
+};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{fs, future::Future, path::PathBuf};
+use strum::{Display, EnumString};
+
+pub mod local_directory;
+pub mod merge;
+pub mod supabase;
+
+/// (access_token, user_id, refresh_token) of a logged in user
+pub type UserSession = (String, String, String);
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, EnumString, Display,
+)]
+pub enum SyncBackendType {
+    #[default]
+    Supabase,
+    LocalDirectory,
+}
+
+/// Where cloud saves are stored. Boards are encrypted with the user's key before they reach a
+/// backend, so a backend only ever stores and returns opaque `CloudData`. Every workspace has
+/// its own cloud saves, save ids are counted per workspace
+pub trait SyncBackend {
+    fn login(
+        &self,
+        email_id: &str,
+        password: &str,
+    ) -> impl Future<Output = Result<UserSession, String>> + Send;
+
+    /// Exchanges the refresh token saved on disk for a new session, used for auto login
+    fn refresh_session(
+        &self,
+        refresh_token: &str,
+    ) -> impl Future<Output = Result<UserSession, String>> + Send;
+
+    fn logout(&self, access_token: &str) -> impl Future<Output = Result<(), String>> + Send;
+
+    fn get_saves(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+    ) -> impl Future<Output = Result<Vec<CloudData>, String>> + Send;
+
+    fn get_save_ids(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+    ) -> impl Future<Output = Result<Vec<usize>, String>> + Send;
+
+    fn upload_save(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+        save_id: usize,
+        board_data: &str,
+        nonce: &str,
+    ) -> impl Future<Output = Result<(), String>> + Send;
+
+    /// `id` is `CloudData::id`, not the save number
+    fn delete_save(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+        id: u64,
+    ) -> impl Future<Output = Result<(), String>> + Send;
+}
+
+/// The backend selected in the config
+pub enum SyncBackendClient {
+    Supabase(SupabaseBackend),
+    LocalDirectory(LocalDirectoryBackend),
+}
+
+impl SyncBackendClient {
+    pub fn from_config(config: &AppConfig, cli_mode: bool) -> Self {
+        match config.sync_backend {
+            SyncBackendType::Supabase => Self::Supabase(SupabaseBackend::new(cli_mode)),
+            SyncBackendType::LocalDirectory => {
+                Self::LocalDirectory(LocalDirectoryBackend::new(config.sync_directory.clone()))
+            }
+        }
+    }
+}
+
+impl SyncBackend for SyncBackendClient {
+    async fn login(&self, email_id: &str, password: &str) -> Result<UserSession, String> {
+        match self {
+            Self::Supabase(backend) => backend.login(email_id, password).await,
+            Self::LocalDirectory(backend) => backend.login(email_id, password).await,
+        }
+    }
+
+    async fn refresh_session(&self, refresh_token: &str) -> Result<UserSession, String> {
+        match self {
+            Self::Supabase(backend) => backend.refresh_session(refresh_token).await,
+            Self::LocalDirectory(backend) => backend.refresh_session(refresh_token).await,
+        }
+    }
+
+    async fn logout(&self, access_token: &str) -> Result<(), String> {
+        match self {
+            Self::Supabase(backend) => backend.logout(access_token).await,
+            Self::LocalDirectory(backend) => backend.logout(access_token).await,
+        }
+    }
+
+    async fn get_saves(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+    ) -> Result<Vec<CloudData>, String> {
+        match self {
+            Self::Supabase(backend) => backend.get_saves(user_id, access_token, workspace).await,
+            Self::LocalDirectory(backend) => {
+                backend.get_saves(user_id, access_token, workspace).await
+            }
+        }
+    }
+
+    async fn get_save_ids(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+    ) -> Result<Vec<usize>, String> {
+        match self {
+            Self::Supabase(backend) => backend.get_save_ids(user_id, access_token, workspace).await,
+            Self::LocalDirectory(backend) => {
+                backend.get_save_ids(user_id, access_token, workspace).await
+            }
+        }
+    }
+
+    async fn upload_save(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+        save_id: usize,
+        board_data: &str,
+        nonce: &str,
+    ) -> Result<(), String> {
+        match self {
+            Self::Supabase(backend) => {
+                backend
+                    .upload_save(user_id, access_token, workspace, save_id, board_data, nonce)
+                    .await
+            }
+            Self::LocalDirectory(backend) => {
+                backend
+                    .upload_save(user_id, access_token, workspace, save_id, board_data, nonce)
+                    .await
+            }
+        }
+    }
+
+    async fn delete_save(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+        id: u64,
+    ) -> Result<(), String> {
+        match self {
+            Self::Supabase(backend) => {
+                backend
+                    .delete_save(user_id, access_token, workspace, id)
+                    .await
+            }
+            Self::LocalDirectory(backend) => {
+                backend
+                    .delete_save(user_id, access_token, workspace, id)
+                    .await
+            }
+        }
+    }
+}
+
+/// The boards as they were after the last sync, the common ancestor when merging local and
+/// remote changes
+#[derive(Debug, Clone)]
+pub struct SyncBase {
+    pub save_id: usize,
+    pub boards: Boards,
+}
+
+/// Every workspace has its own sync base, the default workspace keeps the file it had before
+/// workspaces and the others get one in their folder of the config directory
+fn get_sync_base_path(workspace: &str) -> Result<PathBuf, String> {
+    let config_dir = get_config_dir()?;
+    if workspace == DEFAULT_WORKSPACE_NAME {
+        return Ok(config_dir.join(SYNC_BASE_FILE_NAME));
+    }
+    let folder_name = get_workspace_folder_name(workspace)
+        .ok_or_else(|| format!("Invalid workspace name \"{}\"", workspace))?;
+    Ok(config_dir
+        .join(WORKSPACE_DIR_NAME)
+        .join(folder_name)
+        .join(SYNC_BASE_FILE_NAME))
+}
+
+/// Returns None if there is no base for this user and workspace yet, in which case every card
+/// that differs between local and remote is treated as a conflict
+pub fn get_sync_base(
+    user_id: &str,
+    workspace: &str,
+    date_time_format: DateTimeFormat,
+) -> Option<SyncBase> {
+    let sync_base_path = get_sync_base_path(workspace).ok()?;
+    if !sync_base_path.exists() {
+        return None;
+    }
+    let sync_base = fs::read_to_string(&sync_base_path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| serde_json::from_str::<Value>(&content).map_err(|e| e.to_string()))
+        .and_then(|mut sync_base| {
+            // Bases from before workspaces belong to the default workspace
+            let sync_base_workspace = sync_base["workspace"]
+                .as_str()
+                .unwrap_or(DEFAULT_WORKSPACE_NAME);
+            if sync_base["user_id"].as_str() != Some(user_id) || sync_base_workspace != workspace {
+                return Ok(None);
+            }
+            let save_id = sync_base["save_id"]
+                .as_u64()
+                .ok_or("save_id is missing or invalid")? as usize;
+            migrate_save(&mut sync_base, date_time_format)?;
+            let boards = sync_base["boards"]
+                .as_array()
+                .ok_or("boards are missing or invalid")?
+                .iter()
+                .map(Board::from_json)
+                .collect::<Result<Vec<Board>, String>>()?;
+            Ok(Some(SyncBase {
+                save_id,
+                boards: Boards::from(boards),
+            }))
+        });
+    match sync_base {
+        Ok(sync_base) => sync_base,
+        Err(e) => {
+            warn!(
+                "Ignoring invalid sync base {}: {}",
+                sync_base_path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+pub fn save_sync_base(
+    user_id: &str,
+    workspace: &str,
+    save_id: usize,
+    boards: &Boards,
+) -> Result<(), String> {
+    let sync_base_path = get_sync_base_path(workspace)?;
+    if let Some(sync_base_dir) = sync_base_path.parent() {
+        fs::create_dir_all(sync_base_dir)
+            .map_err(|e| format!("Error creating {}: {}", sync_base_dir.display(), e))?;
+    }
+    let mut sync_base = serde_json::to_value(boards).map_err(|e| e.to_string())?;
+    sync_base["schema_version"] = Value::from(SAVE_SCHEMA_VERSION);
+    sync_base["user_id"] = Value::from(user_id);
+    sync_base["workspace"] = Value::from(workspace);
+    sync_base["save_id"] = Value::from(save_id);
+    write_file_atomically(&sync_base_path, sync_base.to_string())?;
+    debug!("Saved sync base for cloud save {}", save_id);
+    Ok(())
+}
+
+/// A sync that is waiting on the user to resolve its conflicts
+#[derive(Debug, Clone)]
+pub struct PendingSync {
+    pub conflicts: Vec<CardConflict>,
+    pub current_conflict: usize,
+    /// The merged boards, conflicts are applied to it as they are resolved
+    pub merged: Boards,
+    /// The latest cloud save the merge was made against, it becomes the new sync base
+    pub remote_boards: Boards,
+    pub remote_save_id: usize,
+}
+
+impl PendingSync {
+    pub fn get_current_conflict(&self) -> Option<&CardConflict> {
+        self.conflicts.get(self.current_conflict)
+    }
+
+    /// Returns true once every conflict has been resolved
+    pub fn resolve_current_conflict(&mut self, resolution: ConflictResolution) -> bool {
+        if let Some(conflict) = self.conflicts.get(self.current_conflict) {
+            conflict.resolve(&mut self.merged, resolution);
+            self.current_conflict += 1;
+        }
+        self.current_conflict >= self.conflicts.len()
+    }
+}
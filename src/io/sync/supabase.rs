@@ -0,0 +1,608 @@
+use crate::{
+    constants::{DEFAULT_WORKSPACE_NAME, SUPABASE_ANON_KEY, SUPABASE_URL},
+    io::{
+        io_handler::CloudData,
+        sync::{SyncBackend, UserSession},
+    },
+    util::{print_debug, print_error, print_info},
+};
+use eyre::{anyhow, Result};
+use log::{debug, error, info};
+use reqwest::StatusCode;
+use serde_json::json;
+
+/// The hosted rust-kanban Supabase project, accounts are managed by Supabase auth and saves are
+/// rows of the `user_data` table with the workspace they belong to in its `workspace` column
+pub struct SupabaseBackend {
+    cli_mode: bool,
+}
+
+impl SupabaseBackend {
+    /// In cli mode progress and errors are printed instead of logged
+    pub fn new(cli_mode: bool) -> Self {
+        Self { cli_mode }
+    }
+}
+
+impl SyncBackend for SupabaseBackend {
+    async fn login(&self, email_id: &str, password: &str) -> Result<UserSession, String> {
+        login_for_user(email_id, password, self.cli_mode).await
+    }
+
+    async fn refresh_session(&self, refresh_token: &str) -> Result<UserSession, String> {
+        refresh_access_token(refresh_token).await
+    }
+
+    async fn logout(&self, access_token: &str) -> Result<(), String> {
+        let client = reqwest::Client::new();
+        let response = client
+            .post(format!("{}/auth/v1/logout", SUPABASE_URL))
+            .header("apikey", SUPABASE_ANON_KEY)
+            .header("Content-Type", "application/json")
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Error logging out: {}", e))?;
+        let status = response.status();
+        if status == StatusCode::NO_CONTENT {
+            Ok(())
+        } else {
+            Err(format!(
+                "Error logging out, status code {}, response body: {:?}",
+                status,
+                response.text().await
+            ))
+        }
+    }
+
+    async fn get_saves(
+        &self,
+        _user_id: &str,
+        access_token: &str,
+        workspace: &str,
+    ) -> Result<Vec<CloudData>, String> {
+        // Row level security only returns the rows of the logged in user
+        let client = reqwest::Client::new();
+        let response = client
+            .get(format!("{}/rest/v1/user_data", SUPABASE_URL))
+            .query(&[get_workspace_filter(workspace)])
+            .header("apikey", SUPABASE_ANON_KEY)
+            .header("Content-Type", "application/json")
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Error getting cloud saves: {}", e))?;
+        let status = response.status();
+        if status == StatusCode::OK {
+            response
+                .json::<Vec<CloudData>>()
+                .await
+                .map_err(|e| format!("Error parsing cloud saves: {}", e))
+        } else {
+            Err(format!(
+                "Error getting cloud saves, status code {}, response body: {:?}",
+                status,
+                response.text().await
+            ))
+        }
+    }
+
+    async fn get_save_ids(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+    ) -> Result<Vec<usize>, String> {
+        get_all_save_ids_for_user(user_id.to_string(), access_token, workspace)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn upload_save(
+        &self,
+        user_id: &str,
+        access_token: &str,
+        workspace: &str,
+        save_id: usize,
+        board_data: &str,
+        nonce: &str,
+    ) -> Result<(), String> {
+        let client = reqwest::Client::new();
+        let response = client
+            .post(format!("{}/rest/v1/user_data", SUPABASE_URL))
+            .header("apikey", SUPABASE_ANON_KEY)
+            .header("Content-Type", "application/json")
+            .header("Authorization", format!("Bearer {}", access_token))
+            .body(
+                json!(
+                    {
+                        "user_id": user_id,
+                        "workspace": workspace,
+                        "board_data": board_data,
+                        "save_id": save_id,
+                        "nonce": nonce
+                    }
+                )
+                .to_string(),
+            )
+            .send()
+            .await
+            .map_err(|e| format!("Error uploading save: {}", e))?;
+        let status = response.status();
+        if status == StatusCode::CREATED {
+            Ok(())
+        } else {
+            Err(format!(
+                "Error uploading save, status code {}, response body: {:?}",
+                status,
+                response.text().await
+            ))
+        }
+    }
+
+    /// Row ids are unique across workspaces, so the workspace is not needed to find the save
+    async fn delete_save(
+        &self,
+        _user_id: &str,
+        access_token: &str,
+        _workspace: &str,
+        id: u64,
+    ) -> Result<(), String> {
+        delete_a_save_from_database(access_token, self.cli_mode, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// PostgREST filter for the saves of a workspace, saves uploaded before workspaces have no
+/// workspace and belong to the default one
+fn get_workspace_filter(workspace: &str) -> (&'static str, String) {
+    if workspace == DEFAULT_WORKSPACE_NAME {
+        (
+            "or",
+            format!(
+                "(workspace.is.null,workspace.eq.{})",
+                DEFAULT_WORKSPACE_NAME
+            ),
+        )
+    } else {
+        ("workspace", format!("eq.{}", workspace))
+    }
+}
+
+async fn get_all_save_ids_for_user(
+    user_id: String,
+    access_token: &str,
+    workspace: &str,
+) -> Result<Vec<usize>> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!(
+            "{}/rest/v1/user_data?user_id=eq.{}&select=save_id",
+            SUPABASE_URL, user_id
+        ))
+        .query(&[get_workspace_filter(workspace)])
+        .header("apikey", SUPABASE_ANON_KEY)
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", access_token))
+        .header("Range", "0-9")
+        .send()
+        .await;
+    if response.is_err() {
+        debug!("Error getting save ids: {:?}", response.err());
+        return Err(anyhow!("Error getting save ids".to_string()));
+    }
+    let response = response.unwrap();
+    let status = response.status();
+    if status == StatusCode::OK {
+        let body = response.json::<serde_json::Value>().await;
+        match body {
+            Ok(save_instances) => {
+                let mut save_ids: Vec<usize> = Vec::new();
+                let save_instances_as_array = save_instances.as_array();
+                if save_instances_as_array.is_none() {
+                    return Err(anyhow!("Error getting save ids".to_string()));
+                }
+                let save_instances_as_array = save_instances_as_array.unwrap();
+                for save_instance in save_instances_as_array {
+                    let save_id = save_instance.get("save_id");
+                    if save_id.is_none() {
+                        return Err(anyhow!("Error getting save ids".to_string()));
+                    }
+                    let save_id = save_id.unwrap().as_u64();
+                    if save_id.is_none() {
+                        return Err(anyhow!("Error getting save ids".to_string()));
+                    }
+                    debug!("Found save_id: {:?}", save_id.unwrap() as usize);
+                    save_ids.push(save_id.unwrap() as usize);
+                }
+                debug!("Save instances: {:?}", save_instances);
+                Ok(save_ids)
+            }
+            Err(e) => {
+                debug!("Error getting save ids: {:?}", e);
+                Err(anyhow!("Error getting save ids".to_string()))
+            }
+        }
+    } else {
+        debug!("Status: {:?}", status);
+        debug!("Error getting save ids: {:?}", response.text().await);
+        Err(anyhow!("Error getting save ids".to_string()))
+    }
+}
+
+async fn delete_a_save_from_database(
+    access_token: &str,
+    cli_mode: bool,
+    save_id: u64,
+) -> Result<()> {
+    let client = reqwest::Client::new();
+    let response = client
+        .delete(format!(
+            "{}/rest/v1/user_data?id=eq.{}",
+            SUPABASE_URL, save_id
+        ))
+        .header("apikey", SUPABASE_ANON_KEY)
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", access_token))
+        .send()
+        .await?;
+    let status = response.status();
+    if status == StatusCode::NO_CONTENT {
+        if cli_mode {
+            print_info("👍 Cloud save deleted");
+        } else {
+            info!("👍 Cloud save deleted");
+        }
+        Ok(())
+    } else {
+        let body = response.json::<serde_json::Value>().await;
+        if cli_mode {
+            print_error("Error deleting cloud save");
+            print_debug(&format!(
+                "status code {}, response body: {:?}",
+                status, body
+            ));
+        } else {
+            error!("Error deleting cloud save");
+            debug!("status code {}, response body: {:?}", status, body);
+        }
+        Err(anyhow!("Error deleting cloud save"))
+    }
+}
+
+async fn get_user_id_from_database(access_token: &str, cli_mode: bool) -> Result<String> {
+    let user_data_client = reqwest::Client::new();
+    let user_data_response = user_data_client
+        .get(format!("{}/auth/v1/user", SUPABASE_URL))
+        .header("apikey", SUPABASE_ANON_KEY)
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", access_token))
+        .send()
+        .await?;
+    let user_data_status = user_data_response.status();
+    let user_data_body = user_data_response.json::<serde_json::Value>().await;
+    if user_data_status != StatusCode::OK {
+        if cli_mode {
+            print_error("Error retrieving user data");
+            print_debug(&format!(
+                "status code {}, response body: {:?}",
+                user_data_status, user_data_body
+            ));
+        } else {
+            error!("Error retrieving user data");
+            debug!(
+                "status code {}, response body: {:?}",
+                user_data_status, user_data_body
+            );
+        }
+        return Err(anyhow!("Error retrieving user data"));
+    }
+    let user_data_body = user_data_body.unwrap();
+    let user_id = user_data_body.get("id");
+    if user_id.is_none() {
+        if cli_mode {
+            print_error("Error retrieving user data");
+            print_debug(&format!(
+                "status code {}, response body: {:?}, could not find id",
+                user_data_status, user_data_body
+            ));
+        } else {
+            error!("Error retrieving user data");
+            debug!(
+                "status code {}, response body: {:?}, could not find id",
+                user_data_status, user_data_body
+            );
+        }
+        return Err(anyhow!("Error retrieving user data"));
+    }
+    let user_id = user_id.unwrap().as_str();
+    if cli_mode {
+        print_debug(&format!("user_id: {:?}", user_id));
+    } else {
+        debug!("user_id: {:?}", user_id);
+    }
+    Ok(user_id.unwrap().to_string())
+}
+
+async fn login_for_user(
+    email_id: &str,
+    password: &str,
+    cli_mode: bool,
+) -> Result<(String, String, String), String> {
+    let request_body = json!(
+        {
+            "email": email_id,
+            "password": password
+        }
+    );
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!(
+            "{}/auth/v1/token?grant_type=password",
+            SUPABASE_URL
+        ))
+        .header("apikey", SUPABASE_ANON_KEY)
+        .header("Content-Type", "application/json")
+        .body(request_body.to_string())
+        .send()
+        .await;
+    if let Err(e) = response {
+        if cli_mode {
+            print_debug(&format!("Error logging in: {}", e));
+            print_error("Error logging in, Something went wrong, please try again later");
+        } else {
+            debug!("Error logging in: {}", e);
+            error!("Error logging in, Something went wrong, please try again later");
+        }
+        return Err("Error logging in, Something went wrong, please try again later".to_string());
+    }
+    let response = response.unwrap();
+    let status = response.status();
+    let body = response.json::<serde_json::Value>().await;
+    if status == StatusCode::OK {
+        match body {
+            Ok(body) => {
+                let access_token = body.get("access_token");
+                let refresh_token = body.get("refresh_token");
+                let access_token_result = match access_token {
+                    Some(access_token) => {
+                        let access_token = access_token.as_str().unwrap();
+                        if cli_mode {
+                            print_info("🚀 Login successful");
+                            print_debug(&format!("Access token: {}", access_token));
+                        } else {
+                            info!("🚀 Login successful");
+                            debug!("Access token: {}", access_token);
+                        }
+                        let user_id = get_user_id_from_database(access_token, cli_mode)
+                            .await
+                            .unwrap_or_else(|_| "Error getting user id".to_string());
+                        Ok((access_token.to_string(), user_id))
+                    }
+                    None => {
+                        if cli_mode {
+                            print_error("Error logging in");
+                            print_debug(&format!(
+                                "status code {}, response body: {:?}, could not find access token",
+                                status, body
+                            ));
+                        } else {
+                            error!("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer");
+                            debug!(
+                                "status code {}, response body: {:?}, could not find access token",
+                                status, body
+                            );
+                        }
+                        Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
+                    }
+                };
+                let refresh_token_result = match refresh_token {
+                    Some(refresh_token) => {
+                        let refresh_token = refresh_token.as_str().unwrap();
+                        if cli_mode {
+                            print_debug(&format!("Refresh token: {}", refresh_token));
+                        } else {
+                            debug!("Refresh token: {}", refresh_token);
+                        }
+                        Ok(refresh_token.to_string())
+                    }
+                    None => {
+                        if cli_mode {
+                            print_error("Error logging in");
+                            print_debug(&format!(
+                                "status code {}, response body: {:?}, could not find refresh token",
+                                status, body
+                            ));
+                        } else {
+                            error!("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer");
+                            debug!(
+                                "status code {}, response body: {:?}, could not find refresh token",
+                                status, body
+                            );
+                        }
+                        Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
+                    }
+                };
+
+                if access_token_result.is_err() || refresh_token_result.is_err() {
+                    Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
+                } else {
+                    let access_token_result = access_token_result.unwrap();
+                    let refresh_token_result = refresh_token_result.unwrap();
+                    Ok((
+                        access_token_result.0,
+                        access_token_result.1,
+                        refresh_token_result,
+                    ))
+                }
+            }
+            Err(e) => Err(format!("Error logging in: {}", e)),
+        }
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        if cli_mode {
+            print_error("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢");
+            print_debug(&format!(
+                "status code {}, response body: {:?}",
+                status, body
+            ));
+        } else {
+            error!("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢");
+            debug!("status code {}, response body: {:?}", status, body);
+        }
+        Err("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢".to_string())
+    } else {
+        match body {
+            Ok(body) => {
+                let error_description = body.get("error_description");
+                match error_description {
+                    Some(error_description) => {
+                        let error_description = error_description.to_string();
+                        if cli_mode {
+                            print_error(&error_description);
+                            print_debug(&format!(
+                                "status code {}, response body: {:?}",
+                                status, body
+                            ));
+                        } else {
+                            error!("{}", error_description);
+                            debug!("status code {}, response body: {:?}", status, body);
+                        }
+                        Err(format!("Error logging in: {}", error_description))
+                    }
+                    None => {
+                        if cli_mode {
+                            print_error("Error logging in");
+                            print_debug(&format!(
+                                "status code {}, response body: {:?}",
+                                status, body
+                            ));
+                        } else {
+                            error!("Error logging in");
+                            debug!("status code {}, response body: {:?}", status, body);
+                        }
+                        Err("Error logging in".to_string())
+                    }
+                }
+            }
+            Err(e) => {
+                if cli_mode {
+                    print_error(&format!("Error logging in: {}", e));
+                } else {
+                    error!("Error logging in: {}", e);
+                }
+                Err(format!("Error logging in: {}", e))
+            }
+        }
+    }
+}
+
+async fn refresh_access_token(refresh_token: &str) -> Result<(String, String, String), String> {
+    let request_body = json!(
+        {
+            "grant_type": "refresh_token",
+            "refresh_token": refresh_token
+        }
+    );
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!(
+            "{}/auth/v1/token?grant_type=refresh_token",
+            SUPABASE_URL
+        ))
+        .header("apikey", SUPABASE_ANON_KEY)
+        .header("Content-Type", "application/json")
+        .body(request_body.to_string())
+        .send()
+        .await;
+    if let Err(e) = response {
+        debug!("Error logging in: {}", e);
+        error!("Error logging in, Something went wrong, please try again later");
+        return Err("Error logging in, Something went wrong, please try again later".to_string());
+    }
+    let response = response.unwrap();
+    let status = response.status();
+    let body = response.json::<serde_json::Value>().await;
+    if status == StatusCode::OK {
+        match body {
+            Ok(body) => {
+                let access_token = body.get("access_token");
+                let refresh_token = body.get("refresh_token");
+                let access_token_result = match access_token {
+                    Some(access_token) => {
+                        let access_token = access_token.as_str().unwrap();
+                        info!("🚀 Login successful");
+                        debug!("Access token: {}", access_token);
+                        let user_id = get_user_id_from_database(access_token, false)
+                            .await
+                            .unwrap_or_else(|_| "Error getting user id".to_string());
+                        Ok((access_token.to_string(), user_id))
+                    }
+                    None => {
+                        error!("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer");
+                        debug!(
+                            "status code {}, response body: {:?}, could not find access token",
+                            status, body
+                        );
+                        Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
+                    }
+                };
+                let refresh_token_result = match refresh_token {
+                    Some(refresh_token) => {
+                        let refresh_token = refresh_token.as_str().unwrap();
+                        debug!("Refresh token: {}", refresh_token);
+                        Ok(refresh_token.to_string())
+                    }
+                    None => {
+                        error!("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer");
+                        debug!(
+                            "status code {}, response body: {:?}, could not find refresh token",
+                            status, body
+                        );
+                        Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
+                    }
+                };
+
+                if access_token_result.is_err() || refresh_token_result.is_err() {
+                    Err("Error logging in, If this is your first login attempt after signup please login again, if it is not please contact the developer".to_string())
+                } else {
+                    let access_token_result = access_token_result.unwrap();
+                    let refresh_token_result = refresh_token_result.unwrap();
+                    Ok((
+                        access_token_result.0,
+                        access_token_result.1,
+                        refresh_token_result,
+                    ))
+                }
+            }
+            Err(e) => Err(format!("Error logging in: {}", e)),
+        }
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        error!("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢");
+        debug!("status code {}, response body: {:?}", status, body);
+        Err("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢".to_string())
+    } else {
+        match body {
+            Ok(body) => {
+                let error_description = body.get("error_description");
+                match error_description {
+                    Some(error_description) => {
+                        let error_description = error_description.to_string();
+                        error!("{}", error_description);
+                        debug!("status code {}, response body: {:?}", status, body);
+                        Err(format!("Error logging in: {}", error_description))
+                    }
+                    None => {
+                        error!("Error logging in");
+                        debug!("status code {}, response body: {:?}", status, body);
+                        Err("Error logging in".to_string())
+                    }
+                }
+            }
+            Err(e) => {
+                error!("Error logging in: {}", e);
+                Err(format!("Error logging in: {}", e))
+            }
+        }
+    }
+}
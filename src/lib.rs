@@ -1,4 +1,5 @@
 pub mod app;
+pub mod cli;
 pub mod constants;
 pub mod error;
 pub mod inputs;
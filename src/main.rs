@@ -5,6 +5,7 @@ use log::LevelFilter;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use rust_kanban::{
     app::App,
+    cli::{run_cli_command, CliCommand},
     constants::APP_TITLE,
     io::{io_handler::IoAsyncHandler, logger, IoEvent},
     util::{gen_new_key_main, print_error, reset_app_main, start_ui},
@@ -26,6 +27,11 @@ struct CliArgs {
     encryption_key: Option<String>,
     #[arg(short, long, default_value = "false")]
     debug_mode: bool,
+    /// Workspace to use instead of the current one, for this run only
+    #[arg(long, global = true)]
+    workspace: Option<String>,
+    #[command(subcommand)]
+    command: Option<CliCommand>,
 }
 
 #[tokio::main]
@@ -63,12 +69,27 @@ async fn main() -> Result<()> {
         logger::set_default_level(LevelFilter::Info);
     }
 
+    if let Some(command) = args.command {
+        if let Err(e) = run_cli_command(command, args.workspace) {
+            print_error(&e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     let (sync_io_tx, mut sync_io_rx) = tokio::sync::mpsc::channel::<IoEvent>(100);
 
     let main_app_instance = Arc::new(tokio::sync::Mutex::new(App::new(
         sync_io_tx.clone(),
         args.debug_mode,
     )));
+    if let Some(workspace) = &args.workspace {
+        let mut app = main_app_instance.lock().await;
+        if let Err(e) = app.config.override_workspace(workspace) {
+            print_error(&e);
+            std::process::exit(1);
+        }
+    }
     let app_widget_manager_instance = Arc::clone(&main_app_instance);
     let app_ui_instance = Arc::clone(&main_app_instance);
 
@@ -9,9 +9,13 @@ use ratatui::{
 use rendering::{
     popup::{
         widgets::{CommandPalette, DateTimePicker, TagPicker},
-        CardPrioritySelector, CardStatusSelector, ChangeDateFormat, ChangeTheme, ChangeView,
-        ConfirmDiscardCardChanges, CustomHexColorPrompt, EditGeneralConfig, EditSpecificKeybinding,
-        EditThemeStyle, FilterByTag, SaveThemePrompt, SelectDefaultView, ViewCard,
+        ActionHistoryBrowser, ArchivedCardsBrowser, BoardSortModeSelector, BoardTemplatePicker,
+        CardPrioritySelector, CardRecurrenceSelector, CardStatusSelector, CardTemplatePicker,
+        ChangeDateFormat, ChangeTheme, ChangeView, ConfirmDiscardCardChanges,
+        CreateWorkspacePrompt, CustomHexColorPrompt, EditBoard, EditGeneralConfig,
+        EditSpecificKeybinding, EditThemeStyle, FilterByTag, FilterPrompt, ImportBoardsPrompt,
+        SaveBoardTemplatePrompt, SaveCardTemplatePrompt, SaveThemePrompt, SelectDefaultView,
+        SyncConflicts, ViewCard, WorkspacePicker,
     },
     view::{
         BodyHelpLog, BodyLog, ConfigMenu, CreateTheme, EditKeybindings, HelpMenu, LoadASave,
@@ -247,7 +251,7 @@ impl View {
             View::EditKeybindings => vec![Focus::EditKeybindingsTable, Focus::SubmitButton],
             View::HelpMenu => vec![Focus::Help, Focus::Log],
             View::LoadCloudSave => vec![Focus::Body],
-            View::LoadLocalSave => vec![Focus::Body],
+            View::LoadLocalSave => vec![Focus::Body, Focus::SaveDiff],
             View::Login => vec![
                 Focus::Title,
                 Focus::EmailIDField,
@@ -260,9 +264,11 @@ impl View {
             View::NewBoard => vec![
                 Focus::NewBoardName,
                 Focus::NewBoardDescription,
+                Focus::NewBoardWipLimit,
                 Focus::SubmitButton,
             ],
             View::NewCard => vec![
+                Focus::CardTemplate,
                 Focus::CardName,
                 Focus::CardDescription,
                 Focus::CardDueDate,
@@ -430,9 +436,24 @@ pub enum PopUp {
     CustomHexColorPromptBG,
     ConfirmDiscardCardChanges,
     CardPrioritySelector,
+    CardRecurrenceSelector,
     FilterByTag,
     DateTimePicker,
     TagPicker,
+    ImportBoardsPrompt,
+    SyncConflicts,
+    FilterPrompt,
+    ChangeBoardSortMode,
+    SortBoardOnce,
+    EditBoard,
+    ActionHistory,
+    ArchivedCards,
+    CardTemplatePicker,
+    SaveCardTemplatePrompt,
+    BoardTemplatePicker,
+    SaveBoardTemplatePrompt,
+    WorkspacePicker,
+    CreateWorkspacePrompt,
 }
 
 impl fmt::Display for PopUp {
@@ -453,9 +474,24 @@ impl fmt::Display for PopUp {
             PopUp::CustomHexColorPromptBG => write!(f, "Custom Hex Color Prompt BG"),
             PopUp::ConfirmDiscardCardChanges => write!(f, "Confirm Discard Card Changes"),
             PopUp::CardPrioritySelector => write!(f, "Change Card Priority"),
+            PopUp::CardRecurrenceSelector => write!(f, "Change Card Recurrence"),
             PopUp::FilterByTag => write!(f, "Filter By Tag"),
             PopUp::DateTimePicker => write!(f, "Date Time Picker"),
             PopUp::TagPicker => write!(f, "Tag Picker"),
+            PopUp::ImportBoardsPrompt => write!(f, "Import Boards Prompt"),
+            PopUp::SyncConflicts => write!(f, "Sync Conflicts"),
+            PopUp::FilterPrompt => write!(f, "Filter Prompt"),
+            PopUp::ChangeBoardSortMode => write!(f, "Change Board Sort Mode"),
+            PopUp::SortBoardOnce => write!(f, "Sort Board Once"),
+            PopUp::EditBoard => write!(f, "Edit Board"),
+            PopUp::ActionHistory => write!(f, "Action History"),
+            PopUp::ArchivedCards => write!(f, "Archived Cards"),
+            PopUp::CardTemplatePicker => write!(f, "Card Template Picker"),
+            PopUp::SaveCardTemplatePrompt => write!(f, "Save Card Template Prompt"),
+            PopUp::BoardTemplatePicker => write!(f, "Board Template Picker"),
+            PopUp::SaveBoardTemplatePrompt => write!(f, "Save Board Template Prompt"),
+            PopUp::WorkspacePicker => write!(f, "Workspace Picker"),
+            PopUp::CreateWorkspacePrompt => write!(f, "Create Workspace Prompt"),
         }
     }
 }
@@ -469,7 +505,10 @@ impl PopUp {
                 Focus::CardDueDate,
                 Focus::CardPriority,
                 Focus::CardStatus,
+                Focus::CardRecurrence,
                 Focus::CardTags,
+                Focus::CardChecklist,
+                Focus::CardBlockedBy,
                 Focus::CardComments,
                 Focus::SubmitButton,
             ],
@@ -496,6 +535,9 @@ impl PopUp {
             PopUp::CustomHexColorPromptBG => vec![Focus::TextInput, Focus::SubmitButton],
             PopUp::ConfirmDiscardCardChanges => vec![Focus::SubmitButton, Focus::ExtraFocus],
             PopUp::CardPrioritySelector => vec![],
+            PopUp::CardRecurrenceSelector => {
+                vec![Focus::ChangeCardRecurrencePopup, Focus::TextInput]
+            }
             PopUp::FilterByTag => vec![Focus::FilterByTagPopup, Focus::SubmitButton],
             PopUp::DateTimePicker => vec![
                 Focus::DTPCalender,
@@ -507,6 +549,29 @@ impl PopUp {
                 Focus::DTPSecond,
             ],
             PopUp::TagPicker => vec![Focus::CardTags],
+            PopUp::ImportBoardsPrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopUp::SyncConflicts => vec![Focus::SubmitButton, Focus::ExtraFocus],
+            PopUp::FilterPrompt => vec![
+                Focus::TextInput,
+                Focus::FilterName,
+                Focus::SavedFilters,
+                Focus::SubmitButton,
+            ],
+            PopUp::ChangeBoardSortMode | PopUp::SortBoardOnce => vec![],
+            PopUp::ActionHistory => vec![],
+            PopUp::ArchivedCards => vec![Focus::ArchivedCardsList, Focus::TextInput],
+            PopUp::CardTemplatePicker => vec![],
+            PopUp::SaveCardTemplatePrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopUp::BoardTemplatePicker => vec![],
+            PopUp::SaveBoardTemplatePrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopUp::WorkspacePicker => vec![],
+            PopUp::CreateWorkspacePrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopUp::EditBoard => vec![
+                Focus::NewBoardName,
+                Focus::NewBoardDescription,
+                Focus::NewBoardWipLimit,
+                Focus::SubmitButton,
+            ],
         }
     }
 
@@ -576,6 +641,9 @@ impl PopUp {
             PopUp::CardPrioritySelector => {
                 CardPrioritySelector::render(rect, app, is_active);
             }
+            PopUp::CardRecurrenceSelector => {
+                CardRecurrenceSelector::render(rect, app, is_active);
+            }
             PopUp::FilterByTag => {
                 FilterByTag::render(rect, app, is_active);
             }
@@ -588,6 +656,45 @@ impl PopUp {
             PopUp::TagPicker => {
                 TagPicker::render(rect, app, is_active);
             }
+            PopUp::ImportBoardsPrompt => {
+                ImportBoardsPrompt::render(rect, app, is_active);
+            }
+            PopUp::SyncConflicts => {
+                SyncConflicts::render(rect, app, is_active);
+            }
+            PopUp::FilterPrompt => {
+                FilterPrompt::render(rect, app, is_active);
+            }
+            PopUp::ActionHistory => {
+                ActionHistoryBrowser::render(rect, app, is_active);
+            }
+            PopUp::ArchivedCards => {
+                ArchivedCardsBrowser::render(rect, app, is_active);
+            }
+            PopUp::CardTemplatePicker => {
+                CardTemplatePicker::render(rect, app, is_active);
+            }
+            PopUp::SaveCardTemplatePrompt => {
+                SaveCardTemplatePrompt::render(rect, app, is_active);
+            }
+            PopUp::BoardTemplatePicker => {
+                BoardTemplatePicker::render(rect, app, is_active);
+            }
+            PopUp::SaveBoardTemplatePrompt => {
+                SaveBoardTemplatePrompt::render(rect, app, is_active);
+            }
+            PopUp::WorkspacePicker => {
+                WorkspacePicker::render(rect, app, is_active);
+            }
+            PopUp::CreateWorkspacePrompt => {
+                CreateWorkspacePrompt::render(rect, app, is_active);
+            }
+            PopUp::ChangeBoardSortMode | PopUp::SortBoardOnce => {
+                BoardSortModeSelector::render(rect, app, is_active);
+            }
+            PopUp::EditBoard => {
+                EditBoard::render(rect, app, is_active);
+            }
         }
     }
 }
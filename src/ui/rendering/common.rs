@@ -1,9 +1,9 @@
 use crate::{
     app::{
         app_helper::reset_card_drag_mode,
-        kanban::{Boards, Card, CardPriority, CardStatus},
+        kanban::{BoardSortMode, Boards, Card, CardPriority, CardStatus},
         state::{Focus, KeyBindingEnum},
-        App, DateTimeFormat,
+        App,
     },
     constants::{
         APP_TITLE, DEFAULT_BOARD_TITLE_LENGTH, DEFAULT_CARD_TITLE_LENGTH, FIELD_NOT_SET,
@@ -20,9 +20,8 @@ use crate::{
         },
         theme::Theme,
     },
-    util::{date_format_converter, date_format_finder, update_current_board_and_card},
+    util::{days_until, format_date_time, update_current_board_and_card},
 };
-use chrono::{Local, NaiveDate, NaiveDateTime};
 use log::Level;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
@@ -158,8 +157,15 @@ pub fn render_body(
     };
 
     if !app.filtered_boards.is_empty() {
-        let filtered_text = "This is a filtered view, Clear filter to see all boards and cards";
-        let filtered_paragraph = Paragraph::new(filtered_text.to_string())
+        let filtered_text = if let Some(card_filter) = &app.state.card_filter {
+            format!(
+                "This is a filtered view ({}), Clear filter to see all boards and cards",
+                card_filter
+            )
+        } else {
+            "This is a filtered view, Clear filter to see all boards and cards".to_string()
+        };
+        let filtered_paragraph = Paragraph::new(filtered_text)
             .alignment(Alignment::Center)
             .block(Block::default())
             .style(error_text_style);
@@ -202,7 +208,31 @@ pub fn render_body(
         } else {
             board_title
         };
-        let board_title = format!("{} ({})", board_title, board.cards.len());
+        let board_title = match board.wip_limit {
+            Some(wip_limit) if board.is_over_wip_limit() => format!(
+                "{} ({}/{}) [Over WIP Limit]",
+                board_title,
+                board.cards.len(),
+                wip_limit
+            ),
+            Some(wip_limit) => format!("{} ({}/{})", board_title, board.cards.len(), wip_limit),
+            None => format!("{} ({})", board_title, board.cards.len()),
+        };
+        let board_title = if let Some((done, total)) = board.checklist_progress() {
+            format!("{} [{}/{}]", board_title, done, total)
+        } else {
+            board_title
+        };
+        let board_title = if board.in_progress {
+            format!("{} [In Progress]", board_title)
+        } else {
+            board_title
+        };
+        let board_title = if board.sort_mode == BoardSortMode::Manual {
+            board_title
+        } else {
+            format!("{} [By {}]", board_title, board.sort_mode)
+        };
         let board_title = if board_id == current_board_id {
             format!("{} {}", ">>", board_title)
         } else {
@@ -697,6 +727,11 @@ fn render_a_single_card(
     } else {
         card.name.clone()
     };
+    let card_title = if app.boards.is_card_blocked(card) {
+        format!("[Blocked] {}", card_title)
+    } else {
+        card_title
+    };
     let card_title = if app.state.current_card_id.unwrap_or((0, 0)) == card.id {
         format!("{} {}", ">>", card_title)
     } else {
@@ -729,86 +764,40 @@ fn render_a_single_card(
         app.current_theme.inactive_text_style,
         app.current_theme.general_style,
     );
+    let progress_bar_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.progress_bar_style,
+    );
 
+    // The first line is left blank, the checklist progress is drawn over it
     let mut card_extra_info = vec![Line::from("")];
-    if card.due_date == FIELD_NOT_SET {
-        card_extra_info.push(Line::from(Span::styled(
-            format!("Due: {}", FIELD_NOT_SET),
-            card_due_default_style,
-        )))
-    } else {
-        let card_due_date = card.due_date.clone();
-        let parsed_due_date =
-            date_format_converter(card_due_date.trim(), app.config.date_time_format);
-        let card_due_date_styled = if let Ok(parsed_due_date) = parsed_due_date {
-            if parsed_due_date == FIELD_NOT_SET || parsed_due_date.is_empty() {
-                Line::from(Span::styled(
+    if let Some(due_date) = card.due_date {
+        let parsed_due_date = format_date_time(Some(due_date), app.config.date_time_format);
+        let days_left = days_until(due_date, app.config.date_time_format);
+        let card_due_date_styled = if days_left >= 0 {
+            match days_left.cmp(&(app.config.warning_delta as i64)) {
+                Ordering::Less | Ordering::Equal => Line::from(Span::styled(
+                    format!("Due: {}", parsed_due_date),
+                    card_due_warning_style,
+                )),
+                Ordering::Greater => Line::from(Span::styled(
                     format!("Due: {}", parsed_due_date),
                     card_due_default_style,
-                ))
-            } else {
-                let formatted_date_format = date_format_finder(&parsed_due_date).unwrap();
-                let (days_left, parsed_due_date) = match formatted_date_format {
-                    DateTimeFormat::DayMonthYear
-                    | DateTimeFormat::MonthDayYear
-                    | DateTimeFormat::YearMonthDay => {
-                        let today = Local::now().date_naive();
-                        let string_to_naive_date_format = NaiveDate::parse_from_str(
-                            &parsed_due_date,
-                            app.config.date_time_format.to_parser_string(),
-                        )
-                        .unwrap();
-                        let days_left = string_to_naive_date_format
-                            .signed_duration_since(today)
-                            .num_days();
-                        let parsed_due_date = string_to_naive_date_format
-                            .format(app.config.date_time_format.to_parser_string())
-                            .to_string();
-                        (days_left, parsed_due_date)
-                    }
-                    DateTimeFormat::DayMonthYearTime
-                    | DateTimeFormat::MonthDayYearTime
-                    | DateTimeFormat::YearMonthDayTime {} => {
-                        let today = Local::now().naive_local();
-                        let string_to_naive_date_format = NaiveDateTime::parse_from_str(
-                            &parsed_due_date,
-                            app.config.date_time_format.to_parser_string(),
-                        )
-                        .unwrap();
-                        let days_left = string_to_naive_date_format
-                            .signed_duration_since(today)
-                            .num_days();
-                        let parsed_due_date = string_to_naive_date_format
-                            .format(app.config.date_time_format.to_parser_string())
-                            .to_string();
-                        (days_left, parsed_due_date)
-                    }
-                };
-                if days_left >= 0 {
-                    match days_left.cmp(&(app.config.warning_delta as i64)) {
-                        Ordering::Less | Ordering::Equal => Line::from(Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            card_due_warning_style,
-                        )),
-                        Ordering::Greater => Line::from(Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            card_due_default_style,
-                        )),
-                    }
-                } else {
-                    Line::from(Span::styled(
-                        format!("Due: {}", parsed_due_date),
-                        card_due_overdue_style,
-                    ))
-                }
+                )),
             }
         } else {
             Line::from(Span::styled(
-                format!("Due: {}", card_due_date),
-                card_due_default_style,
+                format!("Due: {}", parsed_due_date),
+                card_due_overdue_style,
             ))
         };
         card_extra_info.extend(vec![card_due_date_styled]);
+    } else {
+        card_extra_info.push(Line::from(Span::styled(
+            format!("Due: {}", FIELD_NOT_SET),
+            card_due_default_style,
+        )))
     }
 
     let mut card_status = format!("Status: {}", card.card_status.clone());
@@ -872,6 +861,18 @@ fn render_a_single_card(
     frame_to_render_on.render_widget(card_block, render_area);
     frame_to_render_on.render_widget(card_paragraph, inner_card_chunks[0]);
     frame_to_render_on.render_widget(card_extra_info, inner_card_chunks[1]);
+    if let Some((done, total)) = card.checklist_progress() {
+        let checklist_gauge = Gauge::default()
+            .block(Block::default())
+            .gauge_style(progress_bar_style)
+            .ratio(done as f64 / total as f64)
+            .label(format!("{} / {}", done, total));
+        let checklist_gauge_area = Rect {
+            height: 1,
+            ..inner_card_chunks[1]
+        };
+        frame_to_render_on.render_widget(checklist_gauge, checklist_gauge_area);
+    }
 }
 
 pub fn draw_title<'a>(app: &mut App, render_area: Rect, is_active: bool) -> Paragraph<'a> {
@@ -882,10 +883,21 @@ pub fn draw_title<'a>(app: &mut App, render_area: Rect, is_active: bool) -> Para
     );
     let border_style =
         get_mouse_focusable_field_style(app, Focus::Title, &render_area, is_active, false);
+    let last_save_time = app
+        .state
+        .last_save_time
+        .map(|last_save_time| last_save_time.format("%H:%M:%S").to_string());
+    let save_status = match (app.has_unsaved_changes(), last_save_time) {
+        (true, Some(time)) => format!(" Unsaved changes, last saved {} ", time),
+        (true, None) => " Unsaved changes ".to_string(),
+        (false, Some(time)) => format!(" Last saved {} ", time),
+        (false, None) => String::new(),
+    };
     Paragraph::new(APP_TITLE)
         .alignment(Alignment::Center)
         .block(
             Block::default()
+                .title_top(Line::from(save_status))
                 .style(title_style)
                 .borders(Borders::ALL)
                 .border_style(border_style)
@@ -0,0 +1,108 @@
+use crate::{
+    app::{state::Focus, App, DateTimeFormat},
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ActionHistoryBrowser,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_percentage,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+    util::format_date_time,
+};
+use ratatui::{
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for ActionHistoryBrowser {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_percentage(70, 70, rect.area());
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+
+        let history_index = app.action_history_manager.history_index;
+        let date_format = DateTimeFormat::add_time_to_date_format(app.config.date_time_format);
+        let mut history_items: Vec<ListItem> = app
+            .action_history_manager
+            .history
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, entry)| {
+                let marker = if index + 1 == history_index {
+                    " (current)"
+                } else if index >= history_index {
+                    " (undone)"
+                } else {
+                    ""
+                };
+                let item_style = if index >= history_index {
+                    app.current_theme.inactive_text_style
+                } else {
+                    general_style
+                };
+                ListItem::new(vec![Line::from(format!(
+                    "{}  {}{}",
+                    format_date_time(Some(entry.time), date_format),
+                    entry.action.describe(&app.boards),
+                    marker
+                ))])
+                .style(item_style)
+            })
+            .collect();
+        let start_marker = if history_index == 0 { " (current)" } else { "" };
+        history_items.push(
+            ListItem::new(vec![Line::from(format!(
+                "Start of the history{}",
+                start_marker
+            ))])
+            .style(general_style),
+        );
+
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::ActionHistoryPopup);
+            app.state.set_focus(Focus::ActionHistoryPopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &history_items,
+                popup_area,
+                &mut app.state.app_list_states.action_history,
+            );
+        }
+        let history_list = List::new(history_items)
+            .block(
+                Block::default()
+                    .title("Action History (select a point to undo or redo up to it)")
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            history_list,
+            popup_area,
+            &mut app.state.app_list_states.action_history,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    app::{
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App, DateTimeFormat,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ArchivedCardsBrowser,
+            utils::{
+                calculate_mouse_list_select_index, calculate_viewport_corrected_cursor_position,
+                centered_rect_with_percentage, check_if_active_and_get_style,
+                check_if_mouse_is_in_area, get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+    util::format_date_time,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for ArchivedCardsBrowser {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_percentage(80, 70, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Fill(1),
+                    Constraint::Length(4),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let search_input_style =
+            get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[0], is_active, true);
+        let archived_cards_style = get_mouse_focusable_field_style(
+            app,
+            Focus::ArchivedCardsList,
+            &chunks[1],
+            is_active,
+            false,
+        );
+
+        let query = app.state.text_buffers.archive_search.get_joined_lines();
+        let date_format = DateTimeFormat::add_time_to_date_format(app.config.date_time_format);
+        let archived_cards = app.boards.search_archived_cards(&query);
+        let archived_card_items: Vec<ListItem> = if archived_cards.is_empty() {
+            vec![ListItem::new("No archived cards").style(general_style)]
+        } else {
+            archived_cards
+                .iter()
+                .map(|(board, archived_card)| {
+                    ListItem::new(Line::from(format!(
+                        "{}  {} ({}) from \"{}\"",
+                        format_date_time(Some(archived_card.date_archived), date_format),
+                        archived_card.card.name,
+                        archived_card.card.card_status,
+                        board.name
+                    )))
+                    .style(general_style)
+                })
+                .collect()
+        };
+
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[1])
+            && !archived_cards.is_empty()
+        {
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &archived_card_items,
+                chunks[1],
+                &mut app.state.app_list_states.archived_cards,
+            );
+        }
+
+        let search_input = Paragraph::new(query.clone()).style(general_style).block(
+            Block::default()
+                .title("Search")
+                .borders(Borders::ALL)
+                .border_style(search_input_style)
+                .border_type(BorderType::Rounded),
+        );
+
+        let archived_cards_list = List::new(archived_card_items)
+            .block(
+                Block::default()
+                    .title(format!("Archived Cards ({})", archived_cards.len()))
+                    .borders(Borders::ALL)
+                    .border_style(archived_cards_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let delete_key = app
+            .get_first_keybinding(KeyBindingEnum::DeleteCard)
+            .unwrap_or("".to_string());
+        let input_mode_key = app
+            .get_first_keybinding(KeyBindingEnum::TakeUserInput)
+            .unwrap_or("".to_string());
+        let next_focus_key = app
+            .get_first_keybinding(KeyBindingEnum::NextFocus)
+            .unwrap_or("".to_string());
+        let help_spans = vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to restore a card to its board, ", help_text_style),
+            Span::styled(delete_key, help_key_style),
+            Span::styled(" to delete it permanently, ", help_text_style),
+            Span::styled(next_focus_key, help_key_style),
+            Span::styled(" to change focus and ", help_text_style),
+            Span::styled(input_mode_key, help_key_style),
+            Span::styled(" on Search to start typing", help_text_style),
+        ];
+        let help_text = Paragraph::new(Line::from(help_spans))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(general_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        let border_block = Block::default()
+            .title("Archive")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        if app.state.app_status == AppStatus::UserInput && app.state.focus == Focus::TextInput {
+            let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                &app.state.text_buffers.archive_search,
+                &app.config.show_line_numbers,
+                &chunks[0],
+            );
+            rect.set_cursor_position((x_pos, y_pos));
+        }
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(search_input, chunks[0]);
+        rect.render_stateful_widget(
+            archived_cards_list,
+            chunks[1],
+            &mut app.state.app_list_states.archived_cards,
+        );
+        rect.render_widget(help_text, chunks[2]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
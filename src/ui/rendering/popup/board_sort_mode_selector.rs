@@ -0,0 +1,98 @@
+use crate::{
+    app::{kanban::BoardSortMode, state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::BoardSortModeSelector,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        PopUp, Renderable,
+    },
+};
+use ratatui::{
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for BoardSortModeSelector {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let sort_once = app.state.z_stack.last() == Some(&PopUp::SortBoardOnce);
+        let current_board = app
+            .state
+            .current_board_id
+            .and_then(|board_id| app.boards.get_board_with_id(board_id));
+        let board_name = current_board.map_or("", |board| board.name.as_str());
+        let current_sort_mode = current_board.map(|board| board.sort_mode);
+        let (title, sort_modes) = if sort_once {
+            (
+                format!("Sort \"{}\" Once By", board_name),
+                BoardSortMode::all_automatic(),
+            )
+        } else {
+            (
+                format!("Sort Mode of \"{}\"", board_name),
+                BoardSortMode::all(),
+            )
+        };
+        let all_sort_modes = sort_modes
+            .iter()
+            .map(|sort_mode| {
+                if Some(*sort_mode) == current_sort_mode && !sort_once {
+                    ListItem::new(vec![Line::from(format!("{} (current)", sort_mode))])
+                } else {
+                    ListItem::new(vec![Line::from(sort_mode.to_string())])
+                }
+            })
+            .collect::<Vec<ListItem>>();
+
+        let popup_area =
+            centered_rect_with_length(50, all_sort_modes.len() as u16 + 2, rect.area());
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::ChangeBoardSortModePopup);
+            app.state.set_focus(Focus::ChangeBoardSortModePopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &all_sort_modes,
+                popup_area,
+                &mut app.state.app_list_states.board_sort_mode_selector,
+            );
+        }
+        let sort_modes = List::new(all_sort_modes)
+            .block(
+                Block::default()
+                    .title(title)
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            sort_modes,
+            popup_area,
+            &mut app.state.app_list_states.board_sort_mode_selector,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    app::{
+        board_template::BoardTemplate,
+        state::{Focus, KeyBindingEnum},
+        App,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::BoardTemplatePicker,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for BoardTemplatePicker {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let default_template_names: Vec<String> = BoardTemplate::all_default_templates()
+            .into_iter()
+            .map(|board_template| board_template.name)
+            .collect();
+        let template_items: Vec<ListItem> = app
+            .board_templates
+            .iter()
+            .map(|board_template| {
+                let name = if default_template_names.contains(&board_template.name) {
+                    format!("{} (inbuilt)", board_template.name)
+                } else {
+                    board_template.name.clone()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(name, general_style),
+                    Span::styled(format!("  {}", board_template.summary()), help_text_style),
+                ]))
+            })
+            .collect();
+        let list_height = app.board_templates.len() as u16 + 2;
+        let popup_area = centered_rect_with_length(80, list_height + 5, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(list_height), Constraint::Fill(1)])
+            .margin(1)
+            .split(popup_area);
+
+        if is_active && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0])
+        {
+            app.state.mouse_focus = Some(Focus::SelectBoardTemplatePopup);
+            app.state.set_focus(Focus::SelectBoardTemplatePopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &template_items,
+                chunks[0],
+                &mut app.state.app_list_states.board_template_selector,
+            );
+        }
+        let templates = List::new(template_items)
+            .block(
+                Block::default()
+                    .title("Templates")
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let undo_key = app
+            .get_first_keybinding(KeyBindingEnum::Undo)
+            .unwrap_or("".to_string());
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(
+                " to replace the current boards with empty boards from the template, ",
+                help_text_style,
+            ),
+            Span::styled(undo_key, help_key_style),
+            Span::styled(" to get them back", help_text_style),
+        ]))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+        let border_block = Block::default()
+            .title("Start From a Board Template")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            templates,
+            chunks[0],
+            &mut app.state.app_list_states.board_template_selector,
+        );
+        rect.render_widget(help_text, chunks[1]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
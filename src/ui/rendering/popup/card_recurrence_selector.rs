@@ -0,0 +1,178 @@
+use crate::{
+    app::{
+        kanban::CardRecurrence,
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::CardRecurrenceSelector,
+            utils::{
+                calculate_mouse_list_select_index, calculate_viewport_corrected_cursor_position,
+                centered_rect_with_length, check_if_active_and_get_style,
+                check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for CardRecurrenceSelector {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let keyboard_focus_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.keyboard_focus_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let card_name = match &app.state.card_being_edited {
+            Some((_, card_being_edited)) => card_being_edited.name.clone(),
+            None => app
+                .state
+                .current_card_id
+                .and_then(|card_id| app.boards.find_card_with_id(card_id))
+                .map(|card| card.name.clone())
+                .unwrap_or_default(),
+        };
+
+        let recurrence_items: Vec<ListItem> = std::iter::once("None".to_string())
+            .chain(CardRecurrence::all().iter().map(|r| r.to_string()))
+            .map(|recurrence| ListItem::new(vec![Line::from(recurrence)]))
+            .collect();
+        let popup_area =
+            centered_rect_with_length(60, recurrence_items.len() as u16 + 12, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(recurrence_items.len() as u16 + 2),
+                Constraint::Length(3),
+                Constraint::Fill(1),
+            ])
+            .margin(1)
+            .split(popup_area);
+
+        if is_active && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0])
+        {
+            app.state.mouse_focus = Some(Focus::ChangeCardRecurrencePopup);
+            app.state.set_focus(Focus::ChangeCardRecurrencePopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &recurrence_items,
+                chunks[0],
+                &mut app.state.app_list_states.card_recurrence_selector,
+            );
+        } else if is_active
+            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[1])
+        {
+            app.state.mouse_focus = Some(Focus::TextInput);
+            app.state.set_focus(Focus::TextInput);
+        }
+        let field_style = |focus: Focus| {
+            if app.state.focus == focus {
+                keyboard_focus_style
+            } else {
+                general_style
+            }
+        };
+
+        let recurrences = List::new(recurrence_items)
+            .block(
+                Block::default()
+                    .title("Repeat")
+                    .borders(Borders::ALL)
+                    .border_style(field_style(Focus::ChangeCardRecurrencePopup))
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        let days_input = Paragraph::new(
+            app.state
+                .text_buffers
+                .card_recurrence_days
+                .get_joined_lines(),
+        )
+        .style(general_style)
+        .block(
+            Block::default()
+                .title("Or Every N Days")
+                .borders(Borders::ALL)
+                .border_style(field_style(Focus::TextInput))
+                .border_type(BorderType::Rounded),
+        );
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let next_focus_key = app
+            .get_first_keybinding(KeyBindingEnum::NextFocus)
+            .unwrap_or("".to_string());
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(
+                " to pick how often the card repeats once completed, ",
+                help_text_style,
+            ),
+            Span::styled(next_focus_key, help_key_style),
+            Span::styled(" to enter a number of days instead", help_text_style),
+        ]))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+        let border_block = Block::default()
+            .title(format!("Changing Recurrence of \"{}\"", card_name))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        if app.state.app_status == AppStatus::UserInput && app.state.focus == Focus::TextInput {
+            let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                &app.state.text_buffers.card_recurrence_days,
+                &app.config.show_line_numbers,
+                &chunks[1],
+            );
+            rect.set_cursor_position((x_pos, y_pos));
+        }
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            recurrences,
+            chunks[0],
+            &mut app.state.app_list_states.card_recurrence_selector,
+        );
+        rect.render_widget(days_input, chunks[1]);
+        rect.render_widget(help_text, chunks[2]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
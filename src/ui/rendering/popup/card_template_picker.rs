@@ -0,0 +1,84 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::CardTemplatePicker,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for CardTemplatePicker {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let template_items: Vec<ListItem> = std::iter::once(ListItem::new(Line::from(
+            Span::styled("No Template", general_style),
+        )))
+        .chain(app.card_templates.iter().map(|card_template| {
+            ListItem::new(Line::from(vec![
+                Span::styled(card_template.name.clone(), general_style),
+                Span::styled(format!("  {}", card_template.summary()), help_text_style),
+            ]))
+        }))
+        .collect();
+        let popup_area =
+            centered_rect_with_length(80, template_items.len() as u16 + 2, rect.area());
+        if is_active && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area)
+        {
+            app.state.mouse_focus = Some(Focus::SelectCardTemplatePopup);
+            app.state.set_focus(Focus::SelectCardTemplatePopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &template_items,
+                popup_area,
+                &mut app.state.app_list_states.card_template_selector,
+            );
+        }
+        let templates = List::new(template_items)
+            .block(
+                Block::default()
+                    .title("Pick a Card Template")
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            templates,
+            popup_area,
+            &mut app.state.app_list_states.card_template_selector,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
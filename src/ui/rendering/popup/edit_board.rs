@@ -0,0 +1,213 @@
+use crate::{
+    app::{
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App,
+    },
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::EditBoard,
+            utils::{
+                calculate_viewport_corrected_cursor_position, centered_rect_with_percentage,
+                check_if_active_and_get_style, get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for EditBoard {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_percentage(70, 70, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Fill(1),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(4),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let name_style =
+            get_mouse_focusable_field_style(app, Focus::NewBoardName, &chunks[0], is_active, true);
+        let description_style = get_mouse_focusable_field_style(
+            app,
+            Focus::NewBoardDescription,
+            &chunks[1],
+            is_active,
+            true,
+        );
+        let wip_limit_style = get_mouse_focusable_field_style(
+            app,
+            Focus::NewBoardWipLimit,
+            &chunks[2],
+            is_active,
+            true,
+        );
+        let submit_button_style =
+            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[3], is_active, false);
+
+        let board_name = app
+            .state
+            .current_board_id
+            .and_then(|board_id| app.boards.get_board_with_id(board_id))
+            .map(|board| board.name.clone())
+            .unwrap_or_default();
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+
+        let board_name_block = Block::default()
+            .title("Board Name (required)")
+            .borders(Borders::ALL)
+            .border_style(name_style)
+            .border_type(BorderType::Rounded);
+        app.state
+            .text_buffers
+            .board_name
+            .set_block(board_name_block);
+        rect.render_widget(app.state.text_buffers.board_name.widget(), chunks[0]);
+
+        let board_description_block = Block::default()
+            .title("Board Description")
+            .borders(Borders::ALL)
+            .border_style(description_style)
+            .border_type(BorderType::Rounded);
+        app.state
+            .text_buffers
+            .board_description
+            .set_block(board_description_block);
+        if app.config.show_line_numbers {
+            app.state
+                .text_buffers
+                .board_description
+                .set_line_number_style(general_style)
+        } else {
+            app.state
+                .text_buffers
+                .board_description
+                .remove_line_number()
+        }
+        rect.render_widget(app.state.text_buffers.board_description.widget(), chunks[1]);
+
+        let board_wip_limit_block = Block::default()
+            .title("WIP Limit (empty for none)")
+            .borders(Borders::ALL)
+            .border_style(wip_limit_style)
+            .border_type(BorderType::Rounded);
+        app.state
+            .text_buffers
+            .board_wip_limit
+            .set_block(board_wip_limit_block);
+        rect.render_widget(app.state.text_buffers.board_wip_limit.widget(), chunks[2]);
+
+        let submit_button = Paragraph::new("Save Board")
+            .style(general_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(submit_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        rect.render_widget(submit_button, chunks[3]);
+
+        let input_mode_key = app
+            .get_first_keybinding(KeyBindingEnum::TakeUserInput)
+            .unwrap_or("".to_string());
+        let stop_user_input_key = app
+            .get_first_keybinding(KeyBindingEnum::StopUserInput)
+            .unwrap_or("".to_string());
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let next_focus_key = app
+            .get_first_keybinding(KeyBindingEnum::NextFocus)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let help_spans = vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(input_mode_key, help_key_style),
+            Span::styled(" to start typing, ", help_text_style),
+            Span::styled(stop_user_input_key, help_key_style),
+            Span::styled(" to stop typing, ", help_text_style),
+            Span::styled(next_focus_key, help_key_style),
+            Span::styled(" to change focus, ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" on Save Board to save the changes or ", help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to cancel", help_text_style),
+        ];
+        let help_text = Paragraph::new(Line::from(help_spans))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(general_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        rect.render_widget(help_text, chunks[4]);
+
+        let border_block = Block::default()
+            .title(format!("Edit Board \"{}\"", board_name))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+        rect.render_widget(border_block, popup_area);
+
+        if app.state.app_status == AppStatus::UserInput {
+            let focused_input = match app.state.focus {
+                Focus::NewBoardName => Some((&app.state.text_buffers.board_name, &chunks[0])),
+                Focus::NewBoardDescription => {
+                    Some((&app.state.text_buffers.board_description, &chunks[1]))
+                }
+                Focus::NewBoardWipLimit => {
+                    Some((&app.state.text_buffers.board_wip_limit, &chunks[2]))
+                }
+                _ => None,
+            };
+            if let Some((text_box, input_area)) = focused_input {
+                let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                    text_box,
+                    &app.config.show_line_numbers,
+                    input_area,
+                );
+                rect.set_cursor_position((x_pos, y_pos));
+            }
+        }
+
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
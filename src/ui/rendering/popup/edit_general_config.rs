@@ -106,7 +106,10 @@ impl Renderable for EditGeneralConfig {
             "Theme Name"
         };
         if let Ok(config_enum) = ConfigEnum::from_str(config_item_name) {
-            app.state.path_check_state.path_check_mode = config_enum == ConfigEnum::SaveDirectory;
+            app.state.path_check_state.path_check_mode = matches!(
+                config_enum,
+                ConfigEnum::SaveDirectory | ConfigEnum::SyncDirectory
+            );
         }
         let config_item_value = if app.state.app_table_states.config.selected().is_some() {
             list_items
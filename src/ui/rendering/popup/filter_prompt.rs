@@ -0,0 +1,205 @@
+use crate::{
+    app::{
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::FilterPrompt,
+            utils::{
+                calculate_viewport_corrected_cursor_position, centered_rect_with_length,
+                check_if_active_and_get_style, get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for FilterPrompt {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(90, 26, rect.area());
+        let syntax_text =
+            "Filter with terms like tag:bug priority:high status:active due:<7d text:\"login\", \
+            values separated by commas match any of them and a leading - excludes matching cards";
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(2),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Fill(1),
+                    Constraint::Length(3),
+                    Constraint::Length(4),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let query_input_style =
+            get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], is_active, true);
+        let name_input_style =
+            get_mouse_focusable_field_style(app, Focus::FilterName, &chunks[2], is_active, true);
+        let saved_filters_style =
+            get_mouse_focusable_field_style(app, Focus::SavedFilters, &chunks[3], is_active, false);
+        let submit_button_style =
+            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[4], is_active, false);
+
+        let syntax_text = Paragraph::new(syntax_text)
+            .style(general_style)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        let query_input = Paragraph::new(app.state.text_buffers.filter_query.get_joined_lines())
+            .style(general_style)
+            .block(
+                Block::default()
+                    .title("Query")
+                    .borders(Borders::ALL)
+                    .border_style(query_input_style)
+                    .border_type(BorderType::Rounded),
+            );
+        let name_input = Paragraph::new(app.state.text_buffers.filter_name.get_joined_lines())
+            .style(general_style)
+            .block(
+                Block::default()
+                    .title("Save As (optional)")
+                    .borders(Borders::ALL)
+                    .border_style(name_input_style)
+                    .border_type(BorderType::Rounded),
+            );
+
+        let saved_filter_items: Vec<ListItem> = if app.config.saved_filters.is_empty() {
+            vec![ListItem::new("No saved filters").style(general_style)]
+        } else {
+            app.config
+                .saved_filters
+                .iter()
+                .map(|(name, query)| {
+                    ListItem::new(format!("{}: {}", name, query)).style(general_style)
+                })
+                .collect()
+        };
+        let saved_filters = List::new(saved_filter_items)
+            .block(
+                Block::default()
+                    .title("Saved Filters")
+                    .borders(Borders::ALL)
+                    .border_style(saved_filters_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        let submit_button = Paragraph::new("Apply Filter")
+            .style(general_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(submit_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let next_focus_key = app
+            .get_first_keybinding(KeyBindingEnum::NextFocus)
+            .unwrap_or("".to_string());
+        let delete_key = app
+            .get_first_keybinding(KeyBindingEnum::DeleteCard)
+            .unwrap_or("".to_string());
+        let help_spans = vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key.clone(), help_key_style),
+            Span::styled(
+                " to apply the query (and save it if a name is given), ",
+                help_text_style,
+            ),
+            Span::styled(next_focus_key, help_key_style),
+            Span::styled(" to change focus, ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" on a saved filter to apply it or ", help_text_style),
+            Span::styled(delete_key, help_key_style),
+            Span::styled(" to delete it", help_text_style),
+        ];
+        let help_text = Paragraph::new(Line::from(help_spans))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(general_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        let border_block = Block::default()
+            .title("Filter Cards")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        if app.state.app_status == AppStatus::UserInput {
+            let focused_input = match app.state.focus {
+                Focus::TextInput => Some((&app.state.text_buffers.filter_query, &chunks[1])),
+                Focus::FilterName => Some((&app.state.text_buffers.filter_name, &chunks[2])),
+                _ => None,
+            };
+            if let Some((text_box, input_area)) = focused_input {
+                let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                    text_box,
+                    &app.config.show_line_numbers,
+                    input_area,
+                );
+                rect.set_cursor_position((x_pos, y_pos));
+            }
+        }
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(syntax_text, chunks[0]);
+        rect.render_widget(query_input, chunks[1]);
+        rect.render_widget(name_input, chunks[2]);
+        rect.render_stateful_widget(
+            saved_filters,
+            chunks[3],
+            &mut app.state.app_list_states.saved_filters_list,
+        );
+        rect.render_widget(submit_button, chunks[4]);
+        rect.render_widget(help_text, chunks[5]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
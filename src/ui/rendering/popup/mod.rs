@@ -1,18 +1,32 @@
+pub mod action_history_browser;
+pub mod archived_cards_browser;
+pub mod board_sort_mode_selector;
+pub mod board_template_picker;
 pub mod card_priority_selector;
+pub mod card_recurrence_selector;
 pub mod card_status_selector;
+pub mod card_template_picker;
 pub mod change_date_format;
 pub mod change_theme;
 pub mod change_view;
 pub mod confirm_discard_card_changes;
+pub mod create_workspace_prompt;
 pub mod custom_hex_color_prompt;
+pub mod edit_board;
 pub mod edit_general_config;
 pub mod edit_specific_keybinding;
 pub mod edit_theme_style;
 pub mod filter_by_tag;
+pub mod filter_prompt;
+pub mod import_boards_prompt;
+pub mod save_board_template_prompt;
+pub mod save_card_template_prompt;
 pub mod save_theme_prompt;
 pub mod select_default_view;
+pub mod sync_conflicts;
 pub mod view_card;
 pub mod widgets;
+pub mod workspace_picker;
 
 pub struct ViewCard;
 pub struct CardStatusSelector;
@@ -26,5 +40,19 @@ pub struct SaveThemePrompt;
 pub struct CustomHexColorPrompt;
 pub struct ConfirmDiscardCardChanges;
 pub struct CardPrioritySelector;
+pub struct CardRecurrenceSelector;
+pub struct CardTemplatePicker;
+pub struct SaveCardTemplatePrompt;
+pub struct BoardTemplatePicker;
+pub struct SaveBoardTemplatePrompt;
 pub struct FilterByTag;
 pub struct ChangeDateFormat;
+pub struct ImportBoardsPrompt;
+pub struct SyncConflicts;
+pub struct FilterPrompt;
+pub struct ActionHistoryBrowser;
+pub struct ArchivedCardsBrowser;
+pub struct BoardSortModeSelector;
+pub struct EditBoard;
+pub struct WorkspacePicker;
+pub struct CreateWorkspacePrompt;
@@ -0,0 +1,260 @@
+use crate::{
+    app::{
+        state::{Focus, KeyBindingEnum},
+        App,
+    },
+    io::sync::merge::CardVersion,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::SyncConflicts,
+            utils::{
+                centered_rect_with_percentage, check_if_active_and_get_style,
+                get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+    util::format_date_time,
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for SyncConflicts {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_percentage(80, 80, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(2),
+                    Constraint::Fill(1),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+        let version_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+            .split(chunks[1]);
+        let button_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+            .split(chunks[2]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let (title, card_name, local_lines, remote_lines) =
+            if let Some(pending_sync) = &app.state.pending_sync {
+                let title = format!(
+                    "Sync Conflict {}/{}",
+                    (pending_sync.current_conflict + 1).min(pending_sync.conflicts.len()),
+                    pending_sync.conflicts.len()
+                );
+                if let Some(conflict) = pending_sync.get_current_conflict() {
+                    let card_name = conflict
+                        .local
+                        .as_ref()
+                        .or(conflict.remote.as_ref())
+                        .map(|version| version.card.name.clone())
+                        .unwrap_or_default();
+                    (
+                        title,
+                        card_name,
+                        get_card_version_lines(app, conflict.local.as_ref(), general_style),
+                        get_card_version_lines(app, conflict.remote.as_ref(), general_style),
+                    )
+                } else {
+                    (title, String::new(), vec![], vec![])
+                }
+            } else {
+                ("Sync Conflict".to_string(), String::new(), vec![], vec![])
+            };
+
+        let prompt_text = Paragraph::new(format!(
+            "\"{}\" was changed both locally and in the cloud since the last sync, which version should be kept?",
+            card_name
+        ))
+        .style(general_style)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+        let local_version = Paragraph::new(local_lines)
+            .block(
+                Block::default()
+                    .title("Local")
+                    .borders(Borders::ALL)
+                    .border_style(general_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .wrap(Wrap { trim: false });
+        let remote_version = Paragraph::new(remote_lines)
+            .block(
+                Block::default()
+                    .title("Cloud")
+                    .borders(Borders::ALL)
+                    .border_style(general_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .wrap(Wrap { trim: false });
+
+        let keep_local_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::SubmitButton,
+            &button_chunks[0],
+            is_active,
+            false,
+        );
+        let keep_remote_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::ExtraFocus,
+            &button_chunks[1],
+            is_active,
+            false,
+        );
+        let keep_local_button = Paragraph::new("Keep Local")
+            .style(keep_local_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(keep_local_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let keep_remote_button = Paragraph::new("Keep Cloud")
+            .style(keep_remote_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(keep_remote_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let go_back_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let help_spans = vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to keep the selected version or ", help_text_style),
+            Span::styled(go_back_key, help_key_style),
+            Span::styled(" to cancel the sync", help_text_style),
+        ];
+        let help_text = Paragraph::new(Line::from(help_spans))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(general_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        let border_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(prompt_text, chunks[0]);
+        rect.render_widget(local_version, version_chunks[0]);
+        rect.render_widget(remote_version, version_chunks[1]);
+        rect.render_widget(keep_local_button, button_chunks[0]);
+        rect.render_widget(keep_remote_button, button_chunks[1]);
+        rect.render_widget(help_text, chunks[3]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
+
+fn get_card_version_lines(
+    app: &App,
+    version: Option<&CardVersion>,
+    style: Style,
+) -> Vec<Line<'static>> {
+    let version = if let Some(version) = version {
+        version
+    } else {
+        return vec![Line::styled("Deleted", style)];
+    };
+    let card = &version.card;
+    let date_time_format = app.config.date_time_format;
+    let tags = if card.tags.is_empty() {
+        "None".to_string()
+    } else {
+        card.tags.join(", ")
+    };
+    let mut lines: Vec<Line<'static>> = [
+        ("Board", version.board_name.clone()),
+        ("Name", card.name.clone()),
+        ("Status", card.card_status.to_string()),
+        ("Priority", card.priority.to_string()),
+        (
+            "Due Date",
+            format_date_time(card.due_date, date_time_format),
+        ),
+        ("Tags", tags),
+        (
+            "Modified",
+            format_date_time(card.date_modified, date_time_format),
+        ),
+        ("Revision", card.revision.to_string()),
+        ("Description", card.description.clone()),
+    ]
+    .into_iter()
+    .map(|(field, value)| Line::styled(format!("{}: {}", field, value), style))
+    .collect();
+    if let Some((done, total)) = card.checklist_progress() {
+        lines.push(Line::styled(
+            format!("Checklist: {}/{}", done, total),
+            style,
+        ));
+    }
+    if !card.blocked_by.is_empty() {
+        lines.push(Line::styled(
+            format!("Blocked By: {} card(s)", card.blocked_by.len()),
+            style,
+        ));
+    }
+    lines.push(Line::styled(
+        format!("Comments: {}", card.comments.len()),
+        style,
+    ));
+    for comment in &card.comments {
+        lines.push(Line::styled(
+            format!("- {}: {}", comment.author, comment.body),
+            style,
+        ));
+    }
+    lines
+}
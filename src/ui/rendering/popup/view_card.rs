@@ -4,7 +4,6 @@ use crate::{
         state::{AppStatus, Focus},
         App, DateTimeFormat,
     },
-    constants::FIELD_NOT_SET,
     ui::{
         rendering::{
             common::{render_blank_styled_canvas, render_close_button},
@@ -17,9 +16,8 @@ use crate::{
         widgets::SelfViewportCorrection,
         PopUp, Renderable,
     },
-    util::{date_format_converter, date_format_finder},
+    util::{days_until, format_date_time, utc_to_naive_local},
 };
-use chrono::{Local, NaiveDate, NaiveDateTime};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     text::{Line, Span},
@@ -53,6 +51,10 @@ impl Renderable for ViewCard {
             app.current_theme.list_select_style,
         );
         let card_tags_style = get_button_style(app, Focus::CardTags, None, is_active, false);
+        let card_checklist_style =
+            get_button_style(app, Focus::CardChecklist, None, is_active, false);
+        let card_blocked_by_style =
+            get_button_style(app, Focus::CardBlockedBy, None, is_active, false);
         let card_comments_style =
             get_button_style(app, Focus::CardComments, None, is_active, false);
         let save_changes_style = get_button_style(app, Focus::SubmitButton, None, is_active, false);
@@ -118,19 +120,10 @@ impl Renderable for ViewCard {
         } else {
             card.unwrap().to_owned()
         };
-        if app.widgets.date_time_picker.selected_date_time.is_none()
-            && !card.due_date.is_empty()
-            && card.due_date != FIELD_NOT_SET
-        {
-            if let Ok(current_format) = date_format_finder(card.due_date.trim()) {
+        if app.widgets.date_time_picker.selected_date_time.is_none() {
+            if let Some(due_date) = card.due_date {
                 app.widgets.date_time_picker.selected_date_time =
-                    match NaiveDateTime::parse_from_str(
-                        card.due_date.trim(),
-                        current_format.to_parser_string(),
-                    ) {
-                        Ok(date_time) => Some(date_time),
-                        Err(_) => None,
-                    };
+                    Some(utc_to_naive_local(due_date));
             }
         }
         let board_name = board.name.clone();
@@ -180,146 +173,54 @@ impl Renderable for ViewCard {
 
         // Process Card Extra Info
         let (card_extra_info_widget, card_extra_info_items_len, card_due_date_width) = {
-            let card_date_created = if date_format_finder(&card.date_created).is_ok() {
-                if let Ok(parsed_date) =
-                    date_format_converter(&card.date_created, app.config.date_time_format)
-                {
-                    Span::styled(format!("Created: {}", parsed_date), general_style)
-                } else {
-                    match NaiveDateTime::parse_from_str(
-                        &card.date_created,
-                        app.config.date_time_format.to_parser_string(),
-                    ) {
-                        Ok(parsed_date) => Span::styled(
-                            format!(
-                                "Created: {}",
-                                parsed_date.format(app.config.date_time_format.to_parser_string())
-                            ),
-                            general_style,
-                        ),
-                        Err(_) => {
-                            Span::styled(format!("Created: {}", card.date_created), general_style)
-                        }
-                    }
-                }
-            } else {
-                Span::styled(format!("Created: {}", card.date_created), general_style)
-            };
-            let card_date_modified = if date_format_finder(&card.date_modified).is_ok() {
-                if let Ok(parsed_date) =
-                    date_format_converter(&card.date_modified, app.config.date_time_format)
-                {
-                    Span::styled(format!("Modified: {}", parsed_date), general_style)
-                } else {
-                    Span::styled(format!("Modified: {}", card.date_modified), general_style)
-                }
-            } else {
-                match NaiveDateTime::parse_from_str(
-                    &card.date_modified,
-                    app.config.date_time_format.to_parser_string(),
-                ) {
-                    Ok(parsed_date) => Span::styled(
-                        format!(
-                            "Modified: {}",
-                            parsed_date.format(app.config.date_time_format.to_parser_string())
-                        ),
-                        general_style,
-                    ),
-                    Err(_) => {
-                        Span::styled(format!("Modified: {}", card.date_modified), general_style)
-                    }
-                }
-            };
-            let card_date_completed = if date_format_finder(&card.date_completed).is_ok() {
-                if let Ok(parsed_date) =
-                    date_format_converter(&card.date_completed, app.config.date_time_format)
-                {
-                    Span::styled(format!("Completed: {}", parsed_date), general_style)
-                } else {
-                    Span::styled(format!("Completed: {}", card.date_completed), general_style)
-                }
-            } else {
-                match NaiveDateTime::parse_from_str(
-                    &card.date_completed,
-                    app.config.date_time_format.to_parser_string(),
-                ) {
-                    Ok(parsed_date) => Span::styled(
-                        format!(
-                            "Completed: {}",
-                            parsed_date.format(app.config.date_time_format.to_parser_string())
-                        ),
-                        general_style,
-                    ),
-                    Err(_) => {
-                        Span::styled(format!("Completed: {}", card.date_completed), general_style)
-                    }
-                }
-            };
+            let card_date_created = Span::styled(
+                format!(
+                    "Created: {}",
+                    format_date_time(card.date_created, app.config.date_time_format)
+                ),
+                general_style,
+            );
+            let card_date_modified = Span::styled(
+                format!(
+                    "Modified: {}",
+                    format_date_time(card.date_modified, app.config.date_time_format)
+                ),
+                general_style,
+            );
+            let card_date_completed = Span::styled(
+                format!(
+                    "Completed: {}",
+                    format_date_time(card.date_completed, app.config.date_time_format)
+                ),
+                general_style,
+            );
             let card_priority = format!("Priority: {}", card.priority);
             let card_status = format!("Status: {}", card.card_status);
-            let parsed_due_date = if date_format_finder(&card.due_date).is_ok() {
-                date_format_converter(&card.due_date, app.config.date_time_format)
-            } else {
-                Ok(FIELD_NOT_SET.to_string())
-            };
-            let card_due_date_styled = if let Ok(parsed_due_date) = parsed_due_date {
-                if app.state.focus == Focus::CardDueDate {
-                    Span::styled(format!("Due: {}", parsed_due_date), list_select_style)
-                } else if parsed_due_date == FIELD_NOT_SET || parsed_due_date.is_empty() {
-                    Span::styled(format!("Due: {}", parsed_due_date), card_due_default_style)
+            let parsed_due_date = format_date_time(card.due_date, app.config.date_time_format);
+            let card_due_date_styled = if app.state.focus == Focus::CardDueDate {
+                Span::styled(format!("Due: {}", parsed_due_date), list_select_style)
+            } else if let Some(due_date) = card.due_date {
+                let days_left = days_until(due_date, app.config.date_time_format);
+                if !is_active {
+                    Span::styled(
+                        format!("Due: {}", parsed_due_date),
+                        app.current_theme.inactive_text_style,
+                    )
+                } else if days_left <= app.config.warning_delta.into() && days_left >= 0 {
+                    Span::styled(
+                        format!("Due: {}", parsed_due_date),
+                        app.current_theme.card_due_warning_style,
+                    )
+                } else if days_left < 0 {
+                    Span::styled(
+                        format!("Due: {}", parsed_due_date),
+                        app.current_theme.card_due_overdue_style,
+                    )
                 } else {
-                    let formatted_date_format = date_format_finder(&parsed_due_date).unwrap();
-                    let days_left = match formatted_date_format {
-                        DateTimeFormat::DayMonthYear
-                        | DateTimeFormat::MonthDayYear
-                        | DateTimeFormat::YearMonthDay => {
-                            let today = Local::now().date_naive();
-                            let string_to_naive_date_format = NaiveDate::parse_from_str(
-                                &parsed_due_date,
-                                app.config.date_time_format.to_parser_string(),
-                            )
-                            .unwrap();
-                            string_to_naive_date_format
-                                .signed_duration_since(today)
-                                .num_days()
-                        }
-                        DateTimeFormat::DayMonthYearTime
-                        | DateTimeFormat::MonthDayYearTime
-                        | DateTimeFormat::YearMonthDayTime {} => {
-                            let today = Local::now().naive_local();
-                            let string_to_naive_date_format = NaiveDateTime::parse_from_str(
-                                &parsed_due_date,
-                                app.config.date_time_format.to_parser_string(),
-                            )
-                            .unwrap();
-                            string_to_naive_date_format
-                                .signed_duration_since(today)
-                                .num_days()
-                        }
-                    };
-                    if !is_active {
-                        Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            app.current_theme.inactive_text_style,
-                        )
-                    } else if days_left <= app.config.warning_delta.into() && days_left >= 0 {
-                        Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            app.current_theme.card_due_warning_style,
-                        )
-                    } else if days_left < 0 {
-                        Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            app.current_theme.card_due_overdue_style,
-                        )
-                    } else {
-                        Span::styled(format!("Due: {}", parsed_due_date), card_due_default_style)
-                    }
+                    Span::styled(format!("Due: {}", parsed_due_date), card_due_default_style)
                 }
-            } else if app.state.focus == Focus::CardDueDate {
-                Span::styled(format!("Due: {}", FIELD_NOT_SET), list_select_style)
             } else {
-                Span::styled(format!("Due: {}", FIELD_NOT_SET), general_style)
+                Span::styled(format!("Due: {}", parsed_due_date), card_due_default_style)
             };
             let card_priority_styled = if !is_active {
                 Span::styled(card_priority, app.current_theme.inactive_text_style)
@@ -347,6 +248,18 @@ impl Renderable for ViewCard {
             } else {
                 Span::styled(card_status, app.current_theme.general_style)
             };
+            let card_recurrence = format!(
+                "Repeats: {}",
+                card.recurrence
+                    .map_or("Never".to_string(), |recurrence| recurrence.to_string())
+            );
+            let card_recurrence_styled = if !is_active {
+                Span::styled(card_recurrence, app.current_theme.inactive_text_style)
+            } else if app.state.focus == Focus::CardRecurrence {
+                Span::styled(card_recurrence, app.current_theme.list_select_style)
+            } else {
+                Span::styled(card_recurrence, app.current_theme.general_style)
+            };
             let card_extra_info_items = vec![
                 ListItem::new(vec![Line::from(card_date_created)]),
                 ListItem::new(vec![Line::from(card_date_modified)]),
@@ -354,6 +267,7 @@ impl Renderable for ViewCard {
                 ListItem::new(vec![Line::from(card_date_completed)]),
                 ListItem::new(vec![Line::from(card_priority_styled)]),
                 ListItem::new(vec![Line::from(card_status_styled)]),
+                ListItem::new(vec![Line::from(card_recurrence_styled)]),
             ];
             let card_extra_info_items_len = card_extra_info_items.len();
             let card_extra_info = List::new(card_extra_info_items).block(
@@ -449,78 +363,115 @@ impl Renderable for ViewCard {
             card_tag_lines
         };
 
-        // Process Card Comments
-        let card_comment_lines = {
-            let card_comments = if app.state.focus == Focus::CardComments {
-                let mut comments = vec![];
-                if app
-                    .state
+        // Process Card Checklist
+        let card_checklist_lines: Vec<Line> = {
+            let selected_item = if app.state.focus == Focus::CardChecklist {
+                app.state
                     .app_list_states
-                    .card_view_comment_list
+                    .card_view_checklist_list
                     .selected()
-                    .is_none()
-                {
-                    for (index, comment) in card.comments.iter().enumerate() {
-                        comments.push(Span::styled(
-                            format!("{}) {} ", index + 1, comment),
-                            general_style,
-                        ));
-                    }
-                } else {
-                    let selected_comment = app
-                        .state
-                        .app_list_states
-                        .card_view_comment_list
-                        .selected()
-                        .unwrap();
-                    for (index, comment) in card.comments.iter().enumerate() {
-                        if index == selected_comment {
-                            comments.push(Span::styled(
-                                format!("{}) {} ", index + 1, comment),
-                                keyboard_focus_style,
-                            ));
-                        } else {
-                            comments.push(Span::styled(
-                                format!("{}) {} ", index + 1, comment),
-                                general_style,
-                            ));
+            } else {
+                None
+            };
+            card.checklist
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let item_style = if Some(index) == selected_item {
+                        keyboard_focus_style
+                    } else {
+                        general_style
+                    };
+                    let check_mark = if item.done { "[x]" } else { "[ ]" };
+                    Line::from(Span::styled(
+                        format!("{} {}", check_mark, item.text),
+                        item_style,
+                    ))
+                })
+                .collect()
+        };
+
+        // Process Card Blockers, the cards this card blocks are listed after them
+        let card_blocked_by_lines: Vec<Line> = {
+            let selected_blocker = if app.state.focus == Focus::CardBlockedBy {
+                app.state
+                    .app_list_states
+                    .card_view_blocked_by_list
+                    .selected()
+            } else {
+                None
+            };
+            let mut card_blocked_by_lines: Vec<Line> = card
+                .blocked_by
+                .iter()
+                .enumerate()
+                .map(|(index, blocker_id)| {
+                    let blocker_style = if Some(index) == selected_blocker {
+                        keyboard_focus_style
+                    } else {
+                        general_style
+                    };
+                    let blocker_text = match app.boards.find_board_with_card_id(*blocker_id) {
+                        Some((_, blocker_board)) => {
+                            let blocker =
+                                blocker_board.cards.get_card_with_id(*blocker_id).unwrap();
+                            let status_mark = if blocker.card_status == CardStatus::Complete {
+                                "✓"
+                            } else {
+                                "✗"
+                            };
+                            format!(
+                                "{} {} · {} ({})",
+                                status_mark, blocker.name, blocker_board.name, blocker.card_status
+                            )
                         }
-                    }
-                }
-                comments
+                        None => "? Deleted card".to_string(),
+                    };
+                    Line::from(Span::styled(blocker_text, blocker_style))
+                })
+                .collect();
+            for blocked_card in app.boards.get_cards_blocked_by(card.id) {
+                card_blocked_by_lines.push(Line::from(Span::styled(
+                    format!("→ Blocks {}", blocked_card.name),
+                    general_style,
+                )));
+            }
+            card_blocked_by_lines
+        };
+
+        // Process Card Comments, each comment is a line with its author and date followed by
+        // a line with its body
+        let card_comment_lines: Vec<Line> = {
+            let selected_comment = if app.state.focus == Focus::CardComments {
+                app.state.app_list_states.card_view_comment_list.selected()
             } else {
-                let mut comments = vec![];
-                for (index, comment) in card.comments.iter().enumerate() {
-                    comments.push(Span::styled(
-                        format!("{}) {} ", index + 1, comment),
-                        general_style,
-                    ));
-                }
-                comments
+                None
             };
+            let comment_date_format =
+                DateTimeFormat::add_time_to_date_format(app.config.date_time_format);
             let mut card_comment_lines = vec![];
-            let mut collector = String::new();
-            let mut collector_start = 0;
-            let mut collector_end = 0;
-            for (i, comment) in card.comments.iter().enumerate() {
-                let comment_string = format!("{}) {} ", i + 1, comment);
-                if (collector.len() + comment_string.len()) < (popup_area.width - 2) as usize {
-                    collector.push_str(&comment_string);
-                    collector_end = i + 1;
+            for (index, comment) in card.comments.iter().enumerate() {
+                let comment_style = if Some(index) == selected_comment {
+                    keyboard_focus_style
                 } else {
-                    card_comment_lines.push(Line::from(
-                        card_comments[collector_start..collector_end].to_vec(),
+                    general_style
+                };
+                let mut comment_header = format!(
+                    "● {} · {}",
+                    comment.author,
+                    format_date_time(Some(comment.created_at), comment_date_format)
+                );
+                if let Some(edited_at) = comment.edited_at {
+                    comment_header.push_str(&format!(
+                        " (edited {})",
+                        format_date_time(Some(edited_at), comment_date_format)
                     ));
-                    collector = String::new();
-                    collector.push_str(&comment_string);
-                    collector_start = i;
-                    collector_end = i + 1;
                 }
-            }
-            if !collector.is_empty() {
-                card_comment_lines.push(Line::from(
-                    card_comments[collector_start..collector_end].to_vec(),
-                ));
+                card_comment_lines.push(Line::from(Span::styled(comment_header, comment_style)));
+                card_comment_lines.push(Line::from(Span::styled(
+                    format!("│ {}", comment.body),
+                    comment_style,
+                )));
             }
             card_comment_lines
         };
@@ -532,7 +483,7 @@ impl Renderable for ViewCard {
             let max_height: u16 = popup_area.height - border_height;
             let submit_button_height: u16 = 3;
             let card_name_box_height: u16 = 3;
-            let card_extra_info_height: u16 = 8;
+            let card_extra_info_height: u16 = 9;
             let mut available_height: u16 = if app.state.card_being_edited.is_some() {
                 max_height - card_name_box_height - card_extra_info_height - submit_button_height
             } else {
@@ -543,18 +494,24 @@ impl Renderable for ViewCard {
                 app.state.text_buffers.card_description.get_num_lines() as u16;
 
             let raw_tags_height = card_tag_lines.len() as u16;
+            let raw_checklist_height = card_checklist_lines.len() as u16;
+            let raw_blocked_by_height = card_blocked_by_lines.len() as u16;
             let raw_comments_height = card_comment_lines.len() as u16;
 
             let mut card_description_height = if app.state.focus == Focus::CardDescription {
                 if available_height
                     .saturating_sub(raw_tags_height + border_height)
+                    .saturating_sub(raw_checklist_height + border_height)
+                    .saturating_sub(raw_blocked_by_height + border_height)
                     .saturating_sub(raw_comments_height + border_height)
                     > 0
                 {
                     let calc = available_height
                         - raw_tags_height
+                        - raw_checklist_height
+                        - raw_blocked_by_height
                         - raw_comments_height
-                        - (border_height * 2);
+                        - (border_height * 4);
                     if calc < (raw_card_description_height + border_height) {
                         let diff = (raw_card_description_height + border_height) - calc;
                         if diff < min_box_height {
@@ -572,6 +529,8 @@ impl Renderable for ViewCard {
                 }
             } else if ((raw_card_description_height + border_height) <= available_height)
                 && app.state.focus != Focus::CardTags
+                && app.state.focus != Focus::CardChecklist
+                && app.state.focus != Focus::CardBlockedBy
                 && app.state.focus != Focus::CardComments
             {
                 raw_card_description_height.saturating_sub(border_height)
@@ -593,6 +552,30 @@ impl Renderable for ViewCard {
 
             available_height = available_height.saturating_sub(card_tags_height);
 
+            let card_checklist_height = if available_height > 0 {
+                if app.state.focus == Focus::CardChecklist {
+                    raw_checklist_height + border_height
+                } else {
+                    min_box_height
+                }
+            } else {
+                min_box_height
+            };
+
+            available_height = available_height.saturating_sub(card_checklist_height);
+
+            let card_blocked_by_height = if available_height > 0 {
+                if app.state.focus == Focus::CardBlockedBy {
+                    raw_blocked_by_height + border_height
+                } else {
+                    min_box_height
+                }
+            } else {
+                min_box_height
+            };
+
+            available_height = available_height.saturating_sub(card_blocked_by_height);
+
             let card_comments_height = if available_height > 0 {
                 if app.state.focus == Focus::CardComments {
                     raw_comments_height + border_height
@@ -617,6 +600,8 @@ impl Renderable for ViewCard {
                         Constraint::Length(card_description_height),
                         Constraint::Length(card_extra_info_height),
                         Constraint::Length(card_tags_height),
+                        Constraint::Length(card_checklist_height),
+                        Constraint::Length(card_blocked_by_height),
                         Constraint::Length(card_comments_height),
                         Constraint::Length(submit_button_height),
                     ])
@@ -630,6 +615,8 @@ impl Renderable for ViewCard {
                         Constraint::Length(card_description_height),
                         Constraint::Length(card_extra_info_height),
                         Constraint::Length(card_tags_height),
+                        Constraint::Length(card_checklist_height),
+                        Constraint::Length(card_blocked_by_height),
                         Constraint::Length(card_comments_height),
                     ])
                     .margin(1)
@@ -685,6 +672,15 @@ impl Renderable for ViewCard {
                             .select(None);
                         app.state.app_list_states.card_view_tag_list.select(None);
                     }
+                    6 => {
+                        app.state.set_focus(Focus::CardRecurrence);
+                        app.state.mouse_focus = Some(Focus::CardRecurrence);
+                        app.state
+                            .app_list_states
+                            .card_view_comment_list
+                            .select(None);
+                        app.state.app_list_states.card_view_tag_list.select(None);
+                    }
                     _ => {
                         app.state.set_focus(Focus::NoFocus);
                         app.state.mouse_focus = None;
@@ -731,7 +727,53 @@ impl Renderable for ViewCard {
             )
             .alignment(Alignment::Left);
 
-        let card_comments_widget = Paragraph::new(card_comment_lines.clone())
+        let card_checklist_title = if let Some((done, total)) = card.checklist_progress() {
+            format!("Checklist ({}/{})", done, total)
+        } else {
+            "Checklist (0)".to_string()
+        };
+        let card_checklist_widget = Paragraph::new(card_checklist_lines)
+            .block(
+                Block::default()
+                    .title(card_checklist_title)
+                    .border_type(BorderType::Rounded)
+                    .borders(Borders::ALL)
+                    .border_style(card_checklist_style),
+            )
+            .alignment(Alignment::Left);
+
+        let card_open_blockers = app.boards.get_open_blockers(&card).len();
+        let card_blocked_by_title = if card_open_blockers > 0 {
+            format!(
+                "Blocked By ({}, {} open)",
+                card.blocked_by.len(),
+                card_open_blockers
+            )
+        } else {
+            format!("Blocked By ({})", card.blocked_by.len())
+        };
+        let card_blocked_by_widget = Paragraph::new(card_blocked_by_lines)
+            .block(
+                Block::default()
+                    .title(card_blocked_by_title)
+                    .border_type(BorderType::Rounded)
+                    .borders(Borders::ALL)
+                    .border_style(card_blocked_by_style),
+            )
+            .alignment(Alignment::Left);
+
+        // Keep the body of the selected comment in view
+        let card_comments_scroll = app
+            .state
+            .app_list_states
+            .card_view_comment_list
+            .selected()
+            .filter(|_| app.state.focus == Focus::CardComments)
+            .map_or(0, |selected_index| {
+                ((selected_index * 2) as u16 + 2)
+                    .saturating_sub(card_chunks[6].height.saturating_sub(2))
+            });
+        let card_comments_widget = Paragraph::new(card_comment_lines)
             .block(
                 Block::default()
                     .title(format!("Comments ({})", card.comments.len()))
@@ -739,7 +781,8 @@ impl Renderable for ViewCard {
                     .borders(Borders::ALL)
                     .border_style(card_comments_style),
             )
-            .alignment(Alignment::Left);
+            .alignment(Alignment::Left)
+            .scroll((card_comments_scroll, 0));
 
         if is_active
             && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[3])
@@ -754,6 +797,30 @@ impl Renderable for ViewCard {
 
         if is_active
             && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[4])
+        {
+            app.state.set_focus(Focus::CardChecklist);
+            app.state.mouse_focus = Some(Focus::CardChecklist);
+            app.state
+                .app_list_states
+                .card_view_comment_list
+                .select(None);
+            app.state.app_list_states.card_view_tag_list.select(None);
+        }
+
+        if is_active
+            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[5])
+        {
+            app.state.set_focus(Focus::CardBlockedBy);
+            app.state.mouse_focus = Some(Focus::CardBlockedBy);
+            app.state
+                .app_list_states
+                .card_view_comment_list
+                .select(None);
+            app.state.app_list_states.card_view_tag_list.select(None);
+        }
+
+        if is_active
+            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[6])
         {
             app.state.set_focus(Focus::CardComments);
             app.state.mouse_focus = Some(Focus::CardComments);
@@ -842,55 +909,38 @@ impl Renderable for ViewCard {
                     }
                 }
                 Focus::CardComments => {
-                    if app
+                    if let Some(selected_index) =
+                        app.state.app_list_states.card_view_comment_list.selected()
+                    {
+                        if let Some(text_box) =
+                            app.state.text_buffers.card_comments.get(selected_index)
+                        {
+                            // Border plus the "│ " before the body
+                            let comment_offset = 3;
+                            let x_pos =
+                                card_chunks[6].left() + comment_offset + text_box.cursor().1 as u16;
+                            let y_pos = (card_chunks[6].top() + (selected_index * 2) as u16 + 2)
+                                .saturating_sub(card_comments_scroll);
+                            rect.set_cursor_position((x_pos, y_pos));
+                        }
+                    }
+                }
+                Focus::CardChecklist => {
+                    if let Some(selected_index) = app
                         .state
                         .app_list_states
-                        .card_view_comment_list
+                        .card_view_checklist_list
                         .selected()
-                        .is_some()
-                        && !app.state.text_buffers.card_comments.is_empty()
                     {
-                        let selected_index = app
-                            .state
-                            .app_list_states
-                            .card_view_comment_list
-                            .selected()
-                            .unwrap();
-                        let mut counter = 0;
-                        let mut y_index = 0;
-                        let mut length_before_selected_comment = 0;
-                        let mut prv_spans_length = 0;
-                        let comment_offset = 3;
-                        for line in card_comment_lines.iter() {
-                            for _ in line.spans.iter() {
-                                if counter == selected_index {
-                                    break;
-                                } else {
-                                    let element = line.spans.get(counter - prv_spans_length);
-                                    if let Some(element) = element {
-                                        length_before_selected_comment += element.content.len();
-                                    }
-                                    counter += 1;
-                                }
-                            }
-                            if counter == selected_index {
-                                break;
-                            }
-                            y_index += 1;
-                            prv_spans_length += line.spans.iter().len();
-                            length_before_selected_comment = 0;
-                        }
-                        let digits_in_counter = (counter + 1).to_string().len();
                         if let Some(text_box) =
-                            app.state.text_buffers.card_comments.get(selected_index)
+                            app.state.text_buffers.card_checklist.get(selected_index)
                         {
-                            let text_box_cursor = text_box.cursor();
+                            // Border plus the "[ ] " check mark
+                            let checklist_offset = 5;
                             let x_pos = card_chunks[4].left()
-                                + length_before_selected_comment as u16
-                                + text_box_cursor.1 as u16
-                                + comment_offset
-                                + digits_in_counter as u16;
-                            let y_pos = card_chunks[4].top() + y_index as u16 + 1;
+                                + checklist_offset
+                                + text_box.cursor().1 as u16;
+                            let y_pos = card_chunks[4].top() + selected_index as u16 + 1;
                             rect.set_cursor_position((x_pos, y_pos));
                         }
                     }
@@ -908,12 +958,14 @@ impl Renderable for ViewCard {
         );
         rect.render_widget(card_extra_info_widget, card_chunks[2]);
         rect.render_widget(card_tags_widget, card_chunks[3]);
-        rect.render_widget(card_comments_widget, card_chunks[4]);
+        rect.render_widget(card_checklist_widget, card_chunks[4]);
+        rect.render_widget(card_blocked_by_widget, card_chunks[5]);
+        rect.render_widget(card_comments_widget, card_chunks[6]);
 
         // Render Submit button if card is being edited
         if app.state.card_being_edited.is_some() {
             if is_active
-                && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[5])
+                && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[7])
             {
                 app.state.set_focus(Focus::SubmitButton);
                 app.state.mouse_focus = Some(Focus::SubmitButton);
@@ -932,7 +984,7 @@ impl Renderable for ViewCard {
                         .border_style(save_changes_style),
                 )
                 .alignment(Alignment::Center);
-            rect.render_widget(save_changes_button, card_chunks[5]);
+            rect.render_widget(save_changes_button, card_chunks[7]);
         }
 
         if app.config.enable_mouse_support {
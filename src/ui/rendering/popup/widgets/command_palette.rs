@@ -435,10 +435,15 @@ impl Renderable for CommandPalette {
             .highlight_style(command_search_highlight_style)
             .highlight_symbol(LIST_SELECTED_SYMBOL);
 
+        let card_search_title = if app.widgets.command_palette.pending_blocker_link.is_some() {
+            "Cards (select the blocking card)"
+        } else {
+            "Cards"
+        };
         let card_search_results_list = List::new(card_search_results.clone())
             .block(
                 Block::default()
-                    .title("Cards")
+                    .title(card_search_title)
                     .border_style(card_search_border_style)
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded),
@@ -0,0 +1,133 @@
+use crate::{
+    app::{
+        state::{Focus, KeyBindingEnum},
+        App,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::WorkspacePicker,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for WorkspacePicker {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let active_workspace = app.config.active_workspace().to_string();
+        let workspace_items: Vec<ListItem> = std::iter::once(ListItem::new(Line::from(
+            Span::styled("New Workspace", general_style),
+        )))
+        .chain(app.config.workspace_names().into_iter().map(|workspace| {
+            let summary = app
+                .config
+                .workspaces
+                .get(&workspace)
+                .map(|settings| settings.summary())
+                .unwrap_or_default();
+            let name = if workspace == active_workspace {
+                format!("{} (current)", workspace)
+            } else {
+                workspace
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(name, general_style),
+                Span::styled(format!("  {}", summary), help_text_style),
+            ]))
+        }))
+        .collect();
+        let list_height = workspace_items.len() as u16 + 2;
+        let popup_area = centered_rect_with_length(60, list_height + 5, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(list_height), Constraint::Fill(1)])
+            .margin(1)
+            .split(popup_area);
+
+        if is_active && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0])
+        {
+            app.state.mouse_focus = Some(Focus::SelectWorkspacePopup);
+            app.state.set_focus(Focus::SelectWorkspacePopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &workspace_items,
+                chunks[0],
+                &mut app.state.app_list_states.workspace_selector,
+            );
+        }
+        let workspaces = List::new(workspace_items)
+            .block(
+                Block::default()
+                    .title("Workspaces")
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(
+                " to switch, the boards of the current workspace are saved first",
+                help_text_style,
+            ),
+        ]))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+        let border_block = Block::default()
+            .title("Switch Workspace")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            workspaces,
+            chunks[0],
+            &mut app.state.app_list_states.workspace_selector,
+        );
+        rect.render_widget(help_text, chunks[1]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     app::{
+        save_diff::SaveDiffChange,
         state::{Focus, KeyBindingEnum},
         App,
     },
@@ -18,7 +19,7 @@ use crate::{
     },
 };
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
     Frame,
@@ -38,7 +39,7 @@ impl Renderable for LoadASave {
                 [
                     Constraint::Length(3),
                     Constraint::Fill(1),
-                    Constraint::Length(9),
+                    Constraint::Length(12),
                 ]
                 .as_ref(),
             )
@@ -152,23 +153,64 @@ impl Renderable for LoadASave {
         let cancel_key = app
             .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
             .unwrap_or("".to_string());
+        let compare_key = app
+            .get_first_keybinding(KeyBindingEnum::CompareSaves)
+            .unwrap_or("".to_string());
 
-        let help_text = Line::from(vec![
-            Span::styled("Use ", help_text_style),
-            Span::styled(&up_key, help_key_style),
-            Span::styled(" or ", help_text_style),
-            Span::styled(&down_key, help_key_style),
-            Span::styled(" to navigate. Press ", help_text_style),
-            Span::styled(accept_key, help_key_style),
-            Span::styled(" to Load the selected save file. Press ", help_text_style),
-            Span::styled(cancel_key, help_key_style),
-            Span::styled(" to cancel. Press ", help_text_style),
-            Span::styled(delete_key, help_key_style),
-            Span::styled(
-                " to delete a save file. If using a mouse click on a save file to preview",
-                help_text_style,
-            ),
-        ]);
+        let help_text = if app.state.save_diff_mode {
+            let left_key = app
+                .get_first_keybinding(KeyBindingEnum::Left)
+                .unwrap_or("".to_string());
+            let right_key = app
+                .get_first_keybinding(KeyBindingEnum::Right)
+                .unwrap_or("".to_string());
+            let next_focus_key = app
+                .get_first_keybinding(KeyBindingEnum::NextFocus)
+                .unwrap_or("".to_string());
+            Line::from(vec![
+                Span::styled("Press ", help_text_style),
+                Span::styled(left_key, help_key_style),
+                Span::styled(" or ", help_text_style),
+                Span::styled(right_key, help_key_style),
+                Span::styled(
+                    " to change what the selected save is compared with and ",
+                    help_text_style,
+                ),
+                Span::styled(next_focus_key, help_key_style),
+                Span::styled(
+                    " to switch between the saves and the changes. Press ",
+                    help_text_style,
+                ),
+                Span::styled(accept_key, help_key_style),
+                Span::styled(
+                    " on a card change to restore the card from the older save. Press ",
+                    help_text_style,
+                ),
+                Span::styled(compare_key, help_key_style),
+                Span::styled(" or ", help_text_style),
+                Span::styled(cancel_key, help_key_style),
+                Span::styled(" to stop comparing", help_text_style),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled("Use ", help_text_style),
+                Span::styled(&up_key, help_key_style),
+                Span::styled(" or ", help_text_style),
+                Span::styled(&down_key, help_key_style),
+                Span::styled(" to navigate. Press ", help_text_style),
+                Span::styled(accept_key, help_key_style),
+                Span::styled(" to Load the selected save file. Press ", help_text_style),
+                Span::styled(cancel_key, help_key_style),
+                Span::styled(" to cancel. Press ", help_text_style),
+                Span::styled(delete_key, help_key_style),
+                Span::styled(" to delete a save file. Press ", help_text_style),
+                Span::styled(compare_key, help_key_style),
+                Span::styled(
+                    " to compare saves. If using a mouse click on a save file to preview",
+                    help_text_style,
+                ),
+            ])
+        };
         let help_paragraph = Paragraph::new(help_text)
             .alignment(Alignment::Center)
             .block(
@@ -201,6 +243,8 @@ impl Renderable for LoadASave {
                 .style(general_style)
                 .wrap(ratatui::widgets::Wrap { trim: true });
             rect.render_widget(preview_paragraph, preview_chunks[1]);
+        } else if app.state.save_diff_mode {
+            render_save_diff(rect, app, preview_chunks[1], is_active);
         } else if app.preview_boards_and_cards.is_none() {
             let loading_text = if app.config.enable_mouse_support {
                 "Click on a save file to preview"
@@ -221,7 +265,25 @@ impl Renderable for LoadASave {
             render_body(rect, preview_chunks[1], app, true, is_active)
         }
 
-        let preview_title_paragraph = if let Some(file_name) = &app.state.preview_file_name {
+        let preview_title_paragraph = if app.state.save_diff_mode {
+            let title = if let Some(save_diff) = &app.state.save_diff {
+                format!(
+                    "Changes from {} to {}",
+                    save_diff.older_name, save_diff.newer_name
+                )
+            } else {
+                "Comparing saves...".to_string()
+            };
+            Paragraph::new(title)
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded),
+                )
+                .style(general_style)
+                .wrap(ratatui::widgets::Wrap { trim: true })
+        } else if let Some(file_name) = &app.state.preview_file_name {
             Paragraph::new("Previewing: ".to_string() + file_name)
                 .alignment(Alignment::Center)
                 .block(
@@ -251,3 +313,178 @@ impl Renderable for LoadASave {
         }
     }
 }
+
+fn render_save_diff(rect: &mut Frame, app: &mut App, area: Rect, is_active: bool) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+        .split(area);
+
+    let general_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.general_style,
+    );
+    let help_key_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.help_key_style,
+    );
+    let help_text_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.help_text_style,
+    );
+    let list_select_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.list_select_style,
+    );
+    let list_border_style = if app.state.focus == Focus::SaveDiff {
+        check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.keyboard_focus_style,
+        )
+    } else {
+        general_style
+    };
+
+    let Some(save_diff) = app.state.save_diff.clone() else {
+        let loading_paragraph = Paragraph::new("Loading changes...")
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .style(general_style);
+        rect.render_widget(loading_paragraph, area);
+        return;
+    };
+    if save_diff.entries.is_empty() {
+        let no_changes_paragraph = Paragraph::new(format!(
+            "No changes between {} and {}",
+            save_diff.older_name, save_diff.newer_name
+        ))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(general_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+        rect.render_widget(no_changes_paragraph, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = save_diff
+        .entries
+        .iter()
+        .map(|entry| {
+            let mut spans = vec![
+                Span::styled(format!("{}: ", entry.change), help_key_style),
+                Span::styled(entry.name.clone(), general_style),
+            ];
+            if !entry.board_name.is_empty() {
+                spans.push(Span::styled(
+                    format!(" ({})", entry.board_name),
+                    help_text_style,
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let changes_list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("Changes ({})", save_diff.entries.len()))
+                .borders(Borders::ALL)
+                .border_style(list_border_style)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL)
+        .style(general_style);
+
+    if is_active && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0]) {
+        app.state.mouse_focus = Some(Focus::SaveDiff);
+        app.state.set_focus(Focus::SaveDiff);
+        calculate_mouse_list_select_index(
+            app.state.current_mouse_coordinates.1,
+            &save_diff.entries,
+            chunks[0],
+            &mut app.state.app_list_states.save_diff,
+        );
+    }
+    rect.render_stateful_widget(
+        changes_list,
+        chunks[0],
+        &mut app.state.app_list_states.save_diff,
+    );
+
+    let selected_entry = app
+        .state
+        .app_list_states
+        .save_diff
+        .selected()
+        .and_then(|index| save_diff.entries.get(index));
+    let mut detail_lines = Vec::new();
+    if let Some(entry) = selected_entry {
+        match entry.change {
+            SaveDiffChange::BoardAdded | SaveDiffChange::CardAdded => {
+                detail_lines.push(Line::styled(
+                    format!("Not in {}", save_diff.older_name),
+                    general_style,
+                ));
+            }
+            SaveDiffChange::BoardRemoved | SaveDiffChange::CardRemoved => {
+                detail_lines.push(Line::styled(
+                    format!("Not in {}", save_diff.newer_name),
+                    general_style,
+                ));
+            }
+            SaveDiffChange::CardArchived => {
+                detail_lines.push(Line::styled(
+                    format!("Archived in {}", save_diff.newer_name),
+                    general_style,
+                ));
+            }
+            _ => {}
+        }
+        for field_change in &entry.field_changes {
+            detail_lines.push(Line::from(vec![
+                Span::styled(format!("{}: ", field_change.field), help_key_style),
+                Span::styled(
+                    format!("{} -> {}", field_change.old, field_change.new),
+                    general_style,
+                ),
+            ]));
+        }
+        if entry.older_card.is_some() {
+            let accept_key = app
+                .get_first_keybinding(KeyBindingEnum::Accept)
+                .unwrap_or("".to_string());
+            detail_lines.push(Line::default());
+            detail_lines.push(Line::from(vec![
+                Span::styled("Press ", help_text_style),
+                Span::styled(accept_key, help_key_style),
+                Span::styled(
+                    format!(" to restore the card as it is in {}", save_diff.older_name),
+                    help_text_style,
+                ),
+            ]));
+        }
+    }
+    let details_paragraph = Paragraph::new(detail_lines)
+        .block(
+            Block::default()
+                .title("Details")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(general_style)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    rect.render_widget(details_paragraph, chunks[1]);
+}
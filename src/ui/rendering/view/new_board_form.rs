@@ -31,6 +31,7 @@ impl Renderable for NewBoardForm {
                     Constraint::Length(3),
                     Constraint::Length(5),
                     Constraint::Fill(1),
+                    Constraint::Length(3),
                     Constraint::Length(4),
                     Constraint::Length(3),
                 ]
@@ -62,8 +63,15 @@ impl Renderable for NewBoardForm {
             is_active,
             false,
         );
+        let wip_limit_style = get_mouse_focusable_field_style(
+            app,
+            Focus::NewBoardWipLimit,
+            &chunks[3],
+            is_active,
+            false,
+        );
         let submit_style =
-            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[4], is_active, false);
+            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[5], is_active, false);
 
         let title_paragraph = Paragraph::new("Create a new Board")
             .alignment(Alignment::Center)
@@ -108,6 +116,17 @@ impl Renderable for NewBoardForm {
         }
         rect.render_widget(app.state.text_buffers.board_description.widget(), chunks[2]);
 
+        let board_wip_limit_block = Block::default()
+            .borders(Borders::ALL)
+            .style(wip_limit_style)
+            .border_type(BorderType::Rounded)
+            .title("WIP Limit (optional)");
+        app.state
+            .text_buffers
+            .board_wip_limit
+            .set_block(board_wip_limit_block);
+        rect.render_widget(app.state.text_buffers.board_wip_limit.widget(), chunks[3]);
+
         let input_mode_key = app
             .get_first_keybinding(KeyBindingEnum::TakeUserInput)
             .unwrap_or("".to_string());
@@ -153,7 +172,7 @@ impl Renderable for NewBoardForm {
                     .border_style(general_style),
             )
             .wrap(ratatui::widgets::Wrap { trim: true });
-        rect.render_widget(help_paragraph, chunks[3]);
+        rect.render_widget(help_paragraph, chunks[4]);
 
         let submit_button = Paragraph::new("Submit").alignment(Alignment::Center).block(
             Block::default()
@@ -161,7 +180,7 @@ impl Renderable for NewBoardForm {
                 .style(submit_style)
                 .border_type(BorderType::Rounded),
         );
-        rect.render_widget(submit_button, chunks[4]);
+        rect.render_widget(submit_button, chunks[5]);
 
         if app.state.app_status == AppStatus::UserInput {
             match app.state.focus {
@@ -181,6 +200,14 @@ impl Renderable for NewBoardForm {
                     );
                     rect.set_cursor_position((x_pos, y_pos));
                 }
+                Focus::NewBoardWipLimit => {
+                    let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                        &app.state.text_buffers.board_wip_limit,
+                        &app.config.show_line_numbers,
+                        &chunks[3],
+                    );
+                    rect.set_cursor_position((x_pos, y_pos));
+                }
                 _ => {}
             }
         }
@@ -28,6 +28,7 @@ impl Renderable for NewCardForm {
             .direction(Direction::Vertical)
             .constraints(
                 [
+                    Constraint::Length(3),
                     Constraint::Length(3),
                     Constraint::Length(5),
                     Constraint::Fill(1),
@@ -47,8 +48,8 @@ impl Renderable for NewCardForm {
         if app.state.z_stack.last() == Some(&PopUp::DateTimePicker) {
             if app.widgets.date_time_picker.anchor.is_none() {
                 app.widgets.date_time_picker.anchor = Some((
-                    chunks[3].x + card_due_date.len() as u16 + 2,
-                    chunks[3].y + 3,
+                    chunks[4].x + card_due_date.len() as u16 + 2,
+                    chunks[4].y + 3,
                 )); // offsets to make sure date is visible
             }
             app.widgets.date_time_picker.current_viewport = Some(rect.area());
@@ -59,17 +60,19 @@ impl Renderable for NewCardForm {
             app.current_theme.inactive_text_style,
             app.current_theme.general_style,
         );
+        let template_style =
+            get_mouse_focusable_field_style(app, Focus::CardTemplate, &chunks[1], is_active, false);
         let name_style =
-            get_mouse_focusable_field_style(app, Focus::CardName, &chunks[1], is_active, false);
+            get_mouse_focusable_field_style(app, Focus::CardName, &chunks[2], is_active, false);
         let description_style = get_mouse_focusable_field_style(
             app,
             Focus::CardDescription,
-            &chunks[2],
+            &chunks[3],
             is_active,
             false,
         );
         let due_date_style =
-            get_mouse_focusable_field_style(app, Focus::CardDueDate, &chunks[3], is_active, false);
+            get_mouse_focusable_field_style(app, Focus::CardDueDate, &chunks[4], is_active, false);
         let help_key_style = check_if_active_and_get_style(
             is_active,
             app.current_theme.inactive_text_style,
@@ -81,7 +84,7 @@ impl Renderable for NewCardForm {
             app.current_theme.help_text_style,
         );
         let submit_style =
-            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[5], is_active, false);
+            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[6], is_active, false);
 
         let title_paragraph = Paragraph::new("Create a new Card")
             .alignment(Alignment::Center)
@@ -93,13 +96,36 @@ impl Renderable for NewCardForm {
             );
         rect.render_widget(title_paragraph, chunks[0]);
 
+        let card_template_text = match &app.state.new_card_template {
+            Some(card_template) => Line::from(vec![
+                Span::styled(card_template.name.clone(), general_style),
+                Span::styled(format!("  {}", card_template.summary()), help_text_style),
+            ]),
+            None => Line::from(Span::styled(
+                format!(
+                    "None, press {} to start from a saved template",
+                    app.get_first_keybinding(KeyBindingEnum::Accept)
+                        .unwrap_or("".to_string())
+                ),
+                help_text_style,
+            )),
+        };
+        let card_template_paragraph = Paragraph::new(card_template_text).block(
+            Block::default()
+                .title("Template")
+                .borders(Borders::ALL)
+                .style(template_style)
+                .border_type(BorderType::Rounded),
+        );
+        rect.render_widget(card_template_paragraph, chunks[1]);
+
         let card_name_block = Block::default()
             .borders(Borders::ALL)
             .style(name_style)
             .border_type(BorderType::Rounded)
             .title("Card Name (required)");
         app.state.text_buffers.card_name.set_block(card_name_block);
-        rect.render_widget(app.state.text_buffers.card_name.widget(), chunks[1]);
+        rect.render_widget(app.state.text_buffers.card_name.widget(), chunks[2]);
         let description_length = app.state.text_buffers.card_description.get_num_lines();
         let description_block = Block::default()
             .title(format!("Description ({} line(s))", description_length))
@@ -119,7 +145,7 @@ impl Renderable for NewCardForm {
             .text_buffers
             .card_description
             .set_block(description_block.clone());
-        rect.render_widget(app.state.text_buffers.card_description.widget(), chunks[2]);
+        rect.render_widget(app.state.text_buffers.card_description.widget(), chunks[3]);
 
         let card_due_date = app
             .widgets
@@ -132,7 +158,7 @@ impl Renderable for NewCardForm {
                 .style(due_date_style)
                 .border_type(BorderType::Rounded),
         );
-        rect.render_widget(card_due_date_paragraph, chunks[3]);
+        rect.render_widget(card_due_date_paragraph, chunks[4]);
 
         let input_mode_key = app
             .get_first_keybinding(KeyBindingEnum::TakeUserInput)
@@ -180,7 +206,7 @@ impl Renderable for NewCardForm {
                     .border_style(general_style),
             )
             .wrap(ratatui::widgets::Wrap { trim: true });
-        rect.render_widget(help_paragraph, chunks[4]);
+        rect.render_widget(help_paragraph, chunks[5]);
 
         let submit_button = Paragraph::new("Submit").alignment(Alignment::Center).block(
             Block::default()
@@ -188,7 +214,7 @@ impl Renderable for NewCardForm {
                 .style(submit_style)
                 .border_type(BorderType::Rounded),
         );
-        rect.render_widget(submit_button, chunks[5]);
+        rect.render_widget(submit_button, chunks[6]);
 
         if app.state.app_status == AppStatus::UserInput {
             match app.state.focus {
@@ -196,7 +222,7 @@ impl Renderable for NewCardForm {
                     let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
                         &app.state.text_buffers.card_name,
                         &app.config.show_line_numbers,
-                        &chunks[1],
+                        &chunks[2],
                     );
                     rect.set_cursor_position((x_pos, y_pos));
                 }
@@ -204,7 +230,7 @@ impl Renderable for NewCardForm {
                     let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
                         &app.state.text_buffers.card_description,
                         &app.config.show_line_numbers,
-                        &chunks[2],
+                        &chunks[3],
                     );
                     rect.set_cursor_position((x_pos, y_pos));
                 }
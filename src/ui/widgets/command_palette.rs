@@ -1,14 +1,24 @@
 use crate::{
     app::{
-        app_helper::reset_preview_boards,
+        app_helper::{
+            open_card_template_picker, refresh_card_filter, reset_new_card_form,
+            reset_preview_boards,
+        },
         handle_exit,
+        kanban::Card,
         state::{AppState, AppStatus, Focus},
-        App, AppReturn,
+        workspace::WorkspaceSettings,
+        ActionHistory, App, AppReturn,
     },
     constants::RANDOM_SEARCH_TERM,
-    io::{io_handler::refresh_visible_boards_and_cards, IoEvent},
+    io::{
+        data_handler::{compact_local_saves, export_kanban_to_format, write_config},
+        exchange::ExchangeFormat,
+        io_handler::refresh_visible_boards_and_cards,
+        IoEvent,
+    },
     ui::{widgets::Widget, PopUp, View},
-    util::{send_error_toast, send_info_toast, send_warning_toast},
+    util::{format_file_size, send_error_toast, send_info_toast, send_warning_toast},
 };
 use log::{debug, error, info};
 use std::{
@@ -26,6 +36,8 @@ pub struct CommandPaletteWidget {
     pub command_search_results: Option<Vec<CommandPaletteActions>>,
     pub last_focus: Option<Focus>,
     pub last_search_string: String,
+    /// Card that the next card picked from the card search results is added to as a blocker
+    pub pending_blocker_link: Option<(u64, u64)>,
 }
 
 impl CommandPaletteWidget {
@@ -39,6 +51,7 @@ impl CommandPaletteWidget {
             command_search_results: None,
             last_focus: None,
             last_search_string: RANDOM_SEARCH_TERM.to_string(),
+            pending_blocker_link: None,
         }
     }
 
@@ -47,6 +60,7 @@ impl CommandPaletteWidget {
         self.card_search_results = None;
         self.command_search_results = None;
         self.last_search_string = RANDOM_SEARCH_TERM.to_string();
+        self.pending_blocker_link = None;
         app_state.text_buffers.command_palette.reset();
         Self::reset_list_states(app_state);
     }
@@ -137,9 +151,126 @@ impl CommandPaletteWidget {
                             );
                         }
                     }
+                    CommandPaletteActions::NewCardFromTemplate => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.close_popup();
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot create a new card in this view",
+                            );
+                        } else if app.state.current_board_id.is_none() {
+                            app.close_popup();
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "No board Selected / Available",
+                            );
+                        } else {
+                            app.close_popup();
+                            reset_new_card_form(app);
+                            app.set_view(View::NewCard);
+                            open_card_template_picker(app);
+                        }
+                    }
+                    CommandPaletteActions::SaveCurrentCardAsTemplate => {
+                        let card_found = app
+                            .state
+                            .current_card_id
+                            .and_then(|card_id| app.boards.find_card_with_id(card_id))
+                            .is_some();
+                        if !View::views_with_kanban_board().contains(&app.state.current_view)
+                            || !card_found
+                        {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Select a card to save as a template first",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        app.set_popup(PopUp::SaveCardTemplatePrompt);
+                        // the prompt takes user input, so skip resetting the app status below
+                        app.widgets.command_palette.reset(&mut app.state);
+                        app.widgets.command_palette.already_in_user_input_mode = false;
+                        app.widgets.command_palette.last_focus = None;
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::SaveBoardsAsTemplate => {
+                        if app.boards.is_empty() {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "There are no boards to save as a template",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        app.set_popup(PopUp::SaveBoardTemplatePrompt);
+                        // the prompt takes user input, so skip resetting the app status below
+                        app.widgets.command_palette.reset(&mut app.state);
+                        app.widgets.command_palette.already_in_user_input_mode = false;
+                        app.widgets.command_palette.last_focus = None;
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::StartFromBoardTemplate => {
+                        app.close_popup();
+                        app.set_popup(PopUp::BoardTemplatePicker);
+                    }
+                    CommandPaletteActions::SwitchWorkspace => {
+                        app.close_popup();
+                        app.set_popup(PopUp::WorkspacePicker);
+                    }
+                    CommandPaletteActions::NewWorkspace => {
+                        app.close_popup();
+                        app.set_popup(PopUp::CreateWorkspacePrompt);
+                        // the prompt takes user input, so skip resetting the app status below
+                        app.widgets.command_palette.reset(&mut app.state);
+                        app.widgets.command_palette.already_in_user_input_mode = false;
+                        app.widgets.command_palette.last_focus = None;
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::UseCurrentViewAndThemeForWorkspace => {
+                        app.close_popup();
+                        let current_view = app.state.current_view;
+                        if !View::views_with_kanban_board().contains(&current_view) {
+                            send_warning_toast(
+                                &mut app.widgets.toast_widget,
+                                "Open one of the board views first",
+                            );
+                        } else {
+                            let settings = WorkspaceSettings {
+                                default_view: Some(current_view),
+                                default_theme: Some(app.current_theme.name.clone()),
+                            };
+                            update_workspace_settings(app, settings);
+                        }
+                    }
+                    CommandPaletteActions::ResetWorkspaceViewAndTheme => {
+                        app.close_popup();
+                        update_workspace_settings(app, WorkspaceSettings::default());
+                    }
+                    CommandPaletteActions::CompactSaves => {
+                        app.close_popup();
+                        match compact_local_saves(&app.config) {
+                            Ok(report) => {
+                                let info_msg = format!(
+                                    "Removed {} save files, {} freed",
+                                    report.files_removed,
+                                    format_file_size(report.bytes_removed)
+                                );
+                                info!("{}", info_msg);
+                                send_info_toast(&mut app.widgets.toast_widget, &info_msg);
+                            }
+                            Err(e) => {
+                                error!("Error compacting saves: {}", e);
+                                send_error_toast(
+                                    &mut app.widgets.toast_widget,
+                                    &format!("Error compacting saves: {}", e),
+                                );
+                            }
+                        }
+                    }
                     CommandPaletteActions::ResetUI => {
                         app.close_popup();
-                        app.set_view(app.config.default_view);
+                        app.set_view(app.config.workspace_default_view());
                         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
                     }
                     CommandPaletteActions::ChangeView => {
@@ -156,7 +287,7 @@ impl CommandPaletteWidget {
                         }
                         if let Some(current_board_id) = app.state.current_board_id {
                             if let Some(current_board) =
-                                app.boards.get_mut_board_with_id(current_board_id)
+                                app.boards.get_board_with_id(current_board_id)
                             {
                                 if let Some(current_card_id) = app.state.current_card_id {
                                     if current_board
@@ -191,7 +322,7 @@ impl CommandPaletteWidget {
                         }
                         if let Some(current_board_id) = app.state.current_board_id {
                             if let Some(current_board) =
-                                app.boards.get_mut_board_with_id(current_board_id)
+                                app.boards.get_board_with_id(current_board_id)
                             {
                                 if let Some(current_card_id) = app.state.current_card_id {
                                     if current_board
@@ -216,6 +347,30 @@ impl CommandPaletteWidget {
                             "Could not find current card",
                         );
                     }
+                    CommandPaletteActions::ChangeCurrentCardRecurrence => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot change card recurrence in this view",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        let card_found = app
+                            .state
+                            .current_card_id
+                            .and_then(|card_id| app.boards.find_card_with_id(card_id))
+                            .is_some();
+                        if card_found {
+                            app.close_popup();
+                            app.state.app_status = AppStatus::Initialized;
+                            app.set_popup(PopUp::CardRecurrenceSelector);
+                            return AppReturn::Continue;
+                        }
+                        send_error_toast(
+                            &mut app.widgets.toast_widget,
+                            "Could not find current card",
+                        );
+                    }
                     CommandPaletteActions::LoadASaveLocal => {
                         app.close_popup();
                         reset_preview_boards(app);
@@ -246,6 +401,40 @@ impl CommandPaletteWidget {
                             app.state.all_available_tags = Some(tags);
                         }
                     }
+                    CommandPaletteActions::ChangeCurrentBoardSortMode
+                    | CommandPaletteActions::SortCurrentBoardOnce => {
+                        if app.state.current_board_id.is_none() {
+                            send_warning_toast(&mut app.widgets.toast_widget, "No board selected");
+                            return AppReturn::Continue;
+                        }
+                        let popup = if *command == CommandPaletteActions::SortCurrentBoardOnce {
+                            PopUp::SortBoardOnce
+                        } else {
+                            PopUp::ChangeBoardSortMode
+                        };
+                        app.close_popup();
+                        app.set_popup(popup);
+                    }
+                    CommandPaletteActions::ViewActionHistory => {
+                        app.close_popup();
+                        app.set_popup(PopUp::ActionHistory);
+                    }
+                    CommandPaletteActions::ViewArchivedCards => {
+                        app.close_popup();
+                        app.set_popup(PopUp::ArchivedCards);
+                    }
+                    CommandPaletteActions::EditCurrentBoard => {
+                        if app.state.current_board_id.is_none() {
+                            send_warning_toast(&mut app.widgets.toast_widget, "No board selected");
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        app.set_popup(PopUp::EditBoard);
+                    }
+                    CommandPaletteActions::FilterCards => {
+                        app.close_popup();
+                        app.set_popup(PopUp::FilterPrompt);
+                    }
                     CommandPaletteActions::ClearFilter => {
                         if app.filtered_boards.is_empty() {
                             send_warning_toast(
@@ -256,6 +445,13 @@ impl CommandPaletteWidget {
                         } else {
                             send_info_toast(&mut app.widgets.toast_widget, "All Filters Cleared");
                         }
+                        if let Some(card_filter) = app.state.card_filter.take() {
+                            app.action_history_manager
+                                .new_action(ActionHistory::ChangeFilter(
+                                    Some(card_filter.query().to_string()),
+                                    None,
+                                ));
+                        }
                         app.state.filter_tags = None;
                         app.state.all_available_tags = None;
                         app.state.app_list_states.filter_by_tag_list.select(None);
@@ -267,6 +463,94 @@ impl CommandPaletteWidget {
                         app.close_popup();
                         app.set_popup(PopUp::ChangeDateFormatPopup);
                     }
+                    CommandPaletteActions::ExportToMarkdown
+                    | CommandPaletteActions::ExportToCsv => {
+                        let format = if *command == CommandPaletteActions::ExportToMarkdown {
+                            ExchangeFormat::Markdown
+                        } else {
+                            ExchangeFormat::Csv
+                        };
+                        app.close_popup();
+                        match export_kanban_to_format(app.boards.get_boards(), &app.config, format)
+                        {
+                            Ok(file_path) => {
+                                info!("Exported boards as {} to {}", format, file_path);
+                                send_info_toast(
+                                    &mut app.widgets.toast_widget,
+                                    &format!("Exported boards to {}", file_path),
+                                );
+                            }
+                            Err(e) => {
+                                error!("Error exporting boards as {}: {}", format, e);
+                                send_error_toast(
+                                    &mut app.widgets.toast_widget,
+                                    &format!("Error exporting boards as {}", format),
+                                );
+                            }
+                        }
+                    }
+                    CommandPaletteActions::ImportBoards => {
+                        app.close_popup();
+                        app.set_popup(PopUp::ImportBoardsPrompt);
+                        // the prompt takes user input, so skip resetting the app status below
+                        app.widgets.command_palette.reset(&mut app.state);
+                        app.widgets.command_palette.already_in_user_input_mode = false;
+                        app.widgets.command_palette.last_focus = None;
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::AddBlockerToCurrentCard => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot add a blocker in this view",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        let current_card = app
+                            .state
+                            .current_card_id
+                            .and_then(|card_id| app.boards.find_card_with_id(card_id))
+                            .cloned();
+                        let Some(current_card) = current_card else {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Could not find current card",
+                            );
+                            return AppReturn::Continue;
+                        };
+                        start_blocker_search(app, &current_card);
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::ToggleCurrentBoardInProgress => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot change a board in this view",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        let Some(current_board_id) = app.state.current_board_id else {
+                            send_error_toast(&mut app.widgets.toast_widget, "No board selected");
+                            return AppReturn::Continue;
+                        };
+                        app.close_popup();
+                        if let Some(board) = app.boards.get_mut_board_with_id(current_board_id) {
+                            board.in_progress = !board.in_progress;
+                            let info_msg = if board.in_progress {
+                                format!("Marked board '{}' as in progress", board.name)
+                            } else {
+                                format!("Board '{}' is no longer in progress", board.name)
+                            };
+                            let in_progress = board.in_progress;
+                            if let Some(filtered_board) =
+                                app.filtered_boards.get_mut_board_with_id(current_board_id)
+                            {
+                                filtered_board.in_progress = in_progress;
+                            }
+                            info!("{}", info_msg);
+                            send_info_toast(&mut app.widgets.toast_widget, &info_msg);
+                        }
+                    }
                     CommandPaletteActions::NoCommandsFound => {
                         app.close_popup();
                         app.state.app_status = AppStatus::Initialized;
@@ -340,7 +624,14 @@ impl CommandPaletteWidget {
                                 .boards
                                 .swap(current_board_index, current_board_index - 1);
 
-                            if swap_result.is_err() {
+                            if swap_result.is_ok() {
+                                app.action_history_manager
+                                    .new_action(ActionHistory::MoveBoard(
+                                        current_board_id,
+                                        current_board_index,
+                                        current_board_index - 1,
+                                    ));
+                            } else {
                                 send_error_toast(
                                     &mut app.widgets.toast_widget,
                                     format!("Could not move '{}' to the left", board_name).as_str(),
@@ -352,6 +643,7 @@ impl CommandPaletteWidget {
                                 &mut app.widgets.toast_widget,
                                 format!("'{}' moved to the left", board_name).as_str(),
                             );
+                            refresh_card_filter(app);
                             refresh_visible_boards_and_cards(app);
                         } else {
                             send_error_toast(&mut app.widgets.toast_widget, "No board selected");
@@ -385,7 +677,14 @@ impl CommandPaletteWidget {
                                 .boards
                                 .swap(current_board_index, current_board_index + 1);
 
-                            if swap_result.is_err() {
+                            if swap_result.is_ok() {
+                                app.action_history_manager
+                                    .new_action(ActionHistory::MoveBoard(
+                                        current_board_id,
+                                        current_board_index,
+                                        current_board_index + 1,
+                                    ));
+                            } else {
                                 send_error_toast(
                                     &mut app.widgets.toast_widget,
                                     format!("Could not move '{}' to the right", board_name)
@@ -398,6 +697,7 @@ impl CommandPaletteWidget {
                                 &mut app.widgets.toast_widget,
                                 format!("'{}' moved to the right", board_name).as_str(),
                             );
+                            refresh_card_filter(app);
                             refresh_visible_boards_and_cards(app);
                         } else {
                             send_error_toast(&mut app.widgets.toast_widget, "No board selected");
@@ -424,6 +724,55 @@ impl CommandPaletteWidget {
     }
 }
 
+/// Keeps the command palette open with the card search focused, the card picked next is added
+/// as a blocker of `card`
+/// Replaces the view and theme overrides of the current workspace and saves the config
+fn update_workspace_settings(app: &mut App, settings: WorkspaceSettings) {
+    let mut new_config = app.config.clone();
+    let workspace = new_config.active_workspace().to_string();
+    match new_config.workspace_settings_mut(&workspace) {
+        Ok(workspace_settings) => *workspace_settings = settings,
+        Err(e) => {
+            send_warning_toast(&mut app.widgets.toast_widget, &e);
+            return;
+        }
+    }
+    if let Err(e) = write_config(&new_config) {
+        error!("Error saving workspace settings: {}", e);
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Error saving workspace settings: {}", e),
+        );
+        return;
+    }
+    app.config = new_config;
+    let summary = app.config.workspaces[&workspace].summary();
+    let info_msg = if summary.is_empty() {
+        format!(
+            "Workspace {} uses the view and theme of the config",
+            workspace
+        )
+    } else {
+        format!("Workspace {} opens with {}", workspace, summary)
+    };
+    info!("{}", info_msg);
+    send_info_toast(&mut app.widgets.toast_widget, &info_msg);
+}
+
+pub fn start_blocker_search(app: &mut App, card: &Card) {
+    if app.state.z_stack.last() != Some(&PopUp::CommandPalette) {
+        app.set_popup(PopUp::CommandPalette);
+    }
+    app.widgets.command_palette.reset(&mut app.state);
+    app.widgets.command_palette.pending_blocker_link = Some(card.id);
+    app.state.app_status = AppStatus::UserInput;
+    app.state.set_focus(Focus::CommandPaletteCard);
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Search for the card that blocks '{}'", card.name),
+    );
+}
+
 impl Widget for CommandPaletteWidget {
     fn update(app: &mut App) {
         if let Some(PopUp::CommandPalette) = app.state.z_stack.last() {
@@ -512,7 +861,7 @@ impl Widget for CommandPaletteWidget {
                             {
                                 format!("{} - Matched in Tags", card.name)
                             } else if card.comments.iter().any(|comment| {
-                                comment.to_lowercase().contains(&current_search_string)
+                                comment.body.to_lowercase().contains(&current_search_string)
                             }) {
                                 format!("{} - Matched in Comments", card.name)
                             } else {
@@ -573,17 +922,26 @@ impl Widget for CommandPaletteWidget {
 
 #[derive(Clone, Debug, PartialEq, EnumIter, EnumString)]
 pub enum CommandPaletteActions {
+    AddBlockerToCurrentCard,
     ChangeCurrentCardStatus,
     ChangeCurrentCardPriority,
+    ChangeCurrentCardRecurrence,
+    ChangeCurrentBoardSortMode,
     ChangeDateFormat,
     ChangeTheme,
     ChangeView,
     ClearFilter,
+    CompactSaves,
     ConfigMenu,
     CreateATheme,
     DebugMenu,
+    EditCurrentBoard,
+    ExportToCsv,
+    ExportToMarkdown,
     FilterByTag,
+    FilterCards,
     HelpMenu,
+    ImportBoards,
     LoadASaveCloud,
     LoadASaveLocal,
     Login,
@@ -591,47 +949,82 @@ pub enum CommandPaletteActions {
     MainMenu,
     NewBoard,
     NewCard,
+    NewCardFromTemplate,
+    NewWorkspace,
     NoCommandsFound,
     Quit,
     ResetPassword,
     ResetUI,
+    ResetWorkspaceViewAndTheme,
+    SaveBoardsAsTemplate,
+    SaveCurrentCardAsTemplate,
     SaveKanbanState,
     SignUp,
+    SortCurrentBoardOnce,
+    StartFromBoardTemplate,
+    SwitchWorkspace,
     SyncLocalData,
     MoveBoardLeft,
     MoveBoardRight,
+    ToggleCurrentBoardInProgress,
+    UseCurrentViewAndThemeForWorkspace,
+    ViewActionHistory,
+    ViewArchivedCards,
 }
 
 impl Display for CommandPaletteActions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::AddBlockerToCurrentCard => write!(f, "Add Blocker to Current Card"),
             Self::ChangeCurrentCardStatus => write!(f, "Change Current Card Status"),
             Self::ChangeCurrentCardPriority => write!(f, "Change Current Card Priority"),
+            Self::ChangeCurrentCardRecurrence => write!(f, "Change Current Card Recurrence"),
+            Self::ChangeCurrentBoardSortMode => write!(f, "Change Current Board Sort Mode"),
             Self::ChangeDateFormat => write!(f, "Change Date Format"),
+            Self::EditCurrentBoard => write!(f, "Edit Current Board"),
             Self::ChangeTheme => write!(f, "Change Theme"),
             Self::ChangeView => write!(f, "Change View"),
             Self::ClearFilter => write!(f, "Clear Filter"),
+            Self::CompactSaves => write!(f, "Compact Saves"),
             Self::CreateATheme => write!(f, "Create a Theme"),
             Self::DebugMenu => write!(f, "Toggle Debug Panel"),
+            Self::ExportToCsv => write!(f, "Export Boards to CSV"),
+            Self::ExportToMarkdown => write!(f, "Export Boards to Markdown"),
             Self::FilterByTag => write!(f, "Filter by Tag"),
+            Self::FilterCards => write!(f, "Filter Cards (Query / Saved Filters)"),
             Self::LoadASaveCloud => write!(f, "Load a Save (Cloud)"),
             Self::LoadASaveLocal => write!(f, "Load a Save (Local)"),
             Self::Login => write!(f, "Login"),
             Self::Logout => write!(f, "Logout"),
             Self::NewBoard => write!(f, "New Board"),
             Self::NewCard => write!(f, "New Card"),
+            Self::NewCardFromTemplate => write!(f, "New Card From Template"),
+            Self::NewWorkspace => write!(f, "New Workspace"),
             Self::NoCommandsFound => write!(f, "No Commands Found"),
             Self::ConfigMenu => write!(f, "Configure"),
             Self::HelpMenu => write!(f, "Open Help Menu"),
+            Self::ImportBoards => write!(f, "Import Boards (Markdown / CSV / Trello / GitHub)"),
             Self::MainMenu => write!(f, "Open Main Menu"),
             Self::Quit => write!(f, "Quit"),
             Self::ResetPassword => write!(f, "Reset Password"),
             Self::ResetUI => write!(f, "Reset UI"),
+            Self::ResetWorkspaceViewAndTheme => write!(f, "Reset Workspace View and Theme"),
+            Self::SaveBoardsAsTemplate => write!(f, "Save Boards as Template"),
+            Self::SaveCurrentCardAsTemplate => write!(f, "Save Current Card as Template"),
             Self::SaveKanbanState => write!(f, "Save Kanban State"),
             Self::SignUp => write!(f, "Sign Up"),
+            Self::SortCurrentBoardOnce => write!(f, "Sort Current Board Once"),
+            Self::StartFromBoardTemplate => write!(f, "Start From a Board Template"),
+            Self::SwitchWorkspace => write!(f, "Switch Workspace"),
             Self::SyncLocalData => write!(f, "Sync Local Data"),
             Self::MoveBoardLeft => write!(f, "Move Current Board Left"),
             Self::MoveBoardRight => write!(f, "Move Current Board Right"),
+            Self::ToggleCurrentBoardInProgress => write!(f, "Toggle Current Board In Progress"),
+            Self::UseCurrentViewAndThemeForWorkspace => {
+                write!(f, "Use Current View and Theme for Workspace")
+            }
+            Self::ViewActionHistory => write!(f, "View Action History (Undo / Redo)"),
+            Self::ViewArchivedCards => write!(f, "View Archived Cards"),
         }
     }
 }
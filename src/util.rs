@@ -6,12 +6,9 @@ use crate::{
     constants::{DEFAULT_TOAST_DURATION, ENCRYPTION_KEY_FILE_NAME, FIELD_NOT_SET},
     inputs::{events::Events, key::Key, InputEvent},
     io::{
-        data_handler::reset_config,
-        io_handler::{
-            delete_a_save_from_database, generate_new_encryption_key,
-            get_all_save_ids_and_creation_dates_for_user, get_config_dir, login_for_user,
-            save_user_encryption_key,
-        },
+        data_handler::{get_config, reset_config},
+        io_handler::{generate_new_encryption_key, get_config_dir, save_user_encryption_key},
+        sync::{SyncBackend, SyncBackendClient},
         IoEvent,
     },
     ui::{
@@ -19,7 +16,7 @@ use crate::{
         widgets::toast::{Toast, ToastType, ToastWidget},
     },
 };
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use crossterm::{event::EnableMouseCapture, execute};
 use eyre::Result;
 use ratatui::{backend::CrosstermBackend, layout::Rect, Terminal};
@@ -72,6 +69,9 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
                 if app.state.previous_mouse_coordinates != app.state.current_mouse_coordinates {
                     app.state.previous_mouse_coordinates = app.state.current_mouse_coordinates;
                 }
+                if app.auto_save_due() {
+                    app.dispatch(IoEvent::AutoSave).await;
+                }
                 AppReturn::Continue
             }
         };
@@ -258,6 +258,72 @@ pub fn date_format_converter(
     }
 }
 
+/// Parses a date string written in any of the supported `DateTimeFormat`s as local time.
+/// `date_time_format` (with or without the time) is tried first, a date like 03/04/2024 fits
+/// both day first and month first formats and means whatever the user's format says
+pub fn parse_date_time_string(
+    date_string: &str,
+    date_time_format: DateTimeFormat,
+) -> Result<DateTime<Utc>, String> {
+    let date_string = date_string.trim();
+    let naive_date_time = [
+        DateTimeFormat::add_time_to_date_format(date_time_format),
+        DateTimeFormat::remove_time_from_date_format(date_time_format),
+    ]
+    .into_iter()
+    .chain(date_format_finder(date_string))
+    .find_map(|date_format| parse_naive_date_time(date_string, date_format))
+    .ok_or_else(|| "Invalid date format".to_string())?;
+    naive_local_to_utc(naive_date_time).ok_or_else(|| "Invalid date format".to_string())
+}
+
+fn parse_naive_date_time(date_string: &str, date_format: DateTimeFormat) -> Option<NaiveDateTime> {
+    if DateTimeFormat::all_formats_with_time().contains(&date_format) {
+        NaiveDateTime::parse_from_str(date_string, date_format.to_parser_string()).ok()
+    } else {
+        NaiveDate::parse_from_str(date_string, date_format.to_parser_string())
+            .ok()
+            .map(|date| date.and_time(NaiveTime::MIN))
+    }
+}
+
+pub fn naive_local_to_utc(naive_date_time: NaiveDateTime) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&naive_date_time)
+        .earliest()
+        .map(|date_time| date_time.with_timezone(&Utc))
+}
+
+pub fn utc_to_naive_local(date_time: DateTime<Utc>) -> NaiveDateTime {
+    date_time.with_timezone(&Local).naive_local()
+}
+
+/// Formats an optional card date for display, `FIELD_NOT_SET` is used when the date is missing
+pub fn format_date_time(date_time: Option<DateTime<Utc>>, date_format: DateTimeFormat) -> String {
+    match date_time {
+        Some(date_time) => date_time
+            .with_timezone(&Local)
+            .format(date_format.to_parser_string())
+            .to_string(),
+        None => FIELD_NOT_SET.to_string(),
+    }
+}
+
+/// Days left until the given date, formats without time compare calendar days only
+pub fn days_until(date_time: DateTime<Utc>, date_format: DateTimeFormat) -> i64 {
+    let local_date_time = utc_to_naive_local(date_time);
+    if DateTimeFormat::all_formats_with_time().contains(&date_format) {
+        local_date_time
+            .signed_duration_since(Local::now().naive_local())
+            .num_days()
+    } else {
+        local_date_time
+            .date()
+            .signed_duration_since(Local::now().date_naive())
+            .num_days()
+    }
+}
+
 /// only to be used as a cli argument function
 pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()> {
     let mut previous_key_lost = false;
@@ -283,9 +349,14 @@ pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()>
         );
         previous_key_lost = true;
     }
-    print_info("Trying to login...");
+    let config = get_config(true).unwrap_or_default();
+    let sync_backend = SyncBackendClient::from_config(&config, true);
+    print_info(&format!(
+        "Trying to login using the {} sync backend...",
+        config.sync_backend
+    ));
     let (access_token, user_id, _refresh_token) =
-        match login_for_user(&email_id, &password, false).await {
+        match sync_backend.login(&email_id, &password).await {
             Ok((access_token, user_id, refresh_token)) => (access_token, user_id, refresh_token),
             Err(err) => {
                 print_debug(&format!("Error logging in: {:?}", err));
@@ -294,9 +365,22 @@ pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()>
                 return Ok(());
             }
         };
-    let save_ids =
-        get_all_save_ids_and_creation_dates_for_user(user_id.to_owned(), &access_token, true)
-            .await?;
+    // Saves of every workspace are encrypted with the same key
+    let mut save_ids = Vec::new();
+    for workspace in config.workspace_names() {
+        match sync_backend
+            .get_saves(&user_id, &access_token, &workspace)
+            .await
+        {
+            Ok(saves) => save_ids.extend(saves.into_iter().map(|save| (workspace.clone(), save))),
+            Err(err) => {
+                print_error("Error getting cloud saves");
+                print_debug(&format!("Error: {}", err));
+                print_error("Aborting...");
+                return Ok(());
+            }
+        }
+    }
     if save_ids.is_empty() {
         print_warn("No Cloud save files found");
         print_info("Generating new encryption key...");
@@ -324,12 +408,13 @@ pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()>
         }
         print_info("Cloud save files found:");
         print_info("-------------------------");
-        for (i, save) in save_ids.iter().enumerate() {
+        for (i, (workspace, save)) in save_ids.iter().enumerate() {
             print_info(&format!(
-                "{}) Cloud_save_{} - Created at (UTC) {}",
+                "{}) Cloud_save_{} ({}) - Created at (UTC) {}",
                 i + 1,
-                save.0,
-                save.1
+                save.save_id,
+                workspace,
+                save.created_at
             ));
         }
         print_info("-------------------------");
@@ -348,10 +433,14 @@ pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()>
         println!();
         let input = input.trim().to_lowercase();
         if input == "y" || input == "yes" {
-            for save_id in save_ids {
-                print_info(&format!("Deleting save file: {}", save_id.0));
-                let delete_status =
-                    delete_a_save_from_database(&access_token, true, save_id.2 as u64, None).await;
+            for (workspace, save_id) in save_ids {
+                print_info(&format!(
+                    "Deleting save file: {} ({})",
+                    save_id.save_id, workspace
+                ));
+                let delete_status = sync_backend
+                    .delete_save(&user_id, &access_token, &workspace, save_id.id)
+                    .await;
                 if delete_status.is_err() {
                     print_error("Error deleting save file");
                     print_debug(&format!("Error: {:?}", delete_status.err()));
@@ -413,6 +502,21 @@ pub fn spaces(size: u8) -> &'static str {
     &SPACES[..size as usize]
 }
 
+/// `512 B`, `1.5 KB`, `2.0 MB` or `1.2 GB`
+pub fn format_file_size(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit_index = 0;
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit_index])
+}
+
 pub fn num_digits(i: usize) -> u8 {
     f64::log10(i as f64) as u8 + 1
 }